@@ -1,6 +1,7 @@
 use fft_core::{dft, ift};
 use num_complex::Complex;
 pub mod error;
+pub mod ntt;
 pub mod prelude {
     pub use crate::error::{ConvolutionError, Result as ConvolutionResult};
 }
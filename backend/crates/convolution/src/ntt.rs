@@ -0,0 +1,142 @@
+//! Number-theoretic transform over the prime p = 2^64 - 2^32 + 1.
+//!
+//! p - 1 is divisible by 2^32, so power-of-two transforms up to length 2^32 exist and
+//! integer convolutions are exact as long as every output coefficient is below p.
+
+use crate::error::ConvolutionError;
+use crate::prelude::ConvolutionResult;
+
+/// The NTT modulus 2^64 - 2^32 + 1
+pub const NTT_MODULUS: u64 = 0xFFFF_FFFF_0000_0001;
+/// A generator of the multiplicative group mod `NTT_MODULUS`
+const GENERATOR: u64 = 7;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % NTT_MODULUS as u128) as u64
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    let (s, overflow) = a.overflowing_add(b);
+    if overflow || s >= NTT_MODULUS {
+        s.wrapping_sub(NTT_MODULUS)
+    } else {
+        s
+    }
+}
+
+fn sub_mod(a: u64, b: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        a.wrapping_sub(b).wrapping_add(NTT_MODULUS)
+    }
+}
+
+fn pow_mod(mut b: u64, mut e: u64) -> u64 {
+    let mut acc = 1;
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = mul_mod(acc, b);
+        }
+        b = mul_mod(b, b);
+        e >>= 1;
+    }
+    acc
+}
+
+/// In-place iterative radix-2 NTT. `a.len()` must be a power of two not exceeding 2^32
+/// and every entry must be reduced mod `NTT_MODULUS`. `invert` applies the inverse transform
+/// including the 1/n scaling.
+pub fn ntt(a: &mut [u64], invert: bool) {
+    let n = a.len();
+    assert!(
+        n.is_power_of_two() && n as u64 <= 1 << 32,
+        "NTT length must be a power of two up to 2^32"
+    );
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(GENERATOR, (NTT_MODULUS - 1) / len as u64);
+        if invert {
+            w_len = pow_mod(w_len, NTT_MODULUS - 2);
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut w = 1;
+            for (u, v) in lo.iter_mut().zip(hi.iter_mut()) {
+                let t = mul_mod(*v, w);
+                *v = sub_mod(*u, t);
+                *u = add_mod(*u, t);
+                w = mul_mod(w, w_len);
+            }
+        }
+        len <<= 1;
+    }
+    if invert {
+        let n_inv = pow_mod(n as u64, NTT_MODULUS - 2);
+        for x in a.iter_mut() {
+            *x = mul_mod(*x, n_inv);
+        }
+    }
+}
+
+/// Linear convolution mod `NTT_MODULUS` (length x.len()+h.len()-1). Inputs are reduced first.
+pub fn convolve_ntt_u64(x: &[u64], h: &[u64]) -> ConvolutionResult<Vec<u64>> {
+    if x.is_empty() || h.is_empty() {
+        return Err(ConvolutionError::InvalidArgument {
+            text: "convolution inputs must be non-empty".to_string(),
+        });
+    }
+    let out_len = x.len() + h.len() - 1;
+    let n = out_len.next_power_of_two();
+    if n as u64 > 1 << 32 {
+        return Err(ConvolutionError::InvalidArgument {
+            text: format!("NTT length {n} exceeds 2^32"),
+        });
+    }
+    let mut a: Vec<u64> = x.iter().map(|&v| v % NTT_MODULUS).collect();
+    let mut b: Vec<u64> = h.iter().map(|&v| v % NTT_MODULUS).collect();
+    a.resize(n, 0);
+    b.resize(n, 0);
+    ntt(&mut a, false);
+    ntt(&mut b, false);
+    for (u, v) in a.iter_mut().zip(&b) {
+        *u = mul_mod(*u, *v);
+    }
+    ntt(&mut a, true);
+    a.truncate(out_len);
+    Ok(a)
+}
+
+/// Linear convolution mod m for residues in 0..m. The exact integer result is computed with
+/// a single NTT, so min(x.len(), h.len()) * (m - 1)^2 must stay below `NTT_MODULUS`.
+pub fn convolve_mod_u64(x: &[u64], h: &[u64], m: u64) -> ConvolutionResult<Vec<u64>> {
+    if m < 2 {
+        return Err(ConvolutionError::InvalidArgument {
+            text: format!("modulus must be at least 2, got {m}"),
+        });
+    }
+    let terms = x.len().min(h.len()) as u128;
+    if terms * (m as u128 - 1) * (m as u128 - 1) >= NTT_MODULUS as u128 {
+        return Err(ConvolutionError::InvalidArgument {
+            text: format!("modulus {m} is too large for an exact NTT of {terms} terms"),
+        });
+    }
+    let x: Vec<u64> = x.iter().map(|&v| v % m).collect();
+    let h: Vec<u64> = h.iter().map(|&v| v % m).collect();
+    Ok(convolve_ntt_u64(&x, &h)?
+        .into_iter()
+        .map(|v| v % m)
+        .collect())
+}
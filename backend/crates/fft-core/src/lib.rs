@@ -8,12 +8,11 @@ pub mod prelude {
 /// Public API: compute DFT using an automatically chosen algorithm (FFT when possible).
 pub fn dft(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
     let n = x.len();
-    let y = if is_power_of_two(n) {
+    if is_power_of_two(n) {
         fft_radix2_iterative(x)
     } else {
         mixed_radix_fft(x)
-    };
-    y
+    }
 }
 
 /// Checked variant of DFT (kept for compatibility with earlier staging).
@@ -128,12 +127,12 @@ fn mixed_radix_fft(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
 }
 
 fn smallest_prime_factor(n: usize) -> Option<usize> {
-    if n % 2 == 0 {
+    if n.is_multiple_of(2) {
         return Some(2);
     }
     let mut d = 3usize;
     while d * d <= n {
-        if n % d == 0 {
+        if n.is_multiple_of(d) {
             return Some(d);
         }
         d += 2;
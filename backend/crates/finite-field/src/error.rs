@@ -6,6 +6,8 @@ pub enum FieldError {
     DivisionByZero,
     DegreeOverflow { max: usize, found: usize },
     InvalidArgument { text: String },
+    // 体の大きさ q や乗法群の位数 q^n − 1 が添字・計算に使う整数型に収まらない
+    OrderOverflow { text: String },
     NotImplemented,
 }
 
@@ -18,6 +20,7 @@ impl fmt::Display for FieldError {
                 write!(f, "Degree overflow: max {max}, found {found}")
             }
             FieldError::InvalidArgument { text } => write!(f, "Invalid argument: {text}"),
+            FieldError::OrderOverflow { text } => write!(f, "Order overflow: {text}"),
             FieldError::NotImplemented => write!(f, "Feature not yet implemented"),
         }
     }
@@ -0,0 +1,486 @@
+//! 有限体 GF(q) 上の多項式の因数分解と既約性・原始性の判定
+//!
+//! 分解は 3 段階で行う: 無平方分解 → 次数別分解（x^{q^d} − x との gcd）→ 同次数分解
+//! （Cantor–Zassenhaus の乱択分割、または Berlekamp の部分代数による分割）。
+//! `GFp<P>` と `GFExt<GFp<P>>` の両方で使える。
+
+use linalg::Field;
+use poly::polynomial::factor::Factorization;
+use poly::Polynomial;
+
+use crate::error::{FieldError, Result};
+use crate::traits::FiniteField;
+
+/// 同じ次数の既約因子を分ける方法
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FactorMethod {
+    /// 乱択: gcd(a^{(q^d − 1)/2} − 1, f)（標数 2 ではトレース写像）で分割する
+    #[default]
+    CantorZassenhaus,
+    /// 決定的: v^q ≡ v (mod f) を満たす v の空間を求め、gcd(v − s, f) (s ∈ GF(q)) で分割する。
+    /// 体の元を総当たりするので q が小さいとき向け
+    Berlekamp,
+}
+
+/// f = lc · Π g_i^{e_i} と既約因子（モニック）へ分解する（Cantor–Zassenhaus 法）
+pub fn factor<F: FiniteField>(f: &Polynomial<F>) -> Result<Factorization<F>> {
+    factor_with(f, FactorMethod::default())
+}
+
+pub fn factor_with<F: FiniteField>(
+    f: &Polynomial<F>,
+    method: FactorMethod,
+) -> Result<Factorization<F>> {
+    if f.is_zero() {
+        return Err(FieldError::InvalidArgument {
+            text: "cannot factor the zero polynomial".to_string(),
+        });
+    }
+    let (w, q) = witness(f)?;
+    let sqf = square_free_factorization(f)?;
+    let mut factors = Vec::new();
+    for (part, e) in sqf.factors {
+        let irreducibles = match method {
+            FactorMethod::CantorZassenhaus => ddf(&part, &w, q)
+                .into_iter()
+                .flat_map(|(g, d)| edf(&g, d, &w, q))
+                .collect::<Vec<_>>(),
+            FactorMethod::Berlekamp => berlekamp_with(&part, &w, q),
+        };
+        factors.extend(irreducibles.into_iter().map(|g| (normalize(&w, &g), e)));
+    }
+    sort_factors(&mut factors);
+    Ok(Factorization {
+        unit: w.element(sqf.unit.index()),
+        factors,
+    })
+}
+
+/// 無平方分解 f = lc · Π a_i^i（a_i はモニックで互いに素、既約とは限らない）。
+/// f' = 0 の部分は係数の p 乗根 a^{q/p} を取って f = g(x)^p の g へ再帰する。
+pub fn square_free_factorization<F: FiniteField>(f: &Polynomial<F>) -> Result<Factorization<F>> {
+    if f.deg() <= 0 {
+        return Ok(Factorization {
+            unit: f.get(0),
+            factors: Vec::new(),
+        });
+    }
+    let (w, q) = witness(f)?;
+    let unit = f.coeffs.last().cloned().unwrap();
+    let mut factors = square_free_monic(&f.monic(), &w, q);
+    for (g, _) in factors.iter_mut() {
+        *g = normalize(&w, g);
+    }
+    sort_factors(&mut factors);
+    Ok(Factorization {
+        unit: w.element(unit.index()),
+        factors,
+    })
+}
+
+fn square_free_monic<F: FiniteField>(
+    f: &Polynomial<F>,
+    w: &F,
+    q: u64,
+) -> Vec<(Polynomial<F>, usize)> {
+    let p = w.characteristic() as usize;
+    let mut out = Vec::new();
+    let mut c = Polynomial::gcd(f, &derivative(f, w, q));
+    let mut rest = f.div_rem(&c).0;
+    let mut i = 1;
+    while rest.deg() > 0 {
+        let y = Polynomial::gcd(&rest, &c);
+        let fac = rest.div_rem(&y).0;
+        if fac.deg() > 0 {
+            out.push((fac.monic(), i));
+        }
+        rest = y;
+        c = c.div_rem(&rest).0;
+        i += 1;
+    }
+    if c.deg() > 0 {
+        let root = pth_root(&c, w, q);
+        out.extend(
+            square_free_monic(&root.monic(), w, q)
+                .into_iter()
+                .map(|(g, e)| (g, e * p)),
+        );
+    }
+    out
+}
+
+/// 無平方なモニック多項式を、次数 d の既約因子の積 g_d に分ける: [(g_d, d)]
+pub fn distinct_degree_factorization<F: FiniteField>(
+    f: &Polynomial<F>,
+) -> Result<Vec<(Polynomial<F>, usize)>> {
+    let (w, q) = witness(f)?;
+    Ok(ddf(f, &w, q)
+        .into_iter()
+        .map(|(g, d)| (normalize(&w, &g), d))
+        .collect())
+}
+
+fn ddf<F: FiniteField>(f: &Polynomial<F>, w: &F, q: u64) -> Vec<(Polynomial<F>, usize)> {
+    let x = x_poly(w);
+    let mut rest = f.monic();
+    let mut h = x.clone();
+    let mut out = Vec::new();
+    let mut d = 1;
+    while rest.deg() >= 2 * d as isize {
+        h = pow_mod(&h, q as u128, &rest);
+        let g = Polynomial::gcd(&rest, &(&h - &x));
+        if g.deg() > 0 {
+            rest = rest.div_rem(&g).0;
+            h = h.div_rem(&rest).1;
+            out.push((g, d));
+        }
+        d += 1;
+    }
+    if rest.deg() > 0 {
+        let d = rest.deg() as usize;
+        out.push((rest, d));
+    }
+    out
+}
+
+/// 次数 d の既約因子の積 f を Cantor–Zassenhaus 法で分ける
+pub fn equal_degree_factorization<F: FiniteField>(
+    f: &Polynomial<F>,
+    d: usize,
+) -> Result<Vec<Polynomial<F>>> {
+    let (w, q) = witness(f)?;
+    Ok(edf(f, d, &w, q).iter().map(|g| normalize(&w, g)).collect())
+}
+
+fn edf<F: FiniteField>(f: &Polynomial<F>, d: usize, w: &F, q: u64) -> Vec<Polynomial<F>> {
+    let mut rng = SplitMix64(0x2545_F491_4F6C_DD1D ^ (f.deg() as u64));
+    let mut out = Vec::new();
+    split_equal_degree(&f.monic(), d, w, q, &mut rng, &mut out);
+    out
+}
+
+fn split_equal_degree<F: FiniteField>(
+    f: &Polynomial<F>,
+    d: usize,
+    w: &F,
+    q: u64,
+    rng: &mut SplitMix64,
+    out: &mut Vec<Polynomial<F>>,
+) {
+    let n = f.deg() as usize;
+    if n <= d {
+        out.push(f.clone());
+        return;
+    }
+    let one = Polynomial::new(vec![w.element(1)]);
+    loop {
+        let a = Polynomial::new((0..n).map(|_| w.element(rng.next() % q)).collect());
+        if a.deg() < 1 {
+            continue;
+        }
+        let b = if q % 2 == 1 {
+            // (q^d − 1)/2 = (q − 1)/2 · (1 + q + ... + q^{d−1})
+            let mut norm = a.clone();
+            let mut frob = a.clone();
+            for _ in 1..d {
+                frob = pow_mod(&frob, q as u128, f);
+                norm = (&norm * &frob).div_rem(f).1;
+            }
+            &pow_mod(&norm, ((q - 1) / 2) as u128, f) - &one
+        } else {
+            // 標数 2: トレース Σ_{j < kd} a^{2^j}（q = 2^k）は GF(2) に値を取り、半分の因子で 0 になる
+            let k = q.trailing_zeros() as usize;
+            let mut term = a.clone();
+            let mut trace = a;
+            for _ in 1..k * d {
+                term = (&term * &term).div_rem(f).1;
+                trace = &trace + &term;
+            }
+            trace
+        };
+        let g = Polynomial::gcd(&b, f);
+        if g.deg() > 0 && (g.deg() as usize) < n {
+            let h = f.div_rem(&g).0.monic();
+            split_equal_degree(&g, d, w, q, rng, out);
+            split_equal_degree(&h, d, w, q, rng, out);
+            return;
+        }
+    }
+}
+
+/// 無平方なモニック多項式を Berlekamp 法で既約因子へ分ける
+pub fn berlekamp<F: FiniteField>(f: &Polynomial<F>) -> Result<Vec<Polynomial<F>>> {
+    let (w, q) = witness(f)?;
+    Ok(berlekamp_with(f, &w, q)
+        .iter()
+        .map(|g| normalize(&w, g))
+        .collect())
+}
+
+fn berlekamp_with<F: FiniteField>(f: &Polynomial<F>, w: &F, q: u64) -> Vec<Polynomial<F>> {
+    let f = f.monic();
+    let n = f.deg();
+    if n <= 1 {
+        return if n == 1 { vec![f] } else { Vec::new() };
+    }
+    let n = n as usize;
+    // Q の第 i 行は x^{qi} mod f。v^q = Σ v_i x^{qi} なので v^q ≡ v ⇔ v (Q − I) = 0
+    let xq = pow_mod(&x_poly(w), q as u128, &f);
+    let mut rows = Vec::with_capacity(n);
+    let mut cur = Polynomial::new(vec![w.element(1)]);
+    for _ in 0..n {
+        rows.push(cur.clone());
+        cur = (&cur * &xq).div_rem(&f).1;
+    }
+    let mut system: Vec<Vec<F>> = (0..n)
+        .map(|j| {
+            (0..n)
+                .map(|i| {
+                    let v = rows[i].get(j);
+                    if i == j {
+                        v - w.element(1)
+                    } else {
+                        v
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let basis = null_space(&mut system);
+    let r = basis.len();
+
+    let mut factors = vec![f];
+    for v in basis.iter().map(|v| Polynomial::new(v.clone())) {
+        if factors.len() == r {
+            break;
+        }
+        if v.deg() < 1 {
+            continue;
+        }
+        let mut next = Vec::new();
+        for u in factors {
+            let mut rest = u;
+            for s in 0..q {
+                if rest.deg() <= 1 {
+                    break;
+                }
+                let g = Polynomial::gcd(&(&v - &Polynomial::new(vec![w.element(s)])), &rest);
+                if g.deg() > 0 && g.deg() < rest.deg() {
+                    rest = rest.div_rem(&g).0.monic();
+                    next.push(g);
+                }
+            }
+            next.push(rest);
+        }
+        factors = next;
+    }
+    factors
+}
+
+/// Rabin の判定法: x^{q^n} ≡ x (mod f) かつ n の各素因数 r で gcd(x^{q^{n/r}} − x, f) = 1
+pub fn is_irreducible<F: FiniteField>(f: &Polynomial<F>) -> Result<bool> {
+    let n = f.deg();
+    if n <= 0 {
+        return Ok(false);
+    }
+    let (w, q) = witness(f)?;
+    let f = f.monic();
+    let q = q as u128;
+    let x = x_poly(&w);
+    let frobenius = |k: usize| (0..k).fold(x.clone(), |h, _| pow_mod(&h, q, &f));
+    let n = n as usize;
+    if !(&frobenius(n) - &x).div_rem(&f).1.is_zero() {
+        return Ok(false);
+    }
+    Ok(prime_factors(n as u128)
+        .into_iter()
+        .all(|r| Polynomial::gcd(&(&frobenius(n / r as usize) - &x), &f).deg() == 0))
+}
+
+/// 原始多項式か: 既約で、x の位数が q^n − 1（x が GF(q^n)^× を生成する）。
+/// q^n − 1 の素因数分解は試し割りなので、q^n が大きいと遅い。
+pub fn is_primitive<F: FiniteField>(f: &Polynomial<F>) -> Result<bool> {
+    if !is_irreducible(f)? {
+        return Ok(false);
+    }
+    let (w, q) = witness(f)?;
+    let f = f.monic();
+    let n = f.deg() as u32;
+    // q^n − 1 = (q^{n−1} − 1) q + (q − 1) と組み立て、q^n = 2^128 でもあふれないようにする
+    let q = q as u128;
+    let order = q
+        .checked_pow(n - 1)
+        .and_then(|m| (m - 1).checked_mul(q))
+        .and_then(|m| m.checked_add(q - 1))
+        .ok_or_else(|| FieldError::OrderOverflow {
+            text: format!("q^n - 1 with q = {q}, n = {n} does not fit in u128"),
+        })?;
+    if f.get(0).is_zero() {
+        // f = x は x を 0 にするので原始的でない
+        return Ok(false);
+    }
+    let x = x_poly(&w);
+    Ok(prime_factors(order).into_iter().all(|r| {
+        let h = pow_mod(&x, order / r, &f);
+        !(h.deg() == 0 && h.get(0).index() == 1)
+    }))
+}
+
+// -------- 補助関数 --------
+
+/// 係数のうち最も大きな体に属する元（`GFExt` の法多項式を持つ元）とその体の大きさ q。
+/// 演算の途中で 0/1 が法多項式を失うことがあるので、入口で一度だけ取って内部へ渡す。
+/// 元を u64 の添字で扱うので、q が u64 に収まらない体は `OrderOverflow`
+fn witness<F: FiniteField>(f: &Polynomial<F>) -> Result<(F, u64)> {
+    // None（u64 に収まらない）を最大として比べる
+    let w = f
+        .coeffs
+        .iter()
+        .max_by_key(|c| c.field_size().map_or(u128::MAX, u128::from))
+        .cloned()
+        .unwrap();
+    let q = w.field_size().ok_or_else(|| FieldError::OrderOverflow {
+        text: "field size does not fit in u64".to_string(),
+    })?;
+    Ok((w, q))
+}
+
+/// 係数を witness と同じ体の元として作り直す
+fn normalize<F: FiniteField>(w: &F, f: &Polynomial<F>) -> Polynomial<F> {
+    Polynomial::new(f.coeffs.iter().map(|c| w.element(c.index())).collect())
+}
+
+fn sort_factors<F: FiniteField>(factors: &mut [(Polynomial<F>, usize)]) {
+    let key = |g: &Polynomial<F>| g.coeffs.iter().map(|c| c.index()).collect::<Vec<u64>>();
+    factors.sort_by(|(a, _), (b, _)| a.deg().cmp(&b.deg()).then_with(|| key(a).cmp(&key(b))));
+}
+
+fn x_poly<F: FiniteField>(w: &F) -> Polynomial<F> {
+    Polynomial::new(vec![w.element(0), w.element(1)])
+}
+
+/// 形式微分。係数 k·a は a を k mod p 回足す
+fn derivative<F: FiniteField>(f: &Polynomial<F>, w: &F, q: u64) -> Polynomial<F> {
+    let p = w.characteristic();
+    Polynomial::new(
+        f.coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(k, c)| c.clone() * w.element((k as u64 % p) % q))
+            .collect(),
+    )
+}
+
+/// f(x) = g(x)^p となる g（f' = 0 を仮定）。係数の p 乗根は a^{q/p}
+fn pth_root<F: FiniteField>(f: &Polynomial<F>, w: &F, q: u64) -> Polynomial<F> {
+    let p = w.characteristic() as usize;
+    let e = q / p as u64;
+    Polynomial::new(
+        f.coeffs
+            .iter()
+            .step_by(p)
+            .map(|c| field_pow(c.clone(), e))
+            .collect(),
+    )
+}
+
+fn field_pow<F: Field>(mut b: F, mut e: u64) -> F {
+    let mut acc = F::one();
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = acc * b.clone();
+        }
+        b = b.clone() * b;
+        e >>= 1;
+    }
+    acc
+}
+
+/// base^e mod m（二進法）
+fn pow_mod<F: Field>(base: &Polynomial<F>, mut e: u128, m: &Polynomial<F>) -> Polynomial<F> {
+    let mut acc = Polynomial::one().div_rem(m).1;
+    let mut b = base.div_rem(m).1;
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = (&acc * &b).div_rem(m).1;
+        }
+        e >>= 1;
+        if e > 0 {
+            b = (&b * &b).div_rem(m).1;
+        }
+    }
+    acc
+}
+
+/// 試し割りによる素因数（重複なし、昇順）
+fn prime_factors(mut n: u128) -> Vec<u128> {
+    let mut out = Vec::new();
+    let mut d = 2;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            out.push(d);
+            while n.is_multiple_of(d) {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        out.push(n);
+    }
+    out
+}
+
+/// 連立一次方程式 A v = 0 の解空間の基底（A は破壊される）
+fn null_space<F: FiniteField>(a: &mut [Vec<F>]) -> Vec<Vec<F>> {
+    let n = a.first().map_or(0, |r| r.len());
+    let mut pivot_cols = Vec::new();
+    let mut rank = 0;
+    for col in 0..n {
+        let Some(p) = (rank..a.len()).find(|&r| !a[r][col].is_zero()) else {
+            continue;
+        };
+        a.swap(rank, p);
+        let inv = F::one() / a[rank][col].clone();
+        for v in a[rank].iter_mut() {
+            *v = v.clone() * inv.clone();
+        }
+        let pivot = a[rank].clone();
+        for (r, row) in a.iter_mut().enumerate() {
+            if r != rank && !row[col].is_zero() {
+                let factor = row[col].clone();
+                for (v, pv) in row.iter_mut().zip(&pivot) {
+                    *v = v.clone() - factor.clone() * pv.clone();
+                }
+            }
+        }
+        pivot_cols.push(col);
+        rank += 1;
+    }
+    // 自由変数ごとに 1 本の基底ベクトル
+    (0..n)
+        .filter(|c| !pivot_cols.contains(c))
+        .map(|free| {
+            let mut v = vec![F::zero(); n];
+            v[free] = F::one();
+            for (r, &pc) in pivot_cols.iter().enumerate() {
+                v[pc] = -a[r][free].clone();
+            }
+            v
+        })
+        .collect()
+}
+
+/// 乱択用の SplitMix64
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::gfp::GFp; // for convenience helpers; generic impl works for any Field
+use crate::traits::FiniteField;
 use linalg::Field;
 use num_traits::{One, Zero};
 use poly::Polynomial;
@@ -429,6 +430,44 @@ impl<F: Field + Clone + PartialEq + Zero + One> One for GFExt<F> {
     }
 }
 
+// 拡大次数 k = deg px の GF(q^k)。元の添字は係数を q 進数の桁とみなしたもの。
+// px を持たない元（`Zero::zero()` など）は基礎体の元として扱う。
+impl<F: FiniteField> FiniteField for GFExt<F> {
+    fn characteristic(&self) -> u64 {
+        F::zero().characteristic()
+    }
+    fn field_size(&self) -> Option<u64> {
+        let k = self.px.len().saturating_sub(1).max(1) as u32;
+        F::zero().field_size()?.checked_pow(k)
+    }
+    fn element(&self, index: u64) -> Self {
+        let base = F::zero();
+        let q = base_size::<F>();
+        let k = self.px.len().saturating_sub(1).max(1);
+        let mut rest = index as u128;
+        let coeffs = (0..k)
+            .map(|_| {
+                let digit = base.element((rest % q) as u64);
+                rest /= q;
+                digit
+            })
+            .collect();
+        GFExt::new(self.px.clone(), coeffs)
+    }
+    fn index(&self) -> u64 {
+        let q = base_size::<F>();
+        // field_size が None の体では添字が u64 に収まらないので切り捨てる
+        self.coeffs.iter().rev().fold(0u128, |acc, c| {
+            acc.wrapping_mul(q).wrapping_add(c.index() as u128)
+        }) as u64
+    }
+}
+
+/// 基礎体の大きさ。u64 に収まらなければ 2^64（どの u64 の添字よりも大きい）とみなす
+fn base_size<F: FiniteField>() -> u128 {
+    F::zero().field_size().map_or(1 << 64, u128::from)
+}
+
 // linalg トレイト
 
 // 補助: GF(2^m) 用（u8 との相互変換, AES 多項式など）
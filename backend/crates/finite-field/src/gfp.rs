@@ -134,3 +134,30 @@ impl<const P: u16> linalg::matrix::DisplayElement for GFp<P> {
         self.to_string()
     }
 }
+
+impl<const P: u16> crate::traits::FiniteField for GFp<P> {
+    fn characteristic(&self) -> u64 {
+        P as u64
+    }
+    fn field_size(&self) -> Option<u64> {
+        Some(P as u64)
+    }
+    fn element(&self, index: u64) -> Self {
+        GFp::<P>::new(index as i64)
+    }
+    fn index(&self) -> u64 {
+        self.0 as u64
+    }
+}
+
+// 多項式の高速乗算: 係数を 0..P の整数として NTT で正確に畳み込み、最後に mod P を取る
+impl<const P: u16> poly::polynomial::fast::FastConvolution for GFp<P> {
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> {
+        let a: Vec<u64> = a.iter().map(|c| c.0 as u64).collect();
+        let b: Vec<u64> = b.iter().map(|c| c.0 as u64).collect();
+        match convolution::ntt::convolve_mod_u64(&a, &b, P as u64) {
+            Ok(y) => y.into_iter().map(|v| GFp::<P>(v as u16)).collect(),
+            Err(e) => panic!("convolution failed: {e}"),
+        }
+    }
+}
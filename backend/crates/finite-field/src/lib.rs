@@ -1,7 +1,10 @@
+pub mod factor;
 pub mod gf256;
 pub mod gfext;
 pub mod gfp;
+pub mod traits;
 pub mod prelude {
     pub use crate::error::{FieldError, Result as FieldResult};
+    pub use crate::traits::FiniteField;
 }
 pub mod error;
@@ -0,0 +1,17 @@
+use linalg::Field;
+
+/// 有限体 GF(q)（q = p^k）の元
+///
+/// `GFExt` の `Zero::zero()` / `One::one()` は法多項式を持たないため、体の大きさは値から問い合わせる。
+/// 多項式の因数分解では係数のうち最も大きな体を返す元を基準にする。
+pub trait FiniteField: Field + PartialEq {
+    /// 標数 p
+    fn characteristic(&self) -> u64;
+    /// 元の個数 q。u64 に収まらなければ `None`
+    fn field_size(&self) -> Option<u64>;
+    /// 添字 0..q から同じ体の元を作る（0 は零元、1 は単位元）。
+    /// `field_size` が `None` の体では u64 で表せる範囲の元にしか届かない
+    fn element(&self, index: u64) -> Self;
+    /// `element` の逆写像
+    fn index(&self) -> u64;
+}
@@ -0,0 +1,160 @@
+use finite_field::error::FieldError;
+use finite_field::factor::{self, FactorMethod};
+use finite_field::gf256::gf256_modulus;
+use finite_field::gfext::GFExt;
+use finite_field::gfp::GFp;
+use finite_field::prelude::FiniteField;
+use poly::Polynomial;
+use std::sync::Arc;
+
+fn poly<const P: u16>(coeffs: &[i64]) -> Polynomial<GFp<P>> {
+    Polynomial::new(coeffs.iter().map(|&c| GFp::<P>::new(c)).collect())
+}
+
+fn values<const P: u16>(f: &Polynomial<GFp<P>>) -> Vec<u16> {
+    f.coeffs.iter().map(|c| c.value()).collect()
+}
+
+#[test]
+fn gfp_factorization_and_berlekamp_agree() {
+    // x^4 + 1 = (x^2 + 2)(x^2 + 3) (mod 5)。2, 3 は平方非剰余なのでどちらも既約
+    let f = poly::<5>(&[1, 0, 0, 0, 1]);
+    let cz = factor::factor(&f).unwrap();
+    let bk = factor::factor_with(&f, FactorMethod::Berlekamp).unwrap();
+    assert_eq!(cz, bk);
+    assert_eq!(cz.count(), 2);
+    assert!(cz.factors.iter().all(|(g, e)| g.deg() == 2 && *e == 1));
+    assert_eq!(cz.expand(), f);
+
+    // 3 (x − 1)^2 (x + 2)(x^3 + x + 1) mod 7（x^3 + x + 1 は mod 7 で根を持たない）
+    let f = &(&(&poly::<7>(&[-1, 1]) * &poly::<7>(&[-1, 1])) * &poly::<7>(&[2, 1]))
+        * &poly::<7>(&[1, 1, 0, 1]);
+    let f = &f * GFp::<7>::new(3);
+    for method in [FactorMethod::CantorZassenhaus, FactorMethod::Berlekamp] {
+        let fact = factor::factor_with(&f, method).unwrap();
+        assert_eq!(fact.unit.value(), 3);
+        let got: Vec<(Vec<u16>, usize)> =
+            fact.factors.iter().map(|(g, e)| (values(g), *e)).collect();
+        assert_eq!(
+            got,
+            vec![(vec![2, 1], 1), (vec![6, 1], 2), (vec![1, 1, 0, 1], 1)]
+        );
+        assert_eq!(fact.expand(), f);
+    }
+
+    // x^q − x は GF(q) の全元を根に持つ
+    let mut coeffs = vec![0; 12];
+    coeffs[1] = -1;
+    coeffs[11] = 1;
+    let fact = factor::factor(&poly::<11>(&coeffs)).unwrap();
+    assert_eq!(fact.factors.len(), 11);
+    assert!(factor::factor(&poly::<3>(&[0])).is_err());
+}
+
+#[test]
+fn square_free_part_handles_pth_powers() {
+    // x^6 + 2x^3 + 1 = (x^3 + 1)^2 = (x + 1)^6 (mod 3)。f' = 0 の部分を p 乗根で戻す
+    let f = poly::<3>(&[1, 0, 0, 2, 0, 0, 1]);
+    let sqf = factor::square_free_factorization(&f).unwrap();
+    assert_eq!(sqf.factors, vec![(poly::<3>(&[1, 1]), 6)]);
+
+    // x^2 (x + 1)^3 (x^2 + 1)^5 mod 5: 重複度 5 の部分は導関数から見えない
+    let g = &(&poly::<5>(&[0, 0, 1])
+        * &(0..3).fold(poly::<5>(&[1]), |a, _| &a * &poly::<5>(&[1, 1])))
+        * &(0..5).fold(poly::<5>(&[1]), |a, _| &a * &poly::<5>(&[1, 0, 1]));
+    let fact = factor::factor(&g).unwrap();
+    assert_eq!(fact.expand(), g);
+    let exps: Vec<(Vec<u16>, usize)> = fact.factors.iter().map(|(h, e)| (values(h), *e)).collect();
+    assert_eq!(
+        exps,
+        vec![
+            (vec![0, 1], 2),
+            (vec![1, 1], 3),
+            (vec![2, 1], 5),
+            (vec![3, 1], 5)
+        ]
+    );
+
+    // 既約性（Rabin）と原始性
+    assert!(factor::is_irreducible(&poly::<2>(&[1, 1, 0, 0, 1])).unwrap());
+    assert!(!factor::is_irreducible(&poly::<2>(&[1, 0, 1])).unwrap());
+    assert!(factor::is_primitive(&poly::<2>(&[1, 1, 0, 0, 1])).unwrap());
+    // x^4 + x^3 + x^2 + x + 1 は既約だが x^5 = 1 なので原始的でない
+    assert!(factor::is_irreducible(&poly::<2>(&[1, 1, 1, 1, 1])).unwrap());
+    assert!(!factor::is_primitive(&poly::<2>(&[1, 1, 1, 1, 1])).unwrap());
+}
+
+#[test]
+fn extension_field_factorization() {
+    // GF(4) = GF(2)[a]/(a^2 + a + 1)
+    let px = Arc::new(vec![GFp::<2>::new(1), GFp::<2>::new(1), GFp::<2>::new(1)]);
+    let w = GFExt::from_base(px.clone(), GFp::<2>::new(1));
+    assert_eq!((w.characteristic(), w.field_size()), (2, Some(4)));
+    let e = |i: u64| w.element(i);
+
+    // x^4 − x = Π_{c ∈ GF(4)} (x − c)
+    let f = Polynomial::new(vec![e(0), e(1), e(0), e(0), e(1)]);
+    for method in [FactorMethod::CantorZassenhaus, FactorMethod::Berlekamp] {
+        let fact = factor::factor_with(&f, method).unwrap();
+        let roots: Vec<u64> = fact.factors.iter().map(|(g, _)| g.get(0).index()).collect();
+        assert_eq!(roots, vec![0, 1, 2, 3]);
+        assert!(fact.factors.iter().all(|(g, e)| g.deg() == 1 && *e == 1));
+    }
+
+    // x^2 + x + 1 は GF(2) では既約だが GF(4) では (x + a)(x + a + 1) に分かれる
+    let g = Polynomial::new(vec![e(1), e(1), e(1)]);
+    assert!(factor::is_irreducible(&poly::<2>(&[1, 1, 1])).unwrap());
+    assert!(!factor::is_irreducible(&g).unwrap());
+    let fact = factor::factor(&g).unwrap();
+    assert_eq!(fact.factors.len(), 2);
+    assert_eq!(fact.expand(), g);
+
+    // x^2 + x + a は GF(4) 上既約（トレースが 1）、二乗すると重複度 2 になる
+    let h = Polynomial::new(vec![e(2), e(1), e(1)]);
+    assert!(factor::is_irreducible(&h).unwrap());
+    let fact = factor::factor(&(&h * &h)).unwrap();
+    assert_eq!(fact.factors, vec![(h, 2)]);
+}
+
+#[test]
+fn aes_modulus_is_irreducible_but_not_primitive() {
+    let aes = Polynomial::new(gf256_modulus().to_vec());
+    assert!(factor::is_irreducible(&aes).unwrap());
+    // AES の法では x の位数は 51 で、生成元には x + 1 を使う
+    assert!(!factor::is_primitive(&aes).unwrap());
+    // x^8 + x^4 + x^3 + x^2 + 1 (0x11D) は原始多項式（Reed–Solomon で使う）
+    assert!(factor::is_primitive(&poly::<2>(&[1, 0, 1, 1, 1, 0, 0, 0, 1])).unwrap());
+    assert!(!factor::is_irreducible(&poly::<2>(&[1, 0, 0, 0, 0, 0, 0, 0, 1])).unwrap());
+}
+
+#[test]
+fn oversized_fields_report_order_overflow() {
+    // GF(2^64) の元の個数は u64 に収まらない
+    let mut coeffs = vec![GFp::<2>::new(0); 65];
+    coeffs[0] = GFp::<2>::new(1);
+    coeffs[64] = GFp::<2>::new(1);
+    let w = GFExt::from_base(Arc::new(coeffs), GFp::<2>::new(1));
+    assert_eq!(w.field_size(), None);
+    let f = Polynomial::new(vec![w.clone(), w.clone(), w]);
+    assert!(matches!(
+        factor::factor(&f),
+        Err(FieldError::OrderOverflow { .. })
+    ));
+
+    // x^128 + x^7 + x^2 + x + 1（GCM の法）は原始的。位数 2^128 − 1 はちょうど u128 に収まる
+    let mut gcm = vec![0; 129];
+    for k in [0, 1, 2, 7, 128] {
+        gcm[k] = 1;
+    }
+    assert!(factor::is_primitive(&poly::<2>(&gcm)).unwrap());
+    // x^129 + x^5 + 1 は既約だが 2^129 − 1 は u128 に収まらない
+    let mut big = vec![0; 130];
+    for k in [0, 5, 129] {
+        big[k] = 1;
+    }
+    assert!(factor::is_irreducible(&poly::<2>(&big)).unwrap());
+    assert!(matches!(
+        factor::is_primitive(&poly::<2>(&big)),
+        Err(FieldError::OrderOverflow { .. })
+    ));
+}
@@ -0,0 +1,89 @@
+use finite_field::gfp::GFp;
+use poly::Polynomial;
+
+type F = GFp<65521>;
+
+fn random_poly(deg: usize, seed: u64) -> Polynomial<F> {
+    let mut s = seed;
+    let mut coeffs: Vec<F> = (0..=deg)
+        .map(|_| {
+            s = s
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            F::new((s >> 33) as i64)
+        })
+        .collect();
+    coeffs[deg] = F::new(1);
+    Polynomial::new(coeffs)
+}
+
+#[test]
+fn ntt_multiplication_and_newton_division() {
+    let a = random_poly(700, 1);
+    let b = random_poly(450, 2);
+    assert_eq!(a.mul_fast(&b), &a * &b);
+
+    let (q, r) = a.div_rem_fast(&b);
+    assert_eq!((q.clone(), r.clone()), a.div_rem(&b));
+    assert_eq!(&(&q * &b) + &r, a);
+
+    // f · f^{-1} ≡ 1 (mod x^k)
+    let k = 333;
+    let inv = b.inverse_series(k).unwrap();
+    let prod = b.mul_fast(&inv);
+    assert!(prod
+        .coeffs
+        .iter()
+        .take(k)
+        .enumerate()
+        .all(|(i, c)| c.value() == (i == 0) as u16));
+
+    // GF(2) でも同じ
+    let mut bits = Vec::new();
+    let mut s = 5u32;
+    for _ in 0..400 {
+        s = s.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        bits.push(GFp::<2>::new((s >> 16) as i64));
+    }
+    bits.push(GFp::<2>::new(1));
+    let f = Polynomial::new(bits);
+    let g = Polynomial::new(f.coeffs[..90].to_vec());
+    assert_eq!(f.mul_fast(&g), &f * &g);
+    assert_eq!(f.div_rem_fast(&g), f.div_rem(&g));
+}
+
+#[test]
+fn multipoint_evaluation_and_interpolation_are_exact() {
+    let p = random_poly(299, 3);
+    let xs: Vec<F> = (0..300).map(|i| F::new(7 * i + 1)).collect();
+    let ys = p.eval_many(&xs);
+    assert!(xs.iter().zip(&ys).all(|(x, y)| p.eval(*x) == *y));
+    assert_eq!(Polynomial::interpolate_fast(&xs, &ys).unwrap(), p);
+
+    let mut dup = xs.clone();
+    dup[200] = dup[3];
+    assert!(Polynomial::interpolate_fast(&dup, &ys).is_err());
+}
+
+#[test]
+fn half_gcd_agrees_with_euclid() {
+    let g = random_poly(60, 4);
+    let a = &g * &random_poly(340, 5);
+    let b = &g * &random_poly(250, 6);
+    let expected = Polynomial::gcd(&a, &b);
+    assert_eq!(expected.deg(), 60);
+    assert_eq!(Polynomial::gcd_fast(&a, &b), expected);
+    assert_eq!(Polynomial::gcd_fast(&b, &a), expected);
+    // 同じ次数や零多項式を含む場合
+    assert_eq!(Polynomial::gcd_fast(&a, &(&a + &b)), expected);
+    assert_eq!(Polynomial::gcd_fast(&a, &Polynomial::zero()), a.monic());
+
+    // M (a, b) = (c, d) は deg c ≥ ⌈deg a / 2⌉ > deg d を満たす
+    let m = Polynomial::half_gcd(&a, &b);
+    let c = &(&m[0][0] * &a) + &(&m[0][1] * &b);
+    let d = &(&m[1][0] * &a) + &(&m[1][1] * &b);
+    let half = (a.deg() + 1) / 2;
+    assert!(c.deg() >= half && half > d.deg(), "{} {}", c.deg(), d.deg());
+    // 変換は互除法の途中なので gcd を保つ
+    assert_eq!(Polynomial::gcd(&c, &d), expected);
+}
@@ -0,0 +1,68 @@
+use finite_field::gfp::GFp;
+use poly::multivariate::{GroebnerAlgorithm, GroebnerBasis};
+use poly::{MonomialOrder, MultiPolynomial};
+
+type F = GFp<7>;
+
+fn f(v: i64) -> F {
+    F::new(v)
+}
+
+fn zeros(system: &[MultiPolynomial<F>]) -> Vec<(i64, i64)> {
+    let mut out = Vec::new();
+    for a in 0..7 {
+        for b in 0..7 {
+            if system.iter().all(|p| p.eval(&[f(a), f(b)]).value() == 0) {
+                out.push((a, b));
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn groebner_basis_over_gfp_preserves_solutions() {
+    // x² + y² − 1, xy − 2 over GF(7)
+    let lex = MonomialOrder::Lex;
+    let gens = vec![
+        MultiPolynomial::new(
+            2,
+            lex,
+            vec![(vec![2, 0], f(1)), (vec![0, 2], f(1)), (vec![0, 0], f(-1))],
+        ),
+        MultiPolynomial::new(2, lex, vec![(vec![1, 1], f(1)), (vec![0, 0], f(-2))]),
+    ];
+    let gb = GroebnerBasis::new(&gens);
+    let f4 = GroebnerBasis::with_algorithm(&gens, GroebnerAlgorithm::F4);
+    assert_eq!(gb, f4);
+    assert_eq!(zeros(gb.polynomials()), zeros(&gens));
+
+    // lex 基底の最後の元は y だけの多項式で、その根が解の y 座標
+    let last = gb.elimination(1);
+    assert_eq!(last.len(), 1);
+    let univariate = last[0].to_univariate(1).unwrap();
+    for (_, b) in zeros(&gens) {
+        assert_eq!(univariate.eval(f(b)).value(), 0);
+    }
+    for g in &gens {
+        assert!(gb.contains(g));
+    }
+}
+
+#[test]
+fn ideal_membership_over_gfp_uses_field_arithmetic() {
+    // GF(7) では 7x = 0 なので ⟨x + 7y⟩ = ⟨x⟩ で、x + y は属さない
+    let grevlex = MonomialOrder::GrevLex;
+    let v = MultiPolynomial::<F>::vars(2, grevlex);
+    let (x, y) = (&v[0], &v[1]);
+    let g = x + &(y * f(7));
+    let gb = GroebnerBasis::new(std::slice::from_ref(&g));
+    assert_eq!(gb.polynomials(), std::slice::from_ref(x));
+    assert!(gb.contains(&(x * y)));
+    assert!(!gb.contains(&(x + y)));
+
+    // x^p − x は GF(p) の全点で 0 だが、イデアル ⟨y⟩ には属さない（多項式としては非零）
+    let frob = &x.pow(7) - x;
+    assert!(zeros(std::slice::from_ref(&frob)).len() == 49);
+    assert!(!GroebnerBasis::new(std::slice::from_ref(y)).contains(&frob));
+}
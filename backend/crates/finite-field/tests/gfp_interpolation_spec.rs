@@ -0,0 +1,40 @@
+use finite_field::gfp::GFp;
+use poly::polynomial::interpolation::{divided_differences, Barycentric};
+use poly::Polynomial;
+
+type F = GFp<13>;
+
+fn f(v: i64) -> F {
+    F::new(v)
+}
+
+#[test]
+fn interpolation_over_gfp_recovers_polynomial() {
+    // p(x) = 3x^3 + 5x + 7 over GF(13) を 4 点から復元する
+    let p = Polynomial::new(vec![f(7), f(5), f(0), f(3)]);
+    let xs = [f(1), f(4), f(9), f(12)];
+    let ys: Vec<F> = xs.iter().map(|&x| p.eval(x)).collect();
+
+    assert_eq!(Polynomial::lagrange(&xs, &ys).unwrap(), p);
+    assert_eq!(Polynomial::newton_interpolation(&xs, &ys).unwrap(), p);
+    assert_eq!(divided_differences(&xs, &ys).unwrap()[3], f(3));
+
+    let bary = Barycentric::new(&xs, &ys).unwrap();
+    assert_eq!(bary.to_polynomial(), p);
+    for v in 0..13 {
+        assert_eq!(bary.eval(f(v)), p.eval(f(v)));
+    }
+}
+
+#[test]
+fn interpolation_over_gfp_rejects_repeated_nodes() {
+    // 1 と 14 は GF(13) では同じ元
+    let xs = [f(1), f(14)];
+    assert!(Polynomial::lagrange(&xs, &[f(2), f(3)]).is_err());
+
+    // 全点での値から次数 < p の多項式が一意に決まる（x^13 - x ≡ 0 の分は消える）
+    let xs: Vec<F> = (0..13).map(f).collect();
+    let ys: Vec<F> = xs.iter().map(|&x| x * x * x * x).collect();
+    let q = Polynomial::newton_interpolation(&xs, &ys).unwrap();
+    assert_eq!(q, Polynomial::new(vec![f(0), f(0), f(0), f(0), f(1)]));
+}
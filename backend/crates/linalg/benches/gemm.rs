@@ -0,0 +1,74 @@
+//! 行列積のベンチマーク: 素朴な三重ループ / ブロック GEMM（`*`）/ Strassen を比較する。
+//! 実行: `cargo bench -p linalg --bench gemm`（並列版は `--features rayon`）
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use linalg::Matrix;
+
+fn sample(n: usize, seed: u64) -> Matrix<f64> {
+    // 再現性のある簡易乱数（線形合同法）
+    let mut state = seed;
+    let data = (0..n * n)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 11) as f64 / (1u64 << 53) as f64) - 0.5
+        })
+        .collect();
+    Matrix::new(n, n, data).unwrap()
+}
+
+/// 変更前と同じ i-j-k 順の三重ループ
+fn naive_mul(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64> {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    let mut data = Vec::with_capacity(m * n);
+    for i in 0..m {
+        for j in 0..n {
+            let mut acc = 0.0;
+            for p in 0..k {
+                acc += a[(i, p)] * b[(p, j)];
+            }
+            data.push(acc);
+        }
+    }
+    Matrix::new(m, n, data).unwrap()
+}
+
+fn time<F: FnMut() -> Matrix<f64>>(mut f: F) -> Duration {
+    // 最低 3 回、合計 0.5 秒程度まで繰り返して最速値を取る
+    let mut best = Duration::MAX;
+    let mut total = Duration::ZERO;
+    let mut runs = 0;
+    while runs < 3 || (total < Duration::from_millis(500) && runs < 50) {
+        let start = Instant::now();
+        black_box(f());
+        let elapsed = start.elapsed();
+        best = best.min(elapsed);
+        total += elapsed;
+        runs += 1;
+    }
+    best
+}
+
+fn report(name: &str, n: usize, d: Duration) {
+    let gflops = 2.0 * (n as f64).powi(3) / d.as_secs_f64() / 1e9;
+    println!(
+        "{name:>10} n={n:5}: {:10.3} ms  {gflops:7.2} GFLOP/s",
+        d.as_secs_f64() * 1e3
+    );
+}
+
+fn main() {
+    for &n in &[64usize, 128, 256, 512, 1000] {
+        let a = sample(n, 1);
+        let b = sample(n, 2);
+        if n <= 512 {
+            report("naive", n, time(|| naive_mul(&a, &b)));
+            report("ikj", n, time(|| &a * &b));
+        }
+        report("blocked", n, time(|| a.gemm_mul(&b).unwrap()));
+        report("strassen", n, time(|| a.strassen_mul(&b).unwrap()));
+    }
+}
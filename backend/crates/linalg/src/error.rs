@@ -10,6 +10,7 @@ pub enum LinalgError {
     InvalidDimension { dim: usize, text: String },
     NotImplemented,
     InvalidArgument { text: String },
+    IllConditioned { rcond: f64 },
 }
 
 impl fmt::Display for LinalgError {
@@ -28,6 +29,10 @@ impl fmt::Display for LinalgError {
             }
             LinalgError::NotImplemented => write!(f, "Feature not yet implemented"),
             LinalgError::InvalidArgument { text } => write!(f, "Invalid argument: {text}"),
+            LinalgError::IllConditioned { rcond } => write!(
+                f,
+                "Matrix is ill-conditioned (reciprocal condition number {rcond:.3e}); results may be inaccurate"
+            ),
         }
     }
 }
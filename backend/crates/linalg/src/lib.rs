@@ -7,9 +7,15 @@ mod macros;
 
 pub mod error;
 pub mod matrix;
+pub mod ndarray;
+pub mod sparse;
+pub mod structured;
 pub mod traits;
 pub mod vector;
 
+#[cfg(test)]
+mod test_util;
+
 // --- 公開APIの再エクスポート ---
 // `pub use` を使うことで、ライブラリの利用者が短いパスで型やトレイトにアクセスできるようになります。
 
@@ -17,8 +23,18 @@ pub mod vector;
 pub use error::{LinalgError, Result};
 
 // 主要なデータ構造
-pub use matrix::{Direction, Matrix};
+pub use matrix::{
+    DiophantineSolution, Direction, HermiteNormalForm, Matrix, MatrixView, MatrixViewMut,
+    SmithNormalForm,
+};
+pub use ndarray::NdArray;
+pub use sparse::{CooMatrix, CscMatrix, SparseMatrix};
+pub use structured::{Banded, BandedCholesky, BandedLU, Triangle, Triangular, Tridiagonal};
 pub use vector::Vector;
 
+// 厳密な有理数スカラー（`Matrix<BigRational>` で分数のまま計算できる）
+pub use num_bigint::BigInt;
+pub use num_rational::BigRational;
+
 // 基本的な振る舞いを定義するトレイト
-pub use traits::{Field, Ring, Scalar};
+pub use traits::{Field, GemmScalar, IntegerRing, Ring, Scalar};
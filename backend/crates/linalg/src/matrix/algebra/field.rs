@@ -131,6 +131,51 @@ impl<T: Field> Matrix<T> {
         Ok(det)
     }
 
+    /// Bareiss の分数を作らない消去法による行列式。
+    /// 各段の除算は前段のピボットで割り切れるため、整数（i64, BigInt）では途中も整数のまま、
+    /// 有理数（BigRational）では分母が膨らまずに計算できる。
+    pub fn determinant_bareiss(&self) -> Result<T> {
+        if !self.is_square() {
+            return Err(LinalgError::DimensionMismatch {
+                expected: "square matrix".to_string(),
+                found: format!("{}x{}", self.rows, self.cols),
+            });
+        }
+        let n = self.rows;
+        if n == 0 {
+            return Ok(T::one());
+        }
+        let mut m = self.clone();
+        let mut sign_flipped = false;
+        let mut prev_pivot = T::one();
+        for k in 0..n - 1 {
+            if m[(k, k)].is_zero() {
+                // 下の行から非ゼロのピボットを探して交換する
+                match ((k + 1)..n).find(|&r| !m[(r, k)].is_zero()) {
+                    Some(r) => {
+                        m.swap_rows(k, r)?;
+                        sign_flipped = !sign_flipped;
+                    }
+                    None => return Ok(T::zero()),
+                }
+            }
+            let pivot = m[(k, k)].clone();
+            for i in (k + 1)..n {
+                for j in (k + 1)..n {
+                    let val =
+                        pivot.clone() * m[(i, j)].clone() - m[(i, k)].clone() * m[(k, j)].clone();
+                    m[(i, j)] = val / prev_pivot.clone();
+                }
+                m[(i, k)] = T::zero();
+            }
+            prev_pivot = pivot;
+        }
+        let det = m[(n - 1, n - 1)].clone();
+        Ok(if sign_flipped { -det } else { det })
+    }
+
+    /// 掃き出し法による逆行列（有理数・有限体では厳密）。
+    /// 浮動小数点で悪条件を検出したい場合は `inverse_checked` を使う。
     pub fn inverse(&self) -> Option<Matrix<T>> {
         if !self.is_square() {
             return None; // 正方行列でない場合は逆行列は存在しない
@@ -0,0 +1,269 @@
+//! 行列積のカーネル。
+//! f32 / f64 の `gemm_mul` / `strassen_mul` は BLIS 方式（B パネルと A ブロックをパッキングし、MR×NR のマイクロカーネルで更新）、
+//! `checked_mul` と `*` 演算子はすべての Ring で i-k-j 順のループで計算する。`rayon` フィーチャ有効時は A の行ブロックを並列化する。
+
+use super::super::Matrix;
+use crate::{GemmScalar, LinalgError, Result, Ring};
+
+/// マイクロカーネルの行数・列数
+const MR: usize = 4;
+const NR: usize = 8;
+/// キャッシュブロックの大きさ（A: MC×KC は L2、B: KC×NC は L3 に収まる程度）
+const MC: usize = 64;
+const KC: usize = 256;
+const NC: usize = 2048;
+/// これより小さい積はパッキングせず単純ループで計算する（m n k の目安）
+const SMALL_PRODUCT: usize = 32 * 32 * 32;
+/// Strassen 法で再帰をやめてブロック GEMM に切り替える大きさ
+const STRASSEN_CUTOFF: usize = 128;
+
+/// 汎用 Ring 向けの i-k-j 順の積（右辺を行方向に連続アクセスする）
+pub(super) fn generic_mul<T: Ring>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    let mut data = vec![T::zero(); m * n];
+    for i in 0..m {
+        let row = &mut data[i * n..(i + 1) * n];
+        for p in 0..k {
+            let aip = &a.data[i * k + p];
+            let brow = &b.data[p * n..(p + 1) * n];
+            for (cij, bpj) in row.iter_mut().zip(brow) {
+                let acc = std::mem::replace(cij, T::zero());
+                *cij = acc + aip.clone() * bpj.clone();
+            }
+        }
+    }
+    Matrix {
+        rows: m,
+        cols: n,
+        data,
+    }
+}
+
+/// ブロック化 GEMM: C = A B（次元は呼び出し側で検査済みとする）
+fn gemm<S: GemmScalar>(a: &Matrix<S>, b: &Matrix<S>) -> Matrix<S> {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    let mut c = vec![S::zero(); m * n];
+    if m * n * k <= SMALL_PRODUCT {
+        for i in 0..m {
+            for p in 0..k {
+                let aip = a.data[i * k + p];
+                for j in 0..n {
+                    c[i * n + j] = c[i * n + j] + aip * b.data[p * n + j];
+                }
+            }
+        }
+    } else if n > 0 {
+        gemm_blocked(m, n, k, &a.data, &b.data, &mut c);
+    }
+    Matrix {
+        rows: m,
+        cols: n,
+        data: c,
+    }
+}
+
+fn gemm_blocked<S: GemmScalar>(m: usize, n: usize, k: usize, a: &[S], b: &[S], c: &mut [S]) {
+    let mut packed_b = Vec::new();
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+            pack_b(b, n, pc, kc, jc, nc, &mut packed_b);
+
+            // C の行ブロックは互いに素なので並列に更新できる
+            let update = |(blk, c_rows): (usize, &mut [S])| {
+                let ic = blk * MC;
+                let mc = MC.min(m - ic);
+                let mut packed_a = Vec::new();
+                pack_a(a, k, ic, mc, pc, kc, &mut packed_a);
+                macro_kernel(mc, nc, kc, &packed_a, &packed_b, c_rows, n, jc);
+            };
+            #[cfg(feature = "rayon")]
+            {
+                use rayon::prelude::*;
+                c.par_chunks_mut(MC * n).enumerate().for_each(update);
+            }
+            #[cfg(not(feature = "rayon"))]
+            c.chunks_mut(MC * n).enumerate().for_each(update);
+        }
+    }
+}
+
+/// B[pc..pc+kc, jc..jc+nc] を NR 列ずつのパネルに詰める（端は 0 埋め）
+fn pack_b<S: GemmScalar>(
+    b: &[S],
+    ldb: usize,
+    pc: usize,
+    kc: usize,
+    jc: usize,
+    nc: usize,
+    out: &mut Vec<S>,
+) {
+    let panels = nc.div_ceil(NR);
+    out.clear();
+    out.resize(panels * kc * NR, S::zero());
+    for jp in 0..panels {
+        let base = jp * kc * NR;
+        let width = NR.min(nc - jp * NR);
+        for p in 0..kc {
+            let src = (pc + p) * ldb + jc + jp * NR;
+            out[base + p * NR..base + p * NR + width].copy_from_slice(&b[src..src + width]);
+        }
+    }
+}
+
+/// A[ic..ic+mc, pc..pc+kc] を MR 行ずつのパネルに詰める（端は 0 埋め）
+fn pack_a<S: GemmScalar>(
+    a: &[S],
+    lda: usize,
+    ic: usize,
+    mc: usize,
+    pc: usize,
+    kc: usize,
+    out: &mut Vec<S>,
+) {
+    let panels = mc.div_ceil(MR);
+    out.clear();
+    out.resize(panels * kc * MR, S::zero());
+    for ip in 0..panels {
+        let base = ip * kc * MR;
+        let height = MR.min(mc - ip * MR);
+        for i in 0..height {
+            let row = (ic + ip * MR + i) * lda + pc;
+            for p in 0..kc {
+                out[base + p * MR + i] = a[row + p];
+            }
+        }
+    }
+}
+
+/// パッキング済みブロックで C の (mc × nc) 部分を更新する。`c_rows` は C の該当行ブロック。
+#[allow(clippy::too_many_arguments)]
+fn macro_kernel<S: GemmScalar>(
+    mc: usize,
+    nc: usize,
+    kc: usize,
+    packed_a: &[S],
+    packed_b: &[S],
+    c_rows: &mut [S],
+    ldc: usize,
+    jc: usize,
+) {
+    for jp in 0..nc.div_ceil(NR) {
+        let pb = &packed_b[jp * kc * NR..(jp + 1) * kc * NR];
+        let width = NR.min(nc - jp * NR);
+        for ip in 0..mc.div_ceil(MR) {
+            let pa = &packed_a[ip * kc * MR..(ip + 1) * kc * MR];
+            let height = MR.min(mc - ip * MR);
+            let acc = micro_kernel(kc, pa, pb);
+            for (i, acc_row) in acc.iter().enumerate().take(height) {
+                let start = (ip * MR + i) * ldc + jc + jp * NR;
+                for (cij, &v) in c_rows[start..start + width].iter_mut().zip(acc_row) {
+                    *cij = *cij + v;
+                }
+            }
+        }
+    }
+}
+
+/// MR×NR のレジスタブロックに A パネルと B パネルの積を蓄積する
+#[inline(always)]
+fn micro_kernel<S: GemmScalar>(kc: usize, pa: &[S], pb: &[S]) -> [[S; NR]; MR] {
+    let mut acc = [[S::zero(); NR]; MR];
+    for (a, b) in pa.chunks_exact(MR).zip(pb.chunks_exact(NR)).take(kc) {
+        for i in 0..MR {
+            let ai = a[i];
+            for j in 0..NR {
+                acc[i][j] = acc[i][j] + ai * b[j];
+            }
+        }
+    }
+    acc
+}
+
+impl<S: GemmScalar> Matrix<S> {
+    /// ブロック化 GEMM による積（`rayon` フィーチャで並列化）。結果の加算順は `checked_mul` と同じ k の昇順。
+    pub fn gemm_mul(&self, rhs: &Matrix<S>) -> Result<Matrix<S>> {
+        check_product(self, rhs)?;
+        Ok(gemm(self, rhs))
+    }
+
+    /// Strassen 法による積（7 回の再帰乗算）。大きな行列向けのオプトインで、
+    /// 小さな部分問題や細長い形状はブロック GEMM に切り替える。丸め誤差は通常の積よりやや大きい。
+    pub fn strassen_mul(&self, rhs: &Matrix<S>) -> Result<Matrix<S>> {
+        check_product(self, rhs)?;
+        Ok(strassen(self, rhs))
+    }
+}
+
+fn check_product<S>(a: &Matrix<S>, b: &Matrix<S>) -> Result<()> {
+    if a.cols != b.rows {
+        return Err(LinalgError::DimensionMismatch {
+            expected: format!("left cols {} == right rows {}", a.cols, b.rows),
+            found: format!("{} vs {}", a.cols, b.rows),
+        });
+    }
+    Ok(())
+}
+
+fn strassen<S: GemmScalar>(a: &Matrix<S>, b: &Matrix<S>) -> Matrix<S> {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    if m.min(k).min(n) <= STRASSEN_CUTOFF {
+        return gemm(a, b);
+    }
+    // 各次元を偶数にそろえて 2×2 ブロックに分割する
+    let (hm, hk, hn) = (m.div_ceil(2), k.div_ceil(2), n.div_ceil(2));
+    let a = padded(a, 2 * hm, 2 * hk);
+    let b = padded(b, 2 * hk, 2 * hn);
+    let a11 = a.submatrix(0, hm, 0, hk);
+    let a12 = a.submatrix(0, hm, hk, 2 * hk);
+    let a21 = a.submatrix(hm, 2 * hm, 0, hk);
+    let a22 = a.submatrix(hm, 2 * hm, hk, 2 * hk);
+    let b11 = b.submatrix(0, hk, 0, hn);
+    let b12 = b.submatrix(0, hk, hn, 2 * hn);
+    let b21 = b.submatrix(hk, 2 * hk, 0, hn);
+    let b22 = b.submatrix(hk, 2 * hk, hn, 2 * hn);
+
+    let m1 = strassen(&(&a11 + &a22), &(&b11 + &b22));
+    let m2 = strassen(&(&a21 + &a22), &b11);
+    let m3 = strassen(&a11, &(&b12 - &b22));
+    let m4 = strassen(&a22, &(&b21 - &b11));
+    let m5 = strassen(&(&a11 + &a12), &b22);
+    let m6 = strassen(&(&a21 - &a11), &(&b11 + &b12));
+    let m7 = strassen(&(&a12 - &a22), &(&b21 + &b22));
+
+    let c11 = &(&(&m1 + &m4) - &m5) + &m7;
+    let c12 = &m3 + &m5;
+    let c21 = &m2 + &m4;
+    let c22 = &(&(&m1 - &m2) + &m3) + &m6;
+
+    let mut c = Matrix {
+        rows: m,
+        cols: n,
+        data: vec![S::zero(); m * n],
+    };
+    for i in 0..m {
+        for j in 0..n {
+            let (bi, bj) = (i % hm, j % hn);
+            c.data[i * n + j] = match (i < hm, j < hn) {
+                (true, true) => c11.data[bi * hn + bj],
+                (true, false) => c12.data[bi * hn + bj],
+                (false, true) => c21.data[bi * hn + bj],
+                (false, false) => c22.data[bi * hn + bj],
+            };
+        }
+    }
+    c
+}
+
+/// 右下を 0 で埋めて rows × cols に拡張する
+fn padded<S: GemmScalar>(a: &Matrix<S>, rows: usize, cols: usize) -> Matrix<S> {
+    if a.rows == rows && a.cols == cols {
+        return a.clone();
+    }
+    let mut data = vec![S::zero(); rows * cols];
+    for i in 0..a.rows {
+        data[i * cols..i * cols + a.cols].copy_from_slice(&a.data[i * a.cols..(i + 1) * a.cols]);
+    }
+    Matrix { rows, cols, data }
+}
@@ -0,0 +1,284 @@
+use crate::traits::IntegerRing;
+use crate::{LinalgError, Matrix, Result, Vector};
+
+/// 行 Hermite 標準形 H = U A（U はユニモジュラ）
+pub struct HermiteNormalForm<T> {
+    pub h: Matrix<T>,
+    pub u: Matrix<T>,
+    /// 各非零行のピボット列（左から昇順）
+    pub pivots: Vec<usize>,
+}
+
+impl<T> HermiteNormalForm<T> {
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+}
+
+/// Smith 標準形 D = U A V（U, V はユニモジュラ、D の対角は d_1 | d_2 | ... を満たす非負整数）
+pub struct SmithNormalForm<T> {
+    pub d: Matrix<T>,
+    pub u: Matrix<T>,
+    pub v: Matrix<T>,
+}
+
+impl<T: IntegerRing> SmithNormalForm<T> {
+    /// 零でない対角成分（不変因子）
+    pub fn invariant_factors(&self) -> Vec<T> {
+        (0..self.d.rows.min(self.d.cols))
+            .map(|i| self.d[(i, i)].clone())
+            .take_while(|v| !v.is_zero())
+            .collect()
+    }
+
+    pub fn rank(&self) -> usize {
+        self.invariant_factors().len()
+    }
+}
+
+/// A x = b の整数解全体 { particular + kernel z | z ∈ Z^k }
+pub struct DiophantineSolution<T: IntegerRing> {
+    pub particular: Vector<T>,
+    /// 整数核の基底（列ベクトル）
+    pub kernel: Matrix<T>,
+}
+
+impl<T: IntegerRing> Matrix<T> {
+    /// 行 Hermite 標準形。H は上階段形で、ピボットは正、ピボットより上の成分は [0, ピボット) に簡約される。
+    /// i64 では中間値があふれることがあるので、大きな行列には BigInt を使う。
+    pub fn hermite_normal_form(&self) -> HermiteNormalForm<T> {
+        let (m, n) = (self.rows, self.cols);
+        let mut h = self.clone();
+        let mut u = Matrix::identity(m);
+        let mut pivots = Vec::new();
+        let mut row = 0;
+        for col in 0..n {
+            if row == m {
+                break;
+            }
+            // 拡張ユークリッドの行変換で col 列の row 行より下を消す
+            for i in (row + 1)..m {
+                if !h[(i, col)].is_zero() {
+                    let c = elimination_coefficients(&h[(row, col)], &h[(i, col)]);
+                    combine_rows(&mut h, row, i, &c);
+                    combine_rows(&mut u, row, i, &c);
+                }
+            }
+            if h[(row, col)].is_zero() {
+                continue;
+            }
+            if h[(row, col)].is_negative() {
+                negate_row(&mut h, row);
+                negate_row(&mut u, row);
+            }
+            let pivot = h[(row, col)].clone();
+            for i in 0..row {
+                let q = h[(i, col)].div_floor(&pivot);
+                if !q.is_zero() {
+                    let c = [T::one(), T::zero(), -q, T::one()];
+                    combine_rows(&mut h, row, i, &c);
+                    combine_rows(&mut u, row, i, &c);
+                }
+            }
+            pivots.push(col);
+            row += 1;
+        }
+        HermiteNormalForm { h, u, pivots }
+    }
+
+    /// Smith 標準形。行・列の拡張ユークリッド変換で対角化し、対角成分が順に割り切るよう整える。
+    pub fn smith_normal_form(&self) -> SmithNormalForm<T> {
+        let (m, n) = (self.rows, self.cols);
+        let mut d = self.clone();
+        let mut u = Matrix::identity(m);
+        let mut v = Matrix::identity(n);
+        for t in 0..m.min(n) {
+            let Some((pi, pj)) = smallest_nonzero(&d, t) else {
+                break;
+            };
+            swap_rows(&mut d, t, pi);
+            swap_rows(&mut u, t, pi);
+            swap_cols(&mut d, t, pj);
+            swap_cols(&mut v, t, pj);
+            loop {
+                for i in (t + 1)..m {
+                    if !d[(i, t)].is_zero() {
+                        let c = elimination_coefficients(&d[(t, t)], &d[(i, t)]);
+                        combine_rows(&mut d, t, i, &c);
+                        combine_rows(&mut u, t, i, &c);
+                    }
+                }
+                for j in (t + 1)..n {
+                    if !d[(t, j)].is_zero() {
+                        let c = elimination_coefficients(&d[(t, t)], &d[(t, j)]);
+                        combine_cols(&mut d, t, j, &c);
+                        combine_cols(&mut v, t, j, &c);
+                    }
+                }
+                // 列操作で t 列に非零が戻ったらやり直す（そのたびに |d_tt| は真に減る）
+                if ((t + 1)..m).any(|i| !d[(i, t)].is_zero()) {
+                    continue;
+                }
+                // d_tt が残りの成分をすべて割り切らなければ、その行を足して gcd を取り直す
+                let pivot = d[(t, t)].clone();
+                let bad_row =
+                    ((t + 1)..m).find(|&i| ((t + 1)..n).any(|j| !d[(i, j)].is_multiple_of(&pivot)));
+                match bad_row {
+                    Some(i) => {
+                        let c = [T::one(), T::one(), T::zero(), T::one()];
+                        combine_rows(&mut d, t, i, &c);
+                        combine_rows(&mut u, t, i, &c);
+                    }
+                    None => break,
+                }
+            }
+            if d[(t, t)].is_negative() {
+                negate_row(&mut d, t);
+                negate_row(&mut u, t);
+            }
+        }
+        SmithNormalForm { d, u, v }
+    }
+
+    /// 整数核 { x ∈ Z^n | A x = 0 } の基底を列に並べた n×k 行列
+    pub fn integer_kernel(&self) -> Matrix<T> {
+        // U A^T = H の零行に対応する U の行 y は y A^T = 0、すなわち A y^T = 0
+        let hnf = self.transpose().hermite_normal_form();
+        let n = self.cols;
+        let r = hnf.rank();
+        let mut kernel = Matrix::zeros(n, n - r);
+        for k in 0..(n - r) {
+            for i in 0..n {
+                kernel[(i, k)] = hnf.u[(r + k, i)].clone();
+            }
+        }
+        kernel
+    }
+
+    /// 列ベクトルが生成する格子 A Z^n の基底（Hermite 標準形）を列に並べた m×r 行列
+    pub fn integer_image(&self) -> Matrix<T> {
+        let hnf = self.transpose().hermite_normal_form();
+        let r = hnf.rank();
+        let mut image = Matrix::zeros(self.rows, r);
+        for k in 0..r {
+            for i in 0..self.rows {
+                image[(i, k)] = hnf.h[(k, i)].clone();
+            }
+        }
+        image
+    }
+
+    /// 線形ディオファントス方程式 A x = b の整数解。解がなければ `None`。
+    /// Smith 標準形 U A V = D により D y = U b を成分ごとに解き、x = V y とする。
+    pub fn solve_diophantine(&self, b: &Vector<T>) -> Result<Option<DiophantineSolution<T>>> {
+        if b.len() != self.rows {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{}-dimensional vector", self.rows),
+                found: format!("{}-dimensional vector", b.len()),
+            });
+        }
+        let snf = self.smith_normal_form();
+        let factors = snf.invariant_factors();
+        let r = factors.len();
+        let c = snf.u.checked_mul_vector(b)?;
+        if c.data[r..].iter().any(|v| !v.is_zero()) {
+            return Ok(None);
+        }
+        let mut y = vec![T::zero(); self.cols];
+        for (i, d) in factors.iter().enumerate() {
+            let (q, rem) = c[i].div_rem(d);
+            if !rem.is_zero() {
+                return Ok(None);
+            }
+            y[i] = q;
+        }
+        let particular = snf.v.checked_mul_vector(&Vector::new(y))?;
+        let kernel = snf.v.submatrix(0, self.cols, r, self.cols);
+        Ok(Some(DiophantineSolution { particular, kernel }))
+    }
+}
+
+/// s a + t b = g（g >= 0）となる (g, s, t)
+pub(super) fn extended_gcd<T: IntegerRing>(a: &T, b: &T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (T::one(), T::zero());
+    let (mut t0, mut t1) = (T::zero(), T::one());
+    while !r1.is_zero() {
+        let q = r0.div_floor(&r1);
+        let r2 = r0 - q.clone() * r1.clone();
+        let s2 = s0 - q.clone() * s1.clone();
+        let t2 = t0 - q * t1.clone();
+        (r0, r1) = (r1, r2);
+        (s0, s1) = (s1, s2);
+        (t0, t1) = (t1, t2);
+    }
+    if r0.is_negative() {
+        (-r0, -s0, -t0)
+    } else {
+        (r0, s0, t0)
+    }
+}
+
+/// (a, b) を (g, 0) に移す行列式 1 の 2×2 変換 [[c0, c1], [c2, c3]]。
+/// a が b を割り切るときは a を変えない単純な引き算にする。
+fn elimination_coefficients<T: IntegerRing>(a: &T, b: &T) -> [T; 4] {
+    if !a.is_zero() && b.is_multiple_of(a) {
+        return [T::one(), T::zero(), -(b.clone() / a.clone()), T::one()];
+    }
+    let (g, s, t) = extended_gcd(a, b);
+    [s, t, -(b.clone() / g.clone()), a.clone() / g]
+}
+
+/// (行 r1, 行 r2) ← (c0 r1 + c1 r2, c2 r1 + c3 r2)
+fn combine_rows<T: IntegerRing>(m: &mut Matrix<T>, r1: usize, r2: usize, c: &[T; 4]) {
+    for j in 0..m.cols {
+        let (x, y) = (m[(r1, j)].clone(), m[(r2, j)].clone());
+        m[(r1, j)] = c[0].clone() * x.clone() + c[1].clone() * y.clone();
+        m[(r2, j)] = c[2].clone() * x + c[3].clone() * y;
+    }
+}
+
+/// (列 c1, 列 c2) ← (c0 c1 + c1 c2, c2 c1 + c3 c2)
+fn combine_cols<T: IntegerRing>(m: &mut Matrix<T>, c1: usize, c2: usize, c: &[T; 4]) {
+    for i in 0..m.rows {
+        let (x, y) = (m[(i, c1)].clone(), m[(i, c2)].clone());
+        m[(i, c1)] = c[0].clone() * x.clone() + c[1].clone() * y.clone();
+        m[(i, c2)] = c[2].clone() * x + c[3].clone() * y;
+    }
+}
+
+fn negate_row<T: IntegerRing>(m: &mut Matrix<T>, r: usize) {
+    for j in 0..m.cols {
+        m[(r, j)] = -m[(r, j)].clone();
+    }
+}
+
+fn swap_rows<T: IntegerRing>(m: &mut Matrix<T>, r1: usize, r2: usize) {
+    if r1 != r2 {
+        for j in 0..m.cols {
+            m.data.swap(r1 * m.cols + j, r2 * m.cols + j);
+        }
+    }
+}
+
+fn swap_cols<T: IntegerRing>(m: &mut Matrix<T>, c1: usize, c2: usize) {
+    if c1 != c2 {
+        for i in 0..m.rows {
+            m.data.swap(i * m.cols + c1, i * m.cols + c2);
+        }
+    }
+}
+
+/// d[t.., t..] で絶対値最小の非零成分の位置
+fn smallest_nonzero<T: IntegerRing>(d: &Matrix<T>, t: usize) -> Option<(usize, usize)> {
+    let mut best: Option<(usize, usize, T)> = None;
+    for i in t..d.rows {
+        for j in t..d.cols {
+            let v = d[(i, j)].abs();
+            if !v.is_zero() && best.as_ref().is_none_or(|(_, _, b)| v < *b) {
+                best = Some((i, j, v));
+            }
+        }
+    }
+    best.map(|(i, j, _)| (i, j))
+}
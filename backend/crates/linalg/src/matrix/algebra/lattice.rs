@@ -0,0 +1,352 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
+
+use super::integer::extended_gcd;
+use crate::{LinalgError, Matrix, Result, Vector};
+
+/// BKZ のツアー（全ブロックを一巡する処理）の上限
+const MAX_BKZ_TOURS: usize = 64;
+
+/// 列挙で見つけたベクトルを挿入する条件 |π_k(v)|^2 < ENUM_SLACK |b*_k|^2。
+/// 浮動小数点の列挙誤差で同じ長さのベクトルを入れ替え続けないための余裕。
+const ENUM_SLACK: f64 = 0.99;
+
+/// 格子基底簡約と最近ベクトル近似。基底は行ベクトルとして並べる。
+/// Gram–Schmidt 係数は `BigRational` で厳密に保持するので、小さな次元（数十まで）向け。
+impl Matrix<BigInt> {
+    /// LLL 簡約（Lovász 条件の定数 1/4 < delta <= 1。通常 0.75 か 0.99）。
+    /// 行が一次従属なら `InvalidArgument`。
+    pub fn lll_reduce(&self, delta: f64) -> Result<Matrix<BigInt>> {
+        let delta = lovasz_constant(delta)?;
+        let mut b = self.clone();
+        lll_in_place(&mut b, &delta)?;
+        Ok(b)
+    }
+
+    /// 基底が size-reduced（|μ_ij| <= 1/2）かつ Lovász 条件を満たすか
+    pub fn is_lll_reduced(&self, delta: f64) -> Result<bool> {
+        let delta = lovasz_constant(delta)?;
+        let (gs, _) = GramSchmidt::new(self)?;
+        let half = BigRational::new(BigInt::one(), BigInt::from(2));
+        for i in 0..self.rows {
+            if (0..i).any(|j| gs.mu[i][j].abs() > half) {
+                return Ok(false);
+            }
+            if i > 0 {
+                let mu = &gs.mu[i][i - 1];
+                if gs.norms[i] < (&delta - mu * mu) * &gs.norms[i - 1] {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// BKZ 簡約（簡易版）。各位置 k からの幅 `block_size` の射影部分格子で最短ベクトルを列挙し、
+    /// |b*_k| より十分短ければ基底に挿入して LLL をかけ直す。改善がなくなるまでツアーを繰り返す。
+    pub fn bkz_reduce(&self, block_size: usize, delta: f64) -> Result<Matrix<BigInt>> {
+        if block_size < 2 {
+            return Err(LinalgError::InvalidArgument {
+                text: "block_size must be at least 2".into(),
+            });
+        }
+        let delta_q = lovasz_constant(delta)?;
+        let mut b = self.clone();
+        lll_in_place(&mut b, &delta_q)?;
+        let n = b.rows;
+        for _ in 0..MAX_BKZ_TOURS {
+            let mut improved = false;
+            for k in 0..n.saturating_sub(1) {
+                let end = (k + block_size).min(n);
+                let (gs, _) = GramSchmidt::new(&b)?;
+                let (mu, norms) = gs.to_f64();
+                if let Some(x) = Enumeration::shortest(&mu, &norms, k, end) {
+                    insert_vector(&mut b, k, &x);
+                    lll_in_place(&mut b, &delta_q)?;
+                    improved = true;
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+        Ok(b)
+    }
+
+    /// Babai の最近平面法による最近ベクトル問題（CVP）の近似解。
+    /// 基底を LLL 簡約しておくと、真の最近点との距離比が 2^{n/2} 程度に抑えられる。
+    pub fn babai_nearest_plane(&self, target: &Vector<BigInt>) -> Result<Vector<BigInt>> {
+        if target.len() != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{}-dimensional vector", self.cols),
+                found: format!("{}-dimensional vector", target.len()),
+            });
+        }
+        let (gs, star) = GramSchmidt::new(self)?;
+        let mut residual: Vec<BigRational> = target
+            .data
+            .iter()
+            .map(|v| BigRational::from_integer(v.clone()))
+            .collect();
+        let mut point = vec![BigInt::zero(); self.cols];
+        // 後ろの超平面から順に、残差を最も近い平行移動に丸める
+        for j in (0..self.rows).rev() {
+            let c = (dot(&residual, &star[j]) / &gs.norms[j])
+                .round()
+                .to_integer();
+            if c.is_zero() {
+                continue;
+            }
+            for (col, v) in row(self, j).iter().enumerate() {
+                let step = &c * v;
+                residual[col] -= BigRational::from_integer(step.clone());
+                point[col] += step;
+            }
+        }
+        Ok(Vector::new(point))
+    }
+}
+
+impl Vector<f64> {
+    /// 実数 x_1, ..., x_n の整数関係 Σ a_i x_i ≈ 0 の候補を LLL で探す。
+    /// 基底 [e_i | round(scale x_i)] を簡約した最初の行の係数部分を返す。
+    /// scale は x_i の有効桁に合わせる（f64 なら 1e10 程度まで）。関係の妥当性は呼び出し側で確かめる。
+    pub fn integer_relation(&self, scale: f64) -> Result<Vector<BigInt>> {
+        let n = self.len();
+        if n < 2 {
+            return Err(LinalgError::InvalidArgument {
+                text: "integer_relation needs at least two values".into(),
+            });
+        }
+        let mut basis = Matrix::zeros(n, n + 1);
+        for (i, x) in self.data.iter().enumerate() {
+            basis[(i, i)] = BigInt::one();
+            basis[(i, n)] = BigInt::from_f64((scale * x).round()).ok_or_else(|| {
+                LinalgError::InvalidArgument {
+                    text: format!("cannot scale non-finite value {x}"),
+                }
+            })?;
+        }
+        let reduced = basis.lll_reduce(0.99)?;
+        Ok(Vector::new(row(&reduced, 0)[..n].to_vec()))
+    }
+}
+
+/// 行ベクトルを基底とする格子の Gram–Schmidt 係数 μ_ij と |b*_i|^2
+struct GramSchmidt {
+    mu: Vec<Vec<BigRational>>,
+    norms: Vec<BigRational>,
+}
+
+impl GramSchmidt {
+    /// 係数と直交化したベクトル b*_i を返す。行が一次従属なら `InvalidArgument`。
+    fn new(b: &Matrix<BigInt>) -> Result<(Self, Vec<Vec<BigRational>>)> {
+        let n = b.rows;
+        let mut mu = vec![vec![BigRational::zero(); n]; n];
+        let mut norms: Vec<BigRational> = Vec::with_capacity(n);
+        let mut star: Vec<Vec<BigRational>> = Vec::with_capacity(n);
+        for (i, mu_i) in mu.iter_mut().enumerate() {
+            let bi: Vec<BigRational> = row(b, i)
+                .iter()
+                .map(|v| BigRational::from_integer(v.clone()))
+                .collect();
+            let mut v = bi.clone();
+            for j in 0..i {
+                let m = dot(&bi, &star[j]) / &norms[j];
+                for (vc, sc) in v.iter_mut().zip(&star[j]) {
+                    *vc -= &m * sc;
+                }
+                mu_i[j] = m;
+            }
+            let norm = dot(&v, &v);
+            if norm.is_zero() {
+                return Err(LinalgError::InvalidArgument {
+                    text: "lattice basis rows must be linearly independent".into(),
+                });
+            }
+            mu_i[i] = BigRational::one();
+            norms.push(norm);
+            star.push(v);
+        }
+        Ok((GramSchmidt { mu, norms }, star))
+    }
+
+    /// 列挙用の浮動小数点の近似
+    fn to_f64(&self) -> (Vec<Vec<f64>>, Vec<f64>) {
+        let conv = |v: &BigRational| v.to_f64().unwrap_or(f64::NAN);
+        let mu = self
+            .mu
+            .iter()
+            .map(|r| r.iter().map(conv).collect())
+            .collect();
+        let norms = self.norms.iter().map(conv).collect();
+        (mu, norms)
+    }
+
+    /// b_{k-1} と b_k の入れ替えに伴う係数の更新（Cohen, Algorithm 2.6.3）
+    fn swap(&mut self, k: usize) {
+        let n = self.norms.len();
+        let m = self.mu[k][k - 1].clone();
+        let big = &self.norms[k] + &m * &m * &self.norms[k - 1];
+        self.mu[k][k - 1] = &m * &self.norms[k - 1] / &big;
+        self.norms[k] = &self.norms[k - 1] * &self.norms[k] / &big;
+        self.norms[k - 1] = big;
+        for j in 0..k - 1 {
+            let tmp = self.mu[k][j].clone();
+            self.mu[k][j] = std::mem::replace(&mut self.mu[k - 1][j], tmp);
+        }
+        for i in (k + 1)..n {
+            let t = self.mu[i][k].clone();
+            self.mu[i][k] = &self.mu[i][k - 1] - &m * &t;
+            self.mu[i][k - 1] = t + &self.mu[k][k - 1] * &self.mu[i][k];
+        }
+    }
+}
+
+/// 射影部分格子 π_k(L(b_k, ..., b_{end-1})) の最短ベクトルを深さ優先で列挙する
+struct Enumeration<'a> {
+    mu: &'a [Vec<f64>],
+    norms: &'a [f64],
+    k: usize,
+    end: usize,
+    x: Vec<i64>,
+    radius: f64,
+    best: Option<Vec<i64>>,
+}
+
+impl<'a> Enumeration<'a> {
+    /// |b*_k| より短い射影ベクトルがあればその係数（b_k, ..., b_{end-1} について）を返す
+    fn shortest(mu: &'a [Vec<f64>], norms: &'a [f64], k: usize, end: usize) -> Option<Vec<i64>> {
+        if end - k < 2 || !norms[k..end].iter().all(|v| v.is_finite() && *v > 0.0) {
+            return None;
+        }
+        let mut e = Enumeration {
+            mu,
+            norms,
+            k,
+            end,
+            x: vec![0; end - k],
+            radius: ENUM_SLACK * norms[k],
+            best: None,
+        };
+        e.search(end - 1, 0.0);
+        e.best
+    }
+
+    fn search(&mut self, j: usize, partial: f64) {
+        // 上位の係数で決まる中心 c_j = -Σ_{i>j} x_i μ_ij
+        let center = -((j + 1)..self.end)
+            .map(|i| self.x[i - self.k] as f64 * self.mu[i][j])
+            .sum::<f64>();
+        let slack = (self.radius - partial) / self.norms[j];
+        if slack < 0.0 {
+            return;
+        }
+        let w = slack.sqrt();
+        let (lo, hi) = ((center - w).ceil() as i64, (center + w).floor() as i64);
+        for v in lo..=hi {
+            let d = v as f64 - center;
+            let p = partial + d * d * self.norms[j];
+            if p >= self.radius {
+                continue;
+            }
+            self.x[j - self.k] = v;
+            if j == self.k {
+                if self.x.iter().any(|&c| c != 0) {
+                    self.radius = p;
+                    self.best = Some(self.x.clone());
+                }
+            } else {
+                self.search(j - 1, p);
+            }
+        }
+        self.x[j - self.k] = 0;
+    }
+}
+
+/// 整数 LLL（Gram–Schmidt 係数を逐次更新する版）
+fn lll_in_place(b: &mut Matrix<BigInt>, delta: &BigRational) -> Result<()> {
+    let (mut gs, _) = GramSchmidt::new(b)?;
+    let n = b.rows;
+    let mut k = 1;
+    while k < n {
+        size_reduce(b, &mut gs, k, k - 1);
+        let mu = &gs.mu[k][k - 1];
+        if gs.norms[k] < (delta - mu * mu) * &gs.norms[k - 1] {
+            swap_rows(b, k, k - 1);
+            gs.swap(k);
+            k = (k - 1).max(1);
+        } else {
+            for l in (0..k - 1).rev() {
+                size_reduce(b, &mut gs, k, l);
+            }
+            k += 1;
+        }
+    }
+    Ok(())
+}
+
+/// |μ_kl| > 1/2 なら b_k から round(μ_kl) b_l を引く
+fn size_reduce(b: &mut Matrix<BigInt>, gs: &mut GramSchmidt, k: usize, l: usize) {
+    let half = BigRational::new(BigInt::one(), BigInt::from(2));
+    if gs.mu[k][l].abs() <= half {
+        return;
+    }
+    let q = gs.mu[k][l].round();
+    let qi = q.to_integer();
+    for c in 0..b.cols {
+        let delta = &qi * &b[(l, c)];
+        b[(k, c)] -= delta;
+    }
+    for j in 0..l {
+        let delta = &q * &gs.mu[l][j];
+        gs.mu[k][j] -= delta;
+    }
+    gs.mu[k][l] -= q;
+}
+
+/// v = Σ x_i b_{k+i} が b_k になるよう、2×2 のユニモジュラ変換で b_k, ..., b_{k+len-1} を組み替える
+fn insert_vector(b: &mut Matrix<BigInt>, k: usize, x: &[i64]) {
+    let mut lead = BigInt::from(x[0]);
+    for (j, &xj) in x.iter().enumerate().skip(1) {
+        if xj == 0 {
+            continue;
+        }
+        let xj = BigInt::from(xj);
+        // (lead, xj) → (g, 0) と係数を移すと、基底側は [[lead/g, xj/g], [-t, s]] で変わる
+        let (g, s, t) = extended_gcd(&lead, &xj);
+        let (a0, aj) = (&lead / &g, &xj / &g);
+        for c in 0..b.cols {
+            let (u, w) = (b[(k, c)].clone(), b[(k + j, c)].clone());
+            b[(k, c)] = &a0 * &u + &aj * &w;
+            b[(k + j, c)] = -&t * &u + &s * &w;
+        }
+        lead = g;
+    }
+}
+
+fn lovasz_constant(delta: f64) -> Result<BigRational> {
+    if !(delta > 0.25 && delta <= 1.0) {
+        return Err(LinalgError::InvalidArgument {
+            text: format!("delta must satisfy 1/4 < delta <= 1, got {delta}"),
+        });
+    }
+    BigRational::from_float(delta).ok_or_else(|| LinalgError::InvalidArgument {
+        text: format!("invalid delta {delta}"),
+    })
+}
+
+fn row(b: &Matrix<BigInt>, i: usize) -> &[BigInt] {
+    &b.data[i * b.cols..(i + 1) * b.cols]
+}
+
+fn swap_rows(b: &mut Matrix<BigInt>, r1: usize, r2: usize) {
+    for c in 0..b.cols {
+        b.data.swap(r1 * b.cols + c, r2 * b.cols + c);
+    }
+}
+
+fn dot(a: &[BigRational], b: &[BigRational]) -> BigRational {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
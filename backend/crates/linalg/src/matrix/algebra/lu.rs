@@ -1,13 +1,13 @@
-use crate::traits::LinalgField;
+use crate::traits::ComplexField;
 use crate::{LinalgError, Matrix, Result, Vector};
 
-pub struct LU<T: LinalgField> {
+pub struct LU<T: ComplexField> {
     pub p: Matrix<T>,
     pub l: Matrix<T>,
     pub u: Matrix<T>,
 }
 
-impl<T: LinalgField> Matrix<T> {
+impl<T: ComplexField> Matrix<T> {
     pub fn lu_decompose(&self) -> Result<LU<T>> {
         if !self.is_square() {
             return Err(LinalgError::NotSquareMatrix);
@@ -19,25 +19,27 @@ impl<T: LinalgField> Matrix<T> {
 
         for k in 0..n {
             // pivot
-            let mut max_val = T::zero();
+            let mut max_val = 0.0;
             let mut pivot_row = k;
             for i in k..n {
-                let v = u[(i, k)].clone().abs();
+                let v = u[(i, k)].modulus();
                 if v > max_val {
                     max_val = v;
                     pivot_row = i;
                 }
             }
-            if max_val <= T::epsilon() {
+            if max_val <= T::tolerance() {
                 return Err(LinalgError::SingularMatrix);
             }
 
             if pivot_row != k {
                 p.swap_rows(k, pivot_row)?;
                 u.swap_rows(k, pivot_row)?;
+                // 既に確定した L の列（0..k）も同じ行入替を受ける
                 for j in 0..k {
+                    let tmp = l[(k, j)].clone();
                     l[(k, j)] = l[(pivot_row, j)].clone();
-                    l[(pivot_row, j)] = T::zero();
+                    l[(pivot_row, j)] = tmp;
                 }
             }
 
@@ -0,0 +1,62 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+
+use crate::{LinalgError, Matrix, Result};
+
+/// 厳密な有理数行列（教材用の分数表示や記号的な計算向け）。
+/// `rref` / `determinant` / `inverse` など `Field` 向けの操作はそのまま厳密に動く。
+impl Matrix<BigRational> {
+    /// 整数の成分から有理数行列を作る
+    pub fn from_integers(rows: usize, cols: usize, data: Vec<i64>) -> Result<Self> {
+        let data = data
+            .into_iter()
+            .map(|v| BigRational::from_integer(BigInt::from(v)))
+            .collect();
+        Matrix::new(rows, cols, data)
+    }
+
+    /// (分子, 分母) の組から有理数行列を作る。分母が 0 ならエラー。
+    pub fn from_fractions(rows: usize, cols: usize, data: Vec<(i64, i64)>) -> Result<Self> {
+        let data = data
+            .into_iter()
+            .map(|(p, q)| {
+                if q == 0 {
+                    Err(LinalgError::InvalidArgument {
+                        text: "denominator must be nonzero".into(),
+                    })
+                } else {
+                    Ok(BigRational::new(BigInt::from(p), BigInt::from(q)))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Matrix::new(rows, cols, data)
+    }
+
+    /// f64 行列を丸めずにそのまま有理数へ変換する（2 進小数は分母が 2 のべきになる）
+    pub fn from_f64_exact(m: &Matrix<f64>) -> Result<Self> {
+        let data = m
+            .data
+            .iter()
+            .map(|&v| {
+                BigRational::from_float(v).ok_or_else(|| LinalgError::InvalidArgument {
+                    text: format!("cannot convert non-finite value {v} to a rational"),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Matrix::new(m.rows, m.cols, data)
+    }
+
+    /// 最も近い f64 への変換
+    pub fn to_f64(&self) -> Matrix<f64> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .map(|v| v.to_f64().unwrap_or(f64::NAN))
+                .collect(),
+        }
+    }
+}
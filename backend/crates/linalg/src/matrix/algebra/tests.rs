@@ -1,5 +1,7 @@
 use crate::matrix::Matrix;
-use crate::Vector;
+use crate::test_util::pseudo_random_matrix;
+use crate::{LinalgError, Vector};
+use num_traits::Signed;
 use std::panic;
 
 fn approx(a: f64, b: f64, tol: f64) -> bool {
@@ -204,3 +206,428 @@ fn field_solve_generic_and_matrix_basic_upper_triangular() {
     let solved = a.solve_matrix_generic(&b2).unwrap();
     assert_matrix_approx_eq(&solved, &x2, 1e-10);
 }
+
+fn naive_product(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64> {
+    let mut c = Matrix::zeros(a.rows, b.cols);
+    for i in 0..a.rows {
+        for j in 0..b.cols {
+            for k in 0..a.cols {
+                c[(i, j)] += a[(i, k)] * b[(k, j)];
+            }
+        }
+    }
+    c
+}
+
+#[test]
+fn blocked_gemm_matches_naive_on_ragged_shapes() {
+    // ブロック境界（MR, NR, MC, KC）をまたぐ半端なサイズ
+    for &(m, k, n) in &[
+        (1, 1, 1),
+        (5, 3, 7),
+        (67, 301, 45),
+        (130, 259, 9),
+        (3, 520, 70),
+    ] {
+        let a = pseudo_random_matrix(m, k, 1);
+        let b = pseudo_random_matrix(k, n, 2);
+        let c = a.gemm_mul(&b).unwrap();
+        let expected = naive_product(&a, &b);
+        for (x, y) in c.data.iter().zip(expected.data.iter()) {
+            assert!(approx(*x, *y, 1e-10), "{m}x{k}x{n}: {x} vs {y}");
+        }
+    }
+
+    let af: Matrix<f32> =
+        Matrix::new(40, 50, (0..2000).map(|v| (v % 13) as f32).collect()).unwrap();
+    let bf: Matrix<f32> = Matrix::new(50, 30, (0..1500).map(|v| (v % 7) as f32).collect()).unwrap();
+    let cf = af.gemm_mul(&bf).unwrap();
+    for i in [0, 17, 39] {
+        for j in [0, 11, 29] {
+            let expected: f32 = (0..50).map(|k| af[(i, k)] * bf[(k, j)]).sum();
+            assert_eq!(cf[(i, j)], expected);
+        }
+    }
+}
+
+#[test]
+fn generic_ring_product_uses_exact_arithmetic() {
+    let a: Matrix<i64> = Matrix::new(2, 3, vec![1, -2, 3, 4, 5, -6]).unwrap();
+    let b: Matrix<i64> = Matrix::new(3, 2, vec![7, 8, 9, 10, -11, 12]).unwrap();
+    let c = a.checked_mul(&b).unwrap();
+    assert_eq!(c.data, vec![-44, 24, 139, 10]);
+
+    // Clone + Debug なら何でも要素にできる（Scalar はブランケット実装）
+    let labels: Matrix<&str> = Matrix::new(1, 2, vec!["x", "y"]).unwrap();
+    assert_eq!(labels.transpose().data, vec!["x", "y"]);
+}
+
+#[test]
+fn strassen_matches_blocked_product() {
+    let a = pseudo_random_matrix(300, 257, 3);
+    let b = pseudo_random_matrix(257, 310, 4);
+    let s = a.strassen_mul(&b).unwrap();
+    let c = &a * &b;
+    assert_eq!((s.rows, s.cols), (300, 310));
+    for (x, y) in s.data.iter().zip(c.data.iter()) {
+        assert!(approx(*x, *y, 1e-9), "{x} vs {y}");
+    }
+    assert!(a.strassen_mul(&a).is_err());
+}
+
+#[test]
+fn one_inf_and_two_norms() {
+    let a = Matrix::new(2, 3, vec![1.0, -2.0, 3.0, -4.0, 5.0, -6.0]).unwrap();
+    assert_eq!(a.one_norm(), 9.0);
+    assert_eq!(a.inf_norm(), 15.0);
+    let d = Matrix::new(3, 3, vec![3.0, 0.0, 0.0, 0.0, -5.0, 0.0, 0.0, 0.0, 0.5]).unwrap();
+    assert!(approx(d.two_norm().unwrap(), 5.0, 1e-12));
+    assert!(approx(d.cond().unwrap(), 10.0, 1e-10));
+    let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+    assert!(singular.cond().unwrap() > 1e15);
+}
+
+#[test]
+fn condition_estimate_is_close_to_exact_inverse_norm() {
+    for seed in 0..5 {
+        let n = 6 + seed;
+        let mut a = pseudo_random_matrix(n, n, seed as u64 + 11);
+        for i in 0..n {
+            a[(i, i)] += 0.5;
+        }
+        let exact = a.inverse_checked().unwrap().one_norm();
+        let estimate = a
+            .lu_decompose()
+            .unwrap()
+            .inverse_one_norm_estimate()
+            .unwrap();
+        // Hager–Higham 推定は下界で、実用上は 3 倍以内
+        assert!(estimate <= exact * (1.0 + 1e-12), "{estimate} > {exact}");
+        assert!(estimate >= exact / 3.0, "{estimate} << {exact}");
+    }
+    let eye: Matrix<f64> = Matrix::identity(4);
+    assert!(approx(eye.rcond().unwrap(), 1.0, 1e-12));
+}
+
+#[test]
+fn complex_condition_estimate_matches_exact() {
+    use num_complex::Complex;
+    let c = |re: f64, im: f64| Complex::new(re, im);
+    let a = Matrix::new(
+        3,
+        3,
+        vec![
+            c(2.0, 1.0),
+            c(0.0, -1.0),
+            c(1.0, 0.0),
+            c(1.0, 1.0),
+            c(3.0, 0.0),
+            c(0.0, 2.0),
+            c(0.5, 0.0),
+            c(-1.0, 1.0),
+            c(4.0, -1.0),
+        ],
+    )
+    .unwrap();
+    let exact = a.one_norm() * a.inverse_checked().unwrap().one_norm();
+    let rcond = a.rcond().unwrap();
+    assert!(rcond >= 1.0 / exact * (1.0 - 1e-12));
+    assert!(rcond <= 3.0 / exact);
+}
+
+#[test]
+fn ill_conditioned_solve_is_reported() {
+    // 1e8 の ulp は 2^-26。行列式は 1e8 * 2^-26 ≈ 1.49 で条件数は約 2.7e16
+    let a = Matrix::new(2, 2, vec![1e8, 1e8, 1e8, 1e8 + 2f64.powi(-26)]).unwrap();
+    let b = Vector::new(vec![1.0, 2.0]);
+    let (_, rcond) = a.solve_with_rcond(&b).unwrap();
+    assert!(rcond < f64::EPSILON, "rcond = {rcond}");
+    assert!(matches!(
+        a.solve_checked(&b),
+        Err(LinalgError::IllConditioned { rcond: r }) if r == rcond
+    ));
+    // solve / solve_matrix は判定しない（従来どおり解を返す）
+    assert!(a.solve(&b).is_ok());
+    assert!(a.solve_matrix(&Matrix::identity(2)).is_ok());
+    assert!(matches!(
+        a.solve_matrix_checked(&Matrix::identity(2)),
+        Err(LinalgError::IllConditioned { .. })
+    ));
+    assert!(matches!(
+        a.inverse_checked(),
+        Err(LinalgError::IllConditioned { .. })
+    ));
+
+    let well = Matrix::new(2, 2, vec![4.0, 1.0, 2.0, 3.0]).unwrap();
+    let x = well.solve_checked(&b).unwrap();
+    assert!(approx(x[0], 0.1, 1e-12) && approx(x[1], 0.6, 1e-12));
+    let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+    assert_eq!(singular.rcond().unwrap(), 0.0);
+    assert!(matches!(
+        singular.solve_checked(&b),
+        Err(LinalgError::SingularMatrix)
+    ));
+}
+
+fn ratio(p: i64, q: i64) -> crate::BigRational {
+    crate::BigRational::new(p.into(), q.into())
+}
+
+#[test]
+fn rational_hilbert_determinant_and_inverse_are_exact() {
+    // 4 次 Hilbert 行列 H_ij = 1 / (i + j + 1)
+    let h: Matrix<crate::BigRational> = Matrix::from_fractions(
+        4,
+        4,
+        (0..16).map(|k| (1, (k / 4 + k % 4 + 1) as i64)).collect(),
+    )
+    .unwrap();
+    let expected = ratio(1, 6_048_000);
+    assert_eq!(h.determinant().unwrap(), expected);
+    assert_eq!(h.determinant_bareiss().unwrap(), expected);
+
+    // 逆行列は整数行列になる
+    let inv = h.inverse().expect("Hilbert matrix is invertible");
+    assert_eq!(inv[(0, 0)], ratio(16, 1));
+    assert_eq!(inv[(0, 3)], ratio(-140, 1));
+    assert_eq!(inv[(3, 3)], ratio(2800, 1));
+    assert!(inv.data.iter().all(|v| v.is_integer()));
+    assert_eq!(&h * &inv, Matrix::identity(4));
+}
+
+#[test]
+fn bareiss_determinant_stays_in_integers() {
+    // 先頭ピボットが 0 なので行交換が必要
+    let a: Matrix<i64> = Matrix::new(3, 3, vec![0, 2, 1, 1, 1, 1, 2, 1, 3]).unwrap();
+    assert_eq!(a.determinant_bareiss().unwrap(), -3);
+
+    let big: Matrix<crate::BigInt> = Matrix::new(
+        3,
+        3,
+        [4, -2, 7, 3, 5, -1, 2, 8, 6]
+            .iter()
+            .map(|&v| crate::BigInt::from(v))
+            .collect(),
+    )
+    .unwrap();
+    assert_eq!(big.determinant_bareiss().unwrap(), crate::BigInt::from(290));
+
+    let singular: Matrix<i64> = Matrix::new(2, 2, vec![1, 2, 2, 4]).unwrap();
+    assert_eq!(singular.determinant_bareiss().unwrap(), 0);
+    assert!(Matrix::<i64>::zeros(2, 3).determinant_bareiss().is_err());
+}
+
+#[test]
+fn rational_rref_and_display_show_fractions() {
+    let a = Matrix::<crate::BigRational>::from_integers(2, 3, vec![2, 1, 1, 1, 3, 2]).unwrap();
+    let rref = a.rref().unwrap();
+    let expected =
+        Matrix::from_fractions(2, 3, vec![(1, 1), (0, 1), (1, 5), (0, 1), (1, 1), (3, 5)]).unwrap();
+    assert_eq!(rref, expected);
+    let shown = format!("{rref}");
+    assert!(shown.contains("1/5") && shown.contains("3/5"), "{shown}");
+
+    let singular = Matrix::<crate::BigRational>::from_integers(2, 2, vec![1, 2, 2, 4]).unwrap();
+    assert_eq!(singular.rank().unwrap(), 1);
+    assert!(singular.inverse().is_none());
+    assert!(Matrix::<crate::BigRational>::from_fractions(1, 1, vec![(1, 0)]).is_err());
+}
+
+#[test]
+fn rational_conversion_from_f64_is_exact() {
+    let m = Matrix::new(1, 2, vec![0.5, 0.1]).unwrap();
+    let r = Matrix::<crate::BigRational>::from_f64_exact(&m).unwrap();
+    assert_eq!(r[(0, 0)], ratio(1, 2));
+    // 0.1 は 2 進で正確に表せないので 1/10 にはならない
+    assert_ne!(r[(0, 1)], ratio(1, 10));
+    assert_eq!(r.to_f64(), m);
+    let nan = Matrix::new(1, 1, vec![f64::NAN]).unwrap();
+    assert!(Matrix::<crate::BigRational>::from_f64_exact(&nan).is_err());
+}
+
+fn int_matrix(rows: usize, cols: usize, data: &[i64]) -> Matrix<i64> {
+    Matrix::new(rows, cols, data.to_vec()).unwrap()
+}
+
+fn is_unimodular(u: &Matrix<i64>) -> bool {
+    u.determinant_bareiss().unwrap().abs() == 1
+}
+
+#[test]
+fn hermite_normal_form_matches_known_example() {
+    let a = int_matrix(3, 4, &[2, 3, 6, 2, 5, 6, 1, 6, 8, 3, 1, 1]);
+    let hnf = a.hermite_normal_form();
+    let expected = int_matrix(3, 4, &[1, 0, 50, -11, 0, 3, 28, -2, 0, 0, 61, -13]);
+    assert_eq!(hnf.h, expected);
+    assert_eq!(hnf.pivots, vec![0, 1, 2]);
+    assert_eq!(&hnf.u * &a, hnf.h);
+    assert!(is_unimodular(&hnf.u));
+
+    // ランク落ちでは零行が下に集まる
+    let b = int_matrix(3, 3, &[2, 4, 6, 1, 2, 3, 0, 3, 3]);
+    let hnf = b.hermite_normal_form();
+    assert_eq!(hnf.rank(), 2);
+    assert_eq!(hnf.pivots, vec![0, 1]);
+    assert!((0..3).all(|j| hnf.h[(2, j)] == 0));
+    assert_eq!(&hnf.u * &b, hnf.h);
+}
+
+#[test]
+fn smith_normal_form_has_dividing_invariant_factors() {
+    let a = int_matrix(3, 3, &[2, 4, 4, -6, 6, 12, 10, -4, -16]);
+    let snf = a.smith_normal_form();
+    assert_eq!(snf.invariant_factors(), vec![2, 6, 12]);
+    assert_eq!(&(&snf.u * &a) * &snf.v, snf.d);
+    assert!(is_unimodular(&snf.u) && is_unimodular(&snf.v));
+
+    // 長方形・ランク落ち・BigInt
+    let big: Matrix<crate::BigInt> = Matrix::new(
+        2,
+        4,
+        [6, 4, 0, 10, 9, 6, 0, 15]
+            .iter()
+            .map(|&v| crate::BigInt::from(v))
+            .collect(),
+    )
+    .unwrap();
+    let snf = big.smith_normal_form();
+    assert_eq!(snf.invariant_factors(), vec![crate::BigInt::from(1)]);
+    assert_eq!(snf.rank(), 1);
+    assert_eq!(&(&snf.u * &big) * &snf.v, snf.d);
+}
+
+#[test]
+fn integer_kernel_and_image() {
+    let a = int_matrix(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let kernel = a.integer_kernel();
+    assert_eq!((kernel.rows, kernel.cols), (3, 1));
+    let k: Vec<i64> = kernel.data.clone();
+    assert!(k == vec![1, -2, 1] || k == vec![-1, 2, -1], "{k:?}");
+
+    // 列が生成する格子の指数は |det A| = 8
+    let b = int_matrix(2, 2, &[2, 4, 6, 8]);
+    let image = b.integer_image();
+    assert_eq!(image.determinant_bareiss().unwrap().abs(), 8);
+    assert_eq!(int_matrix(2, 2, &[1, 0, 0, 1]).integer_kernel().cols, 0);
+}
+
+#[test]
+fn diophantine_systems() {
+    // 6x + 10y + 15z = 1 は解を持ち、解空間は 2 次元の格子だけずれる
+    let a = int_matrix(1, 3, &[6, 10, 15]);
+    let sol = a
+        .solve_diophantine(&Vector::new(vec![1]))
+        .unwrap()
+        .expect("gcd(6, 10, 15) = 1");
+    assert_eq!(a.checked_mul_vector(&sol.particular).unwrap().data, vec![1]);
+    assert_eq!(sol.kernel.cols, 2);
+    assert!((&a * &sol.kernel).data.iter().all(|&v| v == 0));
+
+    // 2x + 4y = 3 は整数解を持たない（有理数解はある）
+    let b = int_matrix(1, 2, &[2, 4]);
+    assert!(b
+        .solve_diophantine(&Vector::new(vec![3]))
+        .unwrap()
+        .is_none());
+    // 矛盾する連立方程式
+    let c = int_matrix(2, 2, &[1, 1, 2, 2]);
+    assert!(c
+        .solve_diophantine(&Vector::new(vec![1, 3]))
+        .unwrap()
+        .is_none());
+    assert!(matches!(
+        c.solve_diophantine(&Vector::new(vec![1])),
+        Err(LinalgError::DimensionMismatch { .. })
+    ));
+}
+
+fn big_matrix(rows: usize, cols: usize, data: &[i64]) -> Matrix<crate::BigInt> {
+    Matrix::new(rows, cols, data.iter().map(|&v| v.into()).collect()).unwrap()
+}
+
+fn squared_norm(m: &Matrix<crate::BigInt>, i: usize) -> crate::BigInt {
+    (0..m.cols).map(|j| &m[(i, j)] * &m[(i, j)]).sum()
+}
+
+#[test]
+fn lll_reduces_known_basis() {
+    let b = big_matrix(3, 3, &[1, 1, 1, -1, 0, 2, 3, 5, 6]);
+    assert!(!b.is_lll_reduced(0.75).unwrap());
+    let reduced = b.lll_reduce(0.75).unwrap();
+    assert_eq!(reduced, big_matrix(3, 3, &[0, 1, 0, 1, 0, 1, -1, 0, 2]));
+    assert!(reduced.is_lll_reduced(0.75).unwrap());
+    // 同じ格子なので |det| は変わらない
+    assert_eq!(
+        reduced.determinant_bareiss().unwrap().abs(),
+        b.determinant_bareiss().unwrap().abs()
+    );
+
+    let dependent = big_matrix(2, 2, &[1, 2, 2, 4]);
+    assert!(dependent.lll_reduce(0.75).is_err());
+    assert!(b.lll_reduce(0.2).is_err());
+}
+
+#[test]
+fn bkz_finds_a_vector_no_longer_than_lll() {
+    // ナップサック型の格子 [I | w_i]（短い 0/±1 ベクトルが埋め込まれている）
+    let weights = [
+        366_577, 413_011, 284_023, 103_421, 471_191, 222_863, 397_867, 154_303,
+    ];
+    let n = weights.len();
+    let sum: i64 = [0, 2, 3, 6].iter().map(|&i| weights[i]).sum();
+    let mut data = Vec::new();
+    for (i, w) in weights.iter().enumerate() {
+        data.extend((0..n).map(|j| i64::from(i == j)));
+        data.push(*w);
+    }
+    data.extend(std::iter::repeat_n(0, n));
+    data.push(-sum);
+    let b = big_matrix(n + 1, n + 1, &data);
+
+    let lll = b.lll_reduce(0.99).unwrap();
+    let bkz = b.bkz_reduce(6, 0.99).unwrap();
+    assert!(bkz.is_lll_reduced(0.99).unwrap());
+    assert_eq!(
+        bkz.determinant_bareiss().unwrap().abs(),
+        b.determinant_bareiss().unwrap().abs()
+    );
+    assert!(squared_norm(&bkz, 0) <= squared_norm(&lll, 0));
+    // 部分和の解 (1,0,1,1,0,0,1,0 | 0) の長さ 2 まで縮む
+    assert!(squared_norm(&bkz, 0) <= 4.into());
+    assert!(b.bkz_reduce(1, 0.99).is_err());
+}
+
+#[test]
+fn babai_recovers_nearby_lattice_point() {
+    let b = big_matrix(3, 3, &[10, 1, 0, 1, 11, 2, 0, 2, 12]);
+    let reduced = b.lll_reduce(0.99).unwrap();
+    // 格子点 3 b_0 - 2 b_1 + b_2 = (28, -17, 8) に小さな誤差を加える
+    let target = Vector::new(vec![29.into(), (-18).into(), 9.into()]);
+    let closest = reduced.babai_nearest_plane(&target).unwrap();
+    assert_eq!(
+        closest,
+        Vector::new(vec![28.into(), (-17).into(), 8.into()])
+    );
+    assert!(reduced
+        .babai_nearest_plane(&Vector::new(vec![1.into()]))
+        .is_err());
+}
+
+#[test]
+fn integer_relation_for_golden_ratio() {
+    // φ^2 = φ + 1 なので 1 + φ - φ^2 = 0
+    let phi = (1.0 + 5f64.sqrt()) / 2.0;
+    let relation = Vector::new(vec![1.0, phi, phi * phi])
+        .integer_relation(1e8)
+        .unwrap();
+    let coeffs: Vec<i64> = relation
+        .data
+        .iter()
+        .map(|v| v.try_into().unwrap())
+        .collect();
+    assert!(
+        coeffs == vec![1, 1, -1] || coeffs == vec![-1, -1, 1],
+        "{coeffs:?}"
+    );
+    assert!(Vector::new(vec![1.0]).integer_relation(1e8).is_err());
+}
@@ -19,6 +19,10 @@ mod algebra;
 mod core;
 pub mod numerical;
 mod ops;
+mod view;
 
 // パブリックな再エクスポート
+pub use algebra::integer::{DiophantineSolution, HermiteNormalForm, SmithNormalForm};
+pub use algebra::lu::LU;
 pub use ops::DisplayElement;
+pub use view::{MatrixView, MatrixViewMut};
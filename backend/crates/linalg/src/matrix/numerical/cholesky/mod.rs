@@ -1,7 +1,13 @@
-use crate::{Matrix, Result}; // Result型はエラーを返すためにあると仮定
+use num_complex::Complex;
 
-pub trait CholeskyDecomposition {
-    fn cholesky(&self) -> Result<Matrix<f64>>;
+use crate::{LinalgError, Matrix, Result};
+
+#[cfg(test)]
+mod tests;
+
+pub trait CholeskyDecomposition: Sized {
+    /// A = L L^H（実数では L L^T）となる下三角 L。対角は正の実数。
+    fn cholesky(&self) -> Result<Self>;
 }
 
 impl CholeskyDecomposition for Matrix<f64> {
@@ -9,9 +15,7 @@ impl CholeskyDecomposition for Matrix<f64> {
         let n = self.rows;
         // 正方行列であるかのチェック
         if n != self.cols {
-            // エラーを返すのが望ましい
-            // return Err(...);
-            panic!("Matrix must be square.");
+            return Err(LinalgError::NotSquareMatrix);
         }
 
         // 結果を格納するL行列（下三角行列）
@@ -33,8 +37,9 @@ impl CholeskyDecomposition for Matrix<f64> {
                     let val = self[(i, i)] - sum;
                     if val <= 0.0 {
                         // 行列が正定値ではない
-                        // return Err(...);
-                        panic!("Matrix is not positive-definite.");
+                        return Err(LinalgError::InvalidArgument {
+                            text: "Matrix is not positive-definite".into(),
+                        });
                     }
                     l_data[i * n + i] = val.sqrt();
                 } else {
@@ -49,3 +54,36 @@ impl CholeskyDecomposition for Matrix<f64> {
         Matrix::new(n, n, l_data)
     }
 }
+
+impl CholeskyDecomposition for Matrix<Complex<f64>> {
+    fn cholesky(&self) -> Result<Matrix<Complex<f64>>> {
+        let n = self.rows;
+        if n != self.cols {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+
+        let mut l: Matrix<Complex<f64>> = Matrix::zeros(n, n);
+        for i in 0..n {
+            // 対角: l_ii = sqrt(a_ii - Σ |l_ik|^2)（Hermite なので a_ii は実数）
+            let sum: f64 = (0..i).map(|k| l[(i, k)].norm_sqr()).sum();
+            let val = self[(i, i)].re - sum;
+            if val <= 0.0 {
+                return Err(LinalgError::InvalidArgument {
+                    text: "Matrix is not positive-definite".into(),
+                });
+            }
+            let lii = val.sqrt();
+            l[(i, i)] = Complex::new(lii, 0.0);
+
+            // 非対角: l_ji = (a_ji - Σ l_jk conj(l_ik)) / l_ii
+            for j in (i + 1)..n {
+                let mut s = self[(j, i)];
+                for k in 0..i {
+                    s -= l[(j, k)] * l[(i, k)].conj();
+                }
+                l[(j, i)] = s / lii;
+            }
+        }
+        Ok(l)
+    }
+}
@@ -0,0 +1,61 @@
+use num_complex::Complex;
+
+use super::*;
+
+#[test]
+fn cholesky_real_reconstructs() {
+    let a = Matrix::new(
+        3,
+        3,
+        vec![4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0],
+    )
+    .unwrap();
+    let l = a.cholesky().expect("cholesky failed");
+    let expected = Matrix::new(3, 3, vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]).unwrap();
+    assert_eq!(l, expected);
+
+    let not_pd = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 1.0]).unwrap();
+    assert!(not_pd.cholesky().is_err());
+}
+
+#[test]
+fn cholesky_complex_reconstructs() {
+    let c = |re: f64, im: f64| Complex::new(re, im);
+    let a = Matrix::new(
+        3,
+        3,
+        vec![
+            c(6.0, 0.0),
+            c(1.0, -2.0),
+            c(0.0, 1.0),
+            c(1.0, 2.0),
+            c(5.0, 0.0),
+            c(-1.0, -1.0),
+            c(0.0, -1.0),
+            c(-1.0, 1.0),
+            c(4.0, 0.0),
+        ],
+    )
+    .unwrap();
+    let l = a.cholesky().expect("complex cholesky failed");
+    for i in 0..3 {
+        assert!(l[(i, i)].im == 0.0 && l[(i, i)].re > 0.0);
+        for j in (i + 1)..3 {
+            assert_eq!(l[(i, j)], c(0.0, 0.0));
+        }
+    }
+    let llh = &l * &l.conjugate_transpose();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((llh[(i, j)] - a[(i, j)]).norm() < 1e-12);
+        }
+    }
+
+    let not_pd = Matrix::new(
+        2,
+        2,
+        vec![c(1.0, 0.0), c(0.0, 2.0), c(0.0, -2.0), c(1.0, 0.0)],
+    )
+    .unwrap();
+    assert!(not_pd.cholesky().is_err());
+}
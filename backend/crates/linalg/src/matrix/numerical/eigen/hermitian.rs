@@ -0,0 +1,99 @@
+use num_complex::Complex;
+
+use crate::matrix::numerical::helpers::{
+    apply_householder_left, apply_householder_right, complex_householder,
+};
+use crate::{LinalgError, Matrix, Result};
+
+type C64 = Complex<f64>;
+
+/// Hermite 行列の固有分解 A = V diag(λ) V^H
+pub struct HermitianEigen {
+    /// 昇順の実固有値
+    pub eigen_values: Vec<f64>,
+    /// 正規直交な固有ベクトル（列）
+    pub eigen_vectors: Matrix<C64>,
+}
+
+pub trait HermitianEigenDecomposition {
+    /// Hermite 行列専用の固有分解。非 Hermite な入力は InvalidArgument を返す。
+    fn hermitian_eigen(&self) -> Result<HermitianEigen>;
+}
+
+impl HermitianEigenDecomposition for Matrix<C64> {
+    fn hermitian_eigen(&self) -> Result<HermitianEigen> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let n = self.rows;
+        if n == 0 {
+            return Ok(HermitianEigen {
+                eigen_values: vec![],
+                eigen_vectors: Matrix::zeros(0, 0),
+            });
+        }
+        let scale = self.data.iter().fold(0.0f64, |m, v| m.max(v.norm()));
+        let herm_tol = 1e-10 * scale.max(1.0);
+        for i in 0..n {
+            for j in i..n {
+                if (self[(i, j)] - self[(j, i)].conj()).norm() > herm_tol {
+                    return Err(LinalgError::InvalidArgument {
+                        text: "hermitian_eigen requires a Hermitian matrix".into(),
+                    });
+                }
+            }
+        }
+
+        // 1. Householder で三重対角化 T = Q^H A Q
+        let mut t = self.clone();
+        let mut q: Matrix<C64> = Matrix::identity(n);
+        for k in 0..n.saturating_sub(2) {
+            let x: Vec<C64> = ((k + 1)..n).map(|i| t[(i, k)]).collect();
+            if let Some(v) = complex_householder(&x) {
+                apply_householder_left(&mut t, &v, k + 1, 0);
+                apply_householder_right(&mut t, &v, k + 1);
+                apply_householder_right(&mut q, &v, k + 1);
+            }
+        }
+
+        // 2. 対角ユニタリ D で副対角を非負実数にする: T = D S D^H（S は実対称三重対角）
+        let mut phase = vec![C64::new(1.0, 0.0); n];
+        let mut d = vec![0.0; n];
+        let mut e = vec![0.0; n];
+        for i in 0..n {
+            d[i] = t[(i, i)].re;
+            if i + 1 < n {
+                let o = t[(i + 1, i)];
+                e[i + 1] = o.norm();
+                phase[i + 1] = if o.norm() == 0.0 {
+                    phase[i]
+                } else {
+                    phase[i] * o / o.norm()
+                };
+            }
+        }
+
+        // 3. 実三重対角の QL 法で S = W Λ W^T
+        let mut w: Matrix<f64> = Matrix::identity(n);
+        Matrix::tridiagonal_ql(&mut w, &mut d, &mut e)?;
+
+        // 4. 固有ベクトル V = Q D W を昇順に並べる
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| d[a].partial_cmp(&d[b]).unwrap_or(std::cmp::Ordering::Equal));
+        let mut vecs: Matrix<C64> = Matrix::zeros(n, n);
+        for (col, &k) in order.iter().enumerate() {
+            for i in 0..n {
+                let mut s = C64::new(0.0, 0.0);
+                for j in 0..n {
+                    s += q[(i, j)] * phase[j] * w[(j, k)];
+                }
+                vecs[(i, col)] = s;
+            }
+        }
+
+        Ok(HermitianEigen {
+            eigen_values: order.iter().map(|&k| d[k]).collect(),
+            eigen_vectors: vecs,
+        })
+    }
+}
@@ -22,9 +22,16 @@ pub trait EigenDecomposition {
     /// 行列が正方行列でない場合はNoneを返す。
     fn eigen_decomposition(&self) -> crate::Result<Eigen>;
     fn eigen_decomposition_complex(&self) -> crate::Result<EigenComplex>;
+    /// 対称行列専用の固有分解。固有値は昇順の実数、固有ベクトルは正規直交な列を持つ。
+    /// 非対称な入力は InvalidArgument を返す。
+    fn symmetric_eigen(&self) -> crate::Result<Eigen>;
 }
 
 impl EigenDecomposition for Matrix<f64> {
+    fn symmetric_eigen(&self) -> crate::Result<Eigen> {
+        self.symmetric_eigen_tridiagonal_ql()
+    }
+
     fn eigen_decomposition(&self) -> crate::Result<Eigen> {
         // --- エッジケースの事前処理 (変更なし) ---
         if self.rows == 0 {
@@ -185,7 +192,7 @@ impl EigenDecomposition for Matrix<f64> {
         let (mut h, mut q) = self
             .to_hessenberg()
             .ok_or(crate::LinalgError::InvalidArgument { text: "Hessenberg reduction failed".into() })?;
-        if !Self::qr_iteration_to_schur(&mut h, &mut q, 1e-12) {
+        if Self::qr_iteration_to_schur(&mut h, &mut q, 1e-12).is_err() {
             // 最後の手段としてフォールバック
             return Self::eigen_decomposition_complex_via_roots(self);
         }
@@ -283,63 +290,253 @@ impl Matrix<f64> {
         }
     }
 
-    /// 明示的なQR反復: H_k = R_k Q_k（トップレフト end×end）を繰り返し、デフレーションで end を縮める。
-    fn qr_iteration_to_schur(h: &mut Matrix<f64>, q: &mut Matrix<f64>, tol: f64) -> bool {
-        let n = h.rows;
-        let mut end = n;
-        let max_iter = 5000usize;
-
-        while end > 0 {
-            let mut progressed = false;
-            for _ in 0..max_iter {
-                let Some(qr) = h
-                    .submatrix(0, end, 0, end)
-                    .qr_decomposition()
-                    .ok()
-                else {
-                    return false;
-                };
-                let q_step = qr.q;
-                let r_step = qr.r;
+    /// 上 Hessenberg 行列 `h` を Francis の二重シフト QR 法で実 Schur 形へ変換する（EISPACK hqr2 の Schur 部分）。
+    /// 変換は `v` に右から累積され、`tol` は副対角を 0 とみなす相対許容誤差（ε 未満は ε に切り上げる）。
+    /// 実固有値の 2x2 ブロックは回転で 1x1 に分解する。`schur` と `eigen_decomposition_complex` が共有する。
+    pub(in crate::matrix::numerical) fn qr_iteration_to_schur(
+        h: &mut Matrix<f64>,
+        v: &mut Matrix<f64>,
+        tol: f64,
+    ) -> crate::Result<()> {
+        let nn = h.rows;
+        if nn == 0 {
+            return Ok(());
+        }
+        // 丸めで残った第 1 副対角より下の要素を消してから反復する
+        for i in 2..nn {
+            for j in 0..(i - 1) {
+                h[(i, j)] = 0.0;
+            }
+        }
+        let eps = tol.max(f64::EPSILON);
+        let mut exshift = 0.0;
+        let (mut p, mut q, mut r, mut s, mut z): (f64, f64, f64, f64, f64);
+
+        let mut norm = 0.0;
+        for i in 0..nn {
+            for j in i.saturating_sub(1)..nn {
+                norm += h[(i, j)].abs();
+            }
+        }
 
-                // H <- R Q をトップレフトに反映
-                let rq = &r_step * &q_step;
-                if h.set_submatrix(0, 0, &rq).is_err() {
-                    return false;
+        let max_total_iter = 30 * nn.max(1);
+        let mut total_iter = 0;
+        let mut iter = 0;
+        let mut n = nn as isize - 1;
+        while n >= 0 {
+            let nu = n as usize;
+            // 小さな副対角要素を探す
+            let mut l = nu;
+            while l > 0 {
+                s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+                if s == 0.0 {
+                    s = norm;
                 }
+                if h[(l, l - 1)].abs() < eps * s {
+                    break;
+                }
+                l -= 1;
+            }
 
-                // Q 累積
-                let mut q_full = Matrix::identity(n);
-                if q_full.set_submatrix(0, 0, &q_step).is_err() {
-                    return false;
+            if l == nu {
+                // 1x1 ブロックが収束
+                h[(nu, nu)] += exshift;
+                if nu > 0 {
+                    h[(nu, nu - 1)] = 0.0;
+                }
+                n -= 1;
+                iter = 0;
+            } else if l + 1 == nu {
+                // 2x2 ブロックが収束
+                let w = h[(nu, nu - 1)] * h[(nu - 1, nu)];
+                p = (h[(nu - 1, nu - 1)] - h[(nu, nu)]) / 2.0;
+                q = p * p + w;
+                z = q.abs().sqrt();
+                h[(nu, nu)] += exshift;
+                h[(nu - 1, nu - 1)] += exshift;
+                if nu > 1 {
+                    h[(nu - 1, nu - 2)] = 0.0;
                 }
-                *q = &(*q) * &q_full;
 
-                // 小さいサブ対角を0に
-                for i in 1..end {
-                    if h[(i, i - 1)].abs() <= tol * (h[(i, i)].abs() + h[(i - 1, i - 1)].abs()) {
-                        h[(i, i - 1)] = 0.0;
+                if q >= 0.0 {
+                    // 実固有値の対: 回転で上三角化する
+                    z = if p >= 0.0 { p + z } else { p - z };
+                    let x = h[(nu, nu - 1)];
+                    s = x.abs() + z.abs();
+                    p = x / s;
+                    q = z / s;
+                    r = (p * p + q * q).sqrt();
+                    p /= r;
+                    q /= r;
+                    for j in (nu - 1)..nn {
+                        z = h[(nu - 1, j)];
+                        h[(nu - 1, j)] = q * z + p * h[(nu, j)];
+                        h[(nu, j)] = q * h[(nu, j)] - p * z;
+                    }
+                    for i in 0..=nu {
+                        z = h[(i, nu - 1)];
+                        h[(i, nu - 1)] = q * z + p * h[(i, nu)];
+                        h[(i, nu)] = q * h[(i, nu)] - p * z;
                     }
+                    for i in 0..nn {
+                        z = v[(i, nu - 1)];
+                        v[(i, nu - 1)] = q * z + p * v[(i, nu)];
+                        v[(i, nu)] = q * v[(i, nu)] - p * z;
+                    }
+                    h[(nu, nu - 1)] = 0.0;
+                }
+                n -= 2;
+                iter = 0;
+            } else {
+                total_iter += 1;
+                if total_iter > max_total_iter {
+                    return Err(crate::LinalgError::InvalidArgument {
+                        text: "Maximum total iterations reached".into(),
+                    });
                 }
 
-                // 末尾デフレーションのチェック（1x1 または 2x2）
-                let m = end - 1;
-                if m == 0 || h[(m, m - 1)] == 0.0 {
-                    end -= 1;
-                    progressed = true;
-                    break;
+                // シフトを作る
+                let mut x = h[(nu, nu)];
+                let mut y = h[(nu - 1, nu - 1)];
+                let mut w = h[(nu, nu - 1)] * h[(nu - 1, nu)];
+
+                // Wilkinson の例外シフト
+                if iter == 10 {
+                    exshift += x;
+                    for i in 0..=nu {
+                        h[(i, i)] -= x;
+                    }
+                    s = h[(nu, nu - 1)].abs() + h[(nu - 1, nu - 2)].abs();
+                    x = 0.75 * s;
+                    y = x;
+                    w = -0.4375 * s * s;
                 }
-                if m > 0 && (m == 1 || h[(m - 1, m - 2)] == 0.0) {
-                    end -= 2;
-                    progressed = true;
-                    break;
+                // MATLAB の例外シフト
+                if iter == 30 {
+                    s = (y - x) / 2.0;
+                    s = s * s + w;
+                    if s > 0.0 {
+                        s = s.sqrt();
+                        if y < x {
+                            s = -s;
+                        }
+                        s = x - w / ((y - x) / 2.0 + s);
+                        for i in 0..=nu {
+                            h[(i, i)] -= s;
+                        }
+                        exshift += s;
+                        x = 0.964;
+                        y = x;
+                        w = x;
+                    }
+                }
+                iter += 1;
+
+                // 連続する 2 つの小さな副対角要素を探す
+                let mut m = nu - 2;
+                loop {
+                    z = h[(m, m)];
+                    r = x - z;
+                    s = y - z;
+                    p = (r * s - w) / h[(m + 1, m)] + h[(m, m + 1)];
+                    q = h[(m + 1, m + 1)] - z - r - s;
+                    r = h[(m + 2, m + 1)];
+                    s = p.abs() + q.abs() + r.abs();
+                    p /= s;
+                    q /= s;
+                    r /= s;
+                    if m == l {
+                        break;
+                    }
+                    if h[(m, m - 1)].abs() * (q.abs() + r.abs())
+                        < eps
+                            * (p.abs() * (h[(m - 1, m - 1)].abs() + z.abs() + h[(m + 1, m + 1)].abs()))
+                    {
+                        break;
+                    }
+                    m -= 1;
+                }
+                for i in (m + 2)..=nu {
+                    h[(i, i - 2)] = 0.0;
+                    if i > m + 2 {
+                        h[(i, i - 3)] = 0.0;
+                    }
+                }
+
+                // 行 l..n, 列 m..n に対する二重シフト QR ステップ
+                for k in m..nu {
+                    let notlast = k != nu - 1;
+                    if k != m {
+                        p = h[(k, k - 1)];
+                        q = h[(k + 1, k - 1)];
+                        r = if notlast { h[(k + 2, k - 1)] } else { 0.0 };
+                        x = p.abs() + q.abs() + r.abs();
+                        if x == 0.0 {
+                            continue;
+                        }
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                    s = (p * p + q * q + r * r).sqrt();
+                    if p < 0.0 {
+                        s = -s;
+                    }
+                    if s != 0.0 {
+                        if k != m {
+                            h[(k, k - 1)] = -s * x;
+                        } else if l != m {
+                            h[(k, k - 1)] = -h[(k, k - 1)];
+                        }
+                        p += s;
+                        x = p / s;
+                        y = q / s;
+                        z = r / s;
+                        q /= p;
+                        r /= p;
+
+                        // 行の更新
+                        for j in k..nn {
+                            p = h[(k, j)] + q * h[(k + 1, j)];
+                            if notlast {
+                                p += r * h[(k + 2, j)];
+                                h[(k + 2, j)] -= p * z;
+                            }
+                            h[(k, j)] -= p * x;
+                            h[(k + 1, j)] -= p * y;
+                        }
+                        // 列の更新
+                        for i in 0..=nu.min(k + 3) {
+                            p = x * h[(i, k)] + y * h[(i, k + 1)];
+                            if notlast {
+                                p += z * h[(i, k + 2)];
+                                h[(i, k + 2)] -= p * r;
+                            }
+                            h[(i, k)] -= p;
+                            h[(i, k + 1)] -= p * q;
+                        }
+                        // 変換を累積
+                        for i in 0..nn {
+                            p = x * v[(i, k)] + y * v[(i, k + 1)];
+                            if notlast {
+                                p += z * v[(i, k + 2)];
+                                v[(i, k + 2)] -= p * r;
+                            }
+                            v[(i, k)] -= p;
+                            v[(i, k + 1)] -= p * q;
+                        }
+                    }
                 }
             }
-            if !progressed {
-                return false;
+        }
+
+        // 第 1 副対角より下は厳密に 0
+        for i in 2..nn {
+            for j in 0..(i - 1) {
+                h[(i, j)] = 0.0;
             }
         }
-        true
+        Ok(())
     }
     /// 実シュア形式 `t` の対角を走査し、1x1および2x2ブロックから固有値を抽出する。
     fn extract_eigenvalues_from_schur(t: &Matrix<f64>, tol: f64) -> Vec<Complex<f64>> {
@@ -690,8 +887,19 @@ impl Matrix<f64> {
     }
 }
 
+mod hermitian;
+mod symmetric;
+
+pub use hermitian::{HermitianEigen, HermitianEigenDecomposition};
+
 #[cfg(test)]
 mod tests;
 
 #[cfg(test)]
 mod tests_complex;
+
+#[cfg(test)]
+mod tests_hermitian;
+
+#[cfg(test)]
+mod tests_symmetric;
@@ -0,0 +1,261 @@
+use super::Eigen;
+use crate::{LinalgError, Matrix, Result};
+
+impl Matrix<f64> {
+    /// 対称行列の固有分解（Householder 三重対角化 + 陰的シフト QL 法）。
+    /// 固有値は昇順に並び、固有ベクトル行列は変換行列の積として得るため直交性が保たれる。
+    pub(super) fn symmetric_eigen_tridiagonal_ql(&self) -> Result<Eigen> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let n = self.rows;
+        if n == 0 {
+            return Ok(Eigen {
+                eigen_values: vec![],
+                eigen_vectors: Matrix {
+                    rows: 0,
+                    cols: 0,
+                    data: vec![],
+                },
+            });
+        }
+        let scale = self.data.iter().fold(0.0f64, |m, v| m.max(v.abs()));
+        let sym_tol = 1e-10 * scale.max(1.0);
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if (self[(i, j)] - self[(j, i)]).abs() > sym_tol {
+                    return Err(LinalgError::InvalidArgument {
+                        text: "symmetric_eigen requires a symmetric matrix".into(),
+                    });
+                }
+            }
+        }
+
+        let mut v = self.clone();
+        let mut d = vec![0.0; n];
+        let mut e = vec![0.0; n];
+        Self::tridiagonalize_symmetric(&mut v, &mut d, &mut e);
+        Self::tridiagonal_ql(&mut v, &mut d, &mut e)?;
+
+        // 昇順に並べ替え（選択ソートで列も同時に入れ替える）
+        for i in 0..n.saturating_sub(1) {
+            let mut k = i;
+            for j in (i + 1)..n {
+                if d[j] < d[k] {
+                    k = j;
+                }
+            }
+            if k != i {
+                d.swap(i, k);
+                for r in 0..n {
+                    let tmp = v[(r, i)];
+                    v[(r, i)] = v[(r, k)];
+                    v[(r, k)] = tmp;
+                }
+            }
+        }
+
+        Ok(Eigen {
+            eigen_values: d,
+            eigen_vectors: v,
+        })
+    }
+
+    /// Householder 変換で対称行列を三重対角化する（EISPACK tred2）。
+    /// 終了時、`v` に直交変換行列、`d` に対角、`e[1..]` に副対角が入る。
+    fn tridiagonalize_symmetric(v: &mut Matrix<f64>, d: &mut [f64], e: &mut [f64]) {
+        let n = v.rows;
+        for j in 0..n {
+            d[j] = v[(n - 1, j)];
+        }
+
+        for i in (1..n).rev() {
+            // 下側の行をスケーリングして Householder ベクトルを作る
+            let mut scale = 0.0;
+            let mut h = 0.0;
+            for dk in d.iter().take(i) {
+                scale += dk.abs();
+            }
+            if scale == 0.0 {
+                e[i] = d[i - 1];
+                for j in 0..i {
+                    d[j] = v[(i - 1, j)];
+                    v[(i, j)] = 0.0;
+                    v[(j, i)] = 0.0;
+                }
+            } else {
+                for dk in d.iter_mut().take(i) {
+                    *dk /= scale;
+                    h += *dk * *dk;
+                }
+                let mut f = d[i - 1];
+                let mut g = h.sqrt();
+                if f > 0.0 {
+                    g = -g;
+                }
+                e[i] = scale * g;
+                h -= f * g;
+                d[i - 1] = f - g;
+                for ej in e.iter_mut().take(i) {
+                    *ej = 0.0;
+                }
+
+                // 残りの部分行列に相似変換を適用
+                for j in 0..i {
+                    f = d[j];
+                    v[(j, i)] = f;
+                    g = e[j] + v[(j, j)] * f;
+                    for k in (j + 1)..i {
+                        g += v[(k, j)] * d[k];
+                        e[k] += v[(k, j)] * f;
+                    }
+                    e[j] = g;
+                }
+                f = 0.0;
+                for j in 0..i {
+                    e[j] /= h;
+                    f += e[j] * d[j];
+                }
+                let hh = f / (h + h);
+                for j in 0..i {
+                    e[j] -= hh * d[j];
+                }
+                for j in 0..i {
+                    f = d[j];
+                    g = e[j];
+                    for k in j..i {
+                        v[(k, j)] -= f * e[k] + g * d[k];
+                    }
+                    d[j] = v[(i - 1, j)];
+                    v[(i, j)] = 0.0;
+                }
+            }
+            d[i] = h;
+        }
+
+        // 変換行列を累積
+        for i in 0..n.saturating_sub(1) {
+            v[(n - 1, i)] = v[(i, i)];
+            v[(i, i)] = 1.0;
+            let h = d[i + 1];
+            if h != 0.0 {
+                for k in 0..=i {
+                    d[k] = v[(k, i + 1)] / h;
+                }
+                for j in 0..=i {
+                    let mut g = 0.0;
+                    for k in 0..=i {
+                        g += v[(k, i + 1)] * v[(k, j)];
+                    }
+                    for k in 0..=i {
+                        v[(k, j)] -= g * d[k];
+                    }
+                }
+            }
+            for k in 0..=i {
+                v[(k, i + 1)] = 0.0;
+            }
+        }
+        for j in 0..n {
+            d[j] = v[(n - 1, j)];
+            v[(n - 1, j)] = 0.0;
+        }
+        v[(n - 1, n - 1)] = 1.0;
+        e[0] = 0.0;
+    }
+
+    /// 対称三重対角行列の陰的シフト QL 法（EISPACK tql2）。
+    /// `d` は固有値に、`v` は固有ベクトルに上書きされる。
+    pub(super) fn tridiagonal_ql(v: &mut Matrix<f64>, d: &mut [f64], e: &mut [f64]) -> Result<()> {
+        let n = v.rows;
+        for i in 1..n {
+            e[i - 1] = e[i];
+        }
+        e[n - 1] = 0.0;
+
+        let mut f = 0.0;
+        let mut tst1 = 0.0f64;
+        let eps = f64::EPSILON;
+        let max_iter = 30 * n.max(1);
+        for l in 0..n {
+            // 小さな副対角要素を探す
+            tst1 = tst1.max(d[l].abs() + e[l].abs());
+            let mut m = l;
+            while m < n {
+                if e[m].abs() <= eps * tst1 {
+                    break;
+                }
+                m += 1;
+            }
+            let m = m.min(n - 1);
+
+            // m == l なら d[l] は固有値として確定。そうでなければ反復する。
+            if m > l {
+                let mut iter = 0;
+                loop {
+                    iter += 1;
+                    if iter > max_iter {
+                        return Err(LinalgError::InvalidArgument {
+                            text: "Maximum total iterations reached".into(),
+                        });
+                    }
+
+                    // 暗黙シフトを計算
+                    let mut g = d[l];
+                    let mut p = (d[l + 1] - g) / (2.0 * e[l]);
+                    let mut r = p.hypot(1.0);
+                    if p < 0.0 {
+                        r = -r;
+                    }
+                    d[l] = e[l] / (p + r);
+                    d[l + 1] = e[l] * (p + r);
+                    let dl1 = d[l + 1];
+                    let mut h = g - d[l];
+                    for di in d.iter_mut().take(n).skip(l + 2) {
+                        *di -= h;
+                    }
+                    f += h;
+
+                    // 陰的 QL 変換
+                    p = d[m];
+                    let mut c = 1.0;
+                    let mut c2 = c;
+                    let mut c3 = c;
+                    let el1 = e[l + 1];
+                    let mut s = 0.0;
+                    let mut s2 = 0.0;
+                    for i in (l..m).rev() {
+                        c3 = c2;
+                        c2 = c;
+                        s2 = s;
+                        g = c * e[i];
+                        h = c * p;
+                        r = p.hypot(e[i]);
+                        e[i + 1] = s * r;
+                        s = e[i] / r;
+                        c = p / r;
+                        p = c * d[i] - s * g;
+                        d[i + 1] = h + s * (c * g + s * d[i]);
+
+                        // 固有ベクトルを累積
+                        for k in 0..n {
+                            h = v[(k, i + 1)];
+                            v[(k, i + 1)] = s * v[(k, i)] + c * h;
+                            v[(k, i)] = c * v[(k, i)] - s * h;
+                        }
+                    }
+                    p = -s * s2 * c3 * el1 * e[l] / dl1;
+                    e[l] = s * p;
+                    d[l] = c * p;
+
+                    if e[l].abs() <= eps * tst1 {
+                        break;
+                    }
+                }
+            }
+            d[l] += f;
+            e[l] = 0.0;
+        }
+        Ok(())
+    }
+}
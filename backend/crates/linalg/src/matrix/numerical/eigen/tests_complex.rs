@@ -156,7 +156,7 @@ fn test_qr_iteration_to_schur_keeps_quasi_upper_triangular() {
     let mut q = Matrix::identity(3);
 
     let ok = Matrix::<f64>::qr_iteration_to_schur(&mut h, &mut q, 1e-12);
-    assert!(ok, "QR iteration did not converge");
+    assert!(ok.is_ok(), "QR iteration did not converge");
 
     // 準上三角性: 対角より2つ以上下は ~0
     for i in 0..h.rows {
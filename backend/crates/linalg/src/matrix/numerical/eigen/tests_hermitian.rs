@@ -0,0 +1,79 @@
+use num_complex::Complex;
+
+use crate::matrix::numerical::HermitianEigenDecomposition;
+use crate::Matrix;
+
+type C64 = Complex<f64>;
+
+fn c(re: f64, im: f64) -> C64 {
+    C64::new(re, im)
+}
+
+#[test]
+fn hermitian_eigen_pauli_y() {
+    // σ_y = [[0, -i], [i, 0]] の固有値は ±1
+    let a = Matrix::new(
+        2,
+        2,
+        vec![c(0.0, 0.0), c(0.0, -1.0), c(0.0, 1.0), c(0.0, 0.0)],
+    )
+    .unwrap();
+    let eig = a.hermitian_eigen().expect("hermitian eigen failed");
+    assert!((eig.eigen_values[0] + 1.0).abs() < 1e-12);
+    assert!((eig.eigen_values[1] - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn hermitian_eigen_reconstructs() {
+    let a = Matrix::new(
+        4,
+        4,
+        vec![
+            c(4.0, 0.0),
+            c(1.0, -2.0),
+            c(0.0, 1.0),
+            c(0.5, 0.5),
+            c(1.0, 2.0),
+            c(3.0, 0.0),
+            c(2.0, -1.0),
+            c(0.0, 0.0),
+            c(0.0, -1.0),
+            c(2.0, 1.0),
+            c(-1.0, 0.0),
+            c(1.0, -3.0),
+            c(0.5, -0.5),
+            c(0.0, 0.0),
+            c(1.0, 3.0),
+            c(2.0, 0.0),
+        ],
+    )
+    .unwrap();
+    let eig = a.hermitian_eigen().unwrap();
+    let v = &eig.eigen_vectors;
+    for w in eig.eigen_values.windows(2) {
+        assert!(w[0] <= w[1]);
+    }
+    let vhv = &v.conjugate_transpose() * v;
+    let av = &a * v;
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = if i == j { c(1.0, 0.0) } else { c(0.0, 0.0) };
+            assert!((vhv[(i, j)] - expected).norm() < 1e-12);
+            assert!((av[(i, j)] - v[(i, j)] * eig.eigen_values[j]).norm() < 1e-10);
+        }
+    }
+    // 固有値の和はトレース
+    let sum: f64 = eig.eigen_values.iter().sum();
+    assert!((sum - 8.0).abs() < 1e-10);
+}
+
+#[test]
+fn hermitian_eigen_rejects_non_hermitian() {
+    let a = Matrix::new(
+        2,
+        2,
+        vec![c(1.0, 0.0), c(0.0, 1.0), c(0.0, 1.0), c(1.0, 0.0)],
+    )
+    .unwrap();
+    assert!(a.hermitian_eigen().is_err());
+}
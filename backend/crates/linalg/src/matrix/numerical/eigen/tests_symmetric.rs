@@ -0,0 +1,147 @@
+use crate::{matrix::numerical::EigenDecomposition, Matrix};
+
+fn approx(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol
+}
+
+fn assert_orthonormal(v: &Matrix<f64>, tol: f64) {
+    let vtv = &v.transpose() * v;
+    for i in 0..vtv.rows {
+        for j in 0..vtv.cols {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!(
+                approx(vtv[(i, j)], expected, tol),
+                "V^T V mismatch at ({i}, {j}): {}",
+                vtv[(i, j)]
+            );
+        }
+    }
+}
+
+fn assert_reconstructs(a: &Matrix<f64>, values: &[f64], v: &Matrix<f64>, tol: f64) {
+    let n = a.rows;
+    let mut d = Matrix::zeros(n, n);
+    for i in 0..n {
+        d[(i, i)] = values[i];
+    }
+    let recon = &(v * &d) * &v.transpose();
+    for i in 0..n {
+        for j in 0..n {
+            assert!(
+                approx(recon[(i, j)], a[(i, j)], tol),
+                "V D V^T mismatch at ({i}, {j}): {} vs {}",
+                recon[(i, j)],
+                a[(i, j)]
+            );
+        }
+    }
+}
+
+#[test]
+fn symmetric_eigen_known_values_sorted() {
+    let a = Matrix::new(3, 3, vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]).unwrap();
+    let eig = a.symmetric_eigen().unwrap();
+    let s2 = 2f64.sqrt();
+    let expected = [2.0 - s2, 2.0, 2.0 + s2];
+    for (g, e) in eig.eigen_values.iter().zip(expected.iter()) {
+        assert!(approx(*g, *e, 1e-12), "{g} vs {e}");
+    }
+    assert_orthonormal(&eig.eigen_vectors, 1e-12);
+    assert_reconstructs(&a, &eig.eigen_values, &eig.eigen_vectors, 1e-12);
+}
+
+#[test]
+fn symmetric_eigen_repeated_eigenvalues_keep_orthogonality() {
+    // J - I（J は全要素 1）: 固有値は -1（重複度 3）と 3
+    let n = 4;
+    let mut a = Matrix::zeros(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            a[(i, j)] = if i == j { 0.0 } else { 1.0 };
+        }
+    }
+    let eig = a.symmetric_eigen().unwrap();
+    let expected = [-1.0, -1.0, -1.0, 3.0];
+    for (g, e) in eig.eigen_values.iter().zip(expected.iter()) {
+        assert!(approx(*g, *e, 1e-12), "{g} vs {e}");
+    }
+    assert_orthonormal(&eig.eigen_vectors, 1e-12);
+    assert_reconstructs(&a, &eig.eigen_values, &eig.eigen_vectors, 1e-12);
+}
+
+#[test]
+fn symmetric_eigen_identity_and_diagonal() {
+    let eye = Matrix::<f64>::identity(5);
+    let eig = eye.symmetric_eigen().unwrap();
+    assert!(eig.eigen_values.iter().all(|v| approx(*v, 1.0, 1e-14)));
+    assert_orthonormal(&eig.eigen_vectors, 1e-14);
+
+    let d = Matrix::new(3, 3, vec![3.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 3.0]).unwrap();
+    let eig = d.symmetric_eigen().unwrap();
+    assert_eq!(eig.eigen_values.len(), 3);
+    assert!(approx(eig.eigen_values[0], -1.0, 1e-14));
+    assert!(approx(eig.eigen_values[1], 3.0, 1e-14));
+    assert!(approx(eig.eigen_values[2], 3.0, 1e-14));
+    assert_orthonormal(&eig.eigen_vectors, 1e-14);
+}
+
+#[test]
+fn symmetric_eigen_covariance_like_matrix() {
+    // X^T X 型の半正定値行列（ランク落ちを含む）
+    let x = Matrix::new(
+        4,
+        3,
+        vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.1, -1.0, 0.5, -0.5, 0.0, 1.0, 1.0],
+    )
+    .unwrap();
+    let mut c = &x.transpose() * &x;
+    // 3 列目を 1,2 列目の和に置き換えてランク 2 にする
+    let y = Matrix::new(3, 3, vec![1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0]).unwrap();
+    c = &(&y.transpose() * &c) * &y;
+    let eig = c.symmetric_eigen().unwrap();
+    assert!(eig.eigen_values[0].abs() < 1e-10);
+    assert!(eig.eigen_values.windows(2).all(|w| w[0] <= w[1]));
+    assert_orthonormal(&eig.eigen_vectors, 1e-12);
+    assert_reconstructs(&c, &eig.eigen_values, &eig.eigen_vectors, 1e-9);
+}
+
+#[test]
+fn symmetric_eigen_rejects_invalid_input() {
+    let nonsym = Matrix::new(2, 2, vec![1.0, 2.0, 0.0, 1.0]).unwrap();
+    assert!(nonsym.symmetric_eigen().is_err());
+    let rect = Matrix::new(2, 3, vec![0.0; 6]).unwrap();
+    assert!(rect.symmetric_eigen().is_err());
+    let empty = Matrix::<f64> {
+        rows: 0,
+        cols: 0,
+        data: vec![],
+    };
+    assert!(empty.symmetric_eigen().unwrap().eigen_values.is_empty());
+}
+
+#[test]
+fn symmetric_eigen_larger_matrix() {
+    // 決定的な擬似乱数で 20x20 の対称行列を作る
+    let n = 20;
+    let mut state = 12345u64;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 11) as f64 / (1u64 << 53) as f64) - 0.5
+    };
+    let mut a = Matrix::zeros(n, n);
+    for i in 0..n {
+        for j in i..n {
+            let v = next();
+            a[(i, j)] = v;
+            a[(j, i)] = v;
+        }
+    }
+    let eig = a.symmetric_eigen().unwrap();
+    assert!(eig.eigen_values.windows(2).all(|w| w[0] <= w[1]));
+    assert_orthonormal(&eig.eigen_vectors, 1e-12);
+    assert_reconstructs(&a, &eig.eigen_values, &eig.eigen_vectors, 1e-12);
+    let trace: f64 = (0..n).map(|i| a[(i, i)]).sum();
+    assert!(approx(eig.eigen_values.iter().sum::<f64>(), trace, 1e-12));
+}
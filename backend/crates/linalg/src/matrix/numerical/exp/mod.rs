@@ -0,0 +1,168 @@
+use crate::matrix::Matrix;
+use crate::{LinalgError, Result};
+
+#[cfg(test)]
+mod tests;
+
+/// 行列指数関数。Higham (2005) のスケーリング・スクエアリング + Padé 近似で計算する。
+pub trait MatrixExponential {
+    /// exp(A)
+    fn expm(&self) -> Result<Matrix<f64>>;
+
+    /// exp(A) と、その方向 E への Fréchet 微分 L(A, E) を返す。
+    /// L(A, E) = lim_{h→0} (exp(A + hE) - exp(A)) / h
+    fn expm_frechet(&self, e: &Matrix<f64>) -> Result<(Matrix<f64>, Matrix<f64>)>;
+}
+
+/// 次数 m の Padé 近似が倍精度の誤差限界に収まる ||A||_1 の上限 θ_m
+const PADE_THETA: [(usize, f64); 4] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068e0),
+];
+const PADE_THETA_13: f64 = 5.371920351148152e0;
+
+const PADE_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const PADE_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const PADE_7: [f64; 8] = [
+    17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+];
+const PADE_9: [f64; 10] = [
+    17643225600.0,
+    8821612800.0,
+    2075673600.0,
+    302702400.0,
+    30270240.0,
+    2162160.0,
+    110880.0,
+    3960.0,
+    90.0,
+    1.0,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+impl MatrixExponential for Matrix<f64> {
+    fn expm(&self) -> Result<Matrix<f64>> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        if self.data.iter().any(|v| !v.is_finite()) {
+            return Err(LinalgError::InvalidArgument {
+                text: "expm requires finite matrix entries".into(),
+            });
+        }
+
+        let norm = self.one_norm();
+        for &(m, theta) in PADE_THETA.iter() {
+            if norm <= theta {
+                return pade_low_degree(self, m);
+            }
+        }
+
+        // ||A / 2^s||_1 <= θ_13 となる s を選び、exp(A) = r_13(A / 2^s)^(2^s)
+        let s = (norm / PADE_THETA_13).log2().ceil().max(0.0) as i32;
+        let scaled = self * 2f64.powi(-s);
+        let mut result = pade_13(&scaled)?;
+        for _ in 0..s {
+            result = &result * &result;
+        }
+        Ok(result)
+    }
+
+    fn expm_frechet(&self, e: &Matrix<f64>) -> Result<(Matrix<f64>, Matrix<f64>)> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        if e.rows != self.rows || e.cols != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, self.cols),
+                found: format!("{}x{}", e.rows, e.cols),
+            });
+        }
+        // exp([[A, E], [0, A]]) = [[exp(A), L(A, E)], [0, exp(A)]]
+        let n = self.rows;
+        let mut block = Matrix::zeros(2 * n, 2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                block[(i, j)] = self[(i, j)];
+                block[(n + i, n + j)] = self[(i, j)];
+                block[(i, n + j)] = e[(i, j)];
+            }
+        }
+        let big = block.expm()?;
+        let mut exp_a = Matrix::zeros(n, n);
+        let mut frechet = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                exp_a[(i, j)] = big[(i, j)];
+                frechet[(i, j)] = big[(i, n + j)];
+            }
+        }
+        Ok((exp_a, frechet))
+    }
+}
+
+/// r_m(A) = (V - U)^{-1} (V + U) を解く
+fn pade_solve(u: &Matrix<f64>, v: &Matrix<f64>) -> Result<Matrix<f64>> {
+    (v - u).solve_matrix(&(v + u))
+}
+
+/// m = 3, 5, 7, 9 の対角 Padé 近似
+fn pade_low_degree(a: &Matrix<f64>, m: usize) -> Result<Matrix<f64>> {
+    let b: &[f64] = match m {
+        3 => &PADE_3,
+        5 => &PADE_5,
+        7 => &PADE_7,
+        _ => &PADE_9,
+    };
+    let n = a.rows;
+    let ident: Matrix<f64> = Matrix::identity(n);
+    let a2 = a * a;
+
+    // 偶数次のべき I, A^2, A^4, ... を順に作りながら U, V の係数を足し込む
+    let mut power = ident.clone();
+    let mut u_inner = &ident * b[1];
+    let mut v = &ident * b[0];
+    for k in 1..=(m / 2) {
+        power = &power * &a2;
+        u_inner = &u_inner + &(&power * b[2 * k + 1]);
+        v = &v + &(&power * b[2 * k]);
+    }
+    let u = a * &u_inner;
+    pade_solve(&u, &v)
+}
+
+/// m = 13 の対角 Padé 近似（A^2, A^4, A^6 のみで評価する）
+fn pade_13(a: &Matrix<f64>) -> Result<Matrix<f64>> {
+    let b = &PADE_13;
+    let n = a.rows;
+    let ident: Matrix<f64> = Matrix::identity(n);
+    let a2 = a * a;
+    let a4 = &a2 * &a2;
+    let a6 = &a4 * &a2;
+
+    let u_high = &(&(&a6 * b[13]) + &(&a4 * b[11])) + &(&a2 * b[9]);
+    let u_low = &(&(&(&a6 * b[7]) + &(&a4 * b[5])) + &(&a2 * b[3])) + &(&ident * b[1]);
+    let u = a * &(&(&a6 * &u_high) + &u_low);
+
+    let v_high = &(&(&a6 * b[12]) + &(&a4 * b[10])) + &(&a2 * b[8]);
+    let v_low = &(&(&(&a6 * b[6]) + &(&a4 * b[4])) + &(&a2 * b[2])) + &(&ident * b[0]);
+    let v = &(&a6 * &v_high) + &v_low;
+    pade_solve(&u, &v)
+}
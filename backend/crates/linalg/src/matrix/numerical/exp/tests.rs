@@ -0,0 +1,79 @@
+use super::MatrixExponential;
+use crate::{LinalgError, Matrix};
+
+fn assert_matrix_close(a: &Matrix<f64>, b: &Matrix<f64>, tol: f64) {
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+    for i in 0..a.rows {
+        for j in 0..a.cols {
+            let scale = b[(i, j)].abs().max(1.0);
+            assert!(
+                (a[(i, j)] - b[(i, j)]).abs() <= tol * scale,
+                "mismatch at ({i}, {j}): {} vs {}",
+                a[(i, j)],
+                b[(i, j)]
+            );
+        }
+    }
+}
+
+#[test]
+fn expm_of_zero_is_identity() {
+    let z = Matrix::<f64>::zeros(3, 3);
+    assert_matrix_close(&z.expm().unwrap(), &Matrix::identity(3), 1e-15);
+}
+
+#[test]
+fn expm_nilpotent_and_rotation() {
+    let n = Matrix::new(2, 2, vec![0.0, 1.0, 0.0, 0.0]).unwrap();
+    let expected = Matrix::new(2, 2, vec![1.0, 1.0, 0.0, 1.0]).unwrap();
+    assert_matrix_close(&n.expm().unwrap(), &expected, 1e-15);
+
+    // 大きな回転角でもスケーリング・スクエアリングで精度を保つ
+    let theta = 10.0f64;
+    let r = Matrix::new(2, 2, vec![0.0, -theta, theta, 0.0]).unwrap();
+    let expected = Matrix::new(
+        2,
+        2,
+        vec![theta.cos(), -theta.sin(), theta.sin(), theta.cos()],
+    )
+    .unwrap();
+    assert_matrix_close(&r.expm().unwrap(), &expected, 1e-12);
+}
+
+#[test]
+fn expm_stiff_upper_triangular() {
+    // A = [[-1000, 1], [0, -1]] → exp(A) = [[e^-1000, (e^-1 - e^-1000)/999], [0, e^-1]]
+    let a = Matrix::new(2, 2, vec![-1000.0, 1.0, 0.0, -1.0]).unwrap();
+    let e1 = (-1.0f64).exp();
+    let e1000 = (-1000.0f64).exp();
+    let expected = Matrix::new(2, 2, vec![e1000, (e1 - e1000) / 999.0, 0.0, e1]).unwrap();
+    assert_matrix_close(&a.expm().unwrap(), &expected, 1e-12);
+}
+
+#[test]
+fn expm_inverse_relation() {
+    let a = Matrix::new(3, 3, vec![0.5, -1.2, 0.3, 2.0, 0.1, -0.7, 0.0, 1.5, -0.4]).unwrap();
+    let e = a.expm().unwrap();
+    let e_neg = (&a * -1.0).expm().unwrap();
+    assert_matrix_close(&(&e * &e_neg), &Matrix::identity(3), 1e-12);
+}
+
+#[test]
+fn expm_rejects_non_square() {
+    let a = Matrix::<f64>::zeros(2, 3);
+    assert!(matches!(a.expm(), Err(LinalgError::NotSquareMatrix)));
+}
+
+#[test]
+fn expm_frechet_matches_finite_difference() {
+    let a = Matrix::new(2, 2, vec![1.0, 2.0, -0.5, 0.3]).unwrap();
+    let e = Matrix::new(2, 2, vec![0.2, -0.1, 0.4, 1.0]).unwrap();
+    let (exp_a, l) = a.expm_frechet(&e).unwrap();
+    assert_matrix_close(&exp_a, &a.expm().unwrap(), 1e-12);
+
+    let h = 1e-6;
+    let plus = (&a + &(&e * h)).expm().unwrap();
+    let minus = (&a - &(&e * h)).expm().unwrap();
+    let fd = &(&plus - &minus) * (0.5 / h);
+    assert_matrix_close(&l, &fd, 1e-7);
+}
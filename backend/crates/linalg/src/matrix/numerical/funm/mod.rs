@@ -0,0 +1,325 @@
+use num_complex::Complex;
+
+use crate::matrix::numerical::helpers::{complex_schur, swap_schur_diagonal};
+use crate::{LinalgError, Matrix, Result};
+
+#[cfg(test)]
+mod tests;
+
+type C64 = Complex<f64>;
+
+/// 近い固有値を同じブロックにまとめる閾値（Davies–Higham の δ）
+const CLUSTER_DELTA: f64 = 0.1;
+/// 原子ブロックの Taylor 展開で使う最大項数
+const MAX_TAYLOR_TERMS: usize = 150;
+
+/// 一般の行列関数（対数・平方根・Schur–Parlett 法）
+pub trait MatrixFunction {
+    /// 主対数 log(A)。負の実軸上の固有値や特異な A に対しては実の主対数が存在しないためエラー。
+    fn logm(&self) -> Result<Matrix<f64>>;
+
+    /// 主平方根 A^{1/2}（Schur 法）。負の実固有値を持つ場合や平方根が存在しない場合はエラー。
+    fn sqrtm(&self) -> Result<Matrix<f64>>;
+
+    /// Schur–Parlett 法による f(A)。`f(z, k)` は f の k 階導関数 f^{(k)}(z) を返すこと。
+    /// 近接固有値のブロックは Taylor 展開で評価するため、k >= 1 の導関数も必要になる。
+    fn funm<F>(&self, f: F) -> Result<Matrix<C64>>
+    where
+        F: Fn(C64, usize) -> C64;
+}
+
+impl MatrixFunction for Matrix<f64> {
+    fn logm(&self) -> Result<Matrix<f64>> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let (u, t) = complex_schur(self)?;
+        let tol = 100.0 * f64::EPSILON * self.frobenius_norm().max(1.0);
+        for i in 0..t.rows {
+            let lambda = t[(i, i)];
+            if lambda.norm() <= tol {
+                return Err(LinalgError::SingularMatrix);
+            }
+            if lambda.re < 0.0 && lambda.im.abs() <= tol {
+                return Err(LinalgError::InvalidArgument {
+                    text: "logm: matrix has eigenvalues on the negative real axis".into(),
+                });
+            }
+        }
+        let f = schur_parlett(u, t, &|z: C64, k: usize| {
+            if k == 0 {
+                z.ln()
+            } else {
+                // d^k/dz^k log z = (-1)^{k-1} (k-1)! / z^k
+                let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+                let fact: f64 = (1..k).map(|i| i as f64).product();
+                C64::new(sign * fact, 0.0) / z.powu(k as u32)
+            }
+        })?;
+        Ok(real_part(&f))
+    }
+
+    fn sqrtm(&self) -> Result<Matrix<f64>> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let (u, t) = complex_schur(self)?;
+        let n = t.rows;
+        let tol = 100.0 * f64::EPSILON * self.frobenius_norm().max(1.0);
+        for i in 0..n {
+            let lambda = t[(i, i)];
+            if lambda.re < -tol && lambda.im.abs() <= tol {
+                return Err(LinalgError::InvalidArgument {
+                    text: "sqrtm: matrix has negative real eigenvalues".into(),
+                });
+            }
+        }
+
+        // 上三角 R を R^2 = T となるよう列ごとに求める（Björck–Hammarling）
+        let mut r: Matrix<C64> = Matrix::zeros(n, n);
+        for j in 0..n {
+            r[(j, j)] = t[(j, j)].sqrt();
+            for i in (0..j).rev() {
+                let mut s = t[(i, j)];
+                for k in (i + 1)..j {
+                    s -= r[(i, k)] * r[(k, j)];
+                }
+                let d = r[(i, i)] + r[(j, j)];
+                if d.norm() <= tol {
+                    return Err(LinalgError::InvalidArgument {
+                        text: "sqrtm: matrix has no square root (repeated zero eigenvalue)".into(),
+                    });
+                }
+                r[(i, j)] = s / d;
+            }
+        }
+        Ok(real_part(&unitary_similarity(&u, &r)))
+    }
+
+    fn funm<F>(&self, f: F) -> Result<Matrix<C64>>
+    where
+        F: Fn(C64, usize) -> C64,
+    {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let (u, t) = complex_schur(self)?;
+        schur_parlett(u, t, &f)
+    }
+}
+
+/// Schur–Parlett 法（Davies–Higham）。A = U T U^H に対し f(A) = U f(T) U^H。
+fn schur_parlett<F>(mut u: Matrix<C64>, mut t: Matrix<C64>, f: &F) -> Result<Matrix<C64>>
+where
+    F: Fn(C64, usize) -> C64,
+{
+    let n = t.rows;
+    if n == 0 {
+        return Ok(Matrix::zeros(0, 0));
+    }
+
+    // 1. 固有値を δ 以内の推移閉包でクラスタリング
+    let mut cluster: Vec<usize> = (0..n).collect();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if (t[(i, i)] - t[(j, j)]).norm() <= CLUSTER_DELTA && cluster[i] != cluster[j] {
+                let (keep, drop) = (cluster[i].min(cluster[j]), cluster[i].max(cluster[j]));
+                for c in cluster.iter_mut() {
+                    if *c == drop {
+                        *c = keep;
+                    }
+                }
+            }
+        }
+    }
+
+    // 2. 各クラスタの平均位置の順に並ぶよう、隣接交換で Schur 形を並べ替える
+    let mut rank = vec![0.0; n];
+    for (i, r) in rank.iter_mut().enumerate() {
+        let members: Vec<usize> = (0..n).filter(|&k| cluster[k] == cluster[i]).collect();
+        *r = members.iter().sum::<usize>() as f64 / members.len() as f64;
+    }
+    let mut swapped = true;
+    while swapped {
+        swapped = false;
+        for k in 0..n - 1 {
+            if rank[k] > rank[k + 1] {
+                swap_schur_diagonal(&mut t, &mut u, k);
+                rank.swap(k, k + 1);
+                cluster.swap(k, k + 1);
+                swapped = true;
+            }
+        }
+    }
+
+    // 3. ブロック境界
+    let mut starts = vec![0];
+    for k in 1..n {
+        if cluster[k] != cluster[k - 1] {
+            starts.push(k);
+        }
+    }
+    starts.push(n);
+    let blocks: Vec<(usize, usize)> = starts.windows(2).map(|w| (w[0], w[1])).collect();
+
+    // 4. 対角ブロックを Taylor 展開で評価
+    let mut fm: Matrix<C64> = Matrix::zeros(n, n);
+    for &(p, q) in &blocks {
+        let fb = atomic_block(&sub_block(&t, p, q, p, q), f)?;
+        for i in p..q {
+            for j in p..q {
+                fm[(i, j)] = fb[(i - p, j - p)];
+            }
+        }
+    }
+
+    // 5. 非対角ブロックを Sylvester 方程式 T_II X - X T_JJ = C で求める（Parlett 漸化式のブロック版）
+    for jb in 1..blocks.len() {
+        let (jp, jq) = blocks[jb];
+        for ib in (0..jb).rev() {
+            let (ip, iq) = blocks[ib];
+            let mut c: Matrix<C64> = Matrix::zeros(iq - ip, jq - jp);
+            for i in ip..iq {
+                for j in jp..jq {
+                    let mut s = C64::new(0.0, 0.0);
+                    for k in ip..iq {
+                        s += fm[(i, k)] * t[(k, j)];
+                    }
+                    for k in jp..jq {
+                        s -= t[(i, k)] * fm[(k, j)];
+                    }
+                    for k in iq..jp {
+                        s += fm[(i, k)] * t[(k, j)] - t[(i, k)] * fm[(k, j)];
+                    }
+                    c[(i - ip, j - jp)] = s;
+                }
+            }
+            let x = solve_triangular_sylvester(
+                &sub_block(&t, ip, iq, ip, iq),
+                &sub_block(&t, jp, jq, jp, jq),
+                &c,
+            )?;
+            for i in ip..iq {
+                for j in jp..jq {
+                    fm[(i, j)] = x[(i - ip, j - jp)];
+                }
+            }
+        }
+    }
+
+    Ok(unitary_similarity(&u, &fm))
+}
+
+/// 固有値が近接した上三角ブロックを、平均固有値 σ まわりの Taylor 展開で評価する
+fn atomic_block<F>(t: &Matrix<C64>, f: &F) -> Result<Matrix<C64>>
+where
+    F: Fn(C64, usize) -> C64,
+{
+    let m = t.rows;
+    if m == 1 {
+        return Matrix::new(1, 1, vec![f(t[(0, 0)], 0)]);
+    }
+    let mut sigma = C64::new(0.0, 0.0);
+    for i in 0..m {
+        sigma += t[(i, i)];
+    }
+    sigma /= m as f64;
+    let mut nmat = t.clone();
+    for i in 0..m {
+        nmat[(i, i)] -= sigma;
+    }
+
+    let ident: Matrix<C64> = Matrix::identity(m);
+    let mut result = scale(&ident, f(sigma, 0));
+    let mut power = ident; // N^k / k!
+    let mut small_terms = 0;
+    for k in 1..=MAX_TAYLOR_TERMS {
+        power = scale(&(&power * &nmat), C64::new(1.0 / k as f64, 0.0));
+        let term = scale(&power, f(sigma, k));
+        result = &result + &term;
+        let term_norm = term.frobenius_norm();
+        if !term_norm.is_finite() {
+            break;
+        }
+        if term_norm <= f64::EPSILON * result.frobenius_norm() {
+            small_terms += 1;
+            if k >= m && small_terms >= 2 {
+                return Ok(result);
+            }
+        } else {
+            small_terms = 0;
+        }
+    }
+    Err(LinalgError::InvalidArgument {
+        text: "funm: Taylor series for a clustered block did not converge".into(),
+    })
+}
+
+/// 上三角 A (m×m), B (n×n) に対し A X - X B = C を列ごとの後退代入で解く
+fn solve_triangular_sylvester(
+    a: &Matrix<C64>,
+    b: &Matrix<C64>,
+    c: &Matrix<C64>,
+) -> Result<Matrix<C64>> {
+    let (m, n) = (a.rows, b.rows);
+    let mut x: Matrix<C64> = Matrix::zeros(m, n);
+    for j in 0..n {
+        // (A - b_jj I) x_j = c_j + Σ_{k<j} b_kj x_k
+        let mut rhs: Vec<C64> = (0..m).map(|i| c[(i, j)]).collect();
+        for k in 0..j {
+            for (i, r) in rhs.iter_mut().enumerate() {
+                *r += b[(k, j)] * x[(i, k)];
+            }
+        }
+        for i in (0..m).rev() {
+            let mut s = rhs[i];
+            for k in (i + 1)..m {
+                s -= a[(i, k)] * x[(k, j)];
+            }
+            let d = a[(i, i)] - b[(j, j)];
+            if d.norm() == 0.0 {
+                return Err(LinalgError::SingularMatrix);
+            }
+            x[(i, j)] = s / d;
+        }
+    }
+    Ok(x)
+}
+
+fn sub_block(m: &Matrix<C64>, r0: usize, r1: usize, c0: usize, c1: usize) -> Matrix<C64> {
+    let mut out: Matrix<C64> = Matrix::zeros(r1 - r0, c1 - c0);
+    for i in r0..r1 {
+        for j in c0..c1 {
+            out[(i - r0, j - c0)] = m[(i, j)];
+        }
+    }
+    out
+}
+
+fn scale(m: &Matrix<C64>, s: C64) -> Matrix<C64> {
+    let data = m.data.iter().map(|&v| v * s).collect();
+    Matrix {
+        rows: m.rows,
+        cols: m.cols,
+        data,
+    }
+}
+
+/// U M U^H
+fn unitary_similarity(u: &Matrix<C64>, m: &Matrix<C64>) -> Matrix<C64> {
+    let mut uh: Matrix<C64> = Matrix::zeros(u.cols, u.rows);
+    for i in 0..u.rows {
+        for j in 0..u.cols {
+            uh[(j, i)] = u[(i, j)].conj();
+        }
+    }
+    &(u * m) * &uh
+}
+
+fn real_part(m: &Matrix<C64>) -> Matrix<f64> {
+    Matrix {
+        rows: m.rows,
+        cols: m.cols,
+        data: m.data.iter().map(|v| v.re).collect(),
+    }
+}
@@ -0,0 +1,99 @@
+use num_complex::Complex;
+
+use super::MatrixFunction;
+use crate::matrix::numerical::MatrixExponential;
+use crate::{LinalgError, Matrix};
+
+fn assert_matrix_close(a: &Matrix<f64>, b: &Matrix<f64>, tol: f64) {
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+    for i in 0..a.rows {
+        for j in 0..a.cols {
+            assert!(
+                (a[(i, j)] - b[(i, j)]).abs() <= tol,
+                "mismatch at ({i}, {j}): {} vs {}",
+                a[(i, j)],
+                b[(i, j)]
+            );
+        }
+    }
+}
+
+#[test]
+fn logm_inverts_expm() {
+    let a = Matrix::new(3, 3, vec![0.1, 0.5, -0.2, -0.4, 0.2, 0.3, 0.0, 0.6, -0.1]).unwrap();
+    let l = a.expm().unwrap().logm().unwrap();
+    assert_matrix_close(&l, &a, 1e-12);
+}
+
+#[test]
+fn logm_jordan_block() {
+    // log([[2, 1], [0, 2]]) = [[ln 2, 1/2], [0, ln 2]]
+    let a = Matrix::new(2, 2, vec![2.0, 1.0, 0.0, 2.0]).unwrap();
+    let ln2 = 2f64.ln();
+    let expected = Matrix::new(2, 2, vec![ln2, 0.5, 0.0, ln2]).unwrap();
+    assert_matrix_close(&a.logm().unwrap(), &expected, 1e-13);
+}
+
+#[test]
+fn logm_rejects_negative_real_eigenvalue() {
+    let a = Matrix::new(2, 2, vec![-1.0, 0.0, 0.0, 2.0]).unwrap();
+    assert!(matches!(a.logm(), Err(LinalgError::InvalidArgument { .. })));
+}
+
+#[test]
+fn sqrtm_squares_back() {
+    // 複素共役固有値を持つ非対称行列
+    let a = Matrix::new(3, 3, vec![4.0, 1.0, 0.0, -1.0, 3.0, 0.5, 0.2, 0.0, 2.0]).unwrap();
+    let x = a.sqrtm().unwrap();
+    assert_matrix_close(&(&x * &x), &a, 1e-12);
+
+    let spd = Matrix::new(2, 2, vec![5.0, 2.0, 2.0, 2.0]).unwrap();
+    let y = spd.sqrtm().unwrap();
+    assert_matrix_close(&(&y * &y), &spd, 1e-12);
+    // 対称正定値の主平方根は対称
+    assert!((y[(0, 1)] - y[(1, 0)]).abs() < 1e-12);
+}
+
+#[test]
+fn sqrtm_rejects_negative_eigenvalue() {
+    let a = Matrix::new(2, 2, vec![-4.0, 1.0, 0.0, 1.0]).unwrap();
+    assert!(matches!(
+        a.sqrtm(),
+        Err(LinalgError::InvalidArgument { .. })
+    ));
+}
+
+#[test]
+fn funm_exp_matches_expm_with_clustered_eigenvalues() {
+    // 固有値 1, 1.05 が同じブロックに入り、3 は別ブロックになる
+    let a = Matrix::new(3, 3, vec![1.0, 1.0, 0.0, 0.0, 1.05, 1.0, 0.0, 0.0, 3.0]).unwrap();
+    let f = a.funm(|z: Complex<f64>, _k| z.exp()).unwrap();
+    let expected = a.expm().unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((f[(i, j)].re - expected[(i, j)]).abs() < 1e-12);
+            assert!(f[(i, j)].im.abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn funm_cos_of_rotation_generator() {
+    // A^2 = -t^2 I なので cos(A) = cosh(t) I
+    let t = 0.7f64;
+    let a = Matrix::new(2, 2, vec![0.0, t, -t, 0.0]).unwrap();
+    let f = a
+        .funm(|z: Complex<f64>, k| match k % 4 {
+            0 => z.cos(),
+            1 => -z.sin(),
+            2 => -z.cos(),
+            _ => z.sin(),
+        })
+        .unwrap();
+    for i in 0..2 {
+        for j in 0..2 {
+            let expected = if i == j { t.cosh() } else { 0.0 };
+            assert!((f[(i, j)] - Complex::new(expected, 0.0)).norm() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use num_complex::Complex;
+
+use crate::matrix::numerical::eigen::Eigen;
+use crate::matrix::numerical::{CholeskyDecomposition, EigenDecomposition};
+use crate::{LinalgError, Matrix, Result};
+
+mod qz;
+
+#[cfg(test)]
+mod tests;
+
+/// 一般化 Schur 分解（QZ 分解）: A = Q S Z^H, B = Q T Z^H。
+/// Q, Z はユニタリ、S, T は上三角。固有値は α_i / β_i = s_ii / t_ii。
+pub struct QZ {
+    pub q: Matrix<Complex<f64>>,
+    pub z: Matrix<Complex<f64>>,
+    pub s: Matrix<Complex<f64>>,
+    pub t: Matrix<Complex<f64>>,
+}
+
+/// 一般化固有値問題 A v = λ B v の解。
+/// λ_i = alpha_i / beta_i で、beta_i ≈ 0 のとき λ_i は無限大（`is_infinite`）として返す。
+pub struct GeneralizedEigenComplex {
+    pub alpha: Vec<Complex<f64>>,
+    pub beta: Vec<f64>,
+    pub eigen_values: Vec<Complex<f64>>,
+    pub eigen_vectors: Matrix<Complex<f64>>,
+}
+
+impl GeneralizedEigenComplex {
+    /// i 番目の固有値が無限大（B が特異で beta_i ≈ 0）かどうか
+    pub fn is_infinite(&self, i: usize) -> bool {
+        self.eigen_values[i].re.is_infinite()
+    }
+}
+
+pub trait GeneralizedEigenDecomposition {
+    /// 対称-正定値ペア (A 対称, B 対称正定値) に対する A v = λ B v。
+    /// B = L L^T により標準問題 L^{-1} A L^{-T} y = λ y へ帰着させ、v = L^{-T} y を返す。
+    /// 固有値は昇順、固有ベクトルは B 直交正規（V^T B V = I）。
+    fn generalized_symmetric_eigen(&self, b: &Matrix<f64>) -> Result<Eigen>;
+
+    /// QZ 法による一般化 Schur 分解
+    fn qz(&self, b: &Matrix<f64>) -> Result<QZ>;
+
+    /// 一般のペアに対する A v = λ B v。QZ 分解を経由し、無限固有値も扱う。
+    fn generalized_eigen(&self, b: &Matrix<f64>) -> Result<GeneralizedEigenComplex>;
+}
+
+impl GeneralizedEigenDecomposition for Matrix<f64> {
+    fn generalized_symmetric_eigen(&self, b: &Matrix<f64>) -> Result<Eigen> {
+        check_pair(self, b)?;
+        check_symmetric(self, "A")?;
+        check_symmetric(b, "B")?;
+        let n = self.rows;
+        let l = b.cholesky()?;
+
+        // C = L^{-1} A L^{-T}（W = L^{-1} A を列ごとに前進代入し、C = L^{-1} W^T）
+        let w = solve_lower_columns(&l, self)?;
+        let mut c = solve_lower_columns(&l, &w.transpose())?;
+        // 丸め誤差で崩れた対称性を整える
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let avg = 0.5 * (c[(i, j)] + c[(j, i)]);
+                c[(i, j)] = avg;
+                c[(j, i)] = avg;
+            }
+        }
+        let eig = c.symmetric_eigen()?;
+
+        // V = L^{-T} Y
+        let lt = l.transpose();
+        let mut v = Matrix::zeros(n, n);
+        for j in 0..n {
+            let yj = eig.eigen_vectors.col(j)?;
+            let vj = lt.backward_substitution(&yj)?;
+            v.set_col(j, &vj)?;
+        }
+        Ok(Eigen {
+            eigen_values: eig.eigen_values,
+            eigen_vectors: v,
+        })
+    }
+
+    fn qz(&self, b: &Matrix<f64>) -> Result<QZ> {
+        check_pair(self, b)?;
+        qz::complex_qz(&self.to_complex(), &b.to_complex())
+    }
+
+    fn generalized_eigen(&self, b: &Matrix<f64>) -> Result<GeneralizedEigenComplex> {
+        let qz = self.qz(b)?;
+        let n = self.rows;
+        let b_scale = b.frobenius_norm().max(f64::MIN_POSITIVE);
+        let tol = f64::EPSILON * (n.max(1) as f64) * b_scale;
+
+        // β を非負実数に正規化（α に同じ位相を掛ける）
+        let mut alpha = Vec::with_capacity(n);
+        let mut beta = Vec::with_capacity(n);
+        for i in 0..n {
+            let t = qz.t[(i, i)];
+            let s = qz.s[(i, i)];
+            let r = t.norm();
+            if r > 0.0 {
+                let phase = t.conj() / r;
+                alpha.push(s * phase);
+                beta.push(r);
+            } else {
+                alpha.push(s);
+                beta.push(0.0);
+            }
+        }
+
+        let eigen_vectors = qz::pencil_eigenvectors(&qz, &alpha, &beta)?;
+        let eigen_values: Vec<Complex<f64>> = alpha
+            .iter()
+            .zip(beta.iter())
+            .map(|(a, &bt)| {
+                if bt <= tol {
+                    Complex::new(f64::INFINITY, 0.0)
+                } else {
+                    a / bt
+                }
+            })
+            .collect();
+
+        // 有限固有値を (実部→虚部) 順、無限固有値を末尾に並べる
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| {
+            let (x, y) = (eigen_values[i], eigen_values[j]);
+            match (x.re.is_infinite(), y.re.is_infinite()) {
+                (true, true) => std::cmp::Ordering::Equal,
+                (true, false) => std::cmp::Ordering::Greater,
+                (false, true) => std::cmp::Ordering::Less,
+                (false, false) => {
+                    x.re.partial_cmp(&y.re)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                        .then(x.im.partial_cmp(&y.im).unwrap_or(std::cmp::Ordering::Equal))
+                }
+            }
+        });
+        let mut sorted_vectors = Matrix::zeros(n, n);
+        for (new_idx, &old_idx) in order.iter().enumerate() {
+            sorted_vectors.set_col(new_idx, &eigen_vectors.col(old_idx)?)?;
+        }
+
+        Ok(GeneralizedEigenComplex {
+            alpha: order.iter().map(|&i| alpha[i]).collect(),
+            beta: order.iter().map(|&i| beta[i]).collect(),
+            eigen_values: order.iter().map(|&i| eigen_values[i]).collect(),
+            eigen_vectors: sorted_vectors,
+        })
+    }
+}
+
+fn check_pair(a: &Matrix<f64>, b: &Matrix<f64>) -> Result<()> {
+    if !a.is_square() || !b.is_square() {
+        return Err(LinalgError::NotSquareMatrix);
+    }
+    if a.rows != b.rows {
+        return Err(LinalgError::DimensionMismatch {
+            expected: format!("{}x{}", a.rows, a.cols),
+            found: format!("{}x{}", b.rows, b.cols),
+        });
+    }
+    Ok(())
+}
+
+/// `symmetric_eigen` と同じ許容誤差 1e-10 · max(max|m_ij|, 1) で M = M^T を確かめる。
+/// C の対称化は丸め誤差を整えるだけなので、非対称な入力はここで弾く
+fn check_symmetric(m: &Matrix<f64>, name: &str) -> Result<()> {
+    let scale = m.data.iter().fold(0.0f64, |acc, v| acc.max(v.abs()));
+    let tol = 1e-10 * scale.max(1.0);
+    let symmetric = (0..m.rows).all(|i| (0..i).all(|j| (m[(i, j)] - m[(j, i)]).abs() <= tol));
+    if symmetric {
+        Ok(())
+    } else {
+        Err(LinalgError::InvalidArgument {
+            text: format!("generalized_symmetric_eigen requires a symmetric {name}"),
+        })
+    }
+}
+
+/// 下三角 L に対し L X = M を列ごとの前進代入で解く
+fn solve_lower_columns(l: &Matrix<f64>, m: &Matrix<f64>) -> Result<Matrix<f64>> {
+    let mut x = Matrix::zeros(m.rows, m.cols);
+    for j in 0..m.cols {
+        let col = l.forward_substitution(&m.col(j)?)?;
+        x.set_col(j, &col)?;
+    }
+    Ok(x)
+}
@@ -0,0 +1,263 @@
+use num_complex::Complex;
+
+use super::QZ;
+use crate::matrix::numerical::helpers::{complex_givens, rotate_cols_adjoint, rotate_rows};
+use crate::{LinalgError, Matrix, Result};
+
+type C64 = Complex<f64>;
+
+/// 列 lo, hi に右から G を掛ける（全行）: new_lo = c col_lo - conj(s) col_hi, new_hi = s col_lo + c col_hi
+fn rotate_cols(m: &mut Matrix<C64>, lo: usize, hi: usize, c: f64, s: C64) {
+    for row in 0..m.rows {
+        let (x, y) = (m[(row, lo)], m[(row, hi)]);
+        m[(row, lo)] = x * c - s.conj() * y;
+        m[(row, hi)] = s * x + y * c;
+    }
+}
+
+/// 行 i, k を左回転して m[(k, col)] を消去し、H, T, Q を更新する
+fn left_zero(
+    h: &mut Matrix<C64>,
+    t: &mut Matrix<C64>,
+    q: &mut Matrix<C64>,
+    target_is_h: bool,
+    i: usize,
+    k: usize,
+    col: usize,
+) {
+    let m = if target_is_h { &*h } else { &*t };
+    let (c, s) = complex_givens(m[(i, col)], m[(k, col)]);
+    rotate_rows(h, i, k, c, s);
+    rotate_rows(t, i, k, c, s);
+    // A = Q H Z^H を保つため Q <- Q G^H
+    rotate_cols_adjoint(q, i, k, c, s);
+    if target_is_h {
+        h[(k, col)] = C64::new(0.0, 0.0);
+    } else {
+        t[(k, col)] = C64::new(0.0, 0.0);
+    }
+}
+
+/// 列 lo, lo+1 を右回転して m[(row, lo)] を消去し、H, T, Z を更新する
+fn right_zero(
+    h: &mut Matrix<C64>,
+    t: &mut Matrix<C64>,
+    z: &mut Matrix<C64>,
+    target_is_h: bool,
+    row: usize,
+    lo: usize,
+) {
+    let hi = lo + 1;
+    let m = if target_is_h { &*h } else { &*t };
+    let (c, s) = complex_givens(m[(row, hi)], m[(row, lo)]);
+    rotate_cols(h, lo, hi, c, s);
+    rotate_cols(t, lo, hi, c, s);
+    rotate_cols(z, lo, hi, c, s);
+    if target_is_h {
+        h[(row, lo)] = C64::new(0.0, 0.0);
+    } else {
+        t[(row, lo)] = C64::new(0.0, 0.0);
+    }
+}
+
+/// 複素 QZ 法。Hessenberg-三角化の後、単一シフトの QZ 反復で一般化 Schur 形にする。
+pub(super) fn complex_qz(a: &Matrix<C64>, b: &Matrix<C64>) -> Result<QZ> {
+    let n = a.rows;
+    let mut h = a.clone();
+    let mut t = b.clone();
+    let mut q: Matrix<C64> = Matrix::identity(n);
+    let mut z: Matrix<C64> = Matrix::identity(n);
+
+    // 1. B を上三角化（左 Givens）
+    for j in 0..n {
+        for i in ((j + 1)..n).rev() {
+            left_zero(&mut h, &mut t, &mut q, false, i - 1, i, j);
+        }
+    }
+
+    // 2. T の三角性を保ったまま H を上 Hessenberg 化
+    for j in 0..n.saturating_sub(2) {
+        for i in ((j + 2)..n).rev() {
+            left_zero(&mut h, &mut t, &mut q, true, i - 1, i, j);
+            right_zero(&mut h, &mut t, &mut z, false, i, i - 1);
+        }
+    }
+
+    // 3. QZ 反復
+    let eps = f64::EPSILON;
+    let h_norm = h.frobenius_norm();
+    let t_tol = eps * t.frobenius_norm();
+    let max_iter = 30 * n.max(1);
+    let mut total_iter = 0;
+    let mut since_deflation = 0;
+    let mut ilast = n.saturating_sub(1);
+
+    while ilast > 0 {
+        // 下端の副対角が小さければ 1x1 ブロックを確定
+        if is_negligible_subdiag(&h, ilast, h_norm) {
+            h[(ilast, ilast - 1)] = C64::new(0.0, 0.0);
+            ilast -= 1;
+            since_deflation = 0;
+            continue;
+        }
+        // T の対角がゼロなら無限固有値として右回転で分離
+        if t[(ilast, ilast)].norm() <= t_tol {
+            t[(ilast, ilast)] = C64::new(0.0, 0.0);
+            right_zero(&mut h, &mut t, &mut z, true, ilast, ilast - 1);
+            ilast -= 1;
+            since_deflation = 0;
+            continue;
+        }
+
+        // 活性ブロック [ifirst, ilast] を探す。途中で T の対角ゼロを見つけたら下端へ追い出す。
+        let mut ifirst = 0;
+        let mut chased = false;
+        for j in (0..ilast).rev() {
+            if t[(j, j)].norm() <= t_tol {
+                t[(j, j)] = C64::new(0.0, 0.0);
+                for k in j..ilast {
+                    left_zero(&mut h, &mut t, &mut q, false, k, k + 1, k + 1);
+                    if k > 0 {
+                        right_zero(&mut h, &mut t, &mut z, true, k + 1, k - 1);
+                    }
+                }
+                chased = true;
+                break;
+            }
+            if j > 0 && is_negligible_subdiag(&h, j, h_norm) {
+                h[(j, j - 1)] = C64::new(0.0, 0.0);
+                ifirst = j;
+                break;
+            }
+        }
+        if chased {
+            continue;
+        }
+
+        total_iter += 1;
+        since_deflation += 1;
+        if total_iter > max_iter {
+            return Err(LinalgError::InvalidArgument {
+                text: "Maximum total iterations reached".into(),
+            });
+        }
+
+        let shift = if since_deflation % 10 == 0 {
+            // 停滞を避ける例外シフト
+            let w = h[(ilast, ilast - 1)] / t[(ilast - 1, ilast - 1)];
+            h[(ilast, ilast)] / t[(ilast, ilast)] + C64::new(w.norm(), 0.0)
+        } else {
+            wilkinson_shift(&h, &t, ilast)
+        };
+        qz_sweep(&mut h, &mut t, &mut q, &mut z, ifirst, ilast, shift);
+    }
+
+    Ok(QZ { q, z, s: h, t })
+}
+
+fn is_negligible_subdiag(h: &Matrix<C64>, j: usize, h_norm: f64) -> bool {
+    let mut scale = h[(j, j)].norm() + h[(j - 1, j - 1)].norm();
+    if scale == 0.0 {
+        scale = h_norm;
+    }
+    h[(j, j - 1)].norm() <= f64::EPSILON * scale
+}
+
+/// 末尾 2x2 の H T^{-1} の固有値のうち、右下要素に近い方をシフトに採る
+fn wilkinson_shift(h: &Matrix<C64>, t: &Matrix<C64>, ilast: usize) -> C64 {
+    let k = ilast - 1;
+    let (t11, t12, t22) = (t[(k, k)], t[(k, ilast)], t[(ilast, ilast)]);
+    let (h11, h12, h21, h22) = (h[(k, k)], h[(k, ilast)], h[(ilast, k)], h[(ilast, ilast)]);
+    // C = H2 T2^{-1}
+    let c11 = h11 / t11;
+    let c12 = (h12 - c11 * t12) / t22;
+    let c21 = h21 / t11;
+    let c22 = (h22 - c21 * t12) / t22;
+    let half_tr = (c11 + c22) * 0.5;
+    let det = c11 * c22 - c12 * c21;
+    let disc = (half_tr * half_tr - det).sqrt();
+    let (l1, l2) = (half_tr + disc, half_tr - disc);
+    if (l1 - c22).norm() <= (l2 - c22).norm() {
+        l1
+    } else {
+        l2
+    }
+}
+
+/// 単一シフトの陰的 QZ ステップ（バルジ追跡）
+fn qz_sweep(
+    h: &mut Matrix<C64>,
+    t: &mut Matrix<C64>,
+    q: &mut Matrix<C64>,
+    z: &mut Matrix<C64>,
+    ifirst: usize,
+    ilast: usize,
+    shift: C64,
+) {
+    // (H - σT) の第 1 列から最初の回転を作る
+    let x = h[(ifirst, ifirst)] - shift * t[(ifirst, ifirst)];
+    let y = h[(ifirst + 1, ifirst)];
+    let (c, s) = complex_givens(x, y);
+    rotate_rows(h, ifirst, ifirst + 1, c, s);
+    rotate_rows(t, ifirst, ifirst + 1, c, s);
+    rotate_cols_adjoint(q, ifirst, ifirst + 1, c, s);
+
+    for k in ifirst..ilast {
+        // T に生じた下副対角を右回転で消す
+        right_zero(h, t, z, false, k + 1, k);
+        // H に生じたバルジを左回転で 1 段下へ送る
+        if k + 1 < ilast {
+            left_zero(h, t, q, true, k + 1, k + 2, k);
+        }
+    }
+}
+
+/// 上三角ペア (S, T) から各固有値の固有ベクトル x = Z y を求める。
+/// (β S - α T) y = 0 を後退代入で解き、2 ノルムで正規化する。
+pub(super) fn pencil_eigenvectors(qz: &QZ, alpha: &[C64], beta: &[f64]) -> Result<Matrix<C64>> {
+    let n = qz.s.rows;
+    let s_norm = qz.s.frobenius_norm();
+    let t_norm = qz.t.frobenius_norm();
+    let mut vectors: Matrix<C64> = Matrix::zeros(n, n);
+
+    for i in 0..n {
+        let (a, b) = (alpha[i], beta[i]);
+        let small = (f64::EPSILON * (b * s_norm + a.norm() * t_norm)).max(f64::MIN_POSITIVE);
+        let m = |r: usize, c: usize| qz.s[(r, c)] * b - a * qz.t[(r, c)];
+
+        let mut y = vec![C64::new(0.0, 0.0); n];
+        y[i] = C64::new(1.0, 0.0);
+        for k in (0..i).rev() {
+            let mut sum = C64::new(0.0, 0.0);
+            for (j, yj) in y.iter().enumerate().take(i + 1).skip(k + 1) {
+                sum += m(k, j) * yj;
+            }
+            let mut d = m(k, k);
+            if d.norm() < small {
+                d = C64::new(small, 0.0);
+            }
+            y[k] = -sum / d;
+        }
+
+        let mut norm_sq = 0.0;
+        let mut x = vec![C64::new(0.0, 0.0); n];
+        for (r, xr) in x.iter_mut().enumerate() {
+            let mut acc = C64::new(0.0, 0.0);
+            for (j, yj) in y.iter().enumerate().take(i + 1) {
+                acc += qz.z[(r, j)] * yj;
+            }
+            norm_sq += acc.norm_sqr();
+            *xr = acc;
+        }
+        let norm = norm_sq.sqrt();
+        if norm == 0.0 || !norm.is_finite() {
+            return Err(LinalgError::InvalidArgument {
+                text: "Failed to compute generalized eigenvector".into(),
+            });
+        }
+        for (r, xr) in x.into_iter().enumerate() {
+            vectors[(r, i)] = xr / norm;
+        }
+    }
+    Ok(vectors)
+}
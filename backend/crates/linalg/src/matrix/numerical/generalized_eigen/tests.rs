@@ -0,0 +1,221 @@
+use num_complex::Complex;
+
+use super::{GeneralizedEigenDecomposition, QZ};
+use crate::{LinalgError, Matrix};
+
+fn approx(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol
+}
+
+fn complex_product(a: &Matrix<Complex<f64>>, b: &Matrix<Complex<f64>>) -> Matrix<Complex<f64>> {
+    let mut c = Matrix::zeros(a.rows, b.cols);
+    for i in 0..a.rows {
+        for j in 0..b.cols {
+            let mut acc = Complex::new(0.0, 0.0);
+            for k in 0..a.cols {
+                acc += a[(i, k)] * b[(k, j)];
+            }
+            c[(i, j)] = acc;
+        }
+    }
+    c
+}
+
+fn adjoint(a: &Matrix<Complex<f64>>) -> Matrix<Complex<f64>> {
+    let mut c = Matrix::zeros(a.cols, a.rows);
+    for i in 0..a.rows {
+        for j in 0..a.cols {
+            c[(j, i)] = a[(i, j)].conj();
+        }
+    }
+    c
+}
+
+fn assert_close(m: &Matrix<Complex<f64>>, expected: &Matrix<f64>, tol: f64, label: &str) {
+    for i in 0..m.rows {
+        for j in 0..m.cols {
+            let d = m[(i, j)] - Complex::new(expected[(i, j)], 0.0);
+            assert!(
+                d.norm() <= tol,
+                "{label} mismatch at ({i}, {j}): {}",
+                m[(i, j)]
+            );
+        }
+    }
+}
+
+fn assert_qz_structure(qz: &QZ, a: &Matrix<f64>, b: &Matrix<f64>, tol: f64) {
+    let n = a.rows;
+    for i in 0..n {
+        for j in 0..i {
+            assert!(qz.s[(i, j)].norm() <= tol, "S not triangular at ({i}, {j})");
+            assert!(qz.t[(i, j)].norm() <= tol, "T not triangular at ({i}, {j})");
+        }
+    }
+    let zh = adjoint(&qz.z);
+    assert_close(
+        &complex_product(&complex_product(&qz.q, &qz.s), &zh),
+        a,
+        tol,
+        "Q S Z^H",
+    );
+    assert_close(
+        &complex_product(&complex_product(&qz.q, &qz.t), &zh),
+        b,
+        tol,
+        "Q T Z^H",
+    );
+    assert_close(
+        &complex_product(&adjoint(&qz.q), &qz.q),
+        &Matrix::identity(n),
+        tol,
+        "Q^H Q",
+    );
+    assert_close(
+        &complex_product(&zh, &qz.z),
+        &Matrix::identity(n),
+        tol,
+        "Z^H Z",
+    );
+}
+
+#[test]
+fn generalized_symmetric_eigen_is_b_orthonormal() {
+    let a = Matrix::new(3, 3, vec![4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0]).unwrap();
+    let b = Matrix::new(3, 3, vec![2.0, 0.5, 0.0, 0.5, 2.0, 0.5, 0.0, 0.5, 1.0]).unwrap();
+    let eig = a.generalized_symmetric_eigen(&b).unwrap();
+    let v = &eig.eigen_vectors;
+
+    for w in eig.eigen_values.windows(2) {
+        assert!(w[0] <= w[1]);
+    }
+    // V^T B V = I, V^T A V = diag(λ)
+    let vtbv = &(&v.transpose() * &b) * v;
+    let vtav = &(&v.transpose() * &a) * v;
+    for i in 0..3 {
+        for j in 0..3 {
+            let id = if i == j { 1.0 } else { 0.0 };
+            let lam = if i == j { eig.eigen_values[i] } else { 0.0 };
+            assert!(approx(vtbv[(i, j)], id, 1e-10), "V^T B V at ({i}, {j})");
+            assert!(approx(vtav[(i, j)], lam, 1e-10), "V^T A V at ({i}, {j})");
+        }
+    }
+}
+
+#[test]
+fn generalized_symmetric_eigen_with_identity_matches_standard() {
+    let a = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 2.0]).unwrap();
+    let eig = a.generalized_symmetric_eigen(&Matrix::identity(2)).unwrap();
+    assert!(approx(eig.eigen_values[0], 1.0, 1e-12));
+    assert!(approx(eig.eigen_values[1], 3.0, 1e-12));
+}
+
+#[test]
+fn generalized_symmetric_eigen_rejects_indefinite_b() {
+    let a = Matrix::<f64>::identity(2);
+    let b = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, -1.0]).unwrap();
+    assert!(matches!(
+        a.generalized_symmetric_eigen(&b),
+        Err(LinalgError::InvalidArgument { .. })
+    ));
+}
+
+#[test]
+fn generalized_symmetric_eigen_rejects_nonsymmetric_a() {
+    let a = Matrix::new(2, 2, vec![2.0, 1.0, 0.0, 2.0]).unwrap();
+    assert!(matches!(
+        a.generalized_symmetric_eigen(&Matrix::identity(2)),
+        Err(LinalgError::InvalidArgument { .. })
+    ));
+    // B も対称でなければならない（Cholesky は下三角しか読まない）
+    let b = Matrix::new(2, 2, vec![2.0, 1.0, 0.0, 2.0]).unwrap();
+    assert!(Matrix::<f64>::identity(2)
+        .generalized_symmetric_eigen(&b)
+        .is_err());
+}
+
+#[test]
+fn qz_reconstructs_general_pair() {
+    let a = Matrix::new(
+        4,
+        4,
+        vec![
+            1.0, 2.0, 0.0, 3.0, -1.0, 0.5, 4.0, 1.0, 2.0, 0.0, -3.0, 1.0, 0.0, 1.0, 1.0, 2.0,
+        ],
+    )
+    .unwrap();
+    let b = Matrix::new(
+        4,
+        4,
+        vec![
+            3.0, 1.0, 0.0, 0.0, 1.0, 2.0, 1.0, 0.0, 0.0, -1.0, 2.0, 1.0, 1.0, 0.0, 0.0, 1.0,
+        ],
+    )
+    .unwrap();
+    let qz = a.qz(&b).unwrap();
+    assert_qz_structure(&qz, &a, &b, 1e-10);
+}
+
+#[test]
+fn generalized_eigen_complex_pair_residuals() {
+    // A = [[0, 1], [-1, 0]], B = diag(1, 2) → λ^2 = -1/2
+    let a = Matrix::new(2, 2, vec![0.0, 1.0, -1.0, 0.0]).unwrap();
+    let b = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 2.0]).unwrap();
+    let eig = a.generalized_eigen(&b).unwrap();
+    let w = 0.5f64.sqrt();
+    assert!((eig.eigen_values[0] - Complex::new(0.0, -w)).norm() < 1e-10);
+    assert!((eig.eigen_values[1] - Complex::new(0.0, w)).norm() < 1e-10);
+    assert_pencil_residuals(&a, &b, &eig, 1e-10);
+}
+
+#[test]
+fn generalized_eigen_singular_b_gives_infinite_eigenvalue() {
+    let a = Matrix::new(3, 3, vec![2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0]).unwrap();
+    let b = Matrix::new(3, 3, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]).unwrap();
+    let eig = a.generalized_eigen(&b).unwrap();
+
+    assert!(!eig.is_infinite(0));
+    assert!(!eig.is_infinite(1));
+    assert!(eig.is_infinite(2));
+    assert!(eig.beta[2].abs() < 1e-12);
+    assert_pencil_residuals(&a, &b, &eig, 1e-10);
+}
+
+#[test]
+fn generalized_eigen_rank_deficient_b_in_leading_block() {
+    // B の先頭側に零固有値がある場合（T の対角ゼロを下端へ追い出す経路）
+    let a = Matrix::new(3, 3, vec![1.0, 2.0, 0.0, 0.0, 1.0, 3.0, 1.0, 0.0, 2.0]).unwrap();
+    let b = Matrix::new(3, 3, vec![0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0]).unwrap();
+    let qz = a.qz(&b).unwrap();
+    assert_qz_structure(&qz, &a, &b, 1e-10);
+    let eig = a.generalized_eigen(&b).unwrap();
+    assert_eq!((0..3).filter(|&i| eig.is_infinite(i)).count(), 1);
+    assert!(eig.is_infinite(2));
+    assert_pencil_residuals(&a, &b, &eig, 1e-10);
+}
+
+fn assert_pencil_residuals(
+    a: &Matrix<f64>,
+    b: &Matrix<f64>,
+    eig: &super::GeneralizedEigenComplex,
+    tol: f64,
+) {
+    let n = a.rows;
+    for k in 0..n {
+        let mut norm_sq = 0.0;
+        for i in 0..n {
+            // β A x - α B x = 0
+            let mut r = Complex::new(0.0, 0.0);
+            for j in 0..n {
+                let x = eig.eigen_vectors[(j, k)];
+                r += (x * a[(i, j)]) * eig.beta[k] - eig.alpha[k] * x * b[(i, j)];
+            }
+            norm_sq += r.norm_sqr();
+        }
+        assert!(
+            norm_sq.sqrt() <= tol,
+            "residual for eigenpair {k}: {}",
+            norm_sq.sqrt()
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use num_complex::Complex;
+
+use crate::matrix::numerical::SchurDecomposition;
+use crate::{Matrix, Result};
+
+type C64 = Complex<f64>;
+
+/// 複素 Givens 回転 G = [[c, s], [-conj(s), c]] を求める。G [a; b] = [r; 0]。
+pub(in crate::matrix::numerical) fn complex_givens(a: C64, b: C64) -> (f64, C64) {
+    let (na, nb) = (a.norm(), b.norm());
+    if nb == 0.0 {
+        return (1.0, C64::new(0.0, 0.0));
+    }
+    if na == 0.0 {
+        return (0.0, b.conj() / nb);
+    }
+    let r = na.hypot(nb);
+    (na / r, (a / na) * b.conj() / r)
+}
+
+/// 行 i, k に左から G を掛ける（全列）
+pub(in crate::matrix::numerical) fn rotate_rows(
+    m: &mut Matrix<C64>,
+    i: usize,
+    k: usize,
+    c: f64,
+    s: C64,
+) {
+    for col in 0..m.cols {
+        let (x, y) = (m[(i, col)], m[(k, col)]);
+        m[(i, col)] = x * c + s * y;
+        m[(k, col)] = -s.conj() * x + y * c;
+    }
+}
+
+/// 列 i, k に右から G^H を掛ける（全行）
+pub(in crate::matrix::numerical) fn rotate_cols_adjoint(
+    m: &mut Matrix<C64>,
+    i: usize,
+    k: usize,
+    c: f64,
+    s: C64,
+) {
+    for row in 0..m.rows {
+        let (x, y) = (m[(row, i)], m[(row, k)]);
+        m[(row, i)] = x * c + s.conj() * y;
+        m[(row, k)] = -s * x + y * c;
+    }
+}
+
+/// 相似変換 T <- G T G^H を行 / 列 i, k に適用し、U <- U G^H も更新する
+fn similarity_rotate(t: &mut Matrix<C64>, u: &mut Matrix<C64>, i: usize, k: usize, c: f64, s: C64) {
+    rotate_rows(t, i, k, c, s);
+    rotate_cols_adjoint(t, i, k, c, s);
+    rotate_cols_adjoint(u, i, k, c, s);
+}
+
+/// 実行列の複素 Schur 分解 A = U T U^H（U ユニタリ、T 上三角）。
+/// 実 Schur 分解（`schur`）の 2x2 ブロックを、その固有ベクトルに合わせた複素 Givens 回転で上三角化する（rsf2csf）。
+pub(in crate::matrix::numerical) fn complex_schur(
+    a: &Matrix<f64>,
+) -> Result<(Matrix<C64>, Matrix<C64>)> {
+    let (q, t) = a.schur()?;
+    let mut t = t.to_complex();
+    let mut u = q.to_complex();
+    let n = t.rows;
+    for m in (1..n).rev() {
+        if t[(m, m - 1)] == C64::new(0.0, 0.0) {
+            continue;
+        }
+        // ブロックの固有値 μ の固有ベクトル x = (μ - t_22, t_21) を e_1 方向へ回す
+        let k = m - 1;
+        let (t11, t12, t21, t22) = (t[(k, k)], t[(k, m)], t[(m, k)], t[(m, m)]);
+        let half_tr = (t11 + t22) * 0.5;
+        let mu = half_tr + (half_tr * half_tr - (t11 * t22 - t12 * t21)).sqrt();
+        let (c, s) = complex_givens(mu - t22, t21);
+        similarity_rotate(&mut t, &mut u, k, m, c, s);
+        t[(m, k)] = C64::new(0.0, 0.0);
+    }
+    Ok((u, t))
+}
+
+/// 上三角 Schur 形の隣接対角要素 k, k+1 を入れ替える（T <- G T G^H, U <- U G^H）
+pub(in crate::matrix::numerical) fn swap_schur_diagonal(
+    t: &mut Matrix<C64>,
+    u: &mut Matrix<C64>,
+    k: usize,
+) {
+    let (t11, t22) = (t[(k, k)], t[(k + 1, k + 1)]);
+    let (c, s) = complex_givens(t[(k, k + 1)], t22 - t11);
+    similarity_rotate(t, u, k, k + 1, c, s);
+    t[(k, k)] = t22;
+    t[(k + 1, k + 1)] = t11;
+    t[(k + 1, k)] = C64::new(0.0, 0.0);
+}
+
+/// 複素 Householder ベクトル v（||v|| = 1）を求める。H = I - 2 v v^H は H x = α e_1（|α| = ||x||）を満たす。
+/// x がゼロベクトルなら None。
+pub(in crate::matrix::numerical) fn complex_householder(x: &[C64]) -> Option<Vec<C64>> {
+    let norm = x.iter().map(|z| z.norm_sqr()).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return None;
+    }
+    // α = -e^{i arg x_0} ||x|| として桁落ちを避ける
+    let phase = if x[0].norm() == 0.0 {
+        C64::new(1.0, 0.0)
+    } else {
+        x[0] / x[0].norm()
+    };
+    let mut v = x.to_vec();
+    v[0] += phase * norm;
+    let vnorm = v.iter().map(|z| z.norm_sqr()).sum::<f64>().sqrt();
+    if vnorm == 0.0 {
+        return None;
+    }
+    for z in v.iter_mut() {
+        *z /= vnorm;
+    }
+    Some(v)
+}
+
+/// 行 start_row.. と列 start_col.. の部分に左から H = I - 2 v v^H を掛ける
+pub(in crate::matrix::numerical) fn apply_householder_left(
+    m: &mut Matrix<C64>,
+    v: &[C64],
+    start_row: usize,
+    start_col: usize,
+) {
+    for j in start_col..m.cols {
+        let mut dot = C64::new(0.0, 0.0);
+        for (i, vi) in v.iter().enumerate() {
+            dot += vi.conj() * m[(start_row + i, j)];
+        }
+        for (i, vi) in v.iter().enumerate() {
+            m[(start_row + i, j)] -= *vi * dot * 2.0;
+        }
+    }
+}
+
+/// 全行と列 start_col.. の部分に右から H = I - 2 v v^H を掛ける
+pub(in crate::matrix::numerical) fn apply_householder_right(
+    m: &mut Matrix<C64>,
+    v: &[C64],
+    start_col: usize,
+) {
+    for i in 0..m.rows {
+        let mut dot = C64::new(0.0, 0.0);
+        for (j, vj) in v.iter().enumerate() {
+            dot += m[(i, start_col + j)] * *vj;
+        }
+        for (j, vj) in v.iter().enumerate() {
+            m[(i, start_col + j)] -= dot * vj.conj() * 2.0;
+        }
+    }
+}
+
+/// 先頭 k 列が正規直交な m×m 行列の残りの列を、標準基底の Gram–Schmidt で埋めてユニタリにする
+pub(in crate::matrix::numerical) fn complete_unitary(u: &mut Matrix<C64>, k: usize) {
+    let m = u.rows;
+    let mut filled = k;
+    for e in 0..m {
+        if filled >= m {
+            break;
+        }
+        let mut cand = vec![C64::new(0.0, 0.0); m];
+        cand[e] = C64::new(1.0, 0.0);
+        // 再直交化を 2 回行って直交性を保つ
+        for _ in 0..2 {
+            for j in 0..filled {
+                let mut proj = C64::new(0.0, 0.0);
+                for (i, c) in cand.iter().enumerate() {
+                    proj += u[(i, j)].conj() * c;
+                }
+                for (i, c) in cand.iter_mut().enumerate() {
+                    *c -= u[(i, j)] * proj;
+                }
+            }
+        }
+        let norm = cand.iter().map(|z| z.norm_sqr()).sum::<f64>().sqrt();
+        if norm > 1e-8 {
+            for (i, c) in cand.iter().enumerate() {
+                u[(i, filled)] = c / norm;
+            }
+            filled += 1;
+        }
+    }
+}
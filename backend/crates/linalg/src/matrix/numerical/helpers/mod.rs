@@ -1,8 +1,47 @@
 use num_complex::Complex;
 
-use crate::matrix::{Direction, Matrix};
+use crate::matrix::{Direction, Matrix, MatrixView, MatrixViewMut};
 use crate::Vector;
 
+mod complex;
+
+pub(super) use complex::{
+    apply_householder_left, apply_householder_right, complete_unitary, complex_givens,
+    complex_householder, complex_schur, rotate_cols_adjoint, rotate_rows, swap_schur_diagonal,
+};
+
+/// ビュー（列または行）から Householder ベクトルを作る。要素を Vector に集めて
+/// `Vector::householder_vector` に委ねる（H = I - 2 v v^T は x を -sign(x_0) ||x|| e_1 に写す）。
+pub(super) fn householder_vector(x: MatrixView<'_, f64>) -> Option<Vector<f64>> {
+    Vector::new(x.iter().copied().collect()).householder_vector()
+}
+
+/// ビュー全体に左から H = I - 2 v v^T を掛ける（v の長さはビューの行数）
+pub(super) fn householder_left(mut a: MatrixViewMut<'_, f64>, v: &[f64]) {
+    for j in 0..a.cols() {
+        let mut dot_product = 0.0;
+        for (i, vi) in v.iter().enumerate() {
+            dot_product += vi * a[(i, j)];
+        }
+        for (i, vi) in v.iter().enumerate() {
+            a[(i, j)] -= 2.0 * vi * dot_product;
+        }
+    }
+}
+
+/// ビュー全体に右から H = I - 2 v v^T を掛ける（v の長さはビューの列数）
+pub(super) fn householder_right(mut a: MatrixViewMut<'_, f64>, v: &[f64]) {
+    for i in 0..a.rows() {
+        let mut dot_product = 0.0;
+        for (j, vj) in v.iter().enumerate() {
+            dot_product += a[(i, j)] * vj;
+        }
+        for (j, vj) in v.iter().enumerate() {
+            a[(i, j)] -= 2.0 * vj * dot_product;
+        }
+    }
+}
+
 // このimplブロックに共通ヘルパー関数を集約する
 impl Matrix<f64> {
     pub(super) fn to_hessenberg(&self) -> Option<(Matrix<f64>, Matrix<f64>)> {
@@ -15,11 +54,10 @@ impl Matrix<f64> {
         // usizeの引き算でオーバーフローしないように saturating_sub を使うとより安全です
         for k in 0..self.rows.saturating_sub(2) {
             // k列目のk+1行目以降を対象にベクトルを抽出
-            let x = h.partial_col(k, k + 1, self.rows).ok()?;
+            let x = h.view().slice(k + 1..self.rows, k..k + 1).ok()?;
 
             // 1. ハウスホルダー "ベクトル" を計算 (行列は作らない)
-            // Vectorに実装されたhouseholder_vectorメソッドを使います
-            let Some(h_vec) = x.householder_vector() else {
+            let Some(h_vec) = householder_vector(x) else {
                 continue;
             };
 
@@ -44,40 +82,29 @@ impl Matrix<f64> {
         start_row: usize,
         start_col: usize,
     ) -> Option<()> {
+        let (rows, cols) = (self.rows, self.cols);
+        let v = &householder_vector.data;
+        if start_row >= rows || start_col >= cols {
+            return Some(());
+        }
         match direction {
             Direction::Left => {
-                // H * A の計算
-                for j in start_col..self.cols {
-                    let mut dot_product = 0.0;
-                    for i in 0..householder_vector.dim() {
-                        if start_row + i < self.rows {
-                            dot_product += householder_vector[i] * self[(start_row + i, j)];
-                        }
-                    }
-
-                    for i in 0..householder_vector.dim() {
-                        if start_row + i < self.rows {
-                            self[(start_row + i, j)] -= 2.0 * householder_vector[i] * dot_product;
-                        }
-                    }
-                }
+                // H * A の計算（行 start_row から v の長さ分、列 start_col 以降）
+                let end = rows.min(start_row + v.len());
+                let block = self
+                    .view_mut()
+                    .slice_mut(start_row..end, start_col..cols)
+                    .ok()?;
+                householder_left(block, &v[..end - start_row]);
             }
             Direction::Right => {
-                // A * H の計算
-                for i in start_row..self.rows {
-                    let mut dot_product = 0.0;
-                    for j in 0..householder_vector.dim() {
-                        if start_col + j < self.cols {
-                            dot_product += self[(i, start_col + j)] * householder_vector[j];
-                        }
-                    }
-
-                    for j in 0..householder_vector.dim() {
-                        if start_col + j < self.cols {
-                            self[(i, start_col + j)] -= 2.0 * householder_vector[j] * dot_product;
-                        }
-                    }
-                }
+                // A * H の計算（行 start_row 以降、列 start_col から v の長さ分）
+                let end = cols.min(start_col + v.len());
+                let block = self
+                    .view_mut()
+                    .slice_mut(start_row..rows, start_col..end)
+                    .ok()?;
+                householder_right(block, &v[..end - start_col]);
             }
         }
         Some(())
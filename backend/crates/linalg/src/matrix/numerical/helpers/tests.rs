@@ -252,3 +252,47 @@ fn test_nullspace_vector_full_rank_returns_none() {
         "full rank should have no nullspace"
     );
 }
+
+fn complex_reconstruct(u: &Matrix<Complex<f64>>, t: &Matrix<Complex<f64>>) -> Matrix<Complex<f64>> {
+    let mut uh = Matrix::zeros(u.cols, u.rows);
+    for i in 0..u.rows {
+        for j in 0..u.cols {
+            uh[(j, i)] = u[(i, j)].conj();
+        }
+    }
+    &(u * t) * &uh
+}
+
+#[test]
+fn test_complex_schur_reconstructs_and_is_triangular() {
+    // 実固有値 1 個と複素共役対を持つ行列
+    let a = Matrix::new(3, 3, vec![1.0, 2.0, 0.0, -2.0, 1.0, 1.0, 0.5, 0.0, 3.0]).unwrap();
+    let (u, t) = complex_schur(&a).unwrap();
+    for i in 0..3 {
+        for j in 0..i {
+            assert_eq!(t[(i, j)], Complex::new(0.0, 0.0));
+        }
+    }
+    let recon = complex_reconstruct(&u, &t);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((recon[(i, j)] - Complex::new(a[(i, j)], 0.0)).norm() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_swap_schur_diagonal_preserves_similarity() {
+    let a = Matrix::new(3, 3, vec![4.0, 1.0, 2.0, 0.0, 1.0, -1.0, 1.0, 0.0, 2.0]).unwrap();
+    let (mut u, mut t) = complex_schur(&a).unwrap();
+    let (d0, d1) = (t[(0, 0)], t[(1, 1)]);
+    swap_schur_diagonal(&mut t, &mut u, 0);
+    assert!((t[(0, 0)] - d1).norm() < 1e-12);
+    assert!((t[(1, 1)] - d0).norm() < 1e-12);
+    let recon = complex_reconstruct(&u, &t);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((recon[(i, j)] - Complex::new(a[(i, j)], 0.0)).norm() < 1e-12);
+        }
+    }
+}
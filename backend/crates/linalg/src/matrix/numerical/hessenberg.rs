@@ -0,0 +1,24 @@
+use crate::{LinalgError, Matrix, Result};
+
+pub trait HessenbergDecomposition {
+    /// Householder 変換による上 Hessenberg 分解 A = Q H Q^T。(Q, H) を返す。
+    fn hessenberg(&self) -> Result<(Matrix<f64>, Matrix<f64>)>;
+}
+
+impl HessenbergDecomposition for Matrix<f64> {
+    fn hessenberg(&self) -> Result<(Matrix<f64>, Matrix<f64>)> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let (mut h, q) = self.to_hessenberg().ok_or(LinalgError::InvalidArgument {
+            text: "Hessenberg reduction failed".into(),
+        })?;
+        // 第 1 副対角より下に残る丸め誤差を厳密な 0 にする
+        for i in 2..h.rows {
+            for j in 0..(i - 1) {
+                h[(i, j)] = 0.0;
+            }
+        }
+        Ok((q, h))
+    }
+}
@@ -0,0 +1,335 @@
+use num_complex::Complex;
+
+use super::{
+    norm, orthogonalize, KrylovOptions, LinearOperator, PartialEigen, PartialEigenComplex,
+    SplitMix64, Which,
+};
+use crate::matrix::numerical::schur::{block_eigenvalues, schur_blocks};
+use crate::matrix::numerical::{EigenDecomposition, SchurDecomposition};
+use crate::{LinalgError, Matrix, Result, Vector};
+
+/// Krylov 分解 A V_m = V_m H_m + v_m h_m^T（h_m は H の m 行目）
+struct Factorization {
+    /// 正規直交な基底 v_0, ..., v_m（列ベクトル）
+    v: Vec<Vec<f64>>,
+    /// (m+1) × m の係数行列
+    h: Matrix<f64>,
+    m: usize,
+    rng: SplitMix64,
+}
+
+impl Factorization {
+    fn new(n: usize, m: usize, seed: u64) -> Self {
+        let mut rng = SplitMix64(seed);
+        let mut v0 = rng.normal_vector(n);
+        let nrm = norm(&v0);
+        v0.iter_mut().for_each(|x| *x /= nrm);
+        Self {
+            v: vec![v0],
+            h: Matrix::zeros(m + 1, m),
+            m,
+            rng,
+        }
+    }
+
+    /// 先頭 p 列が確定した状態から、Arnoldi 過程（完全再直交化）で m 列目まで伸ばす
+    fn extend<A: LinearOperator + ?Sized>(&mut self, op: &A, p: usize) {
+        for j in p..self.m {
+            let mut w = op.apply(&Vector::new(self.v[j].clone())).data;
+            let coeffs = orthogonalize(&self.v[..=j], &mut w);
+            for (i, c) in coeffs.iter().enumerate() {
+                self.h[(i, j)] = *c;
+            }
+            let beta = norm(&w);
+            let scale = norm(&coeffs) + beta;
+            if beta <= 1e-12 * scale {
+                // 不変部分空間に達した: 結合係数 0 で新しい方向から続ける
+                self.h[(j + 1, j)] = 0.0;
+                w = self.random_orthogonal(j + 1);
+            } else {
+                self.h[(j + 1, j)] = beta;
+                w.iter_mut().for_each(|x| *x /= beta);
+            }
+            self.v.push(w);
+        }
+    }
+
+    /// v_0, ..., v_{count-1} に直交する単位乱数ベクトル（空間を使い切ったら零）
+    fn random_orthogonal(&mut self, count: usize) -> Vec<f64> {
+        let n = self.v[0].len();
+        if count < n {
+            for _ in 0..3 {
+                let mut w = self.rng.normal_vector(n);
+                orthogonalize(&self.v[..count], &mut w);
+                let nrm = norm(&w);
+                if nrm > 1e-8 {
+                    w.iter_mut().for_each(|x| *x /= nrm);
+                    return w;
+                }
+            }
+        }
+        vec![0.0; n]
+    }
+
+    fn projected(&self) -> Matrix<f64> {
+        self.h.submatrix(0, self.m, 0, self.m)
+    }
+
+    fn beta(&self) -> f64 {
+        self.h[(self.m, self.m - 1)]
+    }
+
+    /// V_m y（y は長さ m の実ベクトル）
+    fn combine(&self, y: impl Fn(usize) -> f64) -> Vec<f64> {
+        let mut x = vec![0.0; self.v[0].len()];
+        for (r, vr) in self.v[..self.m].iter().enumerate() {
+            let c = y(r);
+            for (xi, vi) in x.iter_mut().zip(vr) {
+                *xi += c * vi;
+            }
+        }
+        x
+    }
+
+    /// V_m Y の先頭 p 列と v_m を新しい基底、H を `head`（p×p）と結合行 beta * Y[m-1, :p] にして再始動する
+    fn restart(&mut self, y: &Matrix<f64>, head: &Matrix<f64>, p: usize) {
+        let beta = self.beta();
+        let mut v: Vec<Vec<f64>> = (0..p).map(|c| self.combine(|r| y[(r, c)])).collect();
+        v.push(self.v[self.m].clone());
+        let mut h = Matrix::zeros(self.m + 1, self.m);
+        for i in 0..p {
+            for j in 0..p {
+                h[(i, j)] = head[(i, j)];
+            }
+            h[(p, i)] = beta * y[(self.m - 1, i)];
+        }
+        self.v = v;
+        self.h = h;
+    }
+}
+
+/// ARPACK と同様の収束判定 |残差| <= tol * max(|θ|, ε^{2/3} ||H||)
+fn is_converged(residual: f64, theta: f64, h_norm: f64, tol: f64) -> bool {
+    residual <= tol * theta.max(f64::EPSILON.powf(2.0 / 3.0) * h_norm)
+}
+
+/// 望ましい順（`which` のキーの降順）に並べた添字
+fn rank_by<T: Copy>(
+    values: &[T],
+    which: Which,
+    to_complex: impl Fn(T) -> Complex<f64>,
+) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| {
+        which
+            .key(to_complex(values[b]))
+            .total_cmp(&which.key(to_complex(values[a])))
+    });
+    order
+}
+
+/// 再始動で残す Ritz 値の個数
+fn keep_count(k: usize, m: usize) -> usize {
+    (k + (m - k) / 2).clamp(k.min(m - 1), m - 1)
+}
+
+pub(super) fn symmetric<A: LinearOperator + ?Sized>(
+    op: &A,
+    k: usize,
+    m: usize,
+    which: Which,
+    opts: &KrylovOptions,
+) -> Result<PartialEigen> {
+    let mut f = Factorization::new(op.nrows(), m, opts.seed);
+    let mut p = 0;
+    let mut restarts = 0;
+    loop {
+        f.extend(op, p);
+        // 完全再直交化しているので H_m は丸め誤差を除き対称
+        let hm = f.projected();
+        let sym = &(&hm + &hm.transpose()) * 0.5;
+        let eig = sym.symmetric_eigen()?;
+        let theta = &eig.eigen_values;
+        let y = &eig.eigen_vectors;
+        let beta = f.beta();
+        let order = rank_by(theta, which, |t| Complex::new(t, 0.0));
+        let h_norm = sym.frobenius_norm();
+        let converged = order[..k].iter().all(|&i| {
+            is_converged(
+                (beta * y[(m - 1, i)]).abs(),
+                theta[i].abs(),
+                h_norm,
+                opts.tol,
+            )
+        });
+
+        if converged || restarts >= opts.max_restarts || m == op.nrows() {
+            let mut vectors = Matrix::zeros(op.nrows(), k);
+            for (c, &i) in order[..k].iter().enumerate() {
+                let x = f.combine(|r| y[(r, i)]);
+                for (r, v) in x.into_iter().enumerate() {
+                    vectors[(r, c)] = v;
+                }
+            }
+            return Ok(PartialEigen {
+                values: order[..k].iter().map(|&i| theta[i]).collect(),
+                vectors,
+                restarts,
+                converged: converged || m == op.nrows(),
+            });
+        }
+
+        // 望ましい Ritz 対を残す（H は対角 + 結合行の矢じり形になる）
+        p = keep_count(k, m);
+        let mut y_keep = Matrix::zeros(m, p);
+        let mut head = Matrix::zeros(p, p);
+        for (c, &i) in order[..p].iter().enumerate() {
+            for r in 0..m {
+                y_keep[(r, c)] = y[(r, i)];
+            }
+            head[(c, c)] = theta[i];
+        }
+        f.restart(&y_keep, &head, p);
+        restarts += 1;
+    }
+}
+
+pub(super) fn general<A: LinearOperator + ?Sized>(
+    op: &A,
+    k: usize,
+    m: usize,
+    which: Which,
+    opts: &KrylovOptions,
+) -> Result<PartialEigenComplex> {
+    let mut f = Factorization::new(op.nrows(), m, opts.seed);
+    let mut p = 0;
+    let mut restarts = 0;
+    loop {
+        f.extend(op, p);
+        let hm = f.projected();
+        let beta = f.beta();
+        let (u, t) = hm.schur()?;
+        let ritz = ritz_pairs(&u, &t);
+        let values: Vec<Complex<f64>> = ritz.iter().map(|(z, _)| *z).collect();
+        let order = rank_by(&values, which, |z| z);
+        let h_norm = hm.frobenius_norm();
+        let converged = order[..k].iter().all(|&i| {
+            let y = &ritz[i].1;
+            is_converged((beta * y[m - 1]).norm(), values[i].norm(), h_norm, opts.tol)
+        });
+
+        if converged || restarts >= opts.max_restarts || m == op.nrows() {
+            let mut vectors = Matrix::zeros(op.nrows(), k);
+            for (c, &i) in order[..k].iter().enumerate() {
+                let y = &ritz[i].1;
+                let re = f.combine(|r| y[r].re);
+                let im = f.combine(|r| y[r].im);
+                let nrm = (norm(&re).powi(2) + norm(&im).powi(2)).sqrt();
+                for r in 0..op.nrows() {
+                    vectors[(r, c)] = Complex::new(re[r], im[r]) / nrm;
+                }
+            }
+            return Ok(PartialEigenComplex {
+                values: order[..k].iter().map(|&i| values[i]).collect(),
+                vectors,
+                restarts,
+                converged: converged || m == op.nrows(),
+            });
+        }
+
+        // 望ましい固有値を実 Schur 形の左上へ集める。複素共役対は分けられないので、
+        // 選んだ個数が m に達したら残す数を減らす。
+        let mut keep = keep_count(k, m);
+        let (u_ord, t_ord, sdim) = loop {
+            let threshold = which.key(values[order[keep - 1]]);
+            let margin = 1e-10 * (1.0 + threshold.abs());
+            let (q, s, sdim) = hm.ordered_schur(|z| which.key(z) >= threshold - margin)?;
+            if sdim < m {
+                break (q, s, sdim);
+            }
+            if keep == 1 {
+                return Err(LinalgError::InvalidArgument {
+                    text: "eigs: cannot restart with the requested ncv".into(),
+                });
+            }
+            keep -= 1;
+        };
+        f.restart(&u_ord, &t_ord.submatrix(0, sdim, 0, sdim), sdim);
+        p = sdim;
+        restarts += 1;
+    }
+}
+
+/// 実 Schur 分解 H = U T U^T の各固有値 λ と Ritz ベクトル y = U s（T s = λ s, |y| = 1）
+fn ritz_pairs(u: &Matrix<f64>, t: &Matrix<f64>) -> Vec<(Complex<f64>, Vec<Complex<f64>>)> {
+    let blocks = schur_blocks(t);
+    let mut pairs = Vec::with_capacity(t.rows);
+    for (bi, &(start, size)) in blocks.iter().enumerate() {
+        for lambda in block_eigenvalues(t, start, size) {
+            let s = quasi_triangular_eigenvector(t, &blocks[..bi], start, size, lambda);
+            let mut y: Vec<Complex<f64>> = (0..u.rows)
+                .map(|r| (0..t.rows).map(|c| s[c] * u[(r, c)]).sum())
+                .collect();
+            let nrm = y.iter().map(|z| z.norm_sqr()).sum::<f64>().sqrt();
+            y.iter_mut().for_each(|z| *z /= nrm);
+            pairs.push((lambda, y));
+        }
+    }
+    pairs
+}
+
+/// 上準三角 T の固有ベクトル（固有値 λ はブロック (start, size) のもの）。
+/// 上にあるブロックへ後退代入する。1x1 / 2x2 の対角ブロックごとに小さな複素連立方程式を解く。
+fn quasi_triangular_eigenvector(
+    t: &Matrix<f64>,
+    above: &[(usize, usize)],
+    start: usize,
+    size: usize,
+    lambda: Complex<f64>,
+) -> Vec<Complex<f64>> {
+    let n = t.rows;
+    let tc = |i: usize, j: usize| Complex::new(t[(i, j)], 0.0);
+    let mut s = vec![Complex::new(0.0, 0.0); n];
+    if size == 1 {
+        s[start] = Complex::new(1.0, 0.0);
+    } else {
+        // (T_blk - λ I) の第 1 行 [a - λ, b] に直交するベクトル
+        let (a, b) = (tc(start, start) - lambda, tc(start, start + 1));
+        if a.norm() + b.norm() > 0.0 {
+            s[start] = b;
+            s[start + 1] = -a;
+        } else {
+            s[start] = tc(start + 1, start + 1) - lambda;
+            s[start + 1] = -tc(start + 1, start);
+        }
+    }
+    let end = start + size;
+    // 対角が λ に近すぎるときの摂動（LAPACK trevc と同様）
+    let small = f64::EPSILON * t.frobenius_norm().max(f64::MIN_POSITIVE);
+    for &(j0, js) in above.iter().rev() {
+        let rhs: Vec<Complex<f64>> = (j0..j0 + js)
+            .map(|i| {
+                -(j0 + js..end)
+                    .map(|l| tc(i, l) * s[l])
+                    .sum::<Complex<f64>>()
+            })
+            .collect();
+        if js == 1 {
+            let mut d = tc(j0, j0) - lambda;
+            if d.norm() < small {
+                d = Complex::new(small, 0.0);
+            }
+            s[j0] = rhs[0] / d;
+        } else {
+            let (a, b) = (tc(j0, j0) - lambda, tc(j0, j0 + 1));
+            let (c, d) = (tc(j0 + 1, j0), tc(j0 + 1, j0 + 1) - lambda);
+            let mut det = a * d - b * c;
+            if det.norm() < small {
+                det = Complex::new(small, 0.0);
+            }
+            s[j0] = (rhs[0] * d - b * rhs[1]) / det;
+            s[j0 + 1] = (a * rhs[1] - c * rhs[0]) / det;
+        }
+    }
+    s
+}
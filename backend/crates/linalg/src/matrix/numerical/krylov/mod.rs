@@ -0,0 +1,323 @@
+use num_complex::Complex;
+
+use crate::sparse::SparseMatrix;
+use crate::{LinalgError, Matrix, Result, Vector};
+
+mod krylov_schur;
+mod randomized;
+
+#[cfg(test)]
+mod tests;
+
+pub use randomized::{randomized_svd, RandomizedSvdOptions};
+
+/// 行列ベクトル積だけで定義される線形作用素 A (rows × cols)。
+/// 大きな疎行列や、行列を陽に作らない作用素（差分演算子など）に反復解法を適用するために使う。
+pub trait LinearOperator {
+    fn nrows(&self) -> usize;
+    fn ncols(&self) -> usize;
+    /// y = A x
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64>;
+    /// y = A^T x
+    fn apply_transpose(&self, x: &Vector<f64>) -> Vector<f64>;
+}
+
+impl LinearOperator for Matrix<f64> {
+    fn nrows(&self) -> usize {
+        self.rows
+    }
+    fn ncols(&self) -> usize {
+        self.cols
+    }
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+        let data = (0..self.rows)
+            .map(|i| {
+                let row = &self.data[i * self.cols..(i + 1) * self.cols];
+                row.iter().zip(&x.data).map(|(a, b)| a * b).sum()
+            })
+            .collect();
+        Vector::new(data)
+    }
+    fn apply_transpose(&self, x: &Vector<f64>) -> Vector<f64> {
+        let mut y = vec![0.0; self.cols];
+        for (i, xi) in x.data.iter().enumerate() {
+            let row = &self.data[i * self.cols..(i + 1) * self.cols];
+            for (yj, a) in y.iter_mut().zip(row) {
+                *yj += a * xi;
+            }
+        }
+        Vector::new(y)
+    }
+}
+
+impl LinearOperator for SparseMatrix<f64> {
+    fn nrows(&self) -> usize {
+        self.rows
+    }
+    fn ncols(&self) -> usize {
+        self.cols
+    }
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+        let data = (0..self.rows)
+            .map(|i| self.row_iter(i).map(|(j, v)| v * x[j]).sum())
+            .collect();
+        Vector::new(data)
+    }
+    fn apply_transpose(&self, x: &Vector<f64>) -> Vector<f64> {
+        let mut y = vec![0.0; self.cols];
+        for i in 0..self.rows {
+            for (j, v) in self.row_iter(i) {
+                y[j] += v * x[i];
+            }
+        }
+        Vector::new(y)
+    }
+}
+
+/// クロージャで与える行列フリーの作用素。`rmatvec` を省略した場合は対称（A^T = A）とみなす。
+pub struct FnOperator<F, G = F> {
+    rows: usize,
+    cols: usize,
+    matvec: F,
+    rmatvec: Option<G>,
+}
+
+impl<F> FnOperator<F>
+where
+    F: Fn(&Vector<f64>) -> Vector<f64>,
+{
+    /// 対称な n×n 作用素
+    pub fn symmetric(n: usize, matvec: F) -> Self {
+        Self {
+            rows: n,
+            cols: n,
+            matvec,
+            rmatvec: None,
+        }
+    }
+}
+
+impl<F, G> FnOperator<F, G>
+where
+    F: Fn(&Vector<f64>) -> Vector<f64>,
+    G: Fn(&Vector<f64>) -> Vector<f64>,
+{
+    /// A x を `matvec`、A^T x を `rmatvec` で計算する rows × cols の作用素
+    pub fn new(rows: usize, cols: usize, matvec: F, rmatvec: G) -> Self {
+        Self {
+            rows,
+            cols,
+            matvec,
+            rmatvec: Some(rmatvec),
+        }
+    }
+}
+
+impl<F, G> LinearOperator for FnOperator<F, G>
+where
+    F: Fn(&Vector<f64>) -> Vector<f64>,
+    G: Fn(&Vector<f64>) -> Vector<f64>,
+{
+    fn nrows(&self) -> usize {
+        self.rows
+    }
+    fn ncols(&self) -> usize {
+        self.cols
+    }
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+        (self.matvec)(x)
+    }
+    fn apply_transpose(&self, x: &Vector<f64>) -> Vector<f64> {
+        match &self.rmatvec {
+            Some(g) => g(x),
+            None => (self.matvec)(x),
+        }
+    }
+}
+
+/// どの端の固有値を求めるか
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Which {
+    LargestMagnitude,
+    /// シフトなしでは収束が遅い。小さな固有値には作用素側で (A - σI)^{-1} を使うとよい。
+    SmallestMagnitude,
+    /// 実部が最大（対称なら代数的に最大）
+    LargestReal,
+    SmallestReal,
+}
+
+impl Which {
+    /// 大きいほど望ましい順位づけのキー
+    fn key(self, z: Complex<f64>) -> f64 {
+        match self {
+            Which::LargestMagnitude => z.norm(),
+            Which::SmallestMagnitude => -z.norm(),
+            Which::LargestReal => z.re,
+            Which::SmallestReal => -z.re,
+        }
+    }
+}
+
+/// Lanczos / Arnoldi 反復の設定
+#[derive(Debug, Clone, Copy)]
+pub struct KrylovOptions {
+    /// Krylov 部分空間の最大次元。`None` なら min(n, max(2k + 1, 20))。
+    pub ncv: Option<usize>,
+    /// Ritz 対の残差 ||A x - θ x|| の相対許容値
+    pub tol: f64,
+    pub max_restarts: usize,
+    /// 初期ベクトルの乱数シード
+    pub seed: u64,
+}
+
+impl Default for KrylovOptions {
+    fn default() -> Self {
+        Self {
+            ncv: None,
+            tol: 1e-10,
+            max_restarts: 300,
+            seed: 0x5eed,
+        }
+    }
+}
+
+/// 対称作用素の部分固有分解。固有ベクトルは `vectors` の列。
+#[derive(Debug, Clone)]
+pub struct PartialEigen {
+    pub values: Vec<f64>,
+    pub vectors: Matrix<f64>,
+    pub restarts: usize,
+    /// 要求した k 個すべてが許容値以内に収束したか
+    pub converged: bool,
+}
+
+/// 一般の作用素の部分固有分解（複素共役対を含みうる）
+pub struct PartialEigenComplex {
+    pub values: Vec<Complex<f64>>,
+    pub vectors: Matrix<Complex<f64>>,
+    pub restarts: usize,
+    pub converged: bool,
+}
+
+/// 対称作用素の端の固有対 k 個（thick-restart Lanczos）。
+/// 完全再直交化した Lanczos 過程を Krylov–Schur 法で再始動する。
+pub fn eigsh<A: LinearOperator + ?Sized>(
+    op: &A,
+    k: usize,
+    which: Which,
+    opts: &KrylovOptions,
+) -> Result<PartialEigen> {
+    let m = check_krylov_args(op, k, opts)?;
+    krylov_schur::symmetric(op, k, m, which, opts)
+}
+
+/// 一般の作用素の端の固有対 k 個（Krylov–Schur 法による再始動付き Arnoldi）。
+/// 暗黙的再始動 Arnoldi（ARPACK）と数学的に同等で、実 Schur 形の並べ替えで再始動する。
+pub fn eigs<A: LinearOperator + ?Sized>(
+    op: &A,
+    k: usize,
+    which: Which,
+    opts: &KrylovOptions,
+) -> Result<PartialEigenComplex> {
+    let m = check_krylov_args(op, k, opts)?;
+    krylov_schur::general(op, k, m, which, opts)
+}
+
+/// 部分空間の次元 ncv を決める
+fn check_krylov_args<A: LinearOperator + ?Sized>(
+    op: &A,
+    k: usize,
+    opts: &KrylovOptions,
+) -> Result<usize> {
+    let n = op.nrows();
+    if n != op.ncols() {
+        return Err(LinalgError::NotSquareMatrix);
+    }
+    if k == 0 || k > n {
+        return Err(LinalgError::InvalidArgument {
+            text: format!("k must satisfy 1 <= k <= {n}, got {k}"),
+        });
+    }
+    let m = opts.ncv.unwrap_or((2 * k + 1).max(20)).min(n);
+    if m < k.min(n) || (m < n && m < k + 2) {
+        return Err(LinalgError::InvalidArgument {
+            text: format!("ncv = {m} is too small for k = {k}"),
+        });
+    }
+    Ok(m)
+}
+
+/// 初期ベクトル・乱数行列用の SplitMix64
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// (0, 1] の一様乱数
+    fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+    }
+
+    /// 標準正規乱数（Box–Muller）
+    fn normal(&mut self) -> f64 {
+        let (u1, u2) = (self.next_f64(), self.next_f64());
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+
+    fn normal_vector(&mut self, n: usize) -> Vec<f64> {
+        (0..n).map(|_| self.normal()).collect()
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// y -= alpha x
+fn sub_scaled(y: &mut [f64], alpha: f64, x: &[f64]) {
+    for (yi, xi) in y.iter_mut().zip(x) {
+        *yi -= alpha * xi;
+    }
+}
+
+/// w を正規直交な `basis` に対して 2 回 Gram–Schmidt で直交化し、係数の合計を返す（CGS2）
+fn orthogonalize(basis: &[Vec<f64>], w: &mut [f64]) -> Vec<f64> {
+    let mut coeffs = vec![0.0; basis.len()];
+    for _ in 0..2 {
+        for (c, v) in coeffs.iter_mut().zip(basis) {
+            let h = dot(v, w);
+            sub_scaled(w, h, v);
+            *c += h;
+        }
+    }
+    coeffs
+}
+
+/// 列の集合を正規直交化する（ほぼ一次従属な列は乱数で置き換える）
+fn orthonormalize_columns(cols: &mut [Vec<f64>], rng: &mut SplitMix64) {
+    for j in 0..cols.len() {
+        let (done, rest) = cols.split_at_mut(j);
+        let w = &mut rest[0];
+        let scale = norm(w);
+        orthogonalize(done, w);
+        let mut nrm = norm(w);
+        if nrm <= 1e-10 * scale.max(f64::MIN_POSITIVE) {
+            *w = rng.normal_vector(w.len());
+            orthogonalize(done, w);
+            nrm = norm(w);
+        }
+        if nrm > 0.0 {
+            w.iter_mut().for_each(|x| *x /= nrm);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use super::{dot, orthonormalize_columns, LinearOperator, SplitMix64};
+use crate::matrix::numerical::svd::Svd;
+use crate::matrix::numerical::SvdDeComposition;
+use crate::{LinalgError, Matrix, Result, Vector};
+
+/// 乱択 SVD の設定
+#[derive(Debug, Clone, Copy)]
+pub struct RandomizedSvdOptions {
+    /// 求める階数 k に足す余分な標本数 p（部分空間の次元は k + p）
+    pub oversampling: usize,
+    /// べき乗反復 (A A^T)^q の回数 q。特異値の減衰が緩やかなときに精度を上げる。
+    pub power_iterations: usize,
+    pub seed: u64,
+}
+
+impl Default for RandomizedSvdOptions {
+    fn default() -> Self {
+        Self {
+            oversampling: 10,
+            power_iterations: 2,
+            seed: 0x5eed,
+        }
+    }
+}
+
+/// 上位 k 個の特異値・特異ベクトルを乱択法（Halko–Martinsson–Tropp）で近似する。
+/// U は m×k、V は n×k、特異値は降順。作用素は A x と A^T x だけを使う。
+pub fn randomized_svd<A: LinearOperator + ?Sized>(
+    op: &A,
+    k: usize,
+    opts: &RandomizedSvdOptions,
+) -> Result<Svd> {
+    let (m, n) = (op.nrows(), op.ncols());
+    if k == 0 || k > m.min(n) {
+        return Err(LinalgError::InvalidArgument {
+            text: format!("k must satisfy 1 <= k <= {}, got {k}", m.min(n)),
+        });
+    }
+    let l = (k + opts.oversampling).min(m.min(n));
+    let mut rng = SplitMix64(opts.seed);
+    let apply = |c: &Vec<f64>| op.apply(&Vector::new(c.clone())).data;
+    let apply_t = |c: &Vec<f64>| op.apply_transpose(&Vector::new(c.clone())).data;
+
+    // 値域の近似基底 Q: Y = A Ω を直交化し、べき乗反復のたびに直交化し直す
+    let mut q: Vec<Vec<f64>> = (0..l).map(|_| apply(&rng.normal_vector(n))).collect();
+    orthonormalize_columns(&mut q, &mut rng);
+    for _ in 0..opts.power_iterations {
+        let mut z: Vec<Vec<f64>> = q.iter().map(apply_t).collect();
+        orthonormalize_columns(&mut z, &mut rng);
+        q = z.iter().map(apply).collect();
+        orthonormalize_columns(&mut q, &mut rng);
+    }
+
+    // B = Q^T A。B^T = A^T Q = Q_b R と薄い QR を取り、小さな R (l×l) の SVD に帰着させる
+    let bt: Vec<Vec<f64>> = q.iter().map(apply_t).collect();
+    let mut qb = bt.clone();
+    orthonormalize_columns(&mut qb, &mut rng);
+    let mut r = Matrix::zeros(l, l);
+    for i in 0..l {
+        for j in i..l {
+            r[(i, j)] = dot(&qb[i], &bt[j]);
+        }
+    }
+    // R = U_r Σ V_r^T より B = V_r Σ (Q_b U_r)^T、A ≈ (Q V_r) Σ (Q_b U_r)^T
+    let small = r.svd()?;
+    let u = combine_columns(&q, &small.v, k);
+    let v = combine_columns(&qb, &small.u, k);
+    Ok(Svd {
+        u,
+        sigma: Vector::new(small.sigma.data[..k].to_vec()),
+        v,
+    })
+}
+
+/// 列ベクトルの集合 `basis` と係数行列 `coeffs` から basis · coeffs[:, :k] を作る
+fn combine_columns(basis: &[Vec<f64>], coeffs: &Matrix<f64>, k: usize) -> Matrix<f64> {
+    let rows = basis[0].len();
+    let mut out = Matrix::zeros(rows, k);
+    for (i, b) in basis.iter().enumerate() {
+        for c in 0..k {
+            let w = coeffs[(i, c)];
+            if w == 0.0 {
+                continue;
+            }
+            for (r, bv) in b.iter().enumerate() {
+                out[(r, c)] += w * bv;
+            }
+        }
+    }
+    out
+}
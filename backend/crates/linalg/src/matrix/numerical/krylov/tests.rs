@@ -0,0 +1,243 @@
+use num_complex::Complex;
+
+use super::{
+    eigs, eigsh, randomized_svd, FnOperator, KrylovOptions, LinearOperator, RandomizedSvdOptions,
+    Which,
+};
+use crate::matrix::numerical::SvdDeComposition;
+use crate::sparse::{CooMatrix, SparseMatrix};
+use crate::test_util::pseudo_random;
+use crate::{LinalgError, Matrix, Vector};
+
+/// tridiag(-1, 2, -1)。固有値は 2 - 2 cos(j π / (n + 1))
+fn laplacian_1d(n: usize) -> SparseMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0).unwrap();
+        if i > 0 {
+            coo.push(i, i - 1, -1.0).unwrap();
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -1.0).unwrap();
+        }
+    }
+    coo.to_csr()
+}
+
+fn laplacian_eigenvalue(n: usize, j: usize) -> f64 {
+    2.0 - 2.0 * (j as f64 * std::f64::consts::PI / (n + 1) as f64).cos()
+}
+
+fn column(m: &Matrix<f64>, j: usize) -> Vector<f64> {
+    Vector::new((0..m.rows).map(|i| m[(i, j)]).collect())
+}
+
+#[test]
+fn lanczos_finds_extreme_eigenvalues_of_sparse_laplacian() {
+    let n = 400;
+    let a = laplacian_1d(n);
+    let opts = KrylovOptions::default();
+
+    let top = eigsh(&a, 4, Which::LargestReal, &opts).unwrap();
+    assert!(top.converged);
+    for (i, &lambda) in top.values.iter().enumerate() {
+        assert!(
+            (lambda - laplacian_eigenvalue(n, n - i)).abs() < 1e-8,
+            "{lambda}"
+        );
+        let x = column(&top.vectors, i);
+        let residual = (&a.apply(&x) - &(&x * lambda)).norm();
+        assert!(residual < 1e-7, "residual {residual}");
+        assert!((x.norm() - 1.0).abs() < 1e-10);
+    }
+
+    let bottom = eigsh(
+        &a,
+        2,
+        Which::SmallestReal,
+        &KrylovOptions {
+            ncv: Some(40),
+            max_restarts: 2000,
+            ..opts
+        },
+    )
+    .unwrap();
+    assert!(bottom.converged);
+    assert!((bottom.values[0] - laplacian_eigenvalue(n, 1)).abs() < 1e-8);
+    assert!((bottom.values[1] - laplacian_eigenvalue(n, 2)).abs() < 1e-8);
+}
+
+#[test]
+fn lanczos_accepts_matrix_free_operator() {
+    // 行列を作らずに tridiag(-1, 2, -1) を作用させる
+    let n = 300;
+    let op = FnOperator::symmetric(n, |x: &Vector<f64>| {
+        let y = (0..n)
+            .map(|i| {
+                let left = if i > 0 { x[i - 1] } else { 0.0 };
+                let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+                2.0 * x[i] - left - right
+            })
+            .collect();
+        Vector::new(y)
+    });
+    let res = eigsh(&op, 3, Which::LargestMagnitude, &KrylovOptions::default()).unwrap();
+    assert!(res.converged);
+    for (i, &lambda) in res.values.iter().enumerate() {
+        assert!((lambda - laplacian_eigenvalue(n, n - i)).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn arnoldi_finds_complex_pair_of_nonnormal_matrix() {
+    // 対角ブロック（固有値 ±の複素対と実数）+ 狭義上三角の摂動。固有値はブロックだけで決まる
+    let n = 120;
+    let mut a = Matrix::zeros(n, n);
+    let noise = pseudo_random(n * n, 7);
+    for i in 0..n {
+        a[(i, i)] = 0.5 + 0.004 * i as f64;
+        for j in (i + 1)..n {
+            a[(i, j)] = 0.05 * noise[i * n + j];
+        }
+    }
+    // 10 ± 3i と 9
+    a[(0, 0)] = 10.0;
+    a[(0, 1)] = 3.0;
+    a[(1, 0)] = -3.0;
+    a[(1, 1)] = 10.0;
+    a[(2, 2)] = 9.0;
+
+    let res = eigs(&a, 3, Which::LargestMagnitude, &KrylovOptions::default()).unwrap();
+    assert!(res.converged);
+    let mut expected = [
+        Complex::new(10.0, 3.0),
+        Complex::new(10.0, -3.0),
+        Complex::new(9.0, 0.0),
+    ]
+    .to_vec();
+    for z in &res.values {
+        let pos = expected
+            .iter()
+            .position(|e| (e - z).norm() < 1e-8)
+            .unwrap_or_else(|| panic!("unexpected eigenvalue {z}"));
+        expected.remove(pos);
+    }
+
+    // A x = λ x を複素数で確かめる
+    let ac = a.to_complex();
+    for (c, &lambda) in res.values.iter().enumerate() {
+        let x = Vector::new((0..n).map(|i| res.vectors[(i, c)]).collect());
+        let ax = ac.checked_mul_vector(&x).unwrap();
+        let residual: f64 = ax
+            .data
+            .iter()
+            .zip(&x.data)
+            .map(|(p, q)| (p - q * lambda).norm_sqr())
+            .sum::<f64>()
+            .sqrt();
+        assert!(residual < 1e-7, "residual {residual}");
+    }
+
+    let smallest_real = eigs(&a, 2, Which::SmallestReal, &KrylovOptions::default()).unwrap();
+    assert!(smallest_real.converged);
+    assert!((smallest_real.values[0].re - 0.5 - 0.004 * 3.0).abs() < 1e-8);
+}
+
+#[test]
+fn krylov_on_small_matrix_uses_whole_space() {
+    let a = Matrix::new(
+        4,
+        4,
+        vec![
+            4.0, 1.0, 0.0, 0.0, 1.0, 3.0, 1.0, 0.0, 0.0, 1.0, 2.0, 1.0, 0.0, 0.0, 1.0, 1.0,
+        ],
+    )
+    .unwrap();
+    let res = eigsh(&a, 2, Which::SmallestReal, &KrylovOptions::default()).unwrap();
+    assert!(res.converged);
+    assert!(res.values[0] < res.values[1]);
+    let x = column(&res.vectors, 0);
+    assert!((&a.apply(&x) - &(&x * res.values[0])).norm() < 1e-10);
+}
+
+#[test]
+fn randomized_svd_recovers_low_rank_matrix() {
+    // A = Σ σ_i u_i v_i^T（σ = 100, 50, 20, 10, 5）
+    let (m, n) = (300, 200);
+    let sigma = [100.0, 50.0, 20.0, 10.0, 5.0];
+    let mut us: Vec<Vec<f64>> = (0..5).map(|i| pseudo_random(m, 11 + i)).collect();
+    let mut vs: Vec<Vec<f64>> = (0..5).map(|i| pseudo_random(n, 31 + i)).collect();
+    let mut rng = super::SplitMix64(1);
+    super::orthonormalize_columns(&mut us, &mut rng);
+    super::orthonormalize_columns(&mut vs, &mut rng);
+    let mut a = Matrix::zeros(m, n);
+    for (s, (u, v)) in sigma.iter().zip(us.iter().zip(&vs)) {
+        for i in 0..m {
+            for j in 0..n {
+                a[(i, j)] += s * u[i] * v[j];
+            }
+        }
+    }
+
+    let svd = randomized_svd(&a, 5, &RandomizedSvdOptions::default()).unwrap();
+    assert_eq!(
+        (svd.u.rows, svd.u.cols, svd.v.rows, svd.v.cols),
+        (m, 5, n, 5)
+    );
+    for (got, want) in svd.sigma.data.iter().zip(&sigma) {
+        assert!((got - want).abs() < 1e-9 * want, "{got} vs {want}");
+    }
+    // U Σ V^T で元に戻る
+    let mut us_mat = svd.u.clone();
+    for j in 0..5 {
+        us_mat.scale_col(j, svd.sigma[j]).unwrap();
+    }
+    let recon = &us_mat * &svd.v.transpose();
+    assert!((&recon - &a).frobenius_norm() < 1e-9 * a.frobenius_norm());
+    let utu = &svd.u.transpose() * &svd.u;
+    assert!((&utu - &Matrix::identity(5)).frobenius_norm() < 1e-10);
+}
+
+#[test]
+fn randomized_svd_matches_dense_svd_with_power_iterations() {
+    // 特異値がゆっくり減衰する行列でも、べき乗反復で上位の特異値が合う
+    let (m, n) = (80, 60);
+    let a = Matrix::new(m, n, pseudo_random(m * n, 5)).unwrap();
+    let exact = a.svd().unwrap();
+    let opts = RandomizedSvdOptions {
+        oversampling: 20,
+        power_iterations: 6,
+        seed: 3,
+    };
+    let approx = randomized_svd(&a, 3, &opts).unwrap();
+    for i in 0..3 {
+        let rel = (approx.sigma[i] - exact.sigma[i]).abs() / exact.sigma[i];
+        assert!(
+            rel < 1e-3,
+            "sigma[{i}]: {} vs {}",
+            approx.sigma[i],
+            exact.sigma[i]
+        );
+    }
+}
+
+#[test]
+fn krylov_argument_errors() {
+    let a = laplacian_1d(10);
+    let opts = KrylovOptions::default();
+    assert!(matches!(
+        eigsh(&a, 0, Which::LargestReal, &opts),
+        Err(LinalgError::InvalidArgument { .. })
+    ));
+    let rect = Matrix::<f64>::zeros(3, 4);
+    assert!(matches!(
+        eigs(&rect, 1, Which::LargestMagnitude, &opts),
+        Err(LinalgError::NotSquareMatrix)
+    ));
+    assert!(randomized_svd(&rect, 4, &RandomizedSvdOptions::default()).is_err());
+    let small_ncv = KrylovOptions {
+        ncv: Some(3),
+        ..opts
+    };
+    assert!(eigsh(&laplacian_1d(50), 3, Which::LargestReal, &small_ncv).is_err());
+}
@@ -0,0 +1,153 @@
+use crate::matrix::numerical::SchurDecomposition;
+use crate::{LinalgError, Matrix, Result};
+
+mod riccati;
+mod sylvester;
+
+#[cfg(test)]
+mod tests;
+
+/// 線形行列方程式（Sylvester・Lyapunov）と代数 Riccati 方程式の解法
+pub trait MatrixEquation {
+    /// Sylvester 方程式 A X + X B = C（Bartels–Stewart 法）。
+    /// A と -B が共通の固有値を持つと解が一意に定まらないため `SingularMatrix` を返す。
+    fn solve_sylvester(&self, b: &Matrix<f64>, c: &Matrix<f64>) -> Result<Matrix<f64>>;
+
+    /// 連続時間 Lyapunov 方程式 A X + X A^T + Q = 0
+    fn solve_continuous_lyapunov(&self, q: &Matrix<f64>) -> Result<Matrix<f64>>;
+
+    /// 離散時間 Lyapunov 方程式 A X A^T - X + Q = 0
+    fn solve_discrete_lyapunov(&self, q: &Matrix<f64>) -> Result<Matrix<f64>>;
+
+    /// 連続時間代数 Riccati 方程式 A^T X + X A - X B R^{-1} B^T X + Q = 0 の安定化解。
+    /// Hamiltonian 行列の順序付き Schur 分解（Laub の方法）で求める。
+    fn solve_care(&self, b: &Matrix<f64>, q: &Matrix<f64>, r: &Matrix<f64>) -> Result<Matrix<f64>>;
+
+    /// 離散時間代数 Riccati 方程式
+    /// A^T X A - X - A^T X B (R + B^T X B)^{-1} B^T X A + Q = 0 の安定化解。
+    /// シンプレクティック行列の順序付き Schur 分解で求めるため、A は正則であること。
+    fn solve_dare(&self, b: &Matrix<f64>, q: &Matrix<f64>, r: &Matrix<f64>) -> Result<Matrix<f64>>;
+}
+
+impl MatrixEquation for Matrix<f64> {
+    fn solve_sylvester(&self, b: &Matrix<f64>, c: &Matrix<f64>) -> Result<Matrix<f64>> {
+        check_square(self)?;
+        check_square(b)?;
+        check_shape(c, self.rows, b.rows)?;
+
+        // A = U S U^T, B^T = V R V^T とすると S Y + Y R^T = U^T C V, X = U Y V^T
+        let (u, s) = self.schur()?;
+        let (v, r) = b.transpose().schur()?;
+        let rhs = &(&u.transpose() * c) * &v;
+        let y = sylvester::solve_quasi_triangular_continuous(&s, &r, &rhs)?;
+        let x = &(&u * &y) * &v.transpose();
+
+        let residual = &(&(self * &x) + &(&x * b)) - c;
+        let scale =
+            (self.frobenius_norm() + b.frobenius_norm()) * x.frobenius_norm() + c.frobenius_norm();
+        check_residual("solve_sylvester", &residual, scale)?;
+        Ok(x)
+    }
+
+    fn solve_continuous_lyapunov(&self, q: &Matrix<f64>) -> Result<Matrix<f64>> {
+        check_square(self)?;
+        check_shape(q, self.rows, self.rows)?;
+
+        // B = A^T なので Schur 分解は 1 回で済む: S Y + Y S^T = -U^T Q U
+        let (u, s) = self.schur()?;
+        let rhs = &(&(&u.transpose() * q) * &u) * -1.0;
+        let y = sylvester::solve_quasi_triangular_continuous(&s, &s, &rhs)?;
+        let mut x = &(&u * &y) * &u.transpose();
+        if is_symmetric(q) {
+            symmetrize(&mut x);
+        }
+
+        let residual = &(&(self * &x) + &(&x * &self.transpose())) + q;
+        let scale = 2.0 * self.frobenius_norm() * x.frobenius_norm() + q.frobenius_norm();
+        check_residual("solve_continuous_lyapunov", &residual, scale)?;
+        Ok(x)
+    }
+
+    fn solve_discrete_lyapunov(&self, q: &Matrix<f64>) -> Result<Matrix<f64>> {
+        check_square(self)?;
+        check_shape(q, self.rows, self.rows)?;
+
+        // A = U S U^T: S Y S^T - Y = -U^T Q U
+        let (u, s) = self.schur()?;
+        let rhs = &(&(&u.transpose() * q) * &u) * -1.0;
+        let y = sylvester::solve_quasi_triangular_discrete(&s, &s, &rhs)?;
+        let mut x = &(&u * &y) * &u.transpose();
+        if is_symmetric(q) {
+            symmetrize(&mut x);
+        }
+
+        let residual = &(&(&(self * &x) * &self.transpose()) - &x) + q;
+        let a_norm = self.frobenius_norm();
+        let scale = (a_norm * a_norm + 1.0) * x.frobenius_norm() + q.frobenius_norm();
+        check_residual("solve_discrete_lyapunov", &residual, scale)?;
+        Ok(x)
+    }
+
+    fn solve_care(&self, b: &Matrix<f64>, q: &Matrix<f64>, r: &Matrix<f64>) -> Result<Matrix<f64>> {
+        riccati::check_riccati_args(self, b, q, r)?;
+        riccati::care(self, b, q, r)
+    }
+
+    fn solve_dare(&self, b: &Matrix<f64>, q: &Matrix<f64>, r: &Matrix<f64>) -> Result<Matrix<f64>> {
+        riccati::check_riccati_args(self, b, q, r)?;
+        riccati::dare(self, b, q, r)
+    }
+}
+
+/// 相対残差 ||residual||_F / scale の許容値
+fn residual_tolerance() -> f64 {
+    f64::EPSILON.sqrt()
+}
+
+/// 解の残差を検査し、許容値を超えたら（悪条件・解なし）エラーにする
+fn check_residual(name: &str, residual: &Matrix<f64>, scale: f64) -> Result<()> {
+    let norm = residual.frobenius_norm();
+    let relative = if scale > 0.0 { norm / scale } else { norm };
+    if relative.is_finite() && relative <= residual_tolerance() {
+        Ok(())
+    } else {
+        Err(LinalgError::InvalidArgument {
+            text: format!("{name}: relative residual {relative:.3e} exceeds tolerance"),
+        })
+    }
+}
+
+fn check_square(m: &Matrix<f64>) -> Result<()> {
+    if m.is_square() {
+        Ok(())
+    } else {
+        Err(LinalgError::NotSquareMatrix)
+    }
+}
+
+fn check_shape(m: &Matrix<f64>, rows: usize, cols: usize) -> Result<()> {
+    if m.rows == rows && m.cols == cols {
+        Ok(())
+    } else {
+        Err(LinalgError::DimensionMismatch {
+            expected: format!("{rows}x{cols}"),
+            found: format!("{}x{}", m.rows, m.cols),
+        })
+    }
+}
+
+fn is_symmetric(m: &Matrix<f64>) -> bool {
+    let tol = 1e-12 * m.frobenius_norm().max(1.0);
+    (0..m.rows).all(|i| (0..i).all(|j| (m[(i, j)] - m[(j, i)]).abs() <= tol))
+}
+
+/// 丸め誤差で崩れた対称性を (X + X^T) / 2 で整える
+fn symmetrize(x: &mut Matrix<f64>) {
+    for i in 0..x.rows {
+        for j in 0..i {
+            let avg = 0.5 * (x[(i, j)] + x[(j, i)]);
+            x[(i, j)] = avg;
+            x[(j, i)] = avg;
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use num_complex::Complex;
+
+use super::{check_residual, check_shape, check_square, symmetrize};
+use crate::matrix::numerical::SchurDecomposition;
+use crate::{LinalgError, Matrix, Result};
+
+/// A (n×n), B (n×m), Q (n×n), R (m×m) の形を検査する
+pub(super) fn check_riccati_args(
+    a: &Matrix<f64>,
+    b: &Matrix<f64>,
+    q: &Matrix<f64>,
+    r: &Matrix<f64>,
+) -> Result<()> {
+    check_square(a)?;
+    let n = a.rows;
+    if b.rows != n {
+        return Err(LinalgError::DimensionMismatch {
+            expected: format!("{n}x?"),
+            found: format!("{}x{}", b.rows, b.cols),
+        });
+    }
+    check_shape(q, n, n)?;
+    check_shape(r, b.cols, b.cols)
+}
+
+/// CARE: Hamiltonian 行列 H = [[A, -G], [-Q, -A^T]]（G = B R^{-1} B^T）の
+/// 左半平面の固有値に対応する不変部分空間 [U11; U21] から X = U21 U11^{-1}
+pub(super) fn care(
+    a: &Matrix<f64>,
+    b: &Matrix<f64>,
+    q: &Matrix<f64>,
+    r: &Matrix<f64>,
+) -> Result<Matrix<f64>> {
+    let n = a.rows;
+    let g = b * &r.solve_matrix(&b.transpose())?;
+    let at = a.transpose();
+
+    let mut h = Matrix::zeros(2 * n, 2 * n);
+    for i in 0..n {
+        for j in 0..n {
+            h[(i, j)] = a[(i, j)];
+            h[(i, n + j)] = -g[(i, j)];
+            h[(n + i, j)] = -q[(i, j)];
+            h[(n + i, n + j)] = -at[(i, j)];
+        }
+    }
+    let x = stable_subspace_solution(&h, |z| z.re < 0.0, "solve_care")?;
+
+    let residual = &(&(&(&at * &x) + &(&x * a)) - &(&(&x * &g) * &x)) + q;
+    let x_norm = x.frobenius_norm();
+    let scale = 2.0 * a.frobenius_norm() * x_norm
+        + g.frobenius_norm() * x_norm * x_norm
+        + q.frobenius_norm();
+    check_residual("solve_care", &residual, scale)?;
+    Ok(x)
+}
+
+/// DARE: シンプレクティック行列
+/// Z = [[A + G A^{-T} Q, -G A^{-T}], [-A^{-T} Q, A^{-T}]]
+/// の単位円内の固有値に対応する不変部分空間から X = U21 U11^{-1}
+pub(super) fn dare(
+    a: &Matrix<f64>,
+    b: &Matrix<f64>,
+    q: &Matrix<f64>,
+    r: &Matrix<f64>,
+) -> Result<Matrix<f64>> {
+    let n = a.rows;
+    let g = b * &r.solve_matrix(&b.transpose())?;
+    let at = a.transpose();
+    let a_inv_t =
+        at.solve_matrix(&Matrix::identity(n))
+            .map_err(|_| LinalgError::InvalidArgument {
+                text: "solve_dare: the Schur method requires a nonsingular A".into(),
+            })?;
+    let g_ainvt = &g * &a_inv_t;
+    let ainvt_q = &a_inv_t * q;
+    let top_left = a + &(&g_ainvt * q);
+
+    let mut z = Matrix::zeros(2 * n, 2 * n);
+    for i in 0..n {
+        for j in 0..n {
+            z[(i, j)] = top_left[(i, j)];
+            z[(i, n + j)] = -g_ainvt[(i, j)];
+            z[(n + i, j)] = -ainvt_q[(i, j)];
+            z[(n + i, n + j)] = a_inv_t[(i, j)];
+        }
+    }
+    let x = stable_subspace_solution(&z, |l| l.norm() < 1.0, "solve_dare")?;
+
+    // A^T X B (R + B^T X B)^{-1} B^T X A
+    let bt = b.transpose();
+    let xa = &x * a;
+    let btxa = &bt * &xa;
+    let inner = r + &(&(&bt * &x) * b);
+    let correction = &(&at * &x) * &(b * &inner.solve_matrix(&btxa)?);
+
+    let residual = &(&(&(&at * &xa) - &x) - &correction) + q;
+    let a_norm = a.frobenius_norm();
+    let scale = (a_norm * a_norm + 1.0) * x.frobenius_norm()
+        + correction.frobenius_norm()
+        + q.frobenius_norm();
+    check_residual("solve_dare", &residual, scale)?;
+    Ok(x)
+}
+
+/// 2n×2n の行列 `m` について、`select` を満たす n 個の固有値の不変部分空間 [U11; U21] から
+/// X = U21 U11^{-1} を作る。選ばれた固有値が n 個でなければ安定化解は存在しない。
+fn stable_subspace_solution<F>(m: &Matrix<f64>, select: F, name: &str) -> Result<Matrix<f64>>
+where
+    F: Fn(Complex<f64>) -> bool,
+{
+    let n = m.rows / 2;
+    let (u, _t, sdim) = m.ordered_schur(select)?;
+    if sdim != n {
+        return Err(LinalgError::InvalidArgument {
+            text: format!(
+                "{name}: no stabilizing solution ({sdim} of {} eigenvalues in the stable region)",
+                2 * n
+            ),
+        });
+    }
+    let u11 = u.submatrix(0, n, 0, n);
+    let u21 = u.submatrix(n, 2 * n, 0, n);
+    // X U11 = U21 を U11^T X^T = U21^T として解く
+    let xt = u11
+        .transpose()
+        .solve_matrix(&u21.transpose())
+        .map_err(|_| LinalgError::InvalidArgument {
+            text: format!("{name}: invariant subspace basis is singular; no stabilizing solution"),
+        })?;
+    let mut x = xt.transpose();
+    symmetrize(&mut x);
+    Ok(x)
+}
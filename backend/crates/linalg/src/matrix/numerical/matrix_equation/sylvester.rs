@@ -0,0 +1,121 @@
+use crate::matrix::numerical::schur::schur_blocks;
+use crate::{LinalgError, Matrix, Result, Vector};
+
+/// 準上三角 S (m×m), R (n×n) に対し S Y + Y R^T = C を解く（Bartels–Stewart の後退代入部分）。
+/// R の列ブロックを後ろから、S の行ブロックを下から順に、最大 4 元の小さな連立方程式で埋める。
+pub(super) fn solve_quasi_triangular_continuous(
+    s: &Matrix<f64>,
+    r: &Matrix<f64>,
+    c: &Matrix<f64>,
+) -> Result<Matrix<f64>> {
+    let m = s.rows;
+    let s_blocks = schur_blocks(s);
+    let mut y = Matrix::zeros(m, r.rows);
+
+    for &(j0, q) in schur_blocks(r).iter().rev() {
+        // 既知の列 l >= j0 + q からの寄与 Z = Σ_l Y[:, l] R[j, l]
+        let z = known_columns_times_rt(&y, r, j0, q);
+        for &(i0, p) in s_blocks.iter().rev() {
+            let dim = p * q;
+            let mut kron = Matrix::zeros(dim, dim);
+            let mut rhs = Vector::new(vec![0.0; dim]);
+            for a in 0..p {
+                for cc in 0..q {
+                    let row = a * q + cc;
+                    let mut v = c[(i0 + a, j0 + cc)] - z[(i0 + a, cc)];
+                    for k in (i0 + p)..m {
+                        v -= s[(i0 + a, k)] * y[(k, j0 + cc)];
+                    }
+                    rhs[row] = v;
+                    for l in 0..p {
+                        kron[(row, l * q + cc)] += s[(i0 + a, i0 + l)];
+                    }
+                    for l in 0..q {
+                        kron[(row, a * q + l)] += r[(j0 + cc, j0 + l)];
+                    }
+                }
+            }
+            let block = solve_block(&kron, &rhs)?;
+            for a in 0..p {
+                for cc in 0..q {
+                    y[(i0 + a, j0 + cc)] = block[a * q + cc];
+                }
+            }
+        }
+    }
+    Ok(y)
+}
+
+/// 準上三角 S (m×m), R (n×n) に対し S Y R^T - Y = C を解く（離散時間版、Stein 方程式）。
+/// W = Y R^T の列ブロックを行ブロックごとに更新しながら使い、全体で O(m^2 n + m n^2) に抑える。
+pub(super) fn solve_quasi_triangular_discrete(
+    s: &Matrix<f64>,
+    r: &Matrix<f64>,
+    c: &Matrix<f64>,
+) -> Result<Matrix<f64>> {
+    let m = s.rows;
+    let s_blocks = schur_blocks(s);
+    let mut y = Matrix::zeros(m, r.rows);
+
+    for &(j0, q) in schur_blocks(r).iter().rev() {
+        // W[k, c] = Σ_{l >= j0} Y[k, l] R[j0 + c, l]。未知の列ブロック分は解いた行から足し込む。
+        let mut w = known_columns_times_rt(&y, r, j0, q);
+        for &(i0, p) in s_blocks.iter().rev() {
+            let dim = p * q;
+            let mut kron = Matrix::zeros(dim, dim);
+            let mut rhs = Vector::new(vec![0.0; dim]);
+            for a in 0..p {
+                for cc in 0..q {
+                    let row = a * q + cc;
+                    let mut v = c[(i0 + a, j0 + cc)];
+                    for k in i0..m {
+                        v -= s[(i0 + a, k)] * w[(k, cc)];
+                    }
+                    rhs[row] = v;
+                    for l in 0..p {
+                        for b in 0..q {
+                            kron[(row, l * q + b)] += s[(i0 + a, i0 + l)] * r[(j0 + cc, j0 + b)];
+                        }
+                    }
+                    kron[(row, row)] -= 1.0;
+                }
+            }
+            let block = solve_block(&kron, &rhs)?;
+            for a in 0..p {
+                for cc in 0..q {
+                    y[(i0 + a, j0 + cc)] = block[a * q + cc];
+                }
+                for cc in 0..q {
+                    for b in 0..q {
+                        w[(i0 + a, cc)] += y[(i0 + a, j0 + b)] * r[(j0 + cc, j0 + b)];
+                    }
+                }
+            }
+        }
+    }
+    Ok(y)
+}
+
+/// 解き終えた列 l >= j0 + q について Σ_l Y[:, l] R[j0 + c, l] を (m × q) で返す
+fn known_columns_times_rt(y: &Matrix<f64>, r: &Matrix<f64>, j0: usize, q: usize) -> Matrix<f64> {
+    let mut z = Matrix::zeros(y.rows, q);
+    for i in 0..y.rows {
+        for cc in 0..q {
+            let mut v = 0.0;
+            for l in (j0 + q)..r.cols {
+                v += y[(i, l)] * r[(j0 + cc, l)];
+            }
+            z[(i, cc)] = v;
+        }
+    }
+    z
+}
+
+/// 対角ブロック同士の小さな方程式。特異なら固有値が衝突していて解が一意でない。
+fn solve_block(kron: &Matrix<f64>, rhs: &Vector<f64>) -> Result<Vector<f64>> {
+    let x = kron.solve(rhs).map_err(|_| LinalgError::SingularMatrix)?;
+    if x.data.iter().any(|v| !v.is_finite()) {
+        return Err(LinalgError::SingularMatrix);
+    }
+    Ok(x)
+}
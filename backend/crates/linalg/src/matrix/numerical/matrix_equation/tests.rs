@@ -0,0 +1,180 @@
+use super::MatrixEquation;
+use crate::matrix::numerical::{CholeskyDecomposition, EigenDecomposition};
+use crate::{LinalgError, Matrix};
+
+fn mat(rows: usize, cols: usize, data: &[f64]) -> Matrix<f64> {
+    Matrix::new(rows, cols, data.to_vec()).unwrap()
+}
+
+fn assert_small(m: &Matrix<f64>, tol: f64, label: &str) {
+    let norm = m.frobenius_norm();
+    assert!(norm <= tol, "{label}: residual norm {norm}");
+}
+
+fn assert_symmetric(x: &Matrix<f64>) {
+    for i in 0..x.rows {
+        for j in 0..x.cols {
+            assert_eq!(x[(i, j)], x[(j, i)], "X not symmetric at ({i}, {j})");
+        }
+    }
+}
+
+/// 固有値の実部の最大値
+fn spectral_abscissa(a: &Matrix<f64>) -> f64 {
+    a.eigen_decomposition_complex()
+        .unwrap()
+        .eigen_values
+        .iter()
+        .map(|z| z.re)
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+fn spectral_radius(a: &Matrix<f64>) -> f64 {
+    a.eigen_decomposition_complex()
+        .unwrap()
+        .eigen_values
+        .iter()
+        .map(|z| z.norm())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn sylvester_with_complex_eigenvalues_and_rectangular_c() {
+    // A は複素共役対 (2x2 ブロック) を含み、C は 3x2
+    let a = mat(3, 3, &[1.0, -2.0, 0.5, 3.0, 1.0, 0.0, 0.2, 0.1, 4.0]);
+    let b = mat(2, 2, &[2.0, 1.0, -1.0, 3.0]);
+    let c = mat(3, 2, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let x = a.solve_sylvester(&b, &c).unwrap();
+    assert_eq!((x.rows, x.cols), (3, 2));
+    let residual = &(&(&a * &x) + &(&x * &b)) - &c;
+    assert_small(&residual, 1e-10, "A X + X B - C");
+}
+
+#[test]
+fn sylvester_and_stein_with_several_2x2_blocks() {
+    // 回転成分を持つ 5x5 と 4x4（どちらも複数の 2x2 ブロックを持つ Schur 形になる）
+    let a = mat(
+        5,
+        5,
+        &[
+            0.3, -1.2, 0.4, 0.0, 0.1, 1.1, 0.2, 0.0, 0.3, 0.0, 0.0, 0.5, -0.4, -0.9, 0.2, 0.1, 0.0,
+            0.8, -0.3, 0.4, 0.2, 0.1, 0.0, 0.3, 0.6,
+        ],
+    );
+    let b = mat(
+        4,
+        4,
+        &[
+            2.0, 1.5, 0.0, 0.2, -1.5, 2.0, 0.1, 0.0, 0.0, 0.3, 1.0, -2.0, 0.4, 0.0, 2.0, 1.0,
+        ],
+    );
+    let c = Matrix::new(5, 4, (0..20).map(|v| (v as f64 * 0.7).sin()).collect()).unwrap();
+    let x = a.solve_sylvester(&b, &c).unwrap();
+    assert_small(&(&(&(&a * &x) + &(&x * &b)) - &c), 1e-10, "A X + X B - C");
+
+    let q = &(&c * &c.transpose()) * 0.5;
+    let scaled = &a * 0.6;
+    let y = scaled.solve_discrete_lyapunov(&q).unwrap();
+    let residual = &(&(&(&scaled * &y) * &scaled.transpose()) - &y) + &q;
+    assert_small(&residual, 1e-10, "A Y A^T - Y + Q");
+}
+
+#[test]
+fn sylvester_rejects_shared_eigenvalues_and_bad_shapes() {
+    // A の固有値 1 と -B の固有値 1 が衝突する
+    let a = mat(2, 2, &[1.0, 0.0, 0.0, 2.0]);
+    let b = mat(2, 2, &[-1.0, 0.0, 0.0, 5.0]);
+    let c = mat(2, 2, &[1.0, 1.0, 1.0, 1.0]);
+    assert!(matches!(
+        a.solve_sylvester(&b, &c),
+        Err(LinalgError::SingularMatrix)
+    ));
+
+    let c_bad = mat(2, 3, &[0.0; 6]);
+    assert!(matches!(
+        a.solve_sylvester(&b, &c_bad),
+        Err(LinalgError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn continuous_lyapunov_gives_symmetric_positive_definite_gramian() {
+    // 安定な A（固有値 -1 ± 2i, -3）と Q = I に対する解は対称正定値
+    let a = mat(3, 3, &[-1.0, 2.0, 0.0, -2.0, -1.0, 1.0, 0.0, 0.0, -3.0]);
+    let q: Matrix<f64> = Matrix::identity(3);
+    let x = a.solve_continuous_lyapunov(&q).unwrap();
+    assert_symmetric(&x);
+    let residual = &(&(&a * &x) + &(&x * &a.transpose())) + &q;
+    assert_small(&residual, 1e-10, "A X + X A^T + Q");
+    assert!(x.cholesky().is_ok(), "X should be positive definite");
+}
+
+#[test]
+fn discrete_lyapunov_matches_series_solution() {
+    // ρ(A) < 1 なら X = Σ_k A^k Q (A^T)^k
+    let a = mat(2, 2, &[0.5, 0.3, -0.2, 0.4]);
+    let q = mat(2, 2, &[2.0, 0.5, 0.5, 1.0]);
+    let x = a.solve_discrete_lyapunov(&q).unwrap();
+    assert_symmetric(&x);
+
+    let mut series = Matrix::zeros(2, 2);
+    let mut term = q.clone();
+    for _ in 0..200 {
+        series = &series + &term;
+        term = &(&a * &term) * &a.transpose();
+    }
+    assert_small(&(&x - &series), 1e-10, "X - series");
+}
+
+#[test]
+fn care_scalar_and_double_integrator() {
+    // 2x - x^2 + 1 = 0 の正の解 1 + √2
+    let one = mat(1, 1, &[1.0]);
+    let x = one.solve_care(&one, &one, &one).unwrap();
+    assert!((x[(0, 0)] - (1.0 + 2f64.sqrt())).abs() < 1e-10);
+
+    // 二重積分器 + Q = I, R = 1 の解は [[√3, 1], [1, √3]]
+    let a = mat(2, 2, &[0.0, 1.0, 0.0, 0.0]);
+    let b = mat(2, 1, &[0.0, 1.0]);
+    let q: Matrix<f64> = Matrix::identity(2);
+    let x = a.solve_care(&b, &q, &one).unwrap();
+    let s3 = 3f64.sqrt();
+    assert_small(&(&x - &mat(2, 2, &[s3, 1.0, 1.0, s3])), 1e-9, "X - X_exact");
+
+    // 閉ループ A - B R^{-1} B^T X は安定
+    let closed = &a - &(&(&b * &b.transpose()) * &x);
+    assert!(spectral_abscissa(&closed) < 0.0);
+}
+
+#[test]
+fn dare_scalar_and_closed_loop_stability() {
+    // x = x - x^2 / (1 + x) + 1 より x^2 - x - 1 = 0（黄金比）
+    let one = mat(1, 1, &[1.0]);
+    let x = one.solve_dare(&one, &one, &one).unwrap();
+    assert!((x[(0, 0)] - (1.0 + 5f64.sqrt()) / 2.0).abs() < 1e-10);
+
+    // 不安定な離散系を安定化する
+    let a = mat(2, 2, &[1.1, 0.2, 0.0, 0.9]);
+    let b = mat(2, 1, &[0.0, 1.0]);
+    let q: Matrix<f64> = Matrix::identity(2);
+    let x = a.solve_dare(&b, &q, &one).unwrap();
+    assert_symmetric(&x);
+    // K = (R + B^T X B)^{-1} B^T X A, 閉ループ A - B K
+    let bt = b.transpose();
+    let inner = &one + &(&(&bt * &x) * &b);
+    let k = inner.solve_matrix(&(&(&bt * &x) * &a)).unwrap();
+    let closed = &a - &(&b * &k);
+    assert!(spectral_radius(&closed) < 1.0);
+}
+
+#[test]
+fn riccati_without_stabilizing_solution_is_an_error() {
+    // B = 0 で A が不安定なら安定化できない
+    let a = mat(2, 2, &[1.0, 0.0, 0.0, -1.0]);
+    let b = mat(2, 1, &[0.0, 0.0]);
+    let q: Matrix<f64> = Matrix::identity(2);
+    let r = mat(1, 1, &[1.0]);
+    assert!(a.solve_care(&b, &q, &r).is_err());
+    let a_discrete = mat(2, 2, &[2.0, 0.0, 0.0, 0.5]);
+    assert!(a_discrete.solve_dare(&b, &q, &r).is_err());
+}
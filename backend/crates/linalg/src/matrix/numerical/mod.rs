@@ -1,8 +1,14 @@
 pub mod cholesky;
 pub mod eigen;
 pub mod exp;
+pub mod funm;
+pub mod generalized_eigen;
+pub mod hessenberg;
+pub mod krylov;
+pub mod matrix_equation;
 pub mod pseudoinverse;
 pub mod qr;
+pub mod schur;
 pub mod svd;
 
 mod helpers;
@@ -10,12 +16,22 @@ mod helpers;
 // --- トレイトを短いパスで使えるように再エクスポートする ---
 // これを書いておくと `use crate::matrix::numerical::Svd;` のように書ける
 pub use cholesky::CholeskyDecomposition;
-pub use eigen::EigenDecomposition;
+pub use eigen::{EigenDecomposition, HermitianEigenDecomposition};
 pub use exp::MatrixExponential;
+pub use funm::MatrixFunction;
+pub use generalized_eigen::GeneralizedEigenDecomposition;
+pub use hessenberg::HessenbergDecomposition;
+pub use krylov::{
+    eigs, eigsh, randomized_svd, FnOperator, KrylovOptions, LinearOperator, PartialEigen,
+    PartialEigenComplex, RandomizedSvdOptions, Which,
+};
+pub use matrix_equation::MatrixEquation;
 pub use pseudoinverse::Pseudoinverse;
-pub use qr::QrDecomposition;
+pub use qr::{ComplexQrDecomposition, LeastSquares, QrDecomposition};
+pub use schur::SchurDecomposition;
 pub use svd::SvdDeComposition;
 
+use crate::traits::ComplexField;
 use crate::Matrix;
 use num_complex::Complex;
 
@@ -31,6 +47,30 @@ impl Matrix<f64> {
         }
         s.sqrt()
     }
+
+    /// 2 ノルム（最大特異値）||A||_2
+    pub fn two_norm(&self) -> crate::Result<f64> {
+        Ok(extreme_singular_values(&self.svd()?.sigma.data).0)
+    }
+
+    /// 2 ノルム条件数 σ_max / σ_min。特異なら無限大。
+    pub fn cond(&self) -> crate::Result<f64> {
+        let (max, min) = extreme_singular_values(&self.svd()?.sigma.data);
+        Ok(condition_from_singular_values(max, min))
+    }
+}
+
+impl<T: ComplexField> Matrix<T> {
+    /// 共役転置 A^H（実数では転置と同じ）
+    pub fn conjugate_transpose(&self) -> Matrix<T> {
+        let mut out = Matrix::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                out[(j, i)] = self[(i, j)].conj();
+            }
+        }
+        out
+    }
 }
 
 impl Matrix<Complex<f64>> {
@@ -45,4 +85,33 @@ impl Matrix<Complex<f64>> {
         }
         s.sqrt()
     }
+
+    /// 2 ノルム（最大特異値）||A||_2
+    pub fn two_norm(&self) -> crate::Result<f64> {
+        Ok(extreme_singular_values(&self.svd()?.sigma.data).0)
+    }
+
+    /// 2 ノルム条件数 σ_max / σ_min。特異なら無限大。
+    pub fn cond(&self) -> crate::Result<f64> {
+        let (max, min) = extreme_singular_values(&self.svd()?.sigma.data);
+        Ok(condition_from_singular_values(max, min))
+    }
+}
+
+/// (最大特異値, 最小特異値)。空なら (0, 0)。
+fn extreme_singular_values(sigma: &[f64]) -> (f64, f64) {
+    if sigma.is_empty() {
+        return (0.0, 0.0);
+    }
+    let max = sigma.iter().cloned().fold(0.0, f64::max);
+    let min = sigma.iter().cloned().fold(f64::INFINITY, f64::min);
+    (max, min)
+}
+
+fn condition_from_singular_values(max: f64, min: f64) -> f64 {
+    if min == 0.0 {
+        f64::INFINITY
+    } else {
+        max / min
+    }
 }
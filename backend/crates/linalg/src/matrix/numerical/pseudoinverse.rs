@@ -1,7 +1,12 @@
+use num_complex::Complex;
+
 use crate::{matrix::numerical::SvdDeComposition, Matrix, Result};
 
-pub trait Pseudoinverse {
-    fn pinv(&self) -> Result<Matrix<f64>>;
+const THRESHOLD: f64 = 1e-10;
+
+pub trait Pseudoinverse: Sized {
+    /// Moore–Penrose 疑似逆行列 A^+ = V Σ^+ U^H
+    fn pinv(&self) -> Result<Self>;
 }
 
 impl Pseudoinverse for Matrix<f64> {
@@ -12,8 +17,6 @@ impl Pseudoinverse for Matrix<f64> {
         let v = svd.v;
         let mut sigma_pinv = Matrix::zeros(v.cols, u.cols);
 
-        const THRESHOLD: f64 = 1e-10;
-
         for (i, s) in sigma.iter().enumerate() {
             if *s > THRESHOLD {
                 sigma_pinv[(i, i)] = 1.0 / *s;
@@ -22,3 +25,16 @@ impl Pseudoinverse for Matrix<f64> {
         Ok(v * sigma_pinv * u.transpose())
     }
 }
+
+impl Pseudoinverse for Matrix<Complex<f64>> {
+    fn pinv(&self) -> Result<Matrix<Complex<f64>>> {
+        let svd = self.svd()?;
+        let mut sigma_pinv = Matrix::zeros(svd.v.cols, svd.u.cols);
+        for (i, s) in svd.sigma.iter().enumerate() {
+            if *s > THRESHOLD {
+                sigma_pinv[(i, i)] = Complex::new(1.0 / *s, 0.0);
+            }
+        }
+        Ok(&(&svd.v * &sigma_pinv) * &svd.u.conjugate_transpose())
+    }
+}
@@ -0,0 +1,113 @@
+use num_complex::Complex;
+use std::cmp::min;
+
+use super::{QrPivoted, QR};
+use crate::matrix::numerical::helpers::{apply_householder_left, complex_householder};
+use crate::Matrix;
+
+type C64 = Complex<f64>;
+
+/// 複素行列の QR 分解。実行列の `QrDecomposition` と同じ規約で Q はユニタリ。
+pub trait ComplexQrDecomposition {
+    /// 複素 Householder QR 分解 A = Q R（Q ユニタリ、R の対角は非負実数）
+    fn qr_decomposition(&self) -> crate::Result<QR<C64>>;
+
+    /// 列ピボット付き QR 分解（QRCP）。A[:, perm] = Q R。
+    fn qr_pivoted(&self) -> crate::Result<QrPivoted<C64>>;
+
+    /// 経済型（thin）QR 分解。k = min(m, n) として Q は m×k、R は k×n。
+    fn qr_thin(&self) -> crate::Result<QR<C64>>;
+}
+
+impl ComplexQrDecomposition for Matrix<C64> {
+    fn qr_decomposition(&self) -> crate::Result<QR<C64>> {
+        let (q, r, _) = householder_qr(self, false, self.rows);
+        Ok(QR { q, r })
+    }
+
+    fn qr_pivoted(&self) -> crate::Result<QrPivoted<C64>> {
+        let (q, r, perm) = householder_qr(self, true, self.rows);
+        Ok(QrPivoted { q, r, perm })
+    }
+
+    fn qr_thin(&self) -> crate::Result<QR<C64>> {
+        let k = min(self.rows, self.cols);
+        let (q, r, _) = householder_qr(self, false, k);
+        Ok(QR {
+            q,
+            r: r.submatrix(0, k, 0, self.cols),
+        })
+    }
+}
+
+/// Householder 反射による QR。`pivot` が真なら各段で残り列ノルム最大の列を選ぶ。
+/// Q は先頭 q_cols 列だけを、反射を [I; 0] に逆順で左から掛けて作る。
+fn householder_qr(
+    a: &Matrix<C64>,
+    pivot: bool,
+    q_cols: usize,
+) -> (Matrix<C64>, Matrix<C64>, Vec<usize>) {
+    let (rows, cols) = (a.rows, a.cols);
+    let mut r = a.clone();
+    let mut perm: Vec<usize> = (0..cols).collect();
+    let mut reflectors = Vec::with_capacity(min(rows, cols));
+
+    for k in 0..min(rows, cols) {
+        if pivot {
+            let mut best = k;
+            let mut best_norm = -1.0;
+            for j in k..cols {
+                let norm: f64 = (k..rows).map(|i| r[(i, j)].norm_sqr()).sum();
+                if norm > best_norm {
+                    best = j;
+                    best_norm = norm;
+                }
+            }
+            if best != k {
+                for i in 0..rows {
+                    let tmp = r[(i, k)];
+                    r[(i, k)] = r[(i, best)];
+                    r[(i, best)] = tmp;
+                }
+                perm.swap(k, best);
+            }
+        }
+
+        let x: Vec<C64> = (k..rows).map(|i| r[(i, k)]).collect();
+        let v = complex_householder(&x);
+        if let Some(v) = &v {
+            apply_householder_left(&mut r, v, k, k);
+        }
+        reflectors.push(v);
+        for i in (k + 1)..rows {
+            r[(i, k)] = C64::new(0.0, 0.0);
+        }
+    }
+
+    let mut q: Matrix<C64> = Matrix::zeros(rows, q_cols);
+    for i in 0..min(rows, q_cols) {
+        q[(i, i)] = C64::new(1.0, 0.0);
+    }
+    for (k, v) in reflectors.iter().enumerate().rev() {
+        if let Some(v) = v {
+            apply_householder_left(&mut q, v, k, k);
+        }
+    }
+
+    // 対角を非負実数に揃える: R の k 行に conj(d)、Q の k 列に d を掛ける（d = r_kk / |r_kk|）
+    for k in 0..min(rows, cols) {
+        let rkk = r[(k, k)];
+        if rkk.norm() == 0.0 {
+            continue;
+        }
+        let d = rkk / rkk.norm();
+        for j in 0..cols {
+            r[(k, j)] *= d.conj();
+        }
+        for i in 0..rows {
+            q[(i, k)] *= d;
+        }
+        r[(k, k)] = C64::new(rkk.norm(), 0.0);
+    }
+    (q, r, perm)
+}
@@ -0,0 +1,103 @@
+use crate::matrix::numerical::helpers::{householder_left, householder_vector};
+use crate::matrix::Matrix;
+use crate::Direction;
+use std::cmp::min;
+
+/// Householder 反射 H_0 H_1 … H_{k-1} と対角の符号を、Q を陽に作らずに保持する。
+/// Q = H_0 H_1 … H_{k-1} diag(signs, 1, …, 1)。
+pub(super) struct Reflectors {
+    rows: usize,
+    vs: Vec<Option<Vec<f64>>>,
+    signs: Vec<f64>,
+}
+
+/// A（pivot なら A[:, perm]）を R と反射列に分解する。R の対角は非負にそろえる。
+pub(super) fn factor(
+    a: &Matrix<f64>,
+    pivot: bool,
+) -> crate::Result<(Matrix<f64>, Reflectors, Vec<usize>)> {
+    let (rows, cols) = (a.rows, a.cols);
+    let k = min(rows, cols);
+    let mut r = a.clone();
+    let mut perm: Vec<usize> = (0..cols).collect();
+    let mut vs = Vec::with_capacity(k);
+
+    for j in 0..k {
+        if pivot {
+            // 残りの部分列ノルムが最大の列を j 番目へ移す
+            let mut best = j;
+            let mut best_norm = -1.0;
+            for c in j..cols {
+                let norm: f64 = (j..rows).map(|i| r[(i, c)] * r[(i, c)]).sum();
+                if norm > best_norm {
+                    best = c;
+                    best_norm = norm;
+                }
+            }
+            if best != j {
+                for i in 0..rows {
+                    let tmp = r[(i, j)];
+                    r[(i, j)] = r[(i, best)];
+                    r[(i, best)] = tmp;
+                }
+                perm.swap(j, best);
+            }
+        }
+
+        let x = r.view().slice(j..rows, j..j + 1)?;
+        let h_vec = householder_vector(x);
+        if let Some(h_vec) = &h_vec {
+            r.apply_householder_transform(h_vec, Direction::Left, j, j);
+        }
+        vs.push(h_vec.map(|v| v.data));
+    }
+
+    let mut signs = vec![1.0; k];
+    for (j, sign) in signs.iter_mut().enumerate() {
+        if r[(j, j)] < 0.0 {
+            r.scale_row(j, -1.0)?;
+            *sign = -1.0;
+        }
+    }
+    Ok((r, Reflectors { rows, vs, signs }, perm))
+}
+
+impl Reflectors {
+    /// Q の先頭 cols 列（cols ≥ 反射の個数）。[I; 0] に反射を逆順で左から掛けて作る。
+    pub(super) fn form_q(&self, cols: usize) -> crate::Result<Matrix<f64>> {
+        let mut q = Matrix::zeros(self.rows, cols);
+        for i in 0..min(self.rows, cols) {
+            q[(i, i)] = 1.0;
+        }
+        // H_j は j 行目以降にしか作用せず、j 列目より前の列は e_c のまま
+        for (j, v) in self.vs.iter().enumerate().rev() {
+            if let Some(v) = v {
+                householder_left(q.view_mut().slice_mut(j..self.rows, j..cols)?, v);
+            }
+        }
+        for (j, &s) in self.signs.iter().enumerate() {
+            if s < 0.0 {
+                for i in 0..self.rows {
+                    q[(i, j)] = -q[(i, j)];
+                }
+            }
+        }
+        Ok(q)
+    }
+
+    /// b ← Q^T b（長さ m）。Q は作らずに反射を順に当てる。
+    pub(super) fn apply_qt(&self, b: &mut [f64]) {
+        for (j, v) in self.vs.iter().enumerate() {
+            if let Some(v) = v {
+                let tail = &mut b[j..];
+                let dot: f64 = v.iter().zip(tail.iter()).map(|(vi, bi)| vi * bi).sum();
+                for (bi, vi) in tail.iter_mut().zip(v) {
+                    *bi -= 2.0 * vi * dot;
+                }
+            }
+        }
+        for (bj, &s) in b.iter_mut().zip(&self.signs) {
+            *bj *= s;
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::householder;
+use super::pivoted::diagonal_rank;
+use crate::{LinalgError, Matrix, Result, Vector};
+
+/// 最小二乗問題 min ||A x - b||_2 の解
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lstsq {
+    /// 最小ノルム最小二乗解
+    pub solution: Vector<f64>,
+    /// 残差二乗和 ||A x - b||_2^2
+    pub residuals: f64,
+    /// 列ピボット付き QR の R の対角から判定した数値ランク
+    pub rank: usize,
+}
+
+pub trait LeastSquares {
+    /// 列ピボット付き QR と完全直交分解で最小ノルム最小二乗解を求める。
+    /// rcond は相対ランク判定閾値（省略時は max(m, n) * ε）。
+    fn lstsq(&self, b: &Vector<f64>, rcond: Option<f64>) -> Result<Lstsq>;
+}
+
+impl LeastSquares for Matrix<f64> {
+    fn lstsq(&self, b: &Vector<f64>, rcond: Option<f64>) -> Result<Lstsq> {
+        let (m, n) = (self.rows, self.cols);
+        if b.dim() != m {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{m}-dimensional vector"),
+                found: format!("{}-dimensional vector", b.dim()),
+            });
+        }
+        let k = m.min(n);
+        if k == 0 {
+            return Ok(Lstsq {
+                solution: Vector::new(vec![0.0; n]),
+                residuals: b.data.iter().map(|v| v * v).sum(),
+                rank: 0,
+            });
+        }
+
+        let (r, reflectors, perm) = householder::factor(self, true)?;
+        let rank = diagonal_rank(&r, rcond);
+
+        // c = Q^T b（Q は作らず反射を当てる）。c の rank 番目以降が残差成分になる。
+        let mut c = b.data.clone();
+        reflectors.apply_qt(&mut c);
+        let residuals = c[rank..].iter().map(|v| v * v).sum();
+
+        let mut y = vec![0.0; n];
+        if rank == n {
+            // フルランク: R11 y = c1 を後退代入
+            let r11 = r.submatrix(0, n, 0, n);
+            let sol = r11.backward_substitution(&Vector::new(c[..n].to_vec()))?;
+            y.copy_from_slice(&sol.data);
+        } else if rank > 0 {
+            // ランク落ち: [R11 R12] = S^T Z1^T（[R11 R12]^T の QR）として最小ノルム解 y = Z1 S^{-T} c1
+            let r1t = r.submatrix(0, rank, 0, n).transpose();
+            let zq = r1t.qr_thin()?;
+            let st = zq.r.transpose();
+            let w = st.forward_substitution(&Vector::new(c[..rank].to_vec()))?;
+            for (i, yi) in y.iter_mut().enumerate() {
+                *yi = (0..rank).map(|j| zq.q[(i, j)] * w[j]).sum();
+            }
+        }
+        let mut solution = vec![0.0; n];
+        for (j, &p) in perm.iter().enumerate() {
+            solution[p] = y[j];
+        }
+
+        Ok(Lstsq {
+            solution: Vector::new(solution),
+            residuals,
+            rank,
+        })
+    }
+}
@@ -1,11 +1,22 @@
+use crate::matrix::numerical::helpers::householder_vector;
 use crate::matrix::Matrix;
 use crate::Direction;
 use std::cmp::min;
 
+mod complex;
+mod householder;
+mod lstsq;
+mod pivoted;
+mod update;
+
+pub use complex::ComplexQrDecomposition;
+pub use lstsq::{LeastSquares, Lstsq};
+pub use pivoted::QrPivoted;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct QR {
-    pub q: Matrix<f64>,
-    pub r: Matrix<f64>,
+pub struct QR<T = f64> {
+    pub q: Matrix<T>,
+    pub r: Matrix<T>,
 }
 
 pub trait QrDecomposition {
@@ -21,11 +32,11 @@ impl QrDecomposition for Matrix<f64> {
         let mut q = Matrix::identity(rows);
 
         for k in 0..min(rows, cols) {
-            // 1. 部分ベクトルを抽出
-            let x = r.partial_col(k, k, rows)?;
+            // 1. 部分列をビューとして参照
+            let x = r.view().slice(k..rows, k..k + 1)?;
 
             // 2. ハウスホルダー"ベクトル"を計算
-            if let Some(h_vec) = x.householder_vector() {
+            if let Some(h_vec) = householder_vector(x) {
                 // 3. r に左から変換を適用: r = H * r
                 r.apply_householder_transform(&h_vec, Direction::Left, k, k);
 
@@ -46,5 +57,21 @@ impl QrDecomposition for Matrix<f64> {
     }
 }
 
+impl Matrix<f64> {
+    /// 経済型（thin）QR 分解。k = min(m, n) として Q は m×k、R は k×n。
+    /// 反射を m×k の Q に直接蓄積するので m×m の Q は作らない。
+    pub fn qr_thin(&self) -> crate::Result<QR> {
+        let k = min(self.rows, self.cols);
+        let (r, reflectors, _) = householder::factor(self, false)?;
+        Ok(QR {
+            q: reflectors.form_q(k)?,
+            r: r.submatrix(0, k, 0, self.cols),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests;
+
+#[cfg(test)]
+mod tests_complex;
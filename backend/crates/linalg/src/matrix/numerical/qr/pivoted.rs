@@ -0,0 +1,49 @@
+use super::householder;
+use crate::matrix::Matrix;
+use crate::traits::ComplexField;
+use std::cmp::min;
+
+/// 列ピボット付き QR 分解 A[:, perm] = Q R。
+/// R の対角は絶対値の非増加列になり、数値ランクの判定に使える。
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QrPivoted<T = f64> {
+    pub q: Matrix<T>,
+    pub r: Matrix<T>,
+    pub perm: Vec<usize>,
+}
+
+impl<T: ComplexField> QrPivoted<T> {
+    /// |r_kk| > rcond * |r_00| を満たす対角の個数（数値ランク）。
+    /// rcond を省略した場合は max(m, n) * ε を使う。
+    pub fn rank(&self, rcond: Option<f64>) -> usize {
+        diagonal_rank(&self.r, rcond)
+    }
+}
+
+/// 列ピボット付き QR の R について |r_kk| > rcond * |r_00| を満たす対角の個数
+pub(super) fn diagonal_rank<T: ComplexField>(r: &Matrix<T>, rcond: Option<f64>) -> usize {
+    let k = min(r.rows, r.cols);
+    if k == 0 {
+        return 0;
+    }
+    let rcond = rcond.unwrap_or(r.rows.max(r.cols) as f64 * f64::EPSILON);
+    let tol = rcond * r[(0, 0)].modulus();
+    (0..k)
+        .take_while(|&i| {
+            let d = r[(i, i)].modulus();
+            d > tol && d != 0.0
+        })
+        .count()
+}
+
+impl Matrix<f64> {
+    /// 列ピボット付き QR 分解（QRCP）。A[:, perm] = Q R。
+    pub fn qr_pivoted(&self) -> crate::Result<QrPivoted> {
+        let (r, reflectors, perm) = householder::factor(self, true)?;
+        Ok(QrPivoted {
+            q: reflectors.form_q(self.rows)?,
+            r,
+            perm,
+        })
+    }
+}
@@ -1,5 +1,6 @@
-use super::*;
+use super::{LeastSquares, QrDecomposition};
 use crate::matrix::Matrix;
+use crate::Vector;
 
 fn approx(a: f64, b: f64, tol: f64) -> bool {
     (a - b).abs() <= tol
@@ -157,3 +158,182 @@ fn qr_zero_matrix() {
         assert!(approx(qr.r[(i, i)], 0.0, 1e-12));
     }
 }
+
+fn sample_matrix() -> Matrix<f64> {
+    Matrix::new(
+        5,
+        3,
+        vec![
+            2.0, -1.0, 0.5, //
+            1.0, 3.0, -2.0, //
+            0.0, 1.0, 4.0, //
+            -1.5, 2.0, 1.0, //
+            3.0, 0.0, -1.0,
+        ],
+    )
+    .unwrap()
+}
+
+fn permuted_columns(a: &Matrix<f64>, perm: &[usize]) -> Matrix<f64> {
+    let mut out = Matrix::zeros(a.rows, a.cols);
+    for (j, &p) in perm.iter().enumerate() {
+        for i in 0..a.rows {
+            out[(i, j)] = a[(i, p)];
+        }
+    }
+    out
+}
+
+#[test]
+fn qr_pivoted_reconstructs_and_detects_rank() {
+    // 3 列目 = 1 列目 + 2 列目（ランク 2）
+    let a = Matrix::new(
+        4,
+        3,
+        vec![
+            1.0, 2.0, 3.0, //
+            4.0, 5.0, 9.0, //
+            7.0, 8.0, 15.0, //
+            1.0, 0.0, 1.0,
+        ],
+    )
+    .unwrap();
+    let qrcp = a.qr_pivoted().expect("qr_pivoted failed");
+    assert_orthogonal(&qrcp.q, 1e-10);
+    assert_upper_trapezoidal(&qrcp.r, 1e-10);
+    assert_matrix_approx_eq(
+        &(&qrcp.q * &qrcp.r),
+        &permuted_columns(&a, &qrcp.perm),
+        1e-10,
+    );
+    // 対角成分の絶対値は非増加
+    for k in 1..3 {
+        assert!(qrcp.r[(k, k)].abs() <= qrcp.r[(k - 1, k - 1)].abs() + 1e-12);
+    }
+    assert_eq!(qrcp.rank(None), 2);
+
+    let full = sample_matrix().qr_pivoted().unwrap();
+    assert_eq!(full.rank(None), 3);
+}
+
+#[test]
+fn qr_thin_shapes() {
+    let a = sample_matrix();
+    let qr = a.qr_thin().expect("qr_thin failed");
+    assert_eq!((qr.q.rows, qr.q.cols), (5, 3));
+    assert_eq!((qr.r.rows, qr.r.cols), (3, 3));
+    let qtq = &qr.q.transpose() * &qr.q;
+    assert_matrix_approx_eq(&qtq, &Matrix::identity(3), 1e-12);
+    assert_matrix_approx_eq(&(&qr.q * &qr.r), &a, 1e-12);
+
+    // 横長 (3×5) では Q は 3×3、R は 3×5
+    let wide = a.transpose();
+    let qr = wide.qr_thin().unwrap();
+    assert_orthogonal(&qr.q, 1e-12);
+    assert_eq!((qr.r.rows, qr.r.cols), (3, 5));
+    assert_upper_trapezoidal(&qr.r, 1e-12);
+    assert_matrix_approx_eq(&(&qr.q * &qr.r), &wide, 1e-12);
+}
+
+#[test]
+fn qr_rank_one_update_matches_fresh_factorization() {
+    let a = sample_matrix();
+    let u = Vector::new(vec![1.0, -2.0, 0.5, 3.0, 1.0]);
+    let v = Vector::new(vec![0.5, 1.0, -1.0]);
+    let updated = a
+        .qr_decomposition()
+        .unwrap()
+        .rank_one_update(&u, &v)
+        .unwrap();
+
+    let mut expected = a.clone();
+    for i in 0..5 {
+        for j in 0..3 {
+            expected[(i, j)] += u[i] * v[j];
+        }
+    }
+    assert_orthogonal(&updated.q, 1e-12);
+    assert_upper_trapezoidal(&updated.r, 1e-12);
+    assert_matrix_approx_eq(&(&updated.q * &updated.r), &expected, 1e-12);
+}
+
+#[test]
+fn qr_insert_and_delete_rows() {
+    let a = sample_matrix();
+    let qr = a.qr_decomposition().unwrap();
+
+    let row = Vector::new(vec![7.0, -3.0, 2.0]);
+    let inserted = qr.insert_row(2, &row).unwrap();
+    let mut expected = Matrix::zeros(6, 3);
+    for i in 0..6 {
+        for j in 0..3 {
+            expected[(i, j)] = match i {
+                0 | 1 => a[(i, j)],
+                2 => row[j],
+                _ => a[(i - 1, j)],
+            };
+        }
+    }
+    assert_orthogonal(&inserted.q, 1e-12);
+    assert_upper_trapezoidal(&inserted.r, 1e-12);
+    assert_matrix_approx_eq(&(&inserted.q * &inserted.r), &expected, 1e-12);
+
+    let deleted = qr.delete_row(1).unwrap();
+    let mut expected = Matrix::zeros(4, 3);
+    for (ni, i) in [0, 2, 3, 4].into_iter().enumerate() {
+        for j in 0..3 {
+            expected[(ni, j)] = a[(i, j)];
+        }
+    }
+    assert_orthogonal(&deleted.q, 1e-12);
+    assert_upper_trapezoidal(&deleted.r, 1e-12);
+    assert_matrix_approx_eq(&(&deleted.q * &deleted.r), &expected, 1e-12);
+
+    assert!(qr.delete_row(5).is_err());
+    assert!(a.qr_thin().unwrap().delete_row(0).is_err());
+}
+
+#[test]
+fn lstsq_overdetermined_matches_normal_equations() {
+    // 直線 y = c0 + c1 x のフィット
+    let a = Matrix::new(4, 2, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0]).unwrap();
+    let b = Vector::new(vec![1.0, 2.0, 2.5, 3.0]);
+    let sol = a.lstsq(&b, None).expect("lstsq failed");
+    assert_eq!(sol.rank, 2);
+    assert!(approx(sol.solution[0], 1.15, 1e-12));
+    assert!(approx(sol.solution[1], 0.65, 1e-12));
+
+    let resid = &b - &(&a * &sol.solution);
+    let rss: f64 = resid.data.iter().map(|v| v * v).sum();
+    assert!(approx(sol.residuals, rss, 1e-12));
+}
+
+#[test]
+fn lstsq_rank_deficient_returns_minimum_norm() {
+    // 3 列目 = 1 列目 + 2 列目
+    let a = Matrix::new(
+        4,
+        3,
+        vec![
+            1.0, 2.0, 3.0, //
+            4.0, 5.0, 9.0, //
+            7.0, 8.0, 15.0, //
+            1.0, 0.0, 1.0,
+        ],
+    )
+    .unwrap();
+    let b = Vector::new(vec![1.0, -1.0, 2.0, 0.5]);
+    let sol = a.lstsq(&b, None).unwrap();
+    assert_eq!(sol.rank, 2);
+
+    // 最小ノルム解は零空間 (1, 1, -1) に直交する
+    let x = &sol.solution;
+    assert!(approx(x[0] + x[1] - x[2], 0.0, 1e-10));
+    // 正規方程式 A^T (A x - b) = 0
+    let grad = &a.transpose() * &(&(&a * x) - &b);
+    for i in 0..3 {
+        assert!(approx(grad[i], 0.0, 1e-10), "grad[{i}] = {}", grad[i]);
+    }
+
+    assert!(a.lstsq(&Vector::new(vec![1.0, 2.0]), None).is_err());
+}
@@ -0,0 +1,91 @@
+use num_complex::Complex;
+
+use super::*;
+
+type C64 = Complex<f64>;
+
+fn c(re: f64, im: f64) -> C64 {
+    C64::new(re, im)
+}
+
+fn sample() -> Matrix<C64> {
+    Matrix::new(
+        4,
+        3,
+        vec![
+            c(1.0, 2.0),
+            c(0.5, -1.0),
+            c(2.0, 0.0),
+            c(-1.0, 0.5),
+            c(3.0, 1.0),
+            c(0.0, -2.0),
+            c(0.0, 1.0),
+            c(1.0, 1.0),
+            c(-2.0, 0.5),
+            c(2.0, -1.0),
+            c(0.0, 0.0),
+            c(1.0, 3.0),
+        ],
+    )
+    .unwrap()
+}
+
+fn assert_close(a: &Matrix<C64>, b: &Matrix<C64>, tol: f64) {
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+    for i in 0..a.rows {
+        for j in 0..a.cols {
+            assert!(
+                (a[(i, j)] - b[(i, j)]).norm() <= tol,
+                "mismatch at ({i}, {j}): {} vs {}",
+                a[(i, j)],
+                b[(i, j)]
+            );
+        }
+    }
+}
+
+fn assert_unitary(q: &Matrix<C64>, tol: f64) {
+    let qhq = &q.conjugate_transpose() * q;
+    assert_close(&qhq, &Matrix::identity(q.cols), tol);
+}
+
+#[test]
+fn complex_qr_reconstructs_with_real_diagonal() {
+    let a = sample();
+    let qr = a.qr_decomposition().expect("complex qr failed");
+    assert_eq!((qr.q.rows, qr.q.cols), (4, 4));
+    assert_unitary(&qr.q, 1e-12);
+    for i in 0..qr.r.rows {
+        for j in 0..i.min(qr.r.cols) {
+            assert!(qr.r[(i, j)].norm() <= 1e-12);
+        }
+    }
+    for k in 0..3 {
+        assert!(qr.r[(k, k)].re >= 0.0 && qr.r[(k, k)].im == 0.0);
+    }
+    assert_close(&(&qr.q * &qr.r), &a, 1e-12);
+}
+
+#[test]
+fn complex_qr_thin_and_pivoted() {
+    let a = sample();
+    let thin = a.qr_thin().unwrap();
+    assert_eq!((thin.q.rows, thin.q.cols), (4, 3));
+    assert_unitary(&thin.q, 1e-12);
+    assert_close(&(&thin.q * &thin.r), &a, 1e-12);
+
+    // 3 列目 = i * 1 列目 にしてランク 2
+    let mut b = a.clone();
+    for i in 0..4 {
+        b[(i, 2)] = a[(i, 0)] * c(0.0, 1.0);
+    }
+    let qrcp = b.qr_pivoted().unwrap();
+    assert_unitary(&qrcp.q, 1e-12);
+    let recon = &qrcp.q * &qrcp.r;
+    for (j, &p) in qrcp.perm.iter().enumerate() {
+        for i in 0..4 {
+            assert!((recon[(i, j)] - b[(i, p)]).norm() <= 1e-12);
+        }
+    }
+    assert_eq!(qrcp.rank(None), 2);
+}
@@ -0,0 +1,161 @@
+use super::QR;
+use crate::{LinalgError, Matrix, Result, Vector};
+
+/// Givens 回転 [[c, s], [-s, c]] [a; b] = [r; 0]
+fn givens(a: f64, b: f64) -> (f64, f64) {
+    if b == 0.0 {
+        return (1.0, 0.0);
+    }
+    let r = a.hypot(b);
+    (a / r, b / r)
+}
+
+fn rotate_rows(m: &mut Matrix<f64>, i: usize, k: usize, c: f64, s: f64) {
+    for j in 0..m.cols {
+        let (x, y) = (m[(i, j)], m[(k, j)]);
+        m[(i, j)] = c * x + s * y;
+        m[(k, j)] = -s * x + c * y;
+    }
+}
+
+/// 右から G^T を掛ける（A = Q R を保つため Q <- Q G^T）
+fn rotate_cols(m: &mut Matrix<f64>, i: usize, k: usize, c: f64, s: f64) {
+    for row in 0..m.rows {
+        let (x, y) = (m[(row, i)], m[(row, k)]);
+        m[(row, i)] = c * x + s * y;
+        m[(row, k)] = -s * x + c * y;
+    }
+}
+
+/// 上 Hessenberg になった R の副対角を回転で消す
+fn retriangularize(q: &mut Matrix<f64>, r: &mut Matrix<f64>) {
+    let steps = r.cols.min(r.rows.saturating_sub(1));
+    for k in 0..steps {
+        let (c, s) = givens(r[(k, k)], r[(k + 1, k)]);
+        rotate_rows(r, k, k + 1, c, s);
+        rotate_cols(q, k, k + 1, c, s);
+        r[(k + 1, k)] = 0.0;
+    }
+}
+
+impl QR {
+    fn check_full(&self) -> Result<()> {
+        if self.q.rows != self.q.cols || self.q.cols != self.r.rows {
+            return Err(LinalgError::InvalidArgument {
+                text: "QR update requires the full (square) Q factor".into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// A + u v^T の QR 分解を O(m^2 + mn) で求める。
+    pub fn rank_one_update(&self, u: &Vector<f64>, v: &Vector<f64>) -> Result<QR> {
+        self.check_full()?;
+        let (m, n) = (self.r.rows, self.r.cols);
+        if u.dim() != m || v.dim() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("u: {m}, v: {n}"),
+                found: format!("u: {}, v: {}", u.dim(), v.dim()),
+            });
+        }
+        let mut q = self.q.clone();
+        let mut r = self.r.clone();
+
+        // w = Q^T u を下から回転して ±||w|| e_1 にする（R は上 Hessenberg になる）
+        let mut w: Vec<f64> = (0..m)
+            .map(|j| (0..m).map(|i| q[(i, j)] * u[i]).sum())
+            .collect();
+        for k in (0..m.saturating_sub(1)).rev() {
+            let (c, s) = givens(w[k], w[k + 1]);
+            w[k] = c * w[k] + s * w[k + 1];
+            w[k + 1] = 0.0;
+            rotate_rows(&mut r, k, k + 1, c, s);
+            rotate_cols(&mut q, k, k + 1, c, s);
+        }
+        if m > 0 {
+            for j in 0..n {
+                r[(0, j)] += w[0] * v[j];
+            }
+        }
+        retriangularize(&mut q, &mut r);
+        Ok(QR { q, r })
+    }
+
+    /// 位置 k に行 `row` を挿入した行列の QR 分解
+    pub fn insert_row(&self, k: usize, row: &Vector<f64>) -> Result<QR> {
+        self.check_full()?;
+        let (m, n) = (self.r.rows, self.r.cols);
+        if k > m {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: k,
+                size: m + 1,
+            });
+        }
+        if row.dim() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{n}"),
+                found: format!("{}", row.dim()),
+            });
+        }
+
+        // [row; A] = diag(1, Q) [row; R]。その後 Q の先頭行を位置 k へ移す。
+        let mut r = Matrix::zeros(m + 1, n);
+        for j in 0..n {
+            r[(0, j)] = row[j];
+        }
+        for i in 0..m {
+            for j in 0..n {
+                r[(i + 1, j)] = self.r[(i, j)];
+            }
+        }
+        let mut q = Matrix::zeros(m + 1, m + 1);
+        for i in 0..=m {
+            // 新しい行番号 i に対応する [row; A] の行
+            let src = if i < k {
+                i + 1
+            } else if i == k {
+                0
+            } else {
+                i
+            };
+            if src == 0 {
+                q[(i, 0)] = 1.0;
+            } else {
+                for j in 0..m {
+                    q[(i, j + 1)] = self.q[(src - 1, j)];
+                }
+            }
+        }
+        retriangularize(&mut q, &mut r);
+        Ok(QR { q, r })
+    }
+
+    /// k 行目を削除した行列の QR 分解
+    pub fn delete_row(&self, k: usize) -> Result<QR> {
+        self.check_full()?;
+        let (m, n) = (self.r.rows, self.r.cols);
+        if k >= m {
+            return Err(LinalgError::IndexOutOfBounds { index: k, size: m });
+        }
+        let mut q = self.q.clone();
+        let mut r = self.r.clone();
+
+        // Q の k 行目を ±e_1^T にする回転を列に掛ける（R は上 Hessenberg になる）
+        for j in (0..m - 1).rev() {
+            let (c, s) = givens(q[(k, j)], q[(k, j + 1)]);
+            rotate_cols(&mut q, j, j + 1, c, s);
+            rotate_rows(&mut r, j, j + 1, c, s);
+            q[(k, j + 1)] = 0.0;
+        }
+
+        // Q の k 行目と 1 列目、R の 1 行目を落とす
+        let mut q_new = Matrix::zeros(m - 1, m - 1);
+        for (ni, i) in (0..m).filter(|&i| i != k).enumerate() {
+            for j in 1..m {
+                q_new[(ni, j - 1)] = q[(i, j)];
+            }
+        }
+        let r_new = r.submatrix(1, m, 0, n);
+        Ok(QR { q: q_new, r: r_new })
+    }
+}
@@ -0,0 +1,81 @@
+use num_complex::Complex;
+
+use crate::matrix::numerical::HessenbergDecomposition;
+use crate::{LinalgError, Matrix, Result};
+
+mod reorder;
+
+#[cfg(test)]
+mod tests;
+
+pub trait SchurDecomposition {
+    /// 実 Schur 分解 A = Q T Q^T。(Q, T) を返す。
+    /// T は上準三角で、対角の 2x2 ブロックは複素共役固有値対に対応する（実固有値は 1x1 ブロック）。
+    fn schur(&self) -> Result<(Matrix<f64>, Matrix<f64>)>;
+
+    /// `select(λ)` が真となる固有値を T の左上へ集めた実 Schur 分解 (Q, T, sdim) を返す。
+    /// sdim は選ばれた固有値の個数で、Q の先頭 sdim 列が対応する不変部分空間の正規直交基底になる。
+    /// 複素共役対はどちらか一方が選ばれれば対ごと移動する。
+    fn ordered_schur<F>(&self, select: F) -> Result<(Matrix<f64>, Matrix<f64>, usize)>
+    where
+        F: Fn(Complex<f64>) -> bool;
+}
+
+impl SchurDecomposition for Matrix<f64> {
+    fn schur(&self) -> Result<(Matrix<f64>, Matrix<f64>)> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let (mut q, mut t) = self.hessenberg()?;
+        // 固有値計算と同じ Francis 二重シフト QR を機械精度の許容誤差で使う
+        Matrix::qr_iteration_to_schur(&mut t, &mut q, f64::EPSILON)?;
+        Ok((q, t))
+    }
+
+    fn ordered_schur<F>(&self, select: F) -> Result<(Matrix<f64>, Matrix<f64>, usize)>
+    where
+        F: Fn(Complex<f64>) -> bool,
+    {
+        let (mut q, mut t) = self.schur()?;
+        let sdim = reorder::reorder_schur(&mut t, &mut q, select)?;
+        Ok((q, t, sdim))
+    }
+}
+
+/// 実 Schur 形の対角ブロック (開始位置, サイズ) を列挙する
+pub(super) fn schur_blocks(t: &Matrix<f64>) -> Vec<(usize, usize)> {
+    let n = t.rows;
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < n {
+        if i + 1 < n && t[(i + 1, i)] != 0.0 {
+            blocks.push((i, 2));
+            i += 2;
+        } else {
+            blocks.push((i, 1));
+            i += 1;
+        }
+    }
+    blocks
+}
+
+/// 1x1 / 2x2 ブロックの固有値（2x2 は虚部が正の方を先に返す）
+pub(super) fn block_eigenvalues(t: &Matrix<f64>, start: usize, size: usize) -> Vec<Complex<f64>> {
+    if size == 1 {
+        return vec![Complex::new(t[(start, start)], 0.0)];
+    }
+    let (a, b) = (t[(start, start)], t[(start, start + 1)]);
+    let (c, d) = (t[(start + 1, start)], t[(start + 1, start + 1)]);
+    let half_tr = 0.5 * (a + d);
+    let disc = 0.25 * (a - d) * (a - d) + b * c;
+    if disc >= 0.0 {
+        let s = disc.sqrt();
+        vec![
+            Complex::new(half_tr + s, 0.0),
+            Complex::new(half_tr - s, 0.0),
+        ]
+    } else {
+        let s = (-disc).sqrt();
+        vec![Complex::new(half_tr, s), Complex::new(half_tr, -s)]
+    }
+}
@@ -0,0 +1,112 @@
+use num_complex::Complex;
+
+use super::{block_eigenvalues, schur_blocks};
+use crate::matrix::numerical::QrDecomposition;
+use crate::{LinalgError, Matrix, Result};
+
+/// `select` を満たすブロックを左上へ移動する（LAPACK trsen と同じく隣接ブロック交換の繰り返し）。
+/// 選ばれた固有値の個数を返す。
+pub(super) fn reorder_schur<F>(t: &mut Matrix<f64>, q: &mut Matrix<f64>, select: F) -> Result<usize>
+where
+    F: Fn(Complex<f64>) -> bool,
+{
+    // (サイズ, 選択フラグ) の列
+    let mut blocks: Vec<(usize, bool)> = schur_blocks(t)
+        .into_iter()
+        .map(|(start, size)| {
+            let selected = block_eigenvalues(t, start, size).into_iter().any(&select);
+            (size, selected)
+        })
+        .collect();
+
+    let mut placed = 0; // 先頭から何ブロックが選択済みで確定したか
+    for bi in 0..blocks.len() {
+        if !blocks[bi].1 {
+            continue;
+        }
+        // bi 番目のブロックを placed 番目まで 1 つずつ上へ送る
+        let mut cur = bi;
+        while cur > placed {
+            let start: usize = blocks[..cur - 1].iter().map(|b| b.0).sum();
+            swap_adjacent_blocks(t, q, start, blocks[cur - 1].0, blocks[cur].0)?;
+            blocks.swap(cur - 1, cur);
+            cur -= 1;
+        }
+        placed += 1;
+    }
+
+    Ok(blocks.iter().filter(|b| b.1).map(|b| b.0).sum())
+}
+
+/// 位置 k から始まるサイズ p と、その直後のサイズ q1 の対角ブロックを直交相似変換で入れ替える。
+/// A11 X - X A22 = A12 を解き、[-X; I] の QR 分解の Q を使う（Bai–Demmel の直接交換法）。
+fn swap_adjacent_blocks(
+    t: &mut Matrix<f64>,
+    q: &mut Matrix<f64>,
+    k: usize,
+    p: usize,
+    q1: usize,
+) -> Result<()> {
+    let n = t.rows;
+    let m = p + q1;
+
+    // Sylvester 方程式 A11 X - X A22 = A12（p*q1 元の連立一次方程式）
+    let dim = p * q1;
+    let mut kron = Matrix::zeros(dim, dim);
+    let mut rhs = crate::Vector::new(vec![0.0; dim]);
+    for i in 0..p {
+        for j in 0..q1 {
+            let row = i * q1 + j;
+            rhs[row] = t[(k + i, k + p + j)];
+            for l in 0..p {
+                kron[(row, l * q1 + j)] += t[(k + i, k + l)];
+            }
+            for l in 0..q1 {
+                kron[(row, i * q1 + l)] -= t[(k + p + l, k + p + j)];
+            }
+        }
+    }
+    let x = kron.solve(&rhs).map_err(|_| LinalgError::InvalidArgument {
+        text: "ordered_schur: eigenvalues too close to reorder".into(),
+    })?;
+
+    // [-X; I] の QR 分解。Q の先頭 q1 列が A22 に対応する不変部分空間を張る。
+    let mut basis = Matrix::zeros(m, q1);
+    for i in 0..p {
+        for j in 0..q1 {
+            basis[(i, j)] = -x[i * q1 + j];
+        }
+    }
+    for j in 0..q1 {
+        basis[(p + j, j)] = 1.0;
+    }
+    let g = basis.qr_decomposition()?.q;
+
+    // T <- G^T T G（行 k..k+m と列 k..k+m）、Q <- Q G
+    for col in 0..n {
+        let seg: Vec<f64> = (0..m).map(|i| t[(k + i, col)]).collect();
+        for i in 0..m {
+            t[(k + i, col)] = (0..m).map(|l| g[(l, i)] * seg[l]).sum();
+        }
+    }
+    for row in 0..n {
+        let seg: Vec<f64> = (0..m).map(|j| t[(row, k + j)]).collect();
+        for j in 0..m {
+            t[(row, k + j)] = (0..m).map(|l| seg[l] * g[(l, j)]).sum();
+        }
+    }
+    for row in 0..q.rows {
+        let seg: Vec<f64> = (0..m).map(|j| q[(row, k + j)]).collect();
+        for j in 0..m {
+            q[(row, k + j)] = (0..m).map(|l| seg[l] * g[(l, j)]).sum();
+        }
+    }
+
+    // 交換後のブロック下側（p x q1）は理論上 0
+    for i in q1..m {
+        for j in 0..q1 {
+            t[(k + i, k + j)] = 0.0;
+        }
+    }
+    Ok(())
+}
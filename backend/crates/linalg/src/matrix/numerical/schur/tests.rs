@@ -0,0 +1,152 @@
+use num_complex::Complex;
+
+use super::{block_eigenvalues, schur_blocks, SchurDecomposition};
+use crate::matrix::numerical::HessenbergDecomposition;
+use crate::{LinalgError, Matrix};
+
+fn assert_orthogonal(q: &Matrix<f64>, tol: f64) {
+    let qtq = &q.transpose() * q;
+    for i in 0..q.cols {
+        for j in 0..q.cols {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((qtq[(i, j)] - expected).abs() <= tol, "Q^T Q at ({i}, {j})");
+        }
+    }
+}
+
+fn assert_similar(a: &Matrix<f64>, q: &Matrix<f64>, t: &Matrix<f64>, tol: f64) {
+    let recon = &(q * t) * &q.transpose();
+    for i in 0..a.rows {
+        for j in 0..a.cols {
+            assert!(
+                (recon[(i, j)] - a[(i, j)]).abs() <= tol,
+                "Q T Q^T mismatch at ({i}, {j}): {} vs {}",
+                recon[(i, j)],
+                a[(i, j)]
+            );
+        }
+    }
+}
+
+fn assert_quasi_triangular(t: &Matrix<f64>) {
+    for i in 0..t.rows {
+        for j in 0..i.saturating_sub(1) {
+            assert_eq!(t[(i, j)], 0.0, "nonzero below subdiagonal at ({i}, {j})");
+        }
+    }
+    // 2x2 ブロックが連続しない
+    for i in 1..t.rows.saturating_sub(1) {
+        assert!(t[(i, i - 1)] == 0.0 || t[(i + 1, i)] == 0.0);
+    }
+}
+
+fn eigenvalues(t: &Matrix<f64>) -> Vec<Complex<f64>> {
+    schur_blocks(t)
+        .into_iter()
+        .flat_map(|(start, size)| block_eigenvalues(t, start, size))
+        .collect()
+}
+
+fn sample_matrix() -> Matrix<f64> {
+    // 固有値: 実数 3 個と複素共役対 1 組
+    Matrix::new(
+        5,
+        5,
+        vec![
+            4.0, 1.0, -2.0, 2.0, 0.5, 1.0, 2.0, 0.0, 1.0, -1.0, -2.0, 0.0, 3.0, -2.0, 1.0, 2.0,
+            1.0, -2.0, -1.0, 0.0, -3.0, 0.0, 1.0, 2.0, 1.0,
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn hessenberg_is_orthogonally_similar() {
+    let a = sample_matrix();
+    let (q, h) = a.hessenberg().unwrap();
+    for i in 2..h.rows {
+        for j in 0..(i - 1) {
+            assert_eq!(h[(i, j)], 0.0);
+        }
+    }
+    assert_orthogonal(&q, 1e-12);
+    assert_similar(&a, &q, &h, 1e-12);
+}
+
+#[test]
+fn schur_reconstructs_and_is_quasi_triangular() {
+    let a = sample_matrix();
+    let (q, t) = a.schur().unwrap();
+    assert_quasi_triangular(&t);
+    assert_orthogonal(&q, 1e-12);
+    assert_similar(&a, &q, &t, 1e-11);
+
+    // 固有値の和 = トレース
+    let sum: Complex<f64> = eigenvalues(&t).into_iter().sum();
+    let trace: f64 = (0..5).map(|i| a[(i, i)]).sum();
+    assert!((sum.re - trace).abs() < 1e-10 && sum.im.abs() < 1e-10);
+}
+
+#[test]
+fn schur_of_rotation_keeps_complex_block() {
+    let a = Matrix::new(2, 2, vec![0.0, -2.0, 2.0, 0.0]).unwrap();
+    let (q, t) = a.schur().unwrap();
+    assert_ne!(t[(1, 0)], 0.0);
+    let ev = eigenvalues(&t);
+    assert!((ev[0] - Complex::new(0.0, 2.0)).norm() < 1e-12);
+    assert_similar(&a, &q, &t, 1e-12);
+}
+
+#[test]
+fn schur_rejects_non_square() {
+    let a = Matrix::<f64>::zeros(2, 3);
+    assert!(matches!(a.schur(), Err(LinalgError::NotSquareMatrix)));
+}
+
+#[test]
+fn ordered_schur_moves_stable_eigenvalues_first() {
+    let a = sample_matrix();
+    let (q, t, sdim) = a.ordered_schur(|z| z.re < 0.0).unwrap();
+    assert_quasi_triangular(&t);
+    assert_orthogonal(&q, 1e-10);
+    assert_similar(&a, &q, &t, 1e-10);
+
+    let ev = eigenvalues(&t);
+    let expected_sdim = ev.iter().filter(|z| z.re < 0.0).count();
+    assert_eq!(sdim, expected_sdim);
+    assert!(sdim > 0 && sdim < 5);
+    for (i, z) in ev.iter().enumerate() {
+        assert_eq!(i < sdim, z.re < 0.0, "eigenvalue {z} at position {i}");
+    }
+
+    // 先頭 sdim 列は不変部分空間: A Q1 = Q1 T11
+    let aq = &a * &q;
+    let qt = &q * &t;
+    for i in 0..5 {
+        for j in 0..sdim {
+            assert!((aq[(i, j)] - qt[(i, j)]).abs() < 1e-10);
+        }
+    }
+}
+
+#[test]
+fn ordered_schur_moves_complex_pair_to_top() {
+    // 実固有値 1, 2 と複素対 5 ± 3i。複素対を先頭へ移動させる
+    let mut a = Matrix::zeros(4, 4);
+    a[(0, 0)] = 1.0;
+    a[(1, 1)] = 2.0;
+    a[(2, 2)] = 5.0;
+    a[(2, 3)] = 3.0;
+    a[(3, 2)] = -3.0;
+    a[(3, 3)] = 5.0;
+    a[(0, 2)] = 1.0;
+    a[(1, 3)] = -1.0;
+    a[(0, 1)] = 0.5;
+    let (q, t, sdim) = a.ordered_schur(|z| z.im != 0.0).unwrap();
+    assert_eq!(sdim, 2);
+    assert_ne!(t[(1, 0)], 0.0);
+    let ev = eigenvalues(&t);
+    assert!((ev[0] - Complex::new(5.0, 3.0)).norm() < 1e-10);
+    assert!((ev[2].re - 1.0).abs() < 1e-10 || (ev[2].re - 2.0).abs() < 1e-10);
+    assert_similar(&a, &q, &t, 1e-10);
+}
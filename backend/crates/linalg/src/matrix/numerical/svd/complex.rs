@@ -0,0 +1,163 @@
+use num_complex::Complex;
+
+use super::{Svd, SvdDeComposition};
+use crate::matrix::numerical::helpers::complete_unitary;
+use crate::matrix::numerical::HermitianEigenDecomposition;
+use crate::{LinalgError, Matrix, Vector};
+
+type C64 = Complex<f64>;
+
+const MAX_SWEEPS: usize = 60;
+
+impl SvdDeComposition for Matrix<C64> {
+    type Elem = C64;
+
+    /// 片側 Jacobi 法による複素 SVD A = U Σ V^H
+    fn svd(&self) -> crate::Result<Svd<C64>> {
+        if self.rows < self.cols {
+            let svd_h = self.conjugate_transpose().svd()?;
+            return Ok(Svd {
+                u: svd_h.v,
+                sigma: svd_h.sigma,
+                v: svd_h.u,
+            });
+        }
+        let (m, n) = (self.rows, self.cols);
+        let mut a = self.clone();
+        let mut v: Matrix<C64> = Matrix::identity(n);
+
+        // 列対 (p, q) を直交化する回転を収束まで繰り返す
+        let mut converged = n < 2;
+        for _ in 0..MAX_SWEEPS {
+            if converged {
+                break;
+            }
+            converged = true;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let mut alpha = 0.0;
+                    let mut beta = 0.0;
+                    let mut gamma = C64::new(0.0, 0.0);
+                    for i in 0..m {
+                        alpha += a[(i, p)].norm_sqr();
+                        beta += a[(i, q)].norm_sqr();
+                        gamma += a[(i, p)].conj() * a[(i, q)];
+                    }
+                    let g = gamma.norm();
+                    if g == 0.0 || g <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    converged = false;
+
+                    // q 列に位相 e^{-iφ} を掛けて a_p^H a_q を実数にしてから実回転する
+                    let phase = (gamma / g).conj();
+                    let zeta = (beta - alpha) / (2.0 * g);
+                    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+                    rotate_pair(&mut a, p, q, phase, c, s);
+                    rotate_pair(&mut v, p, q, phase, c, s);
+                }
+            }
+        }
+        if !converged {
+            return Err(LinalgError::InvalidArgument {
+                text: "Maximum iterations reached in complex svd".into(),
+            });
+        }
+
+        // 列ノルムが特異値、正規化した列が左特異ベクトル
+        let mut sigma: Vec<f64> = (0..n)
+            .map(|j| (0..m).map(|i| a[(i, j)].norm_sqr()).sum::<f64>().sqrt())
+            .collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&x, &y| {
+            sigma[y]
+                .partial_cmp(&sigma[x])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let tol = f64::EPSILON * m.max(n) as f64 * sigma.iter().cloned().fold(0.0, f64::max);
+
+        let mut u: Matrix<C64> = Matrix::zeros(m, m);
+        let mut v_sorted: Matrix<C64> = Matrix::zeros(n, n);
+        let mut filled = 0;
+        for (col, &k) in order.iter().enumerate() {
+            for i in 0..n {
+                v_sorted[(i, col)] = v[(i, k)];
+            }
+            if sigma[k] > tol {
+                for i in 0..m {
+                    u[(i, col)] = a[(i, k)] / sigma[k];
+                }
+                filled += 1;
+            }
+        }
+        complete_unitary(&mut u, filled);
+        sigma = order.iter().map(|&k| sigma[k]).collect();
+
+        Ok(Svd {
+            u,
+            sigma: Vector::new(sigma),
+            v: v_sorted,
+        })
+    }
+
+    /// A^H A の Hermite 固有分解から特異値と V を求める簡易版（小さい特異値の精度は劣る）
+    fn simple_svd(&self) -> crate::Result<Svd<C64>> {
+        if self.rows < self.cols {
+            let svd_h = self.conjugate_transpose().simple_svd()?;
+            return Ok(Svd {
+                u: svd_h.v,
+                sigma: svd_h.sigma,
+                v: svd_h.u,
+            });
+        }
+        let (m, n) = (self.rows, self.cols);
+        let aha = &self.conjugate_transpose() * self;
+        let eig = aha.hermitian_eigen()?;
+
+        // 固有値は昇順なので逆順に取り出す
+        let mut v: Matrix<C64> = Matrix::zeros(n, n);
+        let mut sigma = Vec::with_capacity(n);
+        for (col, k) in (0..n).rev().enumerate() {
+            for i in 0..n {
+                v[(i, col)] = eig.eigen_vectors[(i, k)];
+            }
+            sigma.push(eig.eigen_values[k].max(0.0).sqrt());
+        }
+
+        let tol = 1e-14 * sigma.first().cloned().unwrap_or(0.0).max(1.0);
+        let mut u: Matrix<C64> = Matrix::zeros(m, m);
+        let mut filled = 0;
+        for (j, &s) in sigma.iter().enumerate() {
+            if s <= tol {
+                break;
+            }
+            for i in 0..m {
+                let mut acc = C64::new(0.0, 0.0);
+                for k in 0..n {
+                    acc += self[(i, k)] * v[(k, j)];
+                }
+                u[(i, j)] = acc / s;
+            }
+            filled += 1;
+        }
+        complete_unitary(&mut u, filled);
+
+        Ok(Svd {
+            u,
+            sigma: Vector::new(sigma),
+            v,
+        })
+    }
+}
+
+/// [x_p, x_q] <- [x_p, e^{-iφ} x_q] [[c, s], [-s, c]]
+fn rotate_pair(m: &mut Matrix<C64>, p: usize, q: usize, phase: C64, c: f64, s: f64) {
+    for i in 0..m.rows {
+        let xp = m[(i, p)];
+        let xq = m[(i, q)] * phase;
+        m[(i, p)] = xp * c - xq * s;
+        m[(i, q)] = xp * s + xq * c;
+    }
+}
@@ -1,19 +1,25 @@
+use crate::matrix::numerical::helpers::householder_vector;
 use crate::matrix::numerical::{EigenDecomposition, QrDecomposition};
 use crate::matrix::Matrix;
-use crate::{Direction, Vector};
+use crate::{Direction, Ring, Vector};
+
+mod complex;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod tests_complex;
+
 #[derive(Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Svd {
-    pub u: Matrix<f64>,
+pub struct Svd<T = f64> {
+    pub u: Matrix<T>,
     pub sigma: Vector<f64>,
-    pub v: Matrix<f64>,
+    pub v: Matrix<T>,
 }
 
-impl Svd {
+impl<T: Ring> Svd<T> {
     pub fn sort(&mut self) -> crate::Result<()> {
         let mut pairs: Vec<_> = self.sigma.data.iter().cloned().enumerate().collect();
         pairs.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
@@ -46,11 +52,17 @@ impl Svd {
 }
 
 pub trait SvdDeComposition {
-    fn svd(&self) -> crate::Result<Svd>;
-    fn simple_svd(&self) -> crate::Result<Svd>;
+    /// 特異ベクトルの要素型（f64 または Complex<f64>）
+    type Elem;
+
+    /// A = U Σ V^H（実数では V^T）。特異値は降順。
+    fn svd(&self) -> crate::Result<Svd<Self::Elem>>;
+    fn simple_svd(&self) -> crate::Result<Svd<Self::Elem>>;
 }
 
 impl SvdDeComposition for Matrix<f64> {
+    type Elem = f64;
+
     fn svd(&self) -> crate::Result<Svd> {
         if self.rows < self.cols {
             let svd_t = self.transpose().svd()?;
@@ -184,8 +196,8 @@ impl Matrix<f64> {
 
         for k in 0..self.cols {
             // --- 1. 左からのHouseholder変換 (列をゼロにする) ---
-            let x = b.partial_col(k, k, self.rows)?;
-            if let Some(h_vec) = x.householder_vector() {
+            let x = b.view().slice(k..self.rows, k..k + 1)?;
+            if let Some(h_vec) = householder_vector(x) {
                 // b = H * b
                 b.apply_householder_transform(&h_vec, Direction::Left, k, k);
                 // u = u * H (Hは対称なので H^T = H)
@@ -194,8 +206,8 @@ impl Matrix<f64> {
 
             // --- 2. 右からのHouseholder変換 (行をゼロにする) ---
             if k < self.cols - 2 {
-                let y = b.partial_row(k, k + 1, self.cols)?;
-                if let Some(h_vec) = y.householder_vector() {
+                let y = b.view().slice(k..k + 1, k + 1..self.cols)?;
+                if let Some(h_vec) = householder_vector(y) {
                     // b = b * G
                     b.apply_householder_transform(&h_vec, Direction::Right, k, k + 1);
                     // v = v * G
@@ -277,6 +289,11 @@ impl Matrix<f64> {
                 continue;
             }
 
+            // 対角に 0 があると QR 反復が進まないため、回転で隣の超対角を消して分割する
+            if Self::split_at_zero_diagonal(b, u, v, l as usize, k as usize, tol) {
+                continue;
+            }
+
             // Wilkinson シフト計算
             let mu = Self::compute_wilkinson_shift(&d, &e, k as usize);
 
@@ -375,6 +392,49 @@ impl Matrix<f64> {
         }
     }
 
+    /// 末尾の非分割ブロックに |d_i| がほぼ 0 の対角があれば、Givens 回転で隣の超対角を消して分割する。
+    /// 末尾 d_k = 0 は右回転で e_{k-1} を上へ追い出し、途中の d_i = 0 は左回転で e_i を右へ追い出す。
+    fn split_at_zero_diagonal(
+        b: &mut Matrix<f64>,
+        u: &mut Matrix<f64>,
+        v: &mut Matrix<f64>,
+        l: usize,
+        k: usize,
+        tol: f64,
+    ) -> bool {
+        // 末尾の非分割ブロック p..=k（超対角がすべて非ゼロ）
+        let mut p = k;
+        while p > l && b[(p - 1, p)] != 0.0 {
+            p -= 1;
+        }
+        if p == k {
+            return false;
+        }
+        let scale = (p..=k)
+            .map(|i| b[(i, i)].abs() + if i < k { b[(i, i + 1)].abs() } else { 0.0 })
+            .fold(0.0, f64::max);
+        let Some(i) = (p..=k).find(|&i| b[(i, i)].abs() <= tol * scale) else {
+            return false;
+        };
+        b[(i, i)] = 0.0;
+        if i == k {
+            for j in (p..k).rev() {
+                let (c, s) = Self::calculate_givens_params(b[(j, j)], b[(j, k)]);
+                b.apply_right_givens_rotation(j, k, c, s);
+                v.apply_right_givens_rotation(j, k, c, s);
+                b[(j, k)] = 0.0;
+            }
+        } else {
+            for j in (i + 1)..=k {
+                let (c, s) = Self::calculate_left_givens_params(b[(j, j)], b[(i, j)]);
+                b.apply_left_givens_to_rows(j, i, c, s);
+                u.apply_left_givens_rotation(j, i, c, s);
+                b[(i, j)] = 0.0;
+            }
+        }
+        true
+    }
+
     fn zero_small_superdiag(b: &mut Matrix<f64>, d: &mut [f64], e: &mut [f64], tol: f64) {
         let n = d.len();
         let mut to_zero: Vec<usize> = Vec::new();
@@ -441,26 +501,32 @@ impl Matrix<f64> {
             if col.norm() > 1e-12 {
                 continue; // すでに設定済み
             }
-            // 標準基底から開始し、既存の全列に対して直交化
-            let mut cand = Vector::<f64>::zeros(m);
-            cand[target_col.min(m - 1)] = 1.0;
-            for j in 0..m {
-                let cj = u_new.col(j)?;
-                let nj = cj.norm();
-                if nj <= 1e-12 {
-                    continue;
-                }
-                let proj = cj.dot(&cand);
-                for r in 0..m {
-                    cand[r] -= cj[r] * proj;
+            // 標準基底から開始し、既存の全列に対して直交化（張る空間に含まれていたら次の基底を試す）
+            for offset in 0..m {
+                let mut cand = Vector::<f64>::zeros(m);
+                cand[(target_col + offset) % m] = 1.0;
+                // 2 回直交化して丸め誤差を抑える
+                for _ in 0..2 {
+                    for j in 0..m {
+                        let cj = u_new.col(j)?;
+                        let nj = cj.norm();
+                        if nj <= 1e-12 {
+                            continue;
+                        }
+                        let proj = cj.dot(&cand);
+                        for r in 0..m {
+                            cand[r] -= cj[r] * proj;
+                        }
+                    }
                 }
-            }
-            let norm = cand.norm();
-            if norm > 1e-12 {
-                for r in 0..m {
-                    cand[r] /= norm;
+                let norm = cand.norm();
+                if norm > 1e-6 {
+                    for r in 0..m {
+                        cand[r] /= norm;
+                    }
+                    u_new.set_col(target_col, &cand)?;
+                    break;
                 }
-                u_new.set_col(target_col, &cand)?;
             }
         }
         *u = u_new;
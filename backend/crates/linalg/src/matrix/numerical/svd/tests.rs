@@ -491,3 +491,23 @@ fn test_svd_matches_simple_svd_singular_values() {
         assert_matrix_approx_eq(&a, &recon, 1e-8, &format!("recon failed at case {idx}"));
     }
 }
+
+#[test]
+fn test_svd_rank_deficient_matrices_converge() {
+    // 二重対角化で対角に 0 が現れる例（以前は反復が収束しなかった）
+    let a = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+    let svd = a.svd().expect("svd of singular 2x2");
+    validate_svd(&a, &svd);
+    assert!(approx(svd.sigma[0], 5.0, 1e-10));
+    assert!(approx(svd.sigma[1], 0.0, 1e-10));
+
+    let b = Matrix::new(
+        4,
+        3,
+        vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, -1.0, 0.0, 1.0, 0.0, 2.0, 4.0],
+    )
+    .unwrap();
+    let svd = b.svd().expect("svd of rank-2 4x3");
+    validate_svd(&b, &svd);
+    assert!(approx(svd.sigma[2], 0.0, 1e-10));
+}
@@ -0,0 +1,112 @@
+use num_complex::Complex;
+
+use super::*;
+use crate::matrix::numerical::Pseudoinverse;
+
+type C64 = Complex<f64>;
+
+fn c(re: f64, im: f64) -> C64 {
+    C64::new(re, im)
+}
+
+fn sample(rows: usize, cols: usize) -> Matrix<C64> {
+    let data = (0..rows * cols)
+        .map(|k| {
+            let x = k as f64;
+            c(
+                (0.7 * x).sin() + 0.3 * x.cos(),
+                (1.3 * x).cos() - 0.2 * x.sin(),
+            )
+        })
+        .collect();
+    Matrix::new(rows, cols, data).unwrap()
+}
+
+fn assert_close(a: &Matrix<C64>, b: &Matrix<C64>, tol: f64) {
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+    for i in 0..a.rows {
+        for j in 0..a.cols {
+            assert!(
+                (a[(i, j)] - b[(i, j)]).norm() <= tol,
+                "mismatch at ({i}, {j}): {} vs {}",
+                a[(i, j)],
+                b[(i, j)]
+            );
+        }
+    }
+}
+
+fn reconstruct(svd: &Svd<C64>, rows: usize, cols: usize) -> Matrix<C64> {
+    let mut s: Matrix<C64> = Matrix::zeros(rows, cols);
+    for (k, &v) in svd.sigma.iter().enumerate() {
+        s[(k, k)] = c(v, 0.0);
+    }
+    &(&svd.u * &s) * &svd.v.conjugate_transpose()
+}
+
+#[test]
+fn complex_svd_tall_and_wide() {
+    for &(m, n) in &[(5, 3), (3, 5), (4, 4)] {
+        let a = sample(m, n);
+        let svd = a.svd().expect("complex svd failed");
+        assert_eq!((svd.u.rows, svd.u.cols), (m, m));
+        assert_eq!((svd.v.rows, svd.v.cols), (n, n));
+        assert_close(
+            &(&svd.u.conjugate_transpose() * &svd.u),
+            &Matrix::identity(m),
+            1e-10,
+        );
+        assert_close(
+            &(&svd.v.conjugate_transpose() * &svd.v),
+            &Matrix::identity(n),
+            1e-10,
+        );
+        for w in svd.sigma.data.windows(2) {
+            assert!(w[0] >= w[1]);
+        }
+        assert_close(&reconstruct(&svd, m, n), &a, 1e-10);
+    }
+}
+
+#[test]
+fn complex_svd_rank_deficient_and_simple_svd() {
+    // 外積 x y^H はランク 1
+    let x = [c(1.0, 1.0), c(0.0, 2.0), c(-1.0, 0.5)];
+    let y = [c(2.0, 0.0), c(1.0, -1.0)];
+    let mut a: Matrix<C64> = Matrix::zeros(3, 2);
+    for i in 0..3 {
+        for j in 0..2 {
+            a[(i, j)] = x[i] * y[j].conj();
+        }
+    }
+    let svd = a.svd().unwrap();
+    let nx: f64 = x.iter().map(|z| z.norm_sqr()).sum::<f64>().sqrt();
+    let ny: f64 = y.iter().map(|z| z.norm_sqr()).sum::<f64>().sqrt();
+    assert!((svd.sigma[0] - nx * ny).abs() < 1e-12);
+    assert!(svd.sigma[1].abs() < 1e-12);
+    assert_close(
+        &(&svd.u.conjugate_transpose() * &svd.u),
+        &Matrix::identity(3),
+        1e-10,
+    );
+    assert_close(&reconstruct(&svd, 3, 2), &a, 1e-12);
+
+    let b = sample(4, 3);
+    let exact = b.svd().unwrap();
+    let simple = b.simple_svd().unwrap();
+    for k in 0..3 {
+        assert!((exact.sigma[k] - simple.sigma[k]).abs() < 1e-8);
+    }
+    assert_close(&reconstruct(&simple, 4, 3), &b, 1e-8);
+}
+
+#[test]
+fn complex_pinv_satisfies_penrose_conditions() {
+    let a = sample(4, 2);
+    let p = a.pinv().expect("pinv failed");
+    assert_eq!((p.rows, p.cols), (2, 4));
+    assert_close(&(&(&a * &p) * &a), &a, 1e-10);
+    assert_close(&(&(&p * &a) * &p), &p, 1e-10);
+    let ap = &a * &p;
+    assert_close(&ap.conjugate_transpose(), &ap, 1e-10);
+}
@@ -149,6 +149,16 @@ impl_default_display_element!(
     i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, String, &str, bool, char
 );
 
+/// 有理数は既約分数 `p/q`（整数なら `p`）で表示する
+impl<T> DisplayElement for num_rational::Ratio<T>
+where
+    num_rational::Ratio<T>: fmt::Display,
+{
+    fn to_formatted_string(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl<T: crate::Scalar + DisplayElement> fmt::Display for super::Matrix<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let formatted_strings: Vec<String> = self
@@ -0,0 +1,353 @@
+//! 行列のコピーなしビュー（借用 + ストライド）。
+//! 要素 (i, j) は `data[i * row_stride + j * col_stride]` にあり、部分行列・転置・対角は
+//! ストライドとオフセットの付け替えだけで表せる。
+
+use std::ops::{Index, IndexMut, Range};
+
+use super::Matrix;
+use crate::{LinalgError, Result, Scalar, Vector};
+
+#[cfg(test)]
+mod tests;
+
+/// 読み取り専用の行列ビュー
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// 書き込み可能な行列ビュー
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    data: &'a mut [T],
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl<T> Matrix<T> {
+    /// 行列全体の読み取りビュー
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: &self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.cols,
+            col_stride: 1,
+        }
+    }
+
+    /// 行列全体の書き込みビュー
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            row_stride: self.cols,
+            col_stride: 1,
+            rows: self.rows,
+            cols: self.cols,
+            data: &mut self.data,
+        }
+    }
+}
+
+/// 範囲 rows × cols が rows_total × cols_total に収まるか検査する
+fn check_ranges(
+    rows: &Range<usize>,
+    cols: &Range<usize>,
+    rows_total: usize,
+    cols_total: usize,
+) -> Result<()> {
+    if rows.start > rows.end || rows.end > rows_total {
+        return Err(LinalgError::IndexOutOfBounds {
+            index: rows.end,
+            size: rows_total,
+        });
+    }
+    if cols.start > cols.end || cols.end > cols_total {
+        return Err(LinalgError::IndexOutOfBounds {
+            index: cols.end,
+            size: cols_total,
+        });
+    }
+    Ok(())
+}
+
+/// 部分ビューの先頭要素のオフセット（空のビューでは 0）
+fn offset(rows: &Range<usize>, cols: &Range<usize>, row_stride: usize, col_stride: usize) -> usize {
+    if rows.is_empty() || cols.is_empty() {
+        0
+    } else {
+        rows.start * row_stride + cols.start * col_stride
+    }
+}
+
+macro_rules! impl_view_getters {
+    ($View:ident) => {
+        impl<'a, T> $View<'a, T> {
+            pub fn rows(&self) -> usize {
+                self.rows
+            }
+
+            pub fn cols(&self) -> usize {
+                self.cols
+            }
+
+            /// (行数, 列数)
+            pub fn shape(&self) -> (usize, usize) {
+                (self.rows, self.cols)
+            }
+
+            /// (行ストライド, 列ストライド)
+            pub fn strides(&self) -> (usize, usize) {
+                (self.row_stride, self.col_stride)
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.rows == 0 || self.cols == 0
+            }
+
+            pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+                if i < self.rows && j < self.cols {
+                    self.data.get(i * self.row_stride + j * self.col_stride)
+                } else {
+                    None
+                }
+            }
+
+            /// 行優先順に要素を辿るイテレータ
+            pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+                (0..self.rows).flat_map(move |i| (0..self.cols).map(move |j| &self[(i, j)]))
+            }
+
+            /// ビューの内容を新しい行列にコピーする
+            pub fn to_matrix(&self) -> Matrix<T>
+            where
+                T: Scalar,
+            {
+                Matrix {
+                    rows: self.rows,
+                    cols: self.cols,
+                    data: self.iter().cloned().collect(),
+                }
+            }
+
+            /// 行優先順に並べたベクトルにコピーする（1 行 / 1 列のビュー向け）
+            pub fn to_vector(&self) -> Vector<T>
+            where
+                T: Scalar,
+            {
+                Vector::new(self.iter().cloned().collect())
+            }
+        }
+
+        impl<T> Index<(usize, usize)> for $View<'_, T> {
+            type Output = T;
+            fn index(&self, (i, j): (usize, usize)) -> &T {
+                assert!(
+                    i < self.rows && j < self.cols,
+                    "view index ({i}, {j}) out of bounds for {}x{}",
+                    self.rows,
+                    self.cols
+                );
+                &self.data[i * self.row_stride + j * self.col_stride]
+            }
+        }
+    };
+}
+
+impl_view_getters!(MatrixView);
+impl_view_getters!(MatrixViewMut);
+
+impl<'a, T> MatrixView<'a, T> {
+    /// 行範囲 × 列範囲の部分ビュー
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixView<'a, T>> {
+        check_ranges(&rows, &cols, self.rows, self.cols)?;
+        let start = offset(&rows, &cols, self.row_stride, self.col_stride);
+        Ok(MatrixView {
+            data: &self.data[start..],
+            rows: rows.len(),
+            cols: cols.len(),
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        })
+    }
+
+    /// i 行目（1 × cols）
+    pub fn row(&self, i: usize) -> Result<MatrixView<'a, T>> {
+        self.slice(i..i + 1, 0..self.cols)
+    }
+
+    /// j 列目（rows × 1）
+    pub fn col(&self, j: usize) -> Result<MatrixView<'a, T>> {
+        self.slice(0..self.rows, j..j + 1)
+    }
+
+    /// 転置ビュー（ストライドを入れ替えるだけ）
+    pub fn t(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            data: self.data,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    /// 対角成分のビュー（min(rows, cols) × 1）
+    pub fn diag(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            data: self.data,
+            rows: self.rows.min(self.cols),
+            cols: 1,
+            row_stride: self.row_stride + self.col_stride,
+            col_stride: 1,
+        }
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// 読み取りビューとして借用する
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    /// 短い寿命で借り直す（部分ビューを作った後も元のビューを使い続けるため）
+    pub fn reborrow(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    /// 行範囲 × 列範囲の部分ビュー
+    pub fn slice_mut(self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixViewMut<'a, T>> {
+        check_ranges(&rows, &cols, self.rows, self.cols)?;
+        let start = offset(&rows, &cols, self.row_stride, self.col_stride);
+        Ok(MatrixViewMut {
+            data: &mut self.data[start..],
+            rows: rows.len(),
+            cols: cols.len(),
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        })
+    }
+
+    /// i 行目（1 × cols）
+    pub fn row_mut(self, i: usize) -> Result<MatrixViewMut<'a, T>> {
+        let cols = self.cols;
+        self.slice_mut(i..i + 1, 0..cols)
+    }
+
+    /// j 列目（rows × 1）
+    pub fn col_mut(self, j: usize) -> Result<MatrixViewMut<'a, T>> {
+        let rows = self.rows;
+        self.slice_mut(0..rows, j..j + 1)
+    }
+
+    /// 転置ビュー
+    pub fn t_mut(self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            data: self.data,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    /// 対角成分のビュー（min(rows, cols) × 1）
+    pub fn diag_mut(self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            rows: self.rows.min(self.cols),
+            cols: 1,
+            row_stride: self.row_stride + self.col_stride,
+            col_stride: 1,
+            data: self.data,
+        }
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i < self.rows && j < self.cols {
+            self.data.get_mut(i * self.row_stride + j * self.col_stride)
+        } else {
+            None
+        }
+    }
+
+    /// 全要素を value にする
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self[(i, j)] = value.clone();
+            }
+        }
+    }
+
+    /// 同じ形のビューから要素をコピーする
+    pub fn copy_from(&mut self, src: &MatrixView<'_, T>) -> Result<()>
+    where
+        T: Clone,
+    {
+        if src.shape() != self.shape() {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, self.cols),
+                found: format!("{}x{}", src.rows, src.cols),
+            });
+        }
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self[(i, j)] = src[(i, j)].clone();
+            }
+        }
+        Ok(())
+    }
+
+    /// 要素 (i1, j1) と (i2, j2) を入れ替える
+    pub fn swap(&mut self, (i1, j1): (usize, usize), (i2, j2): (usize, usize)) {
+        assert!(i1 < self.rows && j1 < self.cols && i2 < self.rows && j2 < self.cols);
+        self.data.swap(
+            i1 * self.row_stride + j1 * self.col_stride,
+            i2 * self.row_stride + j2 * self.col_stride,
+        );
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(
+            i < self.rows && j < self.cols,
+            "view index ({i}, {j}) out of bounds for {}x{}",
+            self.rows,
+            self.cols
+        );
+        &mut self.data[i * self.row_stride + j * self.col_stride]
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for MatrixView<'a, T> {
+    fn from(m: &'a Matrix<T>) -> Self {
+        m.view()
+    }
+}
+
+impl<'a, T> From<&'a mut Matrix<T>> for MatrixViewMut<'a, T> {
+    fn from(m: &'a mut Matrix<T>) -> Self {
+        m.view_mut()
+    }
+}
@@ -0,0 +1,115 @@
+use crate::matrix::Matrix;
+use crate::LinalgError;
+
+fn sample() -> Matrix<f64> {
+    // [[0, 1, 2, 3],
+    //  [4, 5, 6, 7],
+    //  [8, 9, 10, 11]]
+    Matrix::new(3, 4, (0..12).map(|v| v as f64).collect()).unwrap()
+}
+
+#[test]
+fn slice_reads_submatrix_without_copy() {
+    let m = sample();
+    let v = m.view().slice(1..3, 1..3).unwrap();
+    assert_eq!(v.shape(), (2, 2));
+    assert_eq!(v[(0, 0)], 5.0);
+    assert_eq!(v[(1, 1)], 10.0);
+    assert_eq!(v.to_matrix(), m.submatrix(1, 3, 1, 3));
+
+    // 部分ビューの部分ビュー
+    let w = v.slice(1..2, 0..2).unwrap();
+    assert_eq!(w.to_matrix().data, vec![9.0, 10.0]);
+}
+
+#[test]
+fn transpose_row_col_and_diag_views() {
+    let m = sample();
+    let t = m.view().t();
+    assert_eq!(t.shape(), (4, 3));
+    assert_eq!(t.to_matrix(), m.transpose());
+
+    assert_eq!(
+        m.view().row(2).unwrap().to_matrix().data,
+        vec![8.0, 9.0, 10.0, 11.0]
+    );
+    assert_eq!(
+        m.view().col(1).unwrap().to_vector().data,
+        vec![1.0, 5.0, 9.0]
+    );
+    assert_eq!(m.view().diag().to_vector().data, vec![0.0, 5.0, 10.0]);
+    // 転置した部分ビューの対角
+    let d = m.view().slice(0..3, 1..4).unwrap().t().diag();
+    assert_eq!(d.iter().copied().collect::<Vec<_>>(), vec![1.0, 6.0, 11.0]);
+}
+
+#[test]
+fn mutable_views_write_through_to_matrix() {
+    let mut m = sample();
+    {
+        let mut s = m.view_mut().slice_mut(0..2, 2..4).unwrap();
+        s.fill(-1.0);
+        s[(1, 0)] = 42.0;
+    }
+    assert_eq!(m[(0, 2)], -1.0);
+    assert_eq!(m[(1, 2)], 42.0);
+    assert_eq!(m[(1, 3)], -1.0);
+    assert_eq!(m[(2, 2)], 10.0);
+
+    m.view_mut().diag_mut().fill(7.0);
+    assert_eq!((m[(0, 0)], m[(1, 1)], m[(2, 2)]), (7.0, 7.0, 7.0));
+
+    let mut t = m.view_mut().t_mut();
+    t[(3, 0)] = 100.0;
+    *t.get_mut(0, 2).unwrap() = 200.0;
+    assert!(t.get_mut(4, 0).is_none());
+    assert_eq!(m[(0, 3)], 100.0);
+    assert_eq!(m[(2, 0)], 200.0);
+}
+
+#[test]
+fn copy_from_and_swap_use_view_coordinates() {
+    let src = sample();
+    let mut dst: Matrix<f64> = Matrix::zeros(4, 3);
+    dst.view_mut().copy_from(&src.view().t()).unwrap();
+    assert_eq!(dst, src.transpose());
+
+    let mut view = dst.view_mut();
+    view.swap((0, 0), (3, 2));
+    assert_eq!(view[(0, 0)], 11.0);
+    assert_eq!(view[(3, 2)], 0.0);
+
+    let err = view.copy_from(&src.view()).unwrap_err();
+    assert!(matches!(err, LinalgError::DimensionMismatch { .. }));
+}
+
+#[test]
+fn reborrow_allows_sequential_subviews() {
+    let mut m: Matrix<f64> = Matrix::zeros(3, 3);
+    let mut v = m.view_mut();
+    for k in 0..3 {
+        let mut row = v.reborrow().row_mut(k).unwrap();
+        row.fill(k as f64);
+    }
+    v.reborrow().col_mut(0).unwrap().fill(-1.0);
+    assert_eq!(m.data, vec![-1.0, 0.0, 0.0, -1.0, 1.0, 1.0, -1.0, 2.0, 2.0]);
+}
+
+#[test]
+fn out_of_range_slices_are_errors() {
+    let m = sample();
+    assert!(matches!(
+        m.view().slice(0..4, 0..1),
+        Err(LinalgError::IndexOutOfBounds { index: 4, size: 3 })
+    ));
+    assert!(matches!(
+        m.view().slice(0..1, 2..5),
+        Err(LinalgError::IndexOutOfBounds { index: 5, size: 4 })
+    ));
+    assert!(m.view().row(3).is_err());
+    assert!(m.view().get(0, 4).is_none());
+
+    let empty = m.view().slice(3..3, 0..4).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_matrix().rows, 0);
+}
@@ -0,0 +1,106 @@
+use super::{contiguous_strides, NdArray, Offsets};
+use crate::{Field, LinalgError, Result, Ring, Scalar};
+
+/// NumPy の規則による 2 つの形状のブロードキャスト結果。
+/// 末尾の軸からそろえ、各軸の長さが等しいか一方が 1 なら結合できる（足りない先頭の軸は長さ 1 とみなす）。
+pub fn broadcast_shape(a: &[usize], b: &[usize]) -> Result<Vec<usize>> {
+    let n = a.len().max(b.len());
+    let mut shape = vec![0; n];
+    for k in 0..n {
+        let da = if k < a.len() { a[a.len() - 1 - k] } else { 1 };
+        let db = if k < b.len() { b[b.len() - 1 - k] } else { 1 };
+        shape[n - 1 - k] = match (da, db) {
+            _ if da == db => da,
+            (1, _) => db,
+            (_, 1) => da,
+            _ => {
+                return Err(LinalgError::DimensionMismatch {
+                    expected: format!("shape broadcastable with {a:?}"),
+                    found: format!("{b:?}"),
+                })
+            }
+        };
+    }
+    Ok(shape)
+}
+
+impl<T: Scalar> NdArray<T> {
+    /// `target` へ広げたときのストライド（長さ 1 の軸と補った先頭の軸はストライド 0）
+    fn broadcast_strides(&self, target: &[usize]) -> Vec<usize> {
+        let pad = target.len() - self.ndim();
+        let mut strides = vec![0; target.len()];
+        for k in 0..self.ndim() {
+            if self.shape[k] != 1 {
+                strides[pad + k] = self.strides[k];
+            }
+        }
+        strides
+    }
+
+    /// 形状 `shape` へブロードキャストした配列（要素は複製される）
+    pub fn broadcast_to(&self, shape: &[usize]) -> Result<Self> {
+        if broadcast_shape(&self.shape, shape)? != shape {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("shape broadcastable to {shape:?}"),
+                found: format!("{:?}", self.shape),
+            });
+        }
+        let strides = self.broadcast_strides(shape);
+        let data = Offsets::new(shape, &strides, 0)
+            .map(|k| self.data[k].clone())
+            .collect();
+        Ok(NdArray {
+            data,
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+        })
+    }
+
+    /// 2 つの配列をブロードキャストし、要素ごとに `f` を適用する
+    pub fn zip_with<U, V, F>(&self, rhs: &NdArray<U>, mut f: F) -> Result<NdArray<V>>
+    where
+        U: Scalar,
+        V: Scalar,
+        F: FnMut(&T, &U) -> V,
+    {
+        let shape = broadcast_shape(&self.shape, &rhs.shape)?;
+        let (ls, rs) = (
+            self.broadcast_strides(&shape),
+            rhs.broadcast_strides(&shape),
+        );
+        let data = Offsets::new(&shape, &ls, 0)
+            .zip(Offsets::new(&shape, &rs, 0))
+            .map(|(i, j)| f(&self.data[i], &rhs.data[j]))
+            .collect();
+        Ok(NdArray {
+            data,
+            strides: contiguous_strides(&shape),
+            shape,
+        })
+    }
+}
+
+impl<T: Ring> NdArray<T> {
+    pub fn checked_neg(&self) -> Self {
+        self.map(|x| -x.clone())
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self> {
+        self.zip_with(rhs, |a, b| a.clone() + b.clone())
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self> {
+        self.zip_with(rhs, |a, b| a.clone() - b.clone())
+    }
+
+    /// 要素ごとの積（Hadamard 積）
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self> {
+        self.zip_with(rhs, |a, b| a.clone() * b.clone())
+    }
+}
+
+impl<T: Field> NdArray<T> {
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self> {
+        self.zip_with(rhs, |a, b| a.clone() / b.clone())
+    }
+}
@@ -0,0 +1,257 @@
+use super::{contiguous_strides, NdArray, Offsets};
+use crate::{LinalgError, Matrix, Result, Ring, Scalar, Vector};
+
+impl<T: Scalar> NdArray<T> {
+    /// 行優先に並んだ `data` から配列を作る
+    pub fn from_shape_vec(shape: &[usize], data: Vec<T>) -> Result<Self> {
+        let len: usize = shape.iter().product();
+        if len != data.len() {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{shape:?} ({len} elements)"),
+                found: format!("{} elements", data.len()),
+            });
+        }
+        Ok(NdArray {
+            data,
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+        })
+    }
+
+    pub fn from_elem(shape: &[usize], value: T) -> Self {
+        let len = shape.iter().product();
+        NdArray {
+            data: vec![value; len],
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+        }
+    }
+
+    /// 各要素を多重添字の関数 `f(index)` で埋める
+    pub fn from_fn<F: FnMut(&[usize]) -> T>(shape: &[usize], mut f: F) -> Self {
+        let len = shape.iter().product();
+        let mut data = Vec::with_capacity(len);
+        let mut index = vec![0; shape.len()];
+        for _ in 0..len {
+            data.push(f(&index));
+            for k in (0..shape.len()).rev() {
+                index[k] += 1;
+                if index[k] < shape[k] {
+                    break;
+                }
+                index[k] = 0;
+            }
+        }
+        NdArray {
+            data,
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+        }
+    }
+
+    /// 0 次元配列（要素 1 つ）
+    pub fn scalar(value: T) -> Self {
+        NdArray {
+            data: vec![value],
+            shape: vec![],
+            strides: vec![],
+        }
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `data` が行優先で隙間なく並んでいるか
+    pub fn is_contiguous(&self) -> bool {
+        self.strides == contiguous_strides(&self.shape)
+    }
+
+    /// 連続なら要素を行優先のスライスとして返す
+    pub fn as_slice(&self) -> Option<&[T]> {
+        self.is_contiguous().then_some(self.data.as_slice())
+    }
+
+    fn offset_of(&self, index: &[usize]) -> Option<usize> {
+        if index.len() != self.ndim() || index.iter().zip(&self.shape).any(|(i, n)| i >= n) {
+            return None;
+        }
+        Some(index.iter().zip(&self.strides).map(|(i, s)| i * s).sum())
+    }
+
+    pub fn get(&self, index: &[usize]) -> Option<&T> {
+        self.offset_of(index).map(|k| &self.data[k])
+    }
+
+    pub fn get_mut(&mut self, index: &[usize]) -> Option<&mut T> {
+        self.offset_of(index).map(|k| &mut self.data[k])
+    }
+
+    /// 要素を行優先（最後の軸が最も速く変わる順）で走査する
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &T> + '_ {
+        Offsets::new(&self.shape, &self.strides, 0).map(|k| &self.data[k])
+    }
+
+    /// 要素を行優先に並べた Vec
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        if self.is_contiguous() {
+            self.data
+        } else {
+            self.to_vec()
+        }
+    }
+
+    /// 行優先で連続な配列に詰め直す
+    pub fn to_contiguous(&self) -> Self {
+        NdArray {
+            data: self.to_vec(),
+            shape: self.shape.clone(),
+            strides: contiguous_strides(&self.shape),
+        }
+    }
+
+    pub fn map<U: Scalar, F: FnMut(&T) -> U>(&self, f: F) -> NdArray<U> {
+        NdArray {
+            data: self.iter().map(f).collect(),
+            shape: self.shape.clone(),
+            strides: contiguous_strides(&self.shape),
+        }
+    }
+
+    /// 要素数を保ったまま形状を変える（要素は行優先の順で読み替える）
+    pub fn reshape(&self, shape: &[usize]) -> Result<Self> {
+        let len: usize = shape.iter().product();
+        if len != self.len() {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{} elements", self.len()),
+                found: format!("{shape:?} ({len} elements)"),
+            });
+        }
+        Ok(NdArray {
+            data: self.to_vec(),
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+        })
+    }
+
+    /// 軸を並べ替える。結果の k 番目の軸は元の `axes[k]` 番目の軸。
+    /// self を消費して要素のバッファをそのまま引き継ぎ、ストライドだけを入れ替える
+    /// （元の配列も残したい場合は `clone()` してから呼ぶ）。
+    pub fn permute(self, axes: &[usize]) -> Result<Self> {
+        let n = self.ndim();
+        let mut seen = vec![false; n];
+        for &a in axes.iter().filter(|&&a| a < n) {
+            seen[a] = true;
+        }
+        if axes.len() != n || seen.contains(&false) {
+            return Err(LinalgError::InvalidArgument {
+                text: format!("{axes:?} is not a permutation of the {n} axes"),
+            });
+        }
+        Ok(NdArray {
+            shape: axes.iter().map(|&a| self.shape[a]).collect(),
+            strides: axes.iter().map(|&a| self.strides[a]).collect(),
+            data: self.data,
+        })
+    }
+
+    /// 軸の順序を逆にする（2 次元では転置）。`permute` と同じく self を消費してバッファを引き継ぐ。
+    pub fn transpose(mut self) -> Self {
+        self.shape.reverse();
+        self.strides.reverse();
+        self
+    }
+
+    /// 位置 `axis` に長さ 1 の軸を挿入する（ブロードキャスト用）
+    pub fn insert_axis(&self, axis: usize) -> Result<Self> {
+        if axis > self.ndim() {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: axis,
+                size: self.ndim() + 1,
+            });
+        }
+        // 長さ 1 の軸のストライドは任意だが、連続性が保たれる値にしておく
+        let stride = if axis < self.ndim() {
+            self.shape[axis] * self.strides[axis]
+        } else {
+            1
+        };
+        let mut a = self.clone();
+        a.shape.insert(axis, 1);
+        a.strides.insert(axis, stride);
+        Ok(a)
+    }
+
+    /// 軸 `axis` の `index` 番目で切り出した 1 次元低い配列（画像のチャネル抽出など）
+    pub fn index_axis(&self, axis: usize, index: usize) -> Result<Self> {
+        self.check_axis(axis)?;
+        if index >= self.shape[axis] {
+            return Err(LinalgError::IndexOutOfBounds {
+                index,
+                size: self.shape[axis],
+            });
+        }
+        let mut shape = self.shape.clone();
+        let mut strides = self.strides.clone();
+        shape.remove(axis);
+        strides.remove(axis);
+        let start = index * self.strides[axis];
+        let data = Offsets::new(&shape, &strides, start)
+            .map(|k| self.data[k].clone())
+            .collect();
+        Ok(NdArray {
+            data,
+            strides: contiguous_strides(&shape),
+            shape,
+        })
+    }
+
+    pub fn to_matrix(&self) -> Result<Matrix<T>> {
+        Matrix::try_from(self.clone())
+    }
+
+    pub fn to_vector(&self) -> Result<Vector<T>> {
+        Vector::try_from(self.clone())
+    }
+
+    pub(super) fn check_axis(&self, axis: usize) -> Result<()> {
+        if axis >= self.ndim() {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: axis,
+                size: self.ndim(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<T: Ring> NdArray<T> {
+    pub fn zeros(shape: &[usize]) -> Self {
+        Self::from_elem(shape, T::zero())
+    }
+
+    pub fn ones(shape: &[usize]) -> Self {
+        Self::from_elem(shape, T::one())
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{LinalgError, Matrix, Result, Scalar, Vector};
+
+mod broadcast;
+mod core;
+mod ops;
+mod reduce;
+
+#[cfg(test)]
+mod tests;
+
+pub use broadcast::broadcast_shape;
+
+/// N 次元配列。index の要素は `data[Σ index[k] * strides[k]]` に置かれる。
+/// 生成直後は行優先（C 順）で連続だが、`permute` は `data` を並べ替えずにストライドだけを入れ替える。
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawNdArray<T>"))]
+pub struct NdArray<T: Scalar = f64> {
+    data: Vec<T>,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+}
+
+/// デシリアライズ直後の未検証の表現。`check_layout` を通ったものだけを NdArray にする。
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawNdArray<T> {
+    data: Vec<T>,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Scalar> TryFrom<RawNdArray<T>> for NdArray<T> {
+    type Error = LinalgError;
+    fn try_from(raw: RawNdArray<T>) -> Result<Self> {
+        check_layout(raw.data.len(), &raw.shape, &raw.strides)?;
+        Ok(NdArray {
+            data: raw.data,
+            shape: raw.shape,
+            strides: raw.strides,
+        })
+    }
+}
+
+/// 形状とストライドの組が長さ `len` のバッファの内側だけを指すか検査する
+#[cfg(any(feature = "serde", test))]
+fn check_layout(len: usize, shape: &[usize], strides: &[usize]) -> Result<()> {
+    if shape.len() != strides.len() {
+        return Err(LinalgError::InvalidArgument {
+            text: format!(
+                "shape has {} axes but strides has {}",
+                shape.len(),
+                strides.len()
+            ),
+        });
+    }
+    // 要素が 0 個ならどのオフセットも読まない
+    if shape.contains(&0) {
+        return Ok(());
+    }
+    // 最後の要素のオフセット Σ (n_k - 1) s_k がバッファに収まればすべて収まる
+    let last = shape.iter().zip(strides).try_fold(0usize, |acc, (&n, &s)| {
+        (n - 1).checked_mul(s)?.checked_add(acc)
+    });
+    match last {
+        Some(last) if last < len => Ok(()),
+        _ => Err(LinalgError::InvalidArgument {
+            text: format!("shape {shape:?} with strides {strides:?} exceeds {len} elements"),
+        }),
+    }
+}
+
+// メモリ上の並びではなく、形状と論理的な要素列で比較する
+impl<T: Scalar + PartialEq> PartialEq for NdArray<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Scalar> From<Matrix<T>> for NdArray<T> {
+    fn from(m: Matrix<T>) -> Self {
+        let shape = vec![m.rows, m.cols];
+        let strides = contiguous_strides(&shape);
+        NdArray {
+            data: m.data,
+            shape,
+            strides,
+        }
+    }
+}
+
+impl<T: Scalar> From<Vector<T>> for NdArray<T> {
+    fn from(v: Vector<T>) -> Self {
+        let shape = vec![v.data.len()];
+        NdArray {
+            data: v.data,
+            shape,
+            strides: vec![1],
+        }
+    }
+}
+
+impl<T: Scalar> TryFrom<NdArray<T>> for Matrix<T> {
+    type Error = LinalgError;
+    fn try_from(a: NdArray<T>) -> Result<Self> {
+        if a.ndim() != 2 {
+            return Err(LinalgError::InvalidDimension {
+                dim: a.ndim(),
+                text: "only a 2-dimensional array converts to a matrix".to_string(),
+            });
+        }
+        let (rows, cols) = (a.shape[0], a.shape[1]);
+        Matrix::new(rows, cols, a.into_vec())
+    }
+}
+
+impl<T: Scalar> TryFrom<NdArray<T>> for Vector<T> {
+    type Error = LinalgError;
+    fn try_from(a: NdArray<T>) -> Result<Self> {
+        if a.ndim() != 1 {
+            return Err(LinalgError::InvalidDimension {
+                dim: a.ndim(),
+                text: "only a 1-dimensional array converts to a vector".to_string(),
+            });
+        }
+        Ok(Vector::new(a.into_vec()))
+    }
+}
+
+/// 行優先で連続に並べたときのストライド
+fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for k in (0..shape.len().saturating_sub(1)).rev() {
+        strides[k] = strides[k + 1] * shape[k + 1];
+    }
+    strides
+}
+
+/// 形状 `shape` を行優先で走査したときの、ストライド `strides` 上のオフセット列
+struct Offsets<'a> {
+    shape: &'a [usize],
+    strides: &'a [usize],
+    index: Vec<usize>,
+    offset: usize,
+    remaining: usize,
+}
+
+impl<'a> Offsets<'a> {
+    fn new(shape: &'a [usize], strides: &'a [usize], start: usize) -> Self {
+        Offsets {
+            shape,
+            strides,
+            index: vec![0; shape.len()],
+            offset: start,
+            remaining: shape.iter().product(),
+        }
+    }
+}
+
+impl Iterator for Offsets<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let current = self.offset;
+        // 最後の軸から繰り上げる
+        for k in (0..self.shape.len()).rev() {
+            self.index[k] += 1;
+            self.offset += self.strides[k];
+            if self.index[k] < self.shape[k] {
+                break;
+            }
+            self.offset -= self.strides[k] * self.shape[k];
+            self.index[k] = 0;
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Offsets<'_> {}
@@ -0,0 +1,134 @@
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+use super::NdArray;
+use crate::{Field, Ring, Scalar};
+
+impl<T: Scalar> Index<&[usize]> for NdArray<T> {
+    type Output = T;
+    fn index(&self, index: &[usize]) -> &Self::Output {
+        match self.get(index) {
+            Some(v) => v,
+            None => panic!(
+                "index {index:?} is out of bounds for shape {:?}",
+                self.shape
+            ),
+        }
+    }
+}
+
+impl<T: Scalar> IndexMut<&[usize]> for NdArray<T> {
+    fn index_mut(&mut self, index: &[usize]) -> &mut Self::Output {
+        let shape = self.shape.clone();
+        match self.get_mut(index) {
+            Some(v) => v,
+            None => panic!("index {index:?} is out of bounds for shape {shape:?}"),
+        }
+    }
+}
+
+impl<T: Scalar, const N: usize> Index<[usize; N]> for NdArray<T> {
+    type Output = T;
+    fn index(&self, index: [usize; N]) -> &Self::Output {
+        &self[&index[..]]
+    }
+}
+
+impl<T: Scalar, const N: usize> IndexMut<[usize; N]> for NdArray<T> {
+    fn index_mut(&mut self, index: [usize; N]) -> &mut Self::Output {
+        &mut self[&index[..]]
+    }
+}
+
+impl<T: Ring> Neg for &NdArray<T> {
+    type Output = NdArray<T>;
+    fn neg(self) -> Self::Output {
+        self.checked_neg()
+    }
+}
+
+impl<T: Ring> Neg for NdArray<T> {
+    type Output = NdArray<T>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<'b, T: Ring> Add<&'b NdArray<T>> for &NdArray<T> {
+    type Output = NdArray<T>;
+    fn add(self, rhs: &'b NdArray<T>) -> Self::Output {
+        match self.checked_add(rhs) {
+            Ok(a) => a,
+            Err(e) => panic!("NdArray addition failed: {e}"),
+        }
+    }
+}
+
+impl<'b, T: Ring> Sub<&'b NdArray<T>> for &NdArray<T> {
+    type Output = NdArray<T>;
+    fn sub(self, rhs: &'b NdArray<T>) -> Self::Output {
+        match self.checked_sub(rhs) {
+            Ok(a) => a,
+            Err(e) => panic!("NdArray subtraction failed: {e}"),
+        }
+    }
+}
+
+impl<'b, T: Ring> Mul<&'b NdArray<T>> for &NdArray<T> {
+    type Output = NdArray<T>;
+    fn mul(self, rhs: &'b NdArray<T>) -> Self::Output {
+        match self.checked_mul(rhs) {
+            Ok(a) => a,
+            Err(e) => panic!("NdArray multiplication failed: {e}"),
+        }
+    }
+}
+
+impl<'b, T: Field> Div<&'b NdArray<T>> for &NdArray<T> {
+    type Output = NdArray<T>;
+    fn div(self, rhs: &'b NdArray<T>) -> Self::Output {
+        match self.checked_div(rhs) {
+            Ok(a) => a,
+            Err(e) => panic!("NdArray division failed: {e}"),
+        }
+    }
+}
+
+impl<'b, T: Ring> Add<&'b T> for &NdArray<T> {
+    type Output = NdArray<T>;
+    fn add(self, rhs: &'b T) -> Self::Output {
+        self.map(|x| x.clone() + rhs.clone())
+    }
+}
+
+impl<'b, T: Ring> Sub<&'b T> for &NdArray<T> {
+    type Output = NdArray<T>;
+    fn sub(self, rhs: &'b T) -> Self::Output {
+        self.map(|x| x.clone() - rhs.clone())
+    }
+}
+
+impl<'b, T: Ring> Mul<&'b T> for &NdArray<T> {
+    type Output = NdArray<T>;
+    fn mul(self, rhs: &'b T) -> Self::Output {
+        self.map(|x| x.clone() * rhs.clone())
+    }
+}
+
+impl<'b, T: Field> Div<&'b T> for &NdArray<T> {
+    type Output = NdArray<T>;
+    fn div(self, rhs: &'b T) -> Self::Output {
+        self.map(|x| x.clone() / rhs.clone())
+    }
+}
+
+// マクロで所有/参照の派生を一括実装（配列同士はブロードキャストする）
+impl_ops_by_ref_variants!(NdArray<T>, Add, add, crate::Ring);
+impl_ops_by_ref_variants!(NdArray<T>, Sub, sub, crate::Ring);
+impl_ops_by_ref_variants!(NdArray<T>, Mul, mul, crate::Ring);
+impl_ops_by_ref_variants!(NdArray<T>, Div, div, crate::Field);
+
+// スカラー右辺
+impl_scalar_rhs_by_ref_variants!(NdArray<T>, Add, add, crate::Ring);
+impl_scalar_rhs_by_ref_variants!(NdArray<T>, Sub, sub, crate::Ring);
+impl_scalar_rhs_by_ref_variants!(NdArray<T>, Mul, mul, crate::Ring);
+impl_scalar_rhs_by_ref_variants!(NdArray<T>, Div, div, crate::Field);
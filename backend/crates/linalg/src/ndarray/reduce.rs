@@ -0,0 +1,97 @@
+use std::cmp::Ordering;
+
+use num_traits::FromPrimitive;
+
+use super::{contiguous_strides, NdArray, Offsets};
+use crate::{Field, LinalgError, Result, Ring, Scalar};
+
+impl<T: Scalar> NdArray<T> {
+    /// 軸 `axis` に沿って `f` で畳み込む。結果はその軸を除いた形状になる。
+    pub fn fold_axis<U, F>(&self, axis: usize, init: U, mut f: F) -> Result<NdArray<U>>
+    where
+        U: Scalar,
+        F: FnMut(U, &T) -> U,
+    {
+        self.check_axis(axis)?;
+        let mut shape = self.shape.clone();
+        let mut strides = self.strides.clone();
+        let (len, step) = (shape.remove(axis), strides.remove(axis));
+        let data = Offsets::new(&shape, &strides, 0)
+            .map(|start| (0..len).fold(init.clone(), |acc, j| f(acc, &self.data[start + j * step])))
+            .collect();
+        Ok(NdArray {
+            data,
+            strides: contiguous_strides(&shape),
+            shape,
+        })
+    }
+
+    /// 軸に沿った最大値。NaN は伝播する。
+    pub fn max_axis(&self, axis: usize) -> Result<Self>
+    where
+        T: PartialOrd,
+    {
+        self.extremum_axis(axis, Ordering::Less)
+    }
+
+    /// 軸に沿った最小値。NaN は伝播する。
+    pub fn min_axis(&self, axis: usize) -> Result<Self>
+    where
+        T: PartialOrd,
+    {
+        self.extremum_axis(axis, Ordering::Greater)
+    }
+
+    /// `best.partial_cmp(x) == replace_if` のとき x で置き換える
+    fn extremum_axis(&self, axis: usize, replace_if: Ordering) -> Result<Self>
+    where
+        T: PartialOrd,
+    {
+        self.check_axis(axis)?;
+        if self.shape[axis] == 0 {
+            return Err(LinalgError::InvalidArgument {
+                text: format!("cannot take the extremum along empty axis {axis}"),
+            });
+        }
+        let reduced = self.fold_axis(axis, None, |best: Option<T>, x| match best {
+            // 自分自身と比較できない値（NaN）は一度入ったら残す
+            Some(b) if b.partial_cmp(&b).is_none() => Some(b),
+            Some(b) if x.partial_cmp(x).is_some() && b.partial_cmp(x) != Some(replace_if) => {
+                Some(b)
+            }
+            _ => Some(x.clone()),
+        })?;
+        Ok(reduced.map(|v| v.clone().expect("axis is non-empty")))
+    }
+}
+
+impl<T: Ring> NdArray<T> {
+    pub fn sum_axis(&self, axis: usize) -> Result<Self> {
+        self.fold_axis(axis, T::zero(), |acc, x| acc + x.clone())
+    }
+
+    /// 全要素の総和
+    pub fn sum(&self) -> T {
+        self.iter().cloned().fold(T::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<T: Field + FromPrimitive> NdArray<T> {
+    pub fn mean_axis(&self, axis: usize) -> Result<Self> {
+        self.check_axis(axis)?;
+        let n = self.shape[axis];
+        if n == 0 {
+            return Err(LinalgError::InvalidArgument {
+                text: format!("cannot take the mean along empty axis {axis}"),
+            });
+        }
+        let n = T::from_usize(n).expect("axis length is representable");
+        Ok(self.sum_axis(axis)?.map(|s| s.clone() / n.clone()))
+    }
+
+    /// 全要素の平均（空なら `None`）
+    pub fn mean(&self) -> Option<T> {
+        let n = T::from_usize(self.len()).filter(|_| !self.is_empty())?;
+        Some(self.sum() / n)
+    }
+}
@@ -0,0 +1,144 @@
+use super::*;
+
+fn arange(shape: &[usize]) -> NdArray<f64> {
+    let n = shape.iter().product::<usize>();
+    NdArray::from_shape_vec(shape, (0..n).map(|i| i as f64).collect()).unwrap()
+}
+
+#[test]
+fn shape_strides_and_indexing() {
+    let mut a = arange(&[2, 3, 4]);
+    assert_eq!(a.shape(), &[2, 3, 4]);
+    assert_eq!(a.strides(), &[12, 4, 1]);
+    assert_eq!(a.len(), 24);
+    assert_eq!(a[[1, 2, 3]], 23.0);
+    assert_eq!(a.get(&[1, 0, 2]), Some(&14.0));
+    assert_eq!(a.get(&[2, 0, 0]), None);
+    assert_eq!(a.get(&[0, 0]), None);
+    a[[0, 1, 0]] = -1.0;
+    assert_eq!(a.as_slice().unwrap()[4], -1.0);
+    assert!(NdArray::from_shape_vec(&[2, 2], vec![1.0; 3]).is_err());
+
+    let f = NdArray::from_fn(&[2, 3], |ix| (10 * ix[0] + ix[1]) as i64);
+    assert_eq!(f.to_vec(), vec![0, 1, 2, 10, 11, 12]);
+    assert_eq!(
+        NdArray::scalar(5).iter().copied().collect::<Vec<_>>(),
+        vec![5]
+    );
+}
+
+#[test]
+fn reshape_and_permute() {
+    let a = arange(&[2, 3, 4]);
+    let p = a.clone().permute(&[2, 0, 1]).unwrap();
+    assert_eq!(p.shape(), &[4, 2, 3]);
+    assert!(!p.is_contiguous());
+    assert_eq!(p[[3, 1, 2]], a[[1, 2, 3]]);
+    assert!(a.clone().permute(&[0, 0, 1]).is_err());
+    assert!(a.clone().permute(&[0, 1]).is_err());
+
+    // 非連続な配列の reshape は行優先の論理順で読み替える
+    let r = p.reshape(&[8, 3]).unwrap();
+    assert!(r.is_contiguous());
+    assert_eq!(r.to_vec(), p.to_vec());
+    assert_eq!(r[[1, 0]], a[[1, 0, 0]]);
+    assert!(a.reshape(&[5, 5]).is_err());
+
+    let t = arange(&[2, 3]).transpose();
+    assert_eq!(t.to_vec(), vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0]);
+
+    // 画像 (高さ, 幅, チャネル) からチャネルを取り出す
+    let img = arange(&[2, 2, 3]);
+    let green = img.index_axis(2, 1).unwrap();
+    assert_eq!(green.to_vec(), vec![1.0, 4.0, 7.0, 10.0]);
+    let chw = img.permute(&[2, 0, 1]).unwrap();
+    assert_eq!(chw.index_axis(0, 1).unwrap(), green);
+    let e = green.insert_axis(0).unwrap();
+    assert_eq!(e.shape(), &[1, 2, 2]);
+    assert!(e.is_contiguous());
+}
+
+#[test]
+fn broadcasting_arithmetic() {
+    assert_eq!(
+        broadcast_shape(&[8, 1, 6, 1], &[7, 1, 5]).unwrap(),
+        vec![8, 7, 6, 5]
+    );
+    assert!(broadcast_shape(&[2, 3], &[4]).is_err());
+
+    let a = arange(&[2, 3]);
+    let row = NdArray::from_shape_vec(&[3], vec![10.0, 20.0, 30.0]).unwrap();
+    let col = NdArray::from_shape_vec(&[2, 1], vec![1.0, 2.0]).unwrap();
+    assert_eq!(
+        (&a + &row).to_vec(),
+        vec![10.0, 21.0, 32.0, 13.0, 24.0, 35.0]
+    );
+    assert_eq!((&a * &col).to_vec(), vec![0.0, 1.0, 2.0, 6.0, 8.0, 10.0]);
+    // 外積のように両側が広がる場合
+    let outer = &col - &row;
+    assert_eq!(outer.shape(), &[2, 3]);
+    assert_eq!(outer.to_vec(), vec![-9.0, -19.0, -29.0, -8.0, -18.0, -28.0]);
+    assert_eq!((&a / 2.0).to_vec(), vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.5]);
+    assert!(a.checked_add(&arange(&[2])).is_err());
+
+    // 転置（非連続）との演算
+    let s = &arange(&[3, 3]) + &arange(&[3, 3]).transpose();
+    assert_eq!(s[[0, 2]], s[[2, 0]]);
+    assert_eq!(s[[1, 2]], 12.0);
+}
+
+#[test]
+fn axis_reductions() {
+    let a = arange(&[2, 3, 4]);
+    let s0 = a.sum_axis(0).unwrap();
+    assert_eq!(s0.shape(), &[3, 4]);
+    assert_eq!(s0[[2, 1]], 9.0 + 21.0);
+    let m1 = a.mean_axis(1).unwrap();
+    assert_eq!(m1.shape(), &[2, 4]);
+    assert_eq!(m1[[1, 3]], 19.0);
+    let x2 = a.max_axis(2).unwrap();
+    assert_eq!(x2.to_vec(), vec![3.0, 7.0, 11.0, 15.0, 19.0, 23.0]);
+    assert_eq!(a.min_axis(0).unwrap(), arange(&[3, 4]));
+    assert_eq!(a.sum(), 276.0);
+    assert_eq!(a.mean(), Some(11.5));
+    assert!(a.sum_axis(3).is_err());
+
+    // 中心化: 平均は軸 0 を消すので、そのままブロードキャストできる
+    let centered = &a - &a.mean_axis(0).unwrap();
+    assert!(centered.sum_axis(0).unwrap().iter().all(|&v| v == 0.0));
+
+    let nan = NdArray::from_shape_vec(&[3], vec![1.0, f64::NAN, 2.0]).unwrap();
+    assert!(nan.max_axis(0).unwrap()[[]].is_nan());
+    assert!(NdArray::<f64>::zeros(&[0, 2]).max_axis(0).is_err());
+}
+
+#[test]
+fn matrix_and_vector_conversions() {
+    let m = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    let a = NdArray::from(m.clone());
+    assert_eq!(a.shape(), &[2, 3]);
+    assert_eq!(a[[1, 0]], 4);
+    assert_eq!(a.clone().transpose().to_matrix().unwrap(), m.transpose());
+    assert_eq!(Matrix::try_from(a.clone()).unwrap(), m);
+
+    let v = Vector::new(vec![1.0, 2.0]);
+    let b = NdArray::from(v.clone());
+    assert_eq!(b.to_vector().unwrap(), v);
+    assert!(a.to_vector().is_err());
+    assert!(b.to_matrix().is_err());
+    assert_eq!(
+        a.sum_axis(1).unwrap().to_vector().unwrap().data,
+        vec![6, 15]
+    );
+}
+
+#[test]
+fn layout_check_rejects_out_of_range_strides() {
+    // デシリアライズ時の検査: 形状とストライドがバッファの外を指すものは拒否する
+    assert!(check_layout(6, &[2, 3], &[3, 1]).is_ok());
+    assert!(check_layout(6, &[3, 2], &[1, 3]).is_ok());
+    assert!(check_layout(0, &[0, 4], &[4, 1]).is_ok());
+    assert!(check_layout(5, &[2, 3], &[3, 1]).is_err());
+    assert!(check_layout(6, &[2, 3], &[1]).is_err());
+    assert!(check_layout(6, &[2, 2], &[usize::MAX, 1]).is_err());
+}
@@ -0,0 +1,470 @@
+use super::{CooMatrix, CscMatrix, SparseMatrix};
+use crate::{LinalgError, Matrix, Result, Ring, Scalar, Vector};
+
+#[cfg(test)]
+mod tests;
+
+/// 主軸（CSRなら行, CSCなら列）ごとに三つ組を圧縮する共通処理。
+/// 各主軸内は副軸の昇順に並べ、重複は加算してまとめる。
+fn compress<T: Ring>(
+    major_dim: usize,
+    major: &[usize],
+    minor: &[usize],
+    values: &[T],
+) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    let mut counts = vec![0usize; major_dim + 1];
+    for &m in major {
+        counts[m + 1] += 1;
+    }
+    for i in 0..major_dim {
+        counts[i + 1] += counts[i];
+    }
+
+    // 主軸ごとにバケット詰め
+    let mut next = counts.clone();
+    let mut order = vec![0usize; major.len()];
+    for (k, &m) in major.iter().enumerate() {
+        order[next[m]] = k;
+        next[m] += 1;
+    }
+
+    let mut indptr = Vec::with_capacity(major_dim + 1);
+    let mut indices = Vec::with_capacity(major.len());
+    let mut out_values: Vec<T> = Vec::with_capacity(major.len());
+    indptr.push(0);
+    for i in 0..major_dim {
+        let bucket = &mut order[counts[i]..counts[i + 1]];
+        bucket.sort_by_key(|&k| minor[k]);
+        let mut last: Option<usize> = None;
+        for &k in bucket.iter() {
+            if last == Some(minor[k]) {
+                let idx = out_values.len() - 1;
+                out_values[idx] = out_values[idx].clone() + values[k].clone();
+            } else {
+                indices.push(minor[k]);
+                out_values.push(values[k].clone());
+                last = Some(minor[k]);
+            }
+        }
+        indptr.push(indices.len());
+    }
+    (indptr, indices, out_values)
+}
+
+/// indptr/indices の整合性を検査する（CSR/CSC 共通）
+fn validate_compressed(
+    major_dim: usize,
+    minor_dim: usize,
+    indptr: &[usize],
+    indices: &[usize],
+    nnz: usize,
+) -> Result<()> {
+    if indptr.len() != major_dim + 1 {
+        return Err(LinalgError::DimensionMismatch {
+            expected: format!("indptr of length {}", major_dim + 1),
+            found: format!("length {}", indptr.len()),
+        });
+    }
+    if indices.len() != nnz || indptr[major_dim] != nnz || indptr[0] != 0 {
+        return Err(LinalgError::DimensionMismatch {
+            expected: format!("{} stored entries", indptr[major_dim]),
+            found: format!("{} indices, {} values", indices.len(), nnz),
+        });
+    }
+    // スライスを切る前に indptr 全体を検査する（途中の値が nnz を超えると範囲外になる）
+    if indptr.windows(2).any(|w| w[0] > w[1]) {
+        return Err(LinalgError::InvalidArgument {
+            text: "indptr must be non-decreasing".to_string(),
+        });
+    }
+    for i in 0..major_dim {
+        let slice = &indices[indptr[i]..indptr[i + 1]];
+        for (k, &idx) in slice.iter().enumerate() {
+            if idx >= minor_dim {
+                return Err(LinalgError::IndexOutOfBounds {
+                    index: idx,
+                    size: minor_dim,
+                });
+            }
+            if k > 0 && slice[k - 1] >= idx {
+                return Err(LinalgError::InvalidArgument {
+                    text: "indices must be strictly increasing within each row/column".to_string(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// デシリアライズ直後の未検証の CSR / CSC 配列。`new` の検査を通ったものだけを疎行列にする。
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+pub(super) struct RawCompressed<T> {
+    rows: usize,
+    cols: usize,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Scalar> TryFrom<RawCompressed<T>> for SparseMatrix<T> {
+    type Error = LinalgError;
+    fn try_from(raw: RawCompressed<T>) -> Result<Self> {
+        SparseMatrix::new(raw.rows, raw.cols, raw.indptr, raw.indices, raw.values)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Scalar> TryFrom<RawCompressed<T>> for CscMatrix<T> {
+    type Error = LinalgError;
+    fn try_from(raw: RawCompressed<T>) -> Result<Self> {
+        CscMatrix::new(raw.rows, raw.cols, raw.indptr, raw.indices, raw.values)
+    }
+}
+
+impl<T: Scalar> CooMatrix<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            row_indices: Vec::new(),
+            col_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    pub fn with_capacity(rows: usize, cols: usize, capacity: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            row_indices: Vec::with_capacity(capacity),
+            col_indices: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// 要素 (r, c) に value を追加する。同じ位置への複数回の push は変換時に加算される。
+    pub fn push(&mut self, r: usize, c: usize, value: T) -> Result<()> {
+        if r >= self.rows {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: r,
+                size: self.rows,
+            });
+        }
+        if c >= self.cols {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: c,
+                size: self.cols,
+            });
+        }
+        self.row_indices.push(r);
+        self.col_indices.push(c);
+        self.values.push(value);
+        Ok(())
+    }
+
+    /// 格納済みの三つ組の数（重複を含む）
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<T: Ring> CooMatrix<T> {
+    pub fn to_csr(&self) -> SparseMatrix<T> {
+        let (indptr, indices, values) = compress(
+            self.rows,
+            &self.row_indices,
+            &self.col_indices,
+            &self.values,
+        );
+        SparseMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            indptr,
+            indices,
+            values,
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let (indptr, indices, values) = compress(
+            self.cols,
+            &self.col_indices,
+            &self.row_indices,
+            &self.values,
+        );
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            indptr,
+            indices,
+            values,
+        }
+    }
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    /// CSR 配列から直接構築する。配列の整合性（長さ・列番号の範囲と昇順）を検査する。
+    pub fn new(
+        rows: usize,
+        cols: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        validate_compressed(rows, cols, &indptr, &indices, values.len())?;
+        Ok(Self {
+            rows,
+            cols,
+            indptr,
+            indices,
+            values,
+        })
+    }
+
+    /// 非零要素（格納要素）数
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// 行 r の (列番号, 値) を走査するイテレータ
+    pub fn row_iter(&self, r: usize) -> impl Iterator<Item = (usize, &T)> {
+        let range = self.indptr[r]..self.indptr[r + 1];
+        self.indices[range.clone()]
+            .iter()
+            .cloned()
+            .zip(self.values[range].iter())
+    }
+
+    /// CSR の転置は同じ配列を CSC として読み替えたものに等しい
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let t = self.transpose();
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            indptr: t.indptr,
+            indices: t.indices,
+            values: t.values,
+        }
+    }
+
+    pub fn transpose(&self) -> SparseMatrix<T> {
+        let mut counts = vec![0usize; self.cols + 1];
+        for &c in &self.indices {
+            counts[c + 1] += 1;
+        }
+        for j in 0..self.cols {
+            counts[j + 1] += counts[j];
+        }
+        let indptr = counts.clone();
+        let mut next = counts;
+        let mut slots: Vec<Option<(usize, T)>> = vec![None; self.nnz()];
+        // 行を昇順に走査するので、転置後の各行内も昇順になる
+        for r in 0..self.rows {
+            for k in self.indptr[r]..self.indptr[r + 1] {
+                let c = self.indices[k];
+                slots[next[c]] = Some((r, self.values[k].clone()));
+                next[c] += 1;
+            }
+        }
+        let (indices, values) = slots.into_iter().flatten().unzip();
+        SparseMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            indptr,
+            indices,
+            values,
+        }
+    }
+}
+
+impl<T: Ring> SparseMatrix<T> {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            indptr: vec![0; rows + 1],
+            indices: vec![],
+            values: vec![],
+        }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        Self {
+            rows: size,
+            cols: size,
+            indptr: (0..=size).collect(),
+            indices: (0..size).collect(),
+            values: vec![T::one(); size],
+        }
+    }
+
+    /// (row, col, value) の三つ組から構築する（重複は加算）
+    pub fn from_triplets(rows: usize, cols: usize, triplets: &[(usize, usize, T)]) -> Result<Self> {
+        let mut coo = CooMatrix::with_capacity(rows, cols, triplets.len());
+        for (r, c, v) in triplets.iter().cloned() {
+            coo.push(r, c, v)?;
+        }
+        Ok(coo.to_csr())
+    }
+
+    /// 密行列からゼロでない要素のみを取り出して構築する
+    pub fn from_dense(dense: &Matrix<T>) -> Self {
+        let mut indptr = Vec::with_capacity(dense.rows + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        indptr.push(0);
+        for i in 0..dense.rows {
+            for j in 0..dense.cols {
+                let v = dense[(i, j)].clone();
+                if !v.is_zero() {
+                    indices.push(j);
+                    values.push(v);
+                }
+            }
+            indptr.push(indices.len());
+        }
+        Self {
+            rows: dense.rows,
+            cols: dense.cols,
+            indptr,
+            indices,
+            values,
+        }
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut m: Matrix<T> = Matrix::zeros(self.rows, self.cols);
+        for r in 0..self.rows {
+            for (c, v) in self.row_iter(r) {
+                m[(r, c)] = v.clone();
+            }
+        }
+        m
+    }
+
+    /// 要素 (r, c) を返す。格納されていない要素はゼロ。
+    pub fn get(&self, r: usize, c: usize) -> Result<T> {
+        if r >= self.rows {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: r,
+                size: self.rows,
+            });
+        }
+        if c >= self.cols {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: c,
+                size: self.cols,
+            });
+        }
+        let range = self.indptr[r]..self.indptr[r + 1];
+        match self.indices[range.clone()].binary_search(&c) {
+            Ok(k) => Ok(self.values[range.start + k].clone()),
+            Err(_) => Ok(T::zero()),
+        }
+    }
+
+    /// 対角成分をベクトルとして返す（min(rows, cols)長）
+    pub fn diagonal(&self) -> Vector<T> {
+        let n = self.rows.min(self.cols);
+        let data = (0..n)
+            .map(|i| self.get(i, i).unwrap_or_else(|_| T::zero()))
+            .collect();
+        Vector::new(data)
+    }
+}
+
+impl<T: Scalar> CscMatrix<T> {
+    /// CSC 配列から直接構築する。配列の整合性（長さ・行番号の範囲と昇順）を検査する。
+    pub fn new(
+        rows: usize,
+        cols: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        validate_compressed(cols, rows, &indptr, &indices, values.len())?;
+        Ok(Self {
+            rows,
+            cols,
+            indptr,
+            indices,
+            values,
+        })
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 列 c の (行番号, 値) を走査するイテレータ
+    pub fn col_iter(&self, c: usize) -> impl Iterator<Item = (usize, &T)> {
+        let range = self.indptr[c]..self.indptr[c + 1];
+        self.indices[range.clone()]
+            .iter()
+            .cloned()
+            .zip(self.values[range].iter())
+    }
+
+    pub fn to_csr(&self) -> SparseMatrix<T> {
+        // CSC(A) の配列は CSR(A^T) と同一なので、それを転置すれば CSR(A) になる
+        let at = SparseMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            indptr: self.indptr.clone(),
+            indices: self.indices.clone(),
+            values: self.values.clone(),
+        };
+        at.transpose()
+    }
+
+    pub fn transpose(&self) -> CscMatrix<T> {
+        self.to_csr().to_csc_transposed()
+    }
+}
+
+impl<T: Scalar> SparseMatrix<T> {
+    /// CSR(A) の配列をそのまま CSC(A^T) として解釈する（コピーのみ）
+    fn to_csc_transposed(&self) -> CscMatrix<T> {
+        CscMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            indptr: self.indptr.clone(),
+            indices: self.indices.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<T: Ring> CscMatrix<T> {
+    pub fn from_dense(dense: &Matrix<T>) -> Self {
+        SparseMatrix::from_dense(dense).to_csc()
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut m: Matrix<T> = Matrix::zeros(self.rows, self.cols);
+        for c in 0..self.cols {
+            for (r, v) in self.col_iter(c) {
+                m[(r, c)] = v.clone();
+            }
+        }
+        m
+    }
+}
+
+impl<T: Ring> From<&Matrix<T>> for SparseMatrix<T> {
+    fn from(dense: &Matrix<T>) -> Self {
+        SparseMatrix::from_dense(dense)
+    }
+}
+
+impl<T: Ring> From<&SparseMatrix<T>> for Matrix<T> {
+    fn from(sparse: &SparseMatrix<T>) -> Self {
+        sparse.to_dense()
+    }
+}
@@ -0,0 +1,117 @@
+use crate::sparse::{CooMatrix, CscMatrix, SparseMatrix};
+use crate::{Matrix, Vector};
+
+fn approx(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol
+}
+
+fn sample_dense() -> Matrix<f64> {
+    Matrix::new(
+        3,
+        4,
+        vec![
+            1.0, 0.0, 2.0, 0.0, //
+            0.0, 0.0, 3.0, 4.0, //
+            5.0, 0.0, 0.0, 6.0,
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn coo_builder_sums_duplicates_and_sorts() {
+    let mut coo = CooMatrix::new(2, 3);
+    coo.push(1, 2, 1.0).unwrap();
+    coo.push(0, 1, 2.0).unwrap();
+    coo.push(1, 0, 3.0).unwrap();
+    coo.push(1, 2, 4.0).unwrap();
+    assert!(coo.push(2, 0, 1.0).is_err());
+
+    let csr = coo.to_csr();
+    assert_eq!(csr.indptr, vec![0, 1, 3]);
+    assert_eq!(csr.indices, vec![1, 0, 2]);
+    assert_eq!(csr.values, vec![2.0, 3.0, 5.0]);
+
+    let csc = coo.to_csc();
+    assert_eq!(csc.indptr, vec![0, 1, 2, 3]);
+    assert_eq!(csc.indices, vec![1, 0, 1]);
+    assert_eq!(csc.to_dense(), csr.to_dense());
+}
+
+#[test]
+fn dense_roundtrip_and_get() {
+    let dense = sample_dense();
+    let sp = SparseMatrix::from_dense(&dense);
+    assert_eq!(sp.nnz(), 6);
+    assert_eq!(sp.to_dense(), dense);
+    assert_eq!(sp.get(1, 3).unwrap(), 4.0);
+    assert_eq!(sp.get(1, 1).unwrap(), 0.0);
+    assert!(sp.get(3, 0).is_err());
+
+    let csc = CscMatrix::from_dense(&dense);
+    assert_eq!(csc.to_dense(), dense);
+    assert_eq!(csc.to_csr(), sp);
+}
+
+#[test]
+fn new_validates_arrays() {
+    assert!(SparseMatrix::new(2, 2, vec![0, 1, 2], vec![0, 1], vec![1.0, 2.0]).is_ok());
+    // indptr の長さ不一致
+    assert!(SparseMatrix::new(2, 2, vec![0, 2], vec![0, 1], vec![1.0, 2.0]).is_err());
+    // 列番号が範囲外
+    assert!(SparseMatrix::new(2, 2, vec![0, 1, 2], vec![0, 2], vec![1.0, 2.0]).is_err());
+    // 行内で昇順でない
+    assert!(SparseMatrix::new(1, 3, vec![0, 2], vec![2, 0], vec![1.0, 2.0]).is_err());
+    // 途中の indptr が nnz を超える（スライスする前に弾く）
+    assert!(SparseMatrix::new(2, 2, vec![0, 5, 2], vec![0, 1], vec![1.0, 2.0]).is_err());
+    assert!(CscMatrix::new(2, 2, vec![0, 5, 2], vec![0, 1], vec![1.0, 2.0]).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialization_runs_the_validator() {
+    let raw = |indptr: Vec<usize>| super::RawCompressed {
+        rows: 2,
+        cols: 2,
+        indptr,
+        indices: vec![0, 1],
+        values: vec![1.0, 2.0],
+    };
+    assert!(SparseMatrix::try_from(raw(vec![0, 1, 2])).is_ok());
+    assert!(SparseMatrix::try_from(raw(vec![0, 5, 2])).is_err());
+    assert!(CscMatrix::try_from(raw(vec![0, 5, 2])).is_err());
+}
+
+#[test]
+fn transpose_matches_dense() {
+    let dense = sample_dense();
+    let sp = SparseMatrix::from_dense(&dense);
+    assert_eq!(sp.transpose().to_dense(), dense.transpose());
+    assert_eq!(sp.to_csc().transpose().to_dense(), dense.transpose());
+}
+
+#[test]
+fn products_match_dense() {
+    let a_dense = sample_dense();
+    let b_dense = Matrix::new(4, 2, vec![1.0, -1.0, 0.0, 2.0, 3.0, 0.0, 0.0, 1.0]).unwrap();
+    let a = SparseMatrix::from_dense(&a_dense);
+    let b = SparseMatrix::from_dense(&b_dense);
+    let x = Vector::new(vec![1.0, 2.0, 3.0, 4.0]);
+
+    let ax = &a * &x;
+    let ax_dense = &a_dense * &x;
+    for i in 0..3 {
+        assert!(approx(ax[i], ax_dense[i], 1e-12));
+    }
+    assert_eq!(a.to_csc().checked_mul_vector(&x).unwrap(), ax);
+
+    assert_eq!(&a * &b_dense, &a_dense * &b_dense);
+    assert_eq!((&a * &b).to_dense(), &a_dense * &b_dense);
+    assert!(a.checked_mul(&a).is_err());
+    assert!(a.checked_mul_vector(&Vector::new(vec![1.0])).is_err());
+
+    let sum = &a + &a.checked_mul_scalar(2.0);
+    assert_eq!(sum.to_dense(), &a_dense * 3.0);
+    let diff = &a - &SparseMatrix::identity(3).checked_mul(&a).unwrap();
+    assert!(diff.values.iter().all(|v| *v == 0.0));
+}
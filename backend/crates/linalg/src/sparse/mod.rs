@@ -0,0 +1,51 @@
+use crate::Scalar;
+
+/// 圧縮行格納（CSR）形式の疎行列。
+/// 行 i の非零要素は `indices[indptr[i]..indptr[i + 1]]`（列番号, 昇順）と
+/// `values[indptr[i]..indptr[i + 1]]` に格納される。
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "self::core::RawCompressed<T>"))]
+pub struct SparseMatrix<T: Scalar> {
+    pub rows: usize,
+    pub cols: usize,
+    pub indptr: Vec<usize>,
+    pub indices: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+/// 圧縮列格納（CSC）形式の疎行列。
+/// 列 j の非零要素は `indices[indptr[j]..indptr[j + 1]]`（行番号, 昇順）と
+/// `values[indptr[j]..indptr[j + 1]]` に格納される。
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "self::core::RawCompressed<T>"))]
+pub struct CscMatrix<T: Scalar> {
+    pub rows: usize,
+    pub cols: usize,
+    pub indptr: Vec<usize>,
+    pub indices: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+/// 座標（COO）形式のビルダー。(row, col, value) の三つ組を順不同で積み、
+/// `to_csr` / `to_csc` で圧縮形式へ変換する（重複要素は加算される）。
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T: Scalar> {
+    pub rows: usize,
+    pub cols: usize,
+    pub row_indices: Vec<usize>,
+    pub col_indices: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+// サブモジュールを宣言
+mod core;
+mod ops;
+pub mod solvers;
+
+// パブリックな再エクスポート
+pub use solvers::{
+    IdentityPreconditioner, Ilu0Preconditioner, IterativeOptions, IterativeSolution,
+    JacobiPreconditioner, Preconditioner,
+};
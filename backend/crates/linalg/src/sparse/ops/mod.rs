@@ -0,0 +1,228 @@
+use super::{CscMatrix, SparseMatrix};
+use crate::{LinalgError, Matrix, Result, Ring, Vector};
+use std::ops::{Add, Mul, Sub};
+
+impl<T: Ring> SparseMatrix<T> {
+    /// 疎行列 × ベクトル
+    pub fn checked_mul_vector(&self, rhs: &Vector<T>) -> Result<Vector<T>> {
+        if rhs.dim() != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("vector dim {}", self.cols),
+                found: format!("{}", rhs.dim()),
+            });
+        }
+        let data = (0..self.rows)
+            .map(|r| {
+                self.row_iter(r)
+                    .fold(T::zero(), |acc, (c, v)| acc + v.clone() * rhs[c].clone())
+            })
+            .collect();
+        Ok(Vector::new(data))
+    }
+
+    /// 疎行列 × 密行列
+    pub fn checked_mul_dense(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.cols != rhs.rows {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("left cols {} == right rows {}", self.cols, rhs.rows),
+                found: format!("{} vs {}", self.cols, rhs.rows),
+            });
+        }
+        let mut out: Matrix<T> = Matrix::zeros(self.rows, rhs.cols);
+        for r in 0..self.rows {
+            for (k, v) in self.row_iter(r) {
+                for j in 0..rhs.cols {
+                    out[(r, j)] = out[(r, j)].clone() + v.clone() * rhs[(k, j)].clone();
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// 疎行列 × 疎行列（Gustavson 法: 行ごとに疎アキュムレータで積を集める）
+    pub fn checked_mul(&self, rhs: &SparseMatrix<T>) -> Result<SparseMatrix<T>> {
+        if self.cols != rhs.rows {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("left cols {} == right rows {}", self.cols, rhs.rows),
+                found: format!("{} vs {}", self.cols, rhs.rows),
+            });
+        }
+        let mut indptr = Vec::with_capacity(self.rows + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        let mut acc: Vec<Option<T>> = vec![None; rhs.cols];
+        let mut touched: Vec<usize> = Vec::new();
+        indptr.push(0);
+        for r in 0..self.rows {
+            for (k, a) in self.row_iter(r) {
+                for (c, b) in rhs.row_iter(k) {
+                    let prod = a.clone() * b.clone();
+                    acc[c] = Some(match acc[c].take() {
+                        Some(s) => s + prod,
+                        None => {
+                            touched.push(c);
+                            prod
+                        }
+                    });
+                }
+            }
+            touched.sort_unstable();
+            for &c in &touched {
+                if let Some(v) = acc[c].take() {
+                    indices.push(c);
+                    values.push(v);
+                }
+            }
+            touched.clear();
+            indptr.push(indices.len());
+        }
+        Ok(SparseMatrix {
+            rows: self.rows,
+            cols: rhs.cols,
+            indptr,
+            indices,
+            values,
+        })
+    }
+
+    fn zip_with(&self, rhs: &SparseMatrix<T>, f: impl Fn(T, T) -> T) -> Result<SparseMatrix<T>> {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, self.cols),
+                found: format!("{}x{}", rhs.rows, rhs.cols),
+            });
+        }
+        let mut indptr = Vec::with_capacity(self.rows + 1);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        indptr.push(0);
+        for r in 0..self.rows {
+            // 両行の列番号は昇順なのでマージする
+            let (mut ka, ea) = (self.indptr[r], self.indptr[r + 1]);
+            let (mut kb, eb) = (rhs.indptr[r], rhs.indptr[r + 1]);
+            while ka < ea || kb < eb {
+                let ca = if ka < ea {
+                    self.indices[ka]
+                } else {
+                    usize::MAX
+                };
+                let cb = if kb < eb { rhs.indices[kb] } else { usize::MAX };
+                if ca == cb {
+                    values.push(f(self.values[ka].clone(), rhs.values[kb].clone()));
+                    indices.push(ca);
+                    ka += 1;
+                    kb += 1;
+                } else if ca < cb {
+                    values.push(f(self.values[ka].clone(), T::zero()));
+                    indices.push(ca);
+                    ka += 1;
+                } else {
+                    values.push(f(T::zero(), rhs.values[kb].clone()));
+                    indices.push(cb);
+                    kb += 1;
+                }
+            }
+            indptr.push(indices.len());
+        }
+        Ok(SparseMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            indptr,
+            indices,
+            values,
+        })
+    }
+
+    pub fn checked_add(&self, rhs: &SparseMatrix<T>) -> Result<SparseMatrix<T>> {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+
+    pub fn checked_sub(&self, rhs: &SparseMatrix<T>) -> Result<SparseMatrix<T>> {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+
+    pub fn checked_mul_scalar(&self, scalar: T) -> SparseMatrix<T> {
+        let values = self
+            .values
+            .iter()
+            .map(|x| x.clone() * scalar.clone())
+            .collect();
+        SparseMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            indptr: self.indptr.clone(),
+            indices: self.indices.clone(),
+            values,
+        }
+    }
+}
+
+impl<T: Ring> CscMatrix<T> {
+    /// CSC 疎行列 × ベクトル（列ごとに散布加算）
+    pub fn checked_mul_vector(&self, rhs: &Vector<T>) -> Result<Vector<T>> {
+        if rhs.dim() != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("vector dim {}", self.cols),
+                found: format!("{}", rhs.dim()),
+            });
+        }
+        let mut out: Vector<T> = Vector::zeros(self.rows);
+        for c in 0..self.cols {
+            for (r, v) in self.col_iter(c) {
+                out[r] = out[r].clone() + v.clone() * rhs[c].clone();
+            }
+        }
+        Ok(out)
+    }
+}
+
+// 演算子オーバーロード（次元不一致は密行列と同様に panic）
+impl<'b, T: Ring> Mul<&'b Vector<T>> for &SparseMatrix<T> {
+    type Output = Vector<T>;
+    fn mul(self, rhs: &'b Vector<T>) -> Self::Output {
+        match self.checked_mul_vector(rhs) {
+            Ok(vec) => vec,
+            Err(e) => panic!("Sparse matrix-vector multiplication failed: {e}"),
+        }
+    }
+}
+
+impl<'b, T: Ring> Mul<&'b Matrix<T>> for &SparseMatrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, rhs: &'b Matrix<T>) -> Self::Output {
+        match self.checked_mul_dense(rhs) {
+            Ok(mat) => mat,
+            Err(e) => panic!("Sparse-dense multiplication failed: {e}"),
+        }
+    }
+}
+
+impl<'b, T: Ring> Mul<&'b SparseMatrix<T>> for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+    fn mul(self, rhs: &'b SparseMatrix<T>) -> Self::Output {
+        match self.checked_mul(rhs) {
+            Ok(mat) => mat,
+            Err(e) => panic!("Sparse matrix multiplication failed: {e}"),
+        }
+    }
+}
+
+impl<'b, T: Ring> Add<&'b SparseMatrix<T>> for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+    fn add(self, rhs: &'b SparseMatrix<T>) -> Self::Output {
+        match self.checked_add(rhs) {
+            Ok(mat) => mat,
+            Err(e) => panic!("Sparse matrix addition failed: {e}"),
+        }
+    }
+}
+
+impl<'b, T: Ring> Sub<&'b SparseMatrix<T>> for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+    fn sub(self, rhs: &'b SparseMatrix<T>) -> Self::Output {
+        match self.checked_sub(rhs) {
+            Ok(mat) => mat,
+            Err(e) => panic!("Sparse matrix subtraction failed: {e}"),
+        }
+    }
+}
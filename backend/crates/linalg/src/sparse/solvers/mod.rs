@@ -0,0 +1,459 @@
+use super::SparseMatrix;
+use crate::{LinalgError, Result, Vector};
+
+#[cfg(test)]
+mod tests;
+
+/// 反復解法の停止条件
+#[derive(Debug, Clone, Copy)]
+pub struct IterativeOptions {
+    /// 最大反復回数（GMRES では内側反復の総数）
+    pub max_iter: usize,
+    /// 相対残差 ||b - A x|| / ||b|| の許容値
+    pub tol: f64,
+    /// GMRES の再始動周期 m
+    pub restart: usize,
+}
+
+impl Default for IterativeOptions {
+    fn default() -> Self {
+        Self {
+            max_iter: 1000,
+            tol: 1e-10,
+            restart: 30,
+        }
+    }
+}
+
+/// 反復解法の結果。収束しなかった場合も最後の近似解を返す。
+#[derive(Debug, Clone)]
+pub struct IterativeSolution {
+    pub x: Vector<f64>,
+    pub iterations: usize,
+    /// 最終的な残差ノルム ||b - A x||
+    pub residual_norm: f64,
+    pub converged: bool,
+}
+
+/// 前処理 M ≈ A。`apply` は z = M^{-1} r を返す。
+pub trait Preconditioner {
+    fn apply(&self, r: &Vector<f64>) -> Vector<f64>;
+}
+
+/// 前処理なし（M = I）
+pub struct IdentityPreconditioner;
+
+impl Preconditioner for IdentityPreconditioner {
+    fn apply(&self, r: &Vector<f64>) -> Vector<f64> {
+        r.clone()
+    }
+}
+
+/// Jacobi（対角スケーリング）前処理: M = diag(A)
+pub struct JacobiPreconditioner {
+    inv_diag: Vec<f64>,
+}
+
+impl JacobiPreconditioner {
+    pub fn new(a: &SparseMatrix<f64>) -> Result<Self> {
+        if !a.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let mut inv_diag = Vec::with_capacity(a.rows);
+        for d in a.diagonal().iter() {
+            if *d == 0.0 {
+                return Err(LinalgError::SingularMatrix);
+            }
+            inv_diag.push(1.0 / d);
+        }
+        Ok(Self { inv_diag })
+    }
+}
+
+impl Preconditioner for JacobiPreconditioner {
+    fn apply(&self, r: &Vector<f64>) -> Vector<f64> {
+        let data = r
+            .iter()
+            .zip(self.inv_diag.iter())
+            .map(|(x, d)| x * d)
+            .collect();
+        Vector::new(data)
+    }
+}
+
+/// 不完全 LU 分解 ILU(0) 前処理。A の非零パターン上だけで L（単位下三角）と U を作る。
+pub struct Ilu0Preconditioner {
+    lu: SparseMatrix<f64>,
+    /// 各行の対角要素の格納位置
+    diag_pos: Vec<usize>,
+}
+
+impl Ilu0Preconditioner {
+    pub fn new(a: &SparseMatrix<f64>) -> Result<Self> {
+        if !a.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let n = a.rows;
+        let mut lu = a.clone();
+        let mut diag_pos = Vec::with_capacity(n);
+        for i in 0..n {
+            let range = lu.indptr[i]..lu.indptr[i + 1];
+            match lu.indices[range.clone()].binary_search(&i) {
+                Ok(k) => diag_pos.push(range.start + k),
+                Err(_) => return Err(LinalgError::SingularMatrix),
+            }
+        }
+
+        // IKJ 版ガウス消去をパターン内に制限して実行
+        let mut col_pos: Vec<Option<usize>> = vec![None; n];
+        for i in 1..n {
+            let (start, end) = (lu.indptr[i], lu.indptr[i + 1]);
+            for k in start..end {
+                col_pos[lu.indices[k]] = Some(k);
+            }
+            for kk in start..end {
+                let k = lu.indices[kk];
+                if k >= i {
+                    break;
+                }
+                let pivot = lu.values[diag_pos[k]];
+                if pivot == 0.0 {
+                    return Err(LinalgError::SingularMatrix);
+                }
+                let factor = lu.values[kk] / pivot;
+                lu.values[kk] = factor;
+                for kj in (diag_pos[k] + 1)..lu.indptr[k + 1] {
+                    if let Some(pos) = col_pos[lu.indices[kj]] {
+                        lu.values[pos] -= factor * lu.values[kj];
+                    }
+                }
+            }
+            for k in start..end {
+                col_pos[lu.indices[k]] = None;
+            }
+        }
+        if diag_pos.iter().any(|&p| lu.values[p] == 0.0) {
+            return Err(LinalgError::SingularMatrix);
+        }
+        Ok(Self { lu, diag_pos })
+    }
+}
+
+impl Preconditioner for Ilu0Preconditioner {
+    fn apply(&self, r: &Vector<f64>) -> Vector<f64> {
+        let n = self.lu.rows;
+        let mut z = r.data.clone();
+        // L y = r（単位下三角）
+        for i in 0..n {
+            let mut s = z[i];
+            for k in self.lu.indptr[i]..self.diag_pos[i] {
+                s -= self.lu.values[k] * z[self.lu.indices[k]];
+            }
+            z[i] = s;
+        }
+        // U z = y
+        for i in (0..n).rev() {
+            let mut s = z[i];
+            for k in (self.diag_pos[i] + 1)..self.lu.indptr[i + 1] {
+                s -= self.lu.values[k] * z[self.lu.indices[k]];
+            }
+            z[i] = s / self.lu.values[self.diag_pos[i]];
+        }
+        Vector::new(z)
+    }
+}
+
+/// y += alpha * x
+fn axpy(y: &mut Vector<f64>, alpha: f64, x: &Vector<f64>) {
+    for (yi, xi) in y.iter_mut().zip(x.iter()) {
+        *yi += alpha * xi;
+    }
+}
+
+impl SparseMatrix<f64> {
+    fn check_system(&self, b: &Vector<f64>) -> Result<()> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        if self.rows != b.dim() {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{}-dimensional vector", self.rows),
+                found: format!("{}-dimensional vector", b.dim()),
+            });
+        }
+        Ok(())
+    }
+
+    fn residual(&self, b: &Vector<f64>, x: &Vector<f64>) -> Result<Vector<f64>> {
+        b.checked_sub(&self.checked_mul_vector(x)?)
+    }
+
+    /// 前処理付き共役勾配法（A は対称正定値であること）
+    pub fn solve_cg(
+        &self,
+        b: &Vector<f64>,
+        precond: Option<&dyn Preconditioner>,
+        opts: &IterativeOptions,
+    ) -> Result<IterativeSolution> {
+        self.check_system(b)?;
+        let precond = precond.unwrap_or(&IdentityPreconditioner);
+        let b_norm = b.norm();
+        let mut x = Vector::zeros(self.rows);
+        if b_norm == 0.0 {
+            return Ok(IterativeSolution {
+                x,
+                iterations: 0,
+                residual_norm: 0.0,
+                converged: true,
+            });
+        }
+
+        let mut r = b.clone();
+        let mut z = precond.apply(&r);
+        let mut p = z.clone();
+        let mut rz = r.dot(&z);
+        let mut residual_norm = b_norm;
+
+        for it in 0..opts.max_iter {
+            let ap = self.checked_mul_vector(&p)?;
+            let pap = p.dot(&ap);
+            if pap <= 0.0 {
+                return Err(LinalgError::InvalidArgument {
+                    text: "CG breakdown: matrix is not positive definite".to_string(),
+                });
+            }
+            let alpha = rz / pap;
+            axpy(&mut x, alpha, &p);
+            axpy(&mut r, -alpha, &ap);
+            residual_norm = r.norm();
+            if residual_norm <= opts.tol * b_norm {
+                return Ok(IterativeSolution {
+                    x,
+                    iterations: it + 1,
+                    residual_norm,
+                    converged: true,
+                });
+            }
+            z = precond.apply(&r);
+            let rz_new = r.dot(&z);
+            let beta = rz_new / rz;
+            rz = rz_new;
+            p = &z + &(&p * beta);
+        }
+        Ok(IterativeSolution {
+            x,
+            iterations: opts.max_iter,
+            residual_norm,
+            converged: false,
+        })
+    }
+
+    /// 前処理付き BiCGSTAB（非対称行列向け）
+    pub fn solve_bicgstab(
+        &self,
+        b: &Vector<f64>,
+        precond: Option<&dyn Preconditioner>,
+        opts: &IterativeOptions,
+    ) -> Result<IterativeSolution> {
+        self.check_system(b)?;
+        let precond = precond.unwrap_or(&IdentityPreconditioner);
+        let n = self.rows;
+        let b_norm = b.norm();
+        let mut x = Vector::zeros(n);
+        if b_norm == 0.0 {
+            return Ok(IterativeSolution {
+                x,
+                iterations: 0,
+                residual_norm: 0.0,
+                converged: true,
+            });
+        }
+
+        let mut r = b.clone();
+        let r_hat = r.clone();
+        let (mut rho, mut alpha, mut omega) = (1.0, 1.0, 1.0_f64);
+        let mut v = Vector::zeros(n);
+        let mut p = Vector::zeros(n);
+
+        // 破綻判定のしきい値: rho, (r_hat, v), omega が相対的に丸め誤差程度まで小さくなったら打ち切る
+        let tiny = f64::EPSILON * f64::EPSILON;
+        let r_hat_norm = r_hat.norm();
+        let mut iterations = 0;
+        while iterations < opts.max_iter {
+            let rho_new = r_hat.dot(&r);
+            if rho_new.abs() <= tiny * r_hat_norm * r.norm() || omega.abs() <= tiny {
+                break;
+            }
+            let beta = (rho_new / rho) * (alpha / omega);
+            // p = r + beta (p - omega v)
+            axpy(&mut p, -omega, &v);
+            p = &r + &(&p * beta);
+            let y = precond.apply(&p);
+            v = self.checked_mul_vector(&y)?;
+            let rv = r_hat.dot(&v);
+            if rv.abs() <= tiny * r_hat_norm * v.norm() {
+                break;
+            }
+            iterations += 1;
+            alpha = rho_new / rv;
+            let mut s = r.clone();
+            axpy(&mut s, -alpha, &v);
+            if s.norm() <= opts.tol * b_norm {
+                axpy(&mut x, alpha, &y);
+                let residual_norm = self.residual(b, &x)?.norm();
+                return Ok(IterativeSolution {
+                    x,
+                    iterations,
+                    residual_norm,
+                    converged: true,
+                });
+            }
+            let z = precond.apply(&s);
+            let t = self.checked_mul_vector(&z)?;
+            let tt = t.dot(&t);
+            omega = if tt == 0.0 { 0.0 } else { t.dot(&s) / tt };
+            axpy(&mut x, alpha, &y);
+            axpy(&mut x, omega, &z);
+            r = s;
+            axpy(&mut r, -omega, &t);
+            rho = rho_new;
+            let residual_norm = r.norm();
+            if residual_norm <= opts.tol * b_norm {
+                return Ok(IterativeSolution {
+                    x,
+                    iterations,
+                    residual_norm,
+                    converged: true,
+                });
+            }
+        }
+        // 反復上限または破綻: 実際に行った反復回数と真の残差を未収束として返す
+        let residual_norm = self.residual(b, &x)?.norm();
+        Ok(IterativeSolution {
+            x,
+            iterations,
+            residual_norm,
+            converged: residual_norm <= opts.tol * b_norm,
+        })
+    }
+
+    /// 再始動付き GMRES(m)（右前処理、Givens 回転で最小二乗問題を更新）
+    pub fn solve_gmres(
+        &self,
+        b: &Vector<f64>,
+        precond: Option<&dyn Preconditioner>,
+        opts: &IterativeOptions,
+    ) -> Result<IterativeSolution> {
+        self.check_system(b)?;
+        if opts.restart == 0 {
+            return Err(LinalgError::InvalidArgument {
+                text: "GMRES restart must be positive".to_string(),
+            });
+        }
+        let precond = precond.unwrap_or(&IdentityPreconditioner);
+        let n = self.rows;
+        let m = opts.restart.min(n.max(1));
+        let b_norm = b.norm();
+        let mut x = Vector::zeros(n);
+        if b_norm == 0.0 {
+            return Ok(IterativeSolution {
+                x,
+                iterations: 0,
+                residual_norm: 0.0,
+                converged: true,
+            });
+        }
+
+        let mut iterations = 0;
+        loop {
+            let r = self.residual(b, &x)?;
+            let beta = r.norm();
+            if beta <= opts.tol * b_norm {
+                return Ok(IterativeSolution {
+                    x,
+                    iterations,
+                    residual_norm: beta,
+                    converged: true,
+                });
+            }
+            if iterations >= opts.max_iter {
+                return Ok(IterativeSolution {
+                    x,
+                    iterations,
+                    residual_norm: beta,
+                    converged: false,
+                });
+            }
+
+            let mut basis: Vec<Vector<f64>> = Vec::with_capacity(m + 1);
+            basis.push(&r * (1.0 / beta));
+            // h[j] は Hessenberg 行列の j 列目（長さ j + 2）
+            let mut h: Vec<Vec<f64>> = Vec::with_capacity(m);
+            let mut cs: Vec<f64> = Vec::with_capacity(m);
+            let mut sn: Vec<f64> = Vec::with_capacity(m);
+            let mut g = vec![0.0; m + 1];
+            g[0] = beta;
+
+            let mut k = 0;
+            while k < m && iterations < opts.max_iter {
+                let z = precond.apply(&basis[k]);
+                let mut w = self.checked_mul_vector(&z)?;
+                // 修正 Gram–Schmidt
+                let mut col = vec![0.0; k + 2];
+                for (i, vi) in basis.iter().enumerate() {
+                    col[i] = w.dot(vi);
+                    axpy(&mut w, -col[i], vi);
+                }
+                col[k + 1] = w.norm();
+
+                // 既存の Givens 回転を適用
+                for i in 0..k {
+                    let tmp = cs[i] * col[i] + sn[i] * col[i + 1];
+                    col[i + 1] = -sn[i] * col[i] + cs[i] * col[i + 1];
+                    col[i] = tmp;
+                }
+                // 新しい回転で col[k+1] を消去
+                let denom = col[k].hypot(col[k + 1]);
+                let (c, s) = if denom == 0.0 {
+                    (1.0, 0.0)
+                } else {
+                    (col[k] / denom, col[k + 1] / denom)
+                };
+                let h_next = col[k + 1];
+                col[k] = denom;
+                col[k + 1] = 0.0;
+                g[k + 1] = -s * g[k];
+                g[k] *= c;
+                cs.push(c);
+                sn.push(s);
+                h.push(col);
+                iterations += 1;
+                k += 1;
+
+                if g[k].abs() <= opts.tol * b_norm || h_next == 0.0 {
+                    break;
+                }
+                basis.push(&w * (1.0 / h_next));
+            }
+
+            // 上三角系 H y = g を後退代入で解く
+            let mut y = vec![0.0; k];
+            for i in (0..k).rev() {
+                let mut s = g[i];
+                for (j, yj) in y.iter().enumerate().skip(i + 1) {
+                    s -= h[j][i] * yj;
+                }
+                if h[i][i] == 0.0 {
+                    return Err(LinalgError::SingularMatrix);
+                }
+                y[i] = s / h[i][i];
+            }
+            let mut update = Vector::zeros(n);
+            for (yi, vi) in y.iter().zip(basis.iter()) {
+                axpy(&mut update, *yi, vi);
+            }
+            let dx = precond.apply(&update);
+            axpy(&mut x, 1.0, &dx);
+        }
+    }
+}
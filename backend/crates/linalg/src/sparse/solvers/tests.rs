@@ -0,0 +1,139 @@
+use crate::sparse::{
+    CooMatrix, Ilu0Preconditioner, IterativeOptions, JacobiPreconditioner, SparseMatrix,
+};
+use crate::Vector;
+
+/// 1次元ポアソン方程式の有限差分行列 tridiag(-1, 2, -1)
+fn poisson_1d(n: usize) -> SparseMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0).unwrap();
+        if i > 0 {
+            coo.push(i, i - 1, -1.0).unwrap();
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -1.0).unwrap();
+        }
+    }
+    coo.to_csr()
+}
+
+/// 対流項を含む非対称な三重対角行列
+fn convection_diffusion(n: usize) -> SparseMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 4.0).unwrap();
+        if i > 0 {
+            coo.push(i, i - 1, -1.5).unwrap();
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -0.5).unwrap();
+        }
+    }
+    coo.to_csr()
+}
+
+fn residual_norm(a: &SparseMatrix<f64>, x: &Vector<f64>, b: &Vector<f64>) -> f64 {
+    (b - &(a * x)).norm()
+}
+
+#[test]
+fn cg_solves_poisson_with_and_without_preconditioner() {
+    let n = 200;
+    let a = poisson_1d(n);
+    let b = Vector::ones(n);
+    let opts = IterativeOptions {
+        max_iter: 2 * n,
+        ..Default::default()
+    };
+
+    let plain = a.solve_cg(&b, None, &opts).unwrap();
+    assert!(plain.converged, "iterations={}", plain.iterations);
+    assert!(residual_norm(&a, &plain.x, &b) < 1e-8 * b.norm());
+
+    let jacobi = JacobiPreconditioner::new(&a).unwrap();
+    let pre = a.solve_cg(&b, Some(&jacobi), &opts).unwrap();
+    assert!(pre.converged);
+    assert!(residual_norm(&a, &pre.x, &b) < 1e-8 * b.norm());
+}
+
+#[test]
+fn cg_rejects_indefinite_matrix() {
+    let a = SparseMatrix::from_triplets(2, 2, &[(0, 0, 1.0), (1, 1, -1.0)]).unwrap();
+    let b = Vector::new(vec![1.0, 1.0]);
+    assert!(a.solve_cg(&b, None, &IterativeOptions::default()).is_err());
+}
+
+#[test]
+fn bicgstab_solves_nonsymmetric_system() {
+    let n = 100;
+    let a = convection_diffusion(n);
+    let b = Vector::new((0..n).map(|i| (i as f64).sin()).collect());
+    let opts = IterativeOptions::default();
+
+    let sol = a.solve_bicgstab(&b, None, &opts).unwrap();
+    assert!(sol.converged);
+    assert!(residual_norm(&a, &sol.x, &b) < 1e-8 * b.norm());
+
+    let ilu = Ilu0Preconditioner::new(&a).unwrap();
+    let pre = a.solve_bicgstab(&b, Some(&ilu), &opts).unwrap();
+    assert!(pre.converged);
+    assert!(pre.iterations <= sol.iterations);
+}
+
+#[test]
+fn bicgstab_reports_breakdown_as_not_converged() {
+    // 90° 回転では v = A r が r_hat と直交し、初回で (r_hat, v) = 0 の破綻が起きる
+    let a = SparseMatrix::from_triplets(2, 2, &[(0, 1, -1.0), (1, 0, 1.0)]).unwrap();
+    let b = Vector::new(vec![1.0, 0.0]);
+    let sol = a
+        .solve_bicgstab(&b, None, &IterativeOptions::default())
+        .unwrap();
+    assert!(!sol.converged);
+    assert_eq!(sol.iterations, 0);
+    assert!((sol.residual_norm - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn gmres_with_restart_converges() {
+    let n = 120;
+    let a = convection_diffusion(n);
+    let b = Vector::new((0..n).map(|i| 1.0 + (i % 7) as f64).collect());
+    let opts = IterativeOptions {
+        restart: 10,
+        max_iter: 2000,
+        tol: 1e-10,
+    };
+
+    let sol = a.solve_gmres(&b, None, &opts).unwrap();
+    assert!(sol.converged, "residual={}", sol.residual_norm);
+    assert!(residual_norm(&a, &sol.x, &b) < 1e-8 * b.norm());
+
+    let ilu = Ilu0Preconditioner::new(&a).unwrap();
+    let pre = a.solve_gmres(&b, Some(&ilu), &opts).unwrap();
+    assert!(pre.converged);
+    assert!(residual_norm(&a, &pre.x, &b) < 1e-8 * b.norm());
+}
+
+#[test]
+fn ilu0_is_exact_for_tridiagonal() {
+    // 三重対角行列では ILU(0) は fill-in が生じず完全 LU と一致する
+    let a = poisson_1d(10);
+    let ilu = Ilu0Preconditioner::new(&a).unwrap();
+    use crate::sparse::Preconditioner;
+    let b = Vector::new((0..10).map(|i| i as f64).collect());
+    let x = ilu.apply(&b);
+    assert!(residual_norm(&a, &x, &b) < 1e-10);
+}
+
+#[test]
+fn solvers_report_dimension_errors() {
+    let a = poisson_1d(3);
+    let b = Vector::ones(4);
+    let opts = IterativeOptions::default();
+    assert!(a.solve_cg(&b, None, &opts).is_err());
+    assert!(a.solve_bicgstab(&b, None, &opts).is_err());
+    assert!(a.solve_gmres(&b, None, &opts).is_err());
+    let rect = SparseMatrix::<f64>::zeros(2, 3);
+    assert!(JacobiPreconditioner::new(&rect).is_err());
+}
@@ -0,0 +1,341 @@
+use super::Banded;
+use crate::matrix::numerical::LinearOperator;
+use crate::traits::ComplexField;
+use crate::{LinalgError, Matrix, Result, Ring, Scalar, Vector};
+
+/// 部分ピボット付き帯 LU 分解（LAPACK の gbtrf と同じ形）。
+/// 行交換で U の上帯幅は kl + ku に広がるので、各行 2 kl + ku + 1 個分の領域を持つ。
+#[derive(Debug, Clone)]
+pub struct BandedLU<T: ComplexField> {
+    n: usize,
+    kl: usize,
+    ku: usize,
+    /// 行 i・列 j の成分は `lu[i * (2 kl + ku + 1) + (j + kl - i)]`（対角より下は L の乗数）
+    lu: Vec<T>,
+    /// 第 k 段で行 k と交換した行
+    pivots: Vec<usize>,
+}
+
+/// 対称正定値な帯行列の Cholesky 分解 A = L L^T。L は下帯幅 p の帯行列。
+#[derive(Debug, Clone)]
+pub struct BandedCholesky {
+    pub l: Banded<f64>,
+}
+
+impl<T: Scalar> Banded<T> {
+    pub fn new(n: usize, kl: usize, ku: usize, data: Vec<T>) -> Result<Self> {
+        let len = n * (kl + ku + 1);
+        if data.len() != len {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{n} rows of {} band entries ({len})", kl + ku + 1),
+                found: format!("{} elements", data.len()),
+            });
+        }
+        Ok(Self { n, kl, ku, data })
+    }
+
+    fn width(&self) -> usize {
+        self.kl + self.ku + 1
+    }
+
+    /// (r, c) が帯の中にあれば格納位置を返す
+    fn position(&self, r: usize, c: usize) -> Option<usize> {
+        let inside = r < self.n && c < self.n && c + self.kl >= r && c <= r + self.ku;
+        inside.then(|| r * self.width() + c + self.kl - r)
+    }
+
+    /// 行 r で帯に入る列の範囲
+    fn band_columns(&self, r: usize) -> std::ops::Range<usize> {
+        r.saturating_sub(self.kl)..(r + self.ku + 1).min(self.n)
+    }
+}
+
+impl<T: Ring> Banded<T> {
+    pub fn zeros(n: usize, kl: usize, ku: usize) -> Self {
+        Self {
+            n,
+            kl,
+            ku,
+            data: vec![T::zero(); n * (kl + ku + 1)],
+        }
+    }
+
+    /// 密行列から帯の部分を取り出す。帯の外に非零があればエラー。
+    pub fn from_dense(dense: &Matrix<T>, kl: usize, ku: usize) -> Result<Self> {
+        if !dense.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let mut b = Self::zeros(dense.rows, kl, ku);
+        for i in 0..dense.rows {
+            for j in 0..dense.cols {
+                match b.position(i, j) {
+                    Some(k) => b.data[k] = dense[(i, j)].clone(),
+                    None if !dense[(i, j)].is_zero() => {
+                        return Err(LinalgError::InvalidArgument {
+                            text: format!("entry ({i}, {j}) lies outside the band ({kl}, {ku})"),
+                        });
+                    }
+                    None => {}
+                }
+            }
+        }
+        Ok(b)
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut m = Matrix::zeros(self.n, self.n);
+        for i in 0..self.n {
+            for j in self.band_columns(i) {
+                m[(i, j)] = self.data[i * self.width() + j + self.kl - i].clone();
+            }
+        }
+        m
+    }
+
+    /// 要素 (r, c) を返す。帯の外はゼロ。
+    pub fn get(&self, r: usize, c: usize) -> Result<T> {
+        if r >= self.n || c >= self.n {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: r.max(c),
+                size: self.n,
+            });
+        }
+        Ok(self
+            .position(r, c)
+            .map_or_else(T::zero, |k| self.data[k].clone()))
+    }
+
+    /// 要素 (r, c) を書き換える。帯の外は書き込めない。
+    pub fn set(&mut self, r: usize, c: usize, value: T) -> Result<()> {
+        match self.position(r, c) {
+            Some(k) => {
+                self.data[k] = value;
+                Ok(())
+            }
+            None => Err(LinalgError::InvalidArgument {
+                text: format!(
+                    "entry ({r}, {c}) lies outside the band ({}, {})",
+                    self.kl, self.ku
+                ),
+            }),
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut t = Self::zeros(self.n, self.ku, self.kl);
+        for i in 0..self.n {
+            for j in self.band_columns(i) {
+                let k = j * t.width() + i + t.kl - j;
+                t.data[k] = self.data[i * self.width() + j + self.kl - i].clone();
+            }
+        }
+        t
+    }
+
+    /// 帯行列 × ベクトル（O(n (kl + ku))）
+    pub fn checked_mul_vector(&self, rhs: &Vector<T>) -> Result<Vector<T>> {
+        if rhs.dim() != self.n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("vector dim {}", self.n),
+                found: format!("{}", rhs.dim()),
+            });
+        }
+        let data = (0..self.n)
+            .map(|i| {
+                self.band_columns(i).fold(T::zero(), |acc, j| {
+                    let a = self.data[i * self.width() + j + self.kl - i].clone();
+                    acc + a * rhs[j].clone()
+                })
+            })
+            .collect();
+        Ok(Vector::new(data))
+    }
+}
+
+impl<T: ComplexField> Banded<T> {
+    /// 部分ピボット付き帯 LU 分解。O(n kl (kl + ku)) で、帯の外へは fill-in しない。
+    pub fn lu(&self) -> Result<BandedLU<T>> {
+        let (n, kl, ku) = (self.n, self.kl, self.ku);
+        let w = 2 * kl + ku + 1;
+        let at = |i: usize, j: usize| i * w + j + kl - i;
+        // 各行の左端の位置（列 i - kl）をそろえて、右側に kl 個分の余白を足す
+        let mut lu = vec![T::zero(); n * w];
+        for i in 0..n {
+            let src = &self.data[i * self.width()..(i + 1) * self.width()];
+            lu[i * w..i * w + self.width()].clone_from_slice(src);
+        }
+        let mut pivots = Vec::with_capacity(n);
+        for k in 0..n {
+            let last = (k + kl).min(n - 1);
+            let right = (k + kl + ku).min(n - 1);
+            let mut p = k;
+            for i in k + 1..=last {
+                if lu[at(i, k)].modulus() > lu[at(p, k)].modulus() {
+                    p = i;
+                }
+            }
+            if lu[at(p, k)].modulus() <= T::tolerance() {
+                return Err(LinalgError::SingularMatrix);
+            }
+            pivots.push(p);
+            if p != k {
+                for j in k..=right {
+                    lu.swap(at(k, j), at(p, j));
+                }
+            }
+            let pivot = lu[at(k, k)].clone();
+            for i in k + 1..=last {
+                let l = lu[at(i, k)].clone() / pivot.clone();
+                lu[at(i, k)] = l.clone();
+                if l.is_zero() {
+                    continue;
+                }
+                for j in k + 1..=right {
+                    lu[at(i, j)] = lu[at(i, j)].clone() - l.clone() * lu[at(k, j)].clone();
+                }
+            }
+        }
+        Ok(BandedLU {
+            n,
+            kl,
+            ku,
+            lu,
+            pivots,
+        })
+    }
+
+    /// 帯 LU 分解で A x = b を解く
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        self.lu()?.solve(b)
+    }
+}
+
+impl<T: ComplexField> BandedLU<T> {
+    fn at(&self, i: usize, j: usize) -> usize {
+        i * (2 * self.kl + self.ku + 1) + j + self.kl - i
+    }
+
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        let n = self.n;
+        if b.dim() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{n}-dimensional vector"),
+                found: format!("{}-dimensional vector", b.dim()),
+            });
+        }
+        let mut x = b.data.clone();
+        // 行交換と L の消去を分解と同じ順に適用する
+        for k in 0..n {
+            x.swap(k, self.pivots[k]);
+            for i in k + 1..=(k + self.kl).min(n - 1) {
+                x[i] = x[i].clone() - self.lu[self.at(i, k)].clone() * x[k].clone();
+            }
+        }
+        for i in (0..n).rev() {
+            let right = (i + self.kl + self.ku).min(n - 1);
+            let s = (i + 1..=right).fold(x[i].clone(), |s, j| {
+                s - self.lu[self.at(i, j)].clone() * x[j].clone()
+            });
+            x[i] = s / self.lu[self.at(i, i)].clone();
+        }
+        Ok(Vector::new(x))
+    }
+
+    pub fn determinant(&self) -> T {
+        (0..self.n).fold(T::one(), |det, k| {
+            let d = det * self.lu[self.at(k, k)].clone();
+            if self.pivots[k] != k {
+                -d
+            } else {
+                d
+            }
+        })
+    }
+}
+
+impl Banded<f64> {
+    /// 対称正定値な帯行列（kl = ku）の Cholesky 分解。下側の帯だけを読む。O(n p^2)。
+    pub fn cholesky(&self) -> Result<BandedCholesky> {
+        if self.kl != self.ku {
+            return Err(LinalgError::InvalidArgument {
+                text: format!(
+                    "symmetric band matrix required, got bandwidths ({}, {})",
+                    self.kl, self.ku
+                ),
+            });
+        }
+        let (n, p) = (self.n, self.kl);
+        let mut l = Banded::zeros(n, p, 0);
+        for i in 0..n {
+            for j in i.saturating_sub(p)..=i {
+                let mut s = self.data[i * self.width() + j + p - i];
+                for k in i.saturating_sub(p)..j {
+                    s -= l.data[i * (p + 1) + k + p - i] * l.data[j * (p + 1) + k + p - j];
+                }
+                if i == j {
+                    if s <= 0.0 {
+                        return Err(LinalgError::InvalidArgument {
+                            text: "Matrix is not positive-definite".into(),
+                        });
+                    }
+                    l.data[i * (p + 1) + p] = s.sqrt();
+                } else {
+                    l.data[i * (p + 1) + j + p - i] = s / l.data[j * (p + 1) + p];
+                }
+            }
+        }
+        Ok(BandedCholesky { l })
+    }
+}
+
+impl BandedCholesky {
+    /// L y = b、L^T x = y を順に解く
+    pub fn solve(&self, b: &Vector<f64>) -> Result<Vector<f64>> {
+        let (n, p) = (self.l.n, self.l.kl);
+        if b.dim() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{n}-dimensional vector"),
+                found: format!("{}-dimensional vector", b.dim()),
+            });
+        }
+        let l = |i: usize, j: usize| self.l.data[i * (p + 1) + j + p - i];
+        let mut x = b.data.clone();
+        for i in 0..n {
+            let s: f64 = (i.saturating_sub(p)..i).map(|k| l(i, k) * x[k]).sum();
+            x[i] = (x[i] - s) / l(i, i);
+        }
+        for i in (0..n).rev() {
+            let s: f64 = (i + 1..(i + p + 1).min(n)).map(|k| l(k, i) * x[k]).sum();
+            x[i] = (x[i] - s) / l(i, i);
+        }
+        Ok(Vector::new(x))
+    }
+}
+
+impl LinearOperator for Banded<f64> {
+    fn nrows(&self) -> usize {
+        self.n
+    }
+    fn ncols(&self) -> usize {
+        self.n
+    }
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+        let data = (0..self.n)
+            .map(|i| {
+                self.band_columns(i)
+                    .map(|j| self.data[i * self.width() + j + self.kl - i] * x[j])
+                    .sum()
+            })
+            .collect();
+        Vector::new(data)
+    }
+    fn apply_transpose(&self, x: &Vector<f64>) -> Vector<f64> {
+        let mut y = vec![0.0; self.n];
+        for i in 0..self.n {
+            for j in self.band_columns(i) {
+                y[j] += self.data[i * self.width() + j + self.kl - i] * x[i];
+            }
+        }
+        Vector::new(y)
+    }
+}
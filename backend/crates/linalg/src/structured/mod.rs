@@ -0,0 +1,52 @@
+use crate::Scalar;
+
+/// 三重対角行列（n×n）。
+/// `lower[i] = a_{i+1,i}`、`diag[i] = a_{ii}`、`upper[i] = a_{i,i+1}` で、副対角は長さ n - 1。
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tridiagonal<T: Scalar = f64> {
+    pub lower: Vec<T>,
+    pub diag: Vec<T>,
+    pub upper: Vec<T>,
+}
+
+/// 下帯幅 kl・上帯幅 ku の帯行列（n×n）。
+/// 行 i には列 i - kl ..= i + ku の kl + ku + 1 個の成分を行優先で並べ、
+/// a_ij は `data[i * (kl + ku + 1) + (j + kl - i)]` に置かれる（行列の外にはみ出す位置はゼロ）。
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Banded<T: Scalar = f64> {
+    pub n: usize,
+    pub kl: usize,
+    pub ku: usize,
+    pub data: Vec<T>,
+}
+
+/// 三角行列のどちら側に成分があるか
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Triangle {
+    Lower,
+    Upper,
+}
+
+/// 三角行列（n×n）。成分側だけを行優先で詰めて格納する（長さ n(n+1)/2）。
+/// 下三角では行 i が a_i0..=a_ii、上三角では行 i が a_ii..a_i(n-1) になる。
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Triangular<T: Scalar = f64> {
+    pub n: usize,
+    pub triangle: Triangle,
+    pub data: Vec<T>,
+}
+
+// サブモジュールを宣言
+mod banded;
+mod triangular;
+mod tridiagonal;
+
+#[cfg(test)]
+mod tests;
+
+// パブリックな再エクスポート
+pub use banded::{BandedCholesky, BandedLU};
@@ -0,0 +1,153 @@
+use super::*;
+use crate::matrix::numerical::{eigsh, KrylovOptions, Which};
+use crate::test_util::pseudo_random;
+use crate::{LinalgError, Matrix, Vector};
+
+fn max_abs_diff(a: &Vector<f64>, b: &Vector<f64>) -> f64 {
+    a.data
+        .iter()
+        .zip(&b.data)
+        .map(|(x, y)| (x - y).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn tridiagonal_thomas_and_products() {
+    let n = 50;
+    let t = Tridiagonal::new(
+        pseudo_random(n - 1, 1),
+        pseudo_random(n, 2).iter().map(|d| d + 4.0).collect(),
+        pseudo_random(n - 1, 3),
+    )
+    .unwrap();
+    let dense = t.to_dense();
+    assert_eq!(Tridiagonal::from_dense(&dense).unwrap(), t);
+    let x = Vector::new(pseudo_random(n, 4));
+    let b = t.checked_mul_vector(&x).unwrap();
+    assert!(max_abs_diff(&b, &dense.checked_mul_vector(&x).unwrap()) < 1e-14);
+    assert!(max_abs_diff(&t.solve(&b).unwrap(), &x) < 1e-12);
+    assert_eq!(t.get(3, 2).unwrap(), t.lower[2]);
+    assert_eq!(t.get(0, 5).unwrap(), 0.0);
+    assert_eq!(t.transpose().to_dense(), dense.transpose());
+
+    // 帯の外に非零があれば変換できない
+    let mut bad = dense.clone();
+    bad[(0, 2)] = 1.0;
+    assert!(Tridiagonal::from_dense(&bad).is_err());
+    // ピボットが 0 になると Thomas 法は失敗するが、ピボット付き帯 LU なら解ける
+    let z = Tridiagonal::new(vec![1.0], vec![0.0, 1.0], vec![1.0]).unwrap();
+    let rhs = Vector::new(vec![2.0, 3.0]);
+    assert!(matches!(z.solve(&rhs), Err(LinalgError::SingularMatrix)));
+    let sol = z.to_banded().solve(&rhs).unwrap();
+    assert!(max_abs_diff(&sol, &Vector::new(vec![1.0, 2.0])) < 1e-15);
+}
+
+#[test]
+fn banded_lu_with_pivoting() {
+    let (n, kl, ku) = (40, 2, 3);
+    let mut a = Banded::zeros(n, kl, ku);
+    let values = pseudo_random(n * (kl + ku + 1), 5);
+    for i in 0..n {
+        for j in i.saturating_sub(kl)..(i + ku + 1).min(n) {
+            a.set(i, j, values[i * (kl + ku + 1) + j + kl - i]).unwrap();
+        }
+        // 対角を小さくして行交換を起こさせる
+        a.set(i, i, 1e-3 * values[i]).unwrap();
+    }
+    assert!(a.set(0, ku + 1, 1.0).is_err());
+    let dense = a.to_dense();
+    assert_eq!(Banded::from_dense(&dense, kl, ku).unwrap(), a);
+    assert_eq!(a.transpose().to_dense(), dense.transpose());
+
+    let x = Vector::new(pseudo_random(n, 6));
+    let b = a.checked_mul_vector(&x).unwrap();
+    let lu = a.lu().unwrap();
+    assert!(max_abs_diff(&lu.solve(&b).unwrap(), &x) < 1e-9);
+
+    let small = Banded::from_dense(&dense.submatrix(0, 8, 0, 8), kl, ku).unwrap();
+    let det = small.lu().unwrap().determinant();
+    let expected = dense.submatrix(0, 8, 0, 8).determinant().unwrap();
+    assert!((det - expected).abs() < 1e-10 * expected.abs().max(1.0));
+}
+
+#[test]
+fn banded_cholesky() {
+    // 五重対角の対称正定値行列（離散重調和作用素 + 単位行列）
+    let n = 30;
+    let mut a = Banded::zeros(n, 2, 2);
+    for i in 0..n {
+        a.set(i, i, 7.0).unwrap();
+        if i + 1 < n {
+            a.set(i, i + 1, -4.0).unwrap();
+            a.set(i + 1, i, -4.0).unwrap();
+        }
+        if i + 2 < n {
+            a.set(i, i + 2, 1.0).unwrap();
+            a.set(i + 2, i, 1.0).unwrap();
+        }
+    }
+    let chol = a.cholesky().unwrap();
+    assert_eq!((chol.l.kl, chol.l.ku), (2, 0));
+    let l = chol.l.to_dense();
+    let llt = &l * &l.transpose();
+    let dense = a.to_dense();
+    assert!(llt
+        .data
+        .iter()
+        .zip(&dense.data)
+        .all(|(x, y)| (x - y).abs() < 1e-12));
+
+    let x = Vector::new(pseudo_random(n, 7));
+    let b = a.checked_mul_vector(&x).unwrap();
+    assert!(max_abs_diff(&chol.solve(&b).unwrap(), &x) < 1e-10);
+
+    a.set(0, 0, -1.0).unwrap();
+    assert!(a.cholesky().is_err());
+    assert!(Banded::<f64>::zeros(3, 1, 0).cholesky().is_err());
+}
+
+#[test]
+fn triangular_packed_storage_and_solve() {
+    let lower = Triangular::new(3, Triangle::Lower, vec![2.0, 1.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    let dense = Matrix::new(3, 3, vec![2.0, 0.0, 0.0, 1.0, 3.0, 0.0, 4.0, 5.0, 6.0]).unwrap();
+    assert_eq!(lower.to_dense(), dense);
+    assert_eq!(
+        Triangular::from_dense(&dense, Triangle::Lower).unwrap(),
+        lower
+    );
+    assert!(Triangular::from_dense(&dense, Triangle::Upper).is_err());
+    assert_eq!(lower.get(2, 1).unwrap(), 5.0);
+    assert_eq!(lower.get(1, 2).unwrap(), 0.0);
+    assert_eq!(lower.determinant(), 36.0);
+
+    let upper = lower.transpose();
+    assert_eq!(upper.triangle, Triangle::Upper);
+    assert_eq!(upper.data, vec![2.0, 1.0, 4.0, 3.0, 5.0, 6.0]);
+    assert_eq!(upper.to_dense(), dense.transpose());
+
+    let x = Vector::new(vec![1.0, -2.0, 0.5]);
+    for t in [&lower, &upper] {
+        let b = t.checked_mul_vector(&x).unwrap();
+        assert!(max_abs_diff(&b, &t.to_dense().checked_mul_vector(&x).unwrap()) < 1e-15);
+        assert!(max_abs_diff(&t.solve(&b).unwrap(), &x) < 1e-14);
+    }
+    let singular = Triangular::new(2, Triangle::Upper, vec![1.0, 2.0, 0.0]).unwrap();
+    assert!(matches!(
+        singular.solve(&Vector::new(vec![1.0, 1.0])),
+        Err(LinalgError::SingularMatrix)
+    ));
+}
+
+#[test]
+fn tridiagonal_as_linear_operator() {
+    let n = 200;
+    let lap = Tridiagonal::from_constants(n, -1.0, 2.0, -1.0);
+    let opts = KrylovOptions::default();
+    let eig = eigsh(&lap, 3, Which::LargestReal, &opts).unwrap();
+    assert!(eig.converged);
+    for (j, v) in eig.values.iter().enumerate() {
+        let k = (n - j) as f64;
+        let exact = 2.0 - 2.0 * (k * std::f64::consts::PI / (n + 1) as f64).cos();
+        assert!((v - exact).abs() < 1e-8, "{v} vs {exact}");
+    }
+}
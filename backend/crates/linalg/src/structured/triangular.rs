@@ -0,0 +1,197 @@
+use super::{Triangle, Triangular};
+use crate::matrix::numerical::LinearOperator;
+use crate::{Field, LinalgError, Matrix, Result, Ring, Scalar, Vector};
+
+impl<T: Scalar> Triangular<T> {
+    pub fn new(n: usize, triangle: Triangle, data: Vec<T>) -> Result<Self> {
+        let len = n * (n + 1) / 2;
+        if data.len() != len {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{len} packed elements for n = {n}"),
+                found: format!("{} elements", data.len()),
+            });
+        }
+        Ok(Self { n, triangle, data })
+    }
+
+    /// (r, c) が成分側にあれば格納位置を返す
+    fn position(&self, r: usize, c: usize) -> Option<usize> {
+        if r >= self.n || c >= self.n {
+            return None;
+        }
+        match self.triangle {
+            Triangle::Lower if c <= r => Some(r * (r + 1) / 2 + c),
+            Triangle::Upper if c >= r => Some(r * self.n - r * r.saturating_sub(1) / 2 + c - r),
+            _ => None,
+        }
+    }
+
+    /// 行 r で成分側に入る列の範囲
+    fn row_columns(&self, r: usize) -> std::ops::Range<usize> {
+        match self.triangle {
+            Triangle::Lower => 0..r + 1,
+            Triangle::Upper => r..self.n,
+        }
+    }
+
+    /// 転置（下三角と上三角が入れ替わる）
+    pub fn transpose(&self) -> Self {
+        let triangle = match self.triangle {
+            Triangle::Lower => Triangle::Upper,
+            Triangle::Upper => Triangle::Lower,
+        };
+        let mut data = Vec::with_capacity(self.data.len());
+        let t = Self {
+            n: self.n,
+            triangle,
+            data: vec![],
+        };
+        for i in 0..self.n {
+            for j in t.row_columns(i) {
+                data.push(self.data[self.position(j, i).expect("inside the triangle")].clone());
+            }
+        }
+        Self { data, ..t }
+    }
+}
+
+impl<T: Ring> Triangular<T> {
+    /// 密行列から三角部分を取り出す。反対側に非零があればエラー。
+    pub fn from_dense(dense: &Matrix<T>, triangle: Triangle) -> Result<Self> {
+        if !dense.is_square() {
+            return Err(LinalgError::NotSquareMatrix);
+        }
+        let n = dense.rows;
+        let mut t = Self {
+            n,
+            triangle,
+            data: Vec::with_capacity(n * (n + 1) / 2),
+        };
+        for i in 0..n {
+            for j in 0..n {
+                if t.row_columns(i).contains(&j) {
+                    t.data.push(dense[(i, j)].clone());
+                } else if !dense[(i, j)].is_zero() {
+                    return Err(LinalgError::InvalidArgument {
+                        text: format!("entry ({i}, {j}) lies outside the {triangle:?} triangle"),
+                    });
+                }
+            }
+        }
+        Ok(t)
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut m = Matrix::zeros(self.n, self.n);
+        let mut k = 0;
+        for i in 0..self.n {
+            for j in self.row_columns(i) {
+                m[(i, j)] = self.data[k].clone();
+                k += 1;
+            }
+        }
+        m
+    }
+
+    /// 要素 (r, c) を返す。反対側の三角はゼロ。
+    pub fn get(&self, r: usize, c: usize) -> Result<T> {
+        if r >= self.n || c >= self.n {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: r.max(c),
+                size: self.n,
+            });
+        }
+        Ok(self
+            .position(r, c)
+            .map_or_else(T::zero, |k| self.data[k].clone()))
+    }
+
+    /// 対角成分の積
+    pub fn determinant(&self) -> T {
+        (0..self.n).fold(T::one(), |det, i| {
+            det * self.data[self.position(i, i).expect("diagonal")].clone()
+        })
+    }
+
+    /// 三角行列 × ベクトル（O(n^2 / 2)）
+    pub fn checked_mul_vector(&self, rhs: &Vector<T>) -> Result<Vector<T>> {
+        if rhs.dim() != self.n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("vector dim {}", self.n),
+                found: format!("{}", rhs.dim()),
+            });
+        }
+        let mut k = 0;
+        let mut y = Vec::with_capacity(self.n);
+        for i in 0..self.n {
+            let mut acc = T::zero();
+            for j in self.row_columns(i) {
+                acc = acc + self.data[k].clone() * rhs[j].clone();
+                k += 1;
+            }
+            y.push(acc);
+        }
+        Ok(Vector::new(y))
+    }
+}
+
+impl<T: Field> Triangular<T> {
+    /// 前進代入（下三角）または後退代入（上三角）で A x = b を解く
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        let n = self.n;
+        if b.dim() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{n}-dimensional vector"),
+                found: format!("{}-dimensional vector", b.dim()),
+            });
+        }
+        let order: Vec<usize> = match self.triangle {
+            Triangle::Lower => (0..n).collect(),
+            Triangle::Upper => (0..n).rev().collect(),
+        };
+        let mut x = vec![T::zero(); n];
+        for i in order {
+            let mut s = b[i].clone();
+            for j in self.row_columns(i).filter(|&j| j != i) {
+                s = s - self.data[self.position(i, j).expect("inside")].clone() * x[j].clone();
+            }
+            let diag = self.data[self.position(i, i).expect("diagonal")].clone();
+            if diag.is_zero() {
+                return Err(LinalgError::SingularMatrix);
+            }
+            x[i] = s / diag;
+        }
+        Ok(Vector::new(x))
+    }
+}
+
+impl LinearOperator for Triangular<f64> {
+    fn nrows(&self) -> usize {
+        self.n
+    }
+    fn ncols(&self) -> usize {
+        self.n
+    }
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+        let mut y = vec![0.0; self.n];
+        let mut k = 0;
+        for (i, yi) in y.iter_mut().enumerate() {
+            for j in self.row_columns(i) {
+                *yi += self.data[k] * x[j];
+                k += 1;
+            }
+        }
+        Vector::new(y)
+    }
+    fn apply_transpose(&self, x: &Vector<f64>) -> Vector<f64> {
+        let mut y = vec![0.0; self.n];
+        let mut k = 0;
+        for i in 0..self.n {
+            for j in self.row_columns(i) {
+                y[j] += self.data[k] * x[i];
+                k += 1;
+            }
+        }
+        Vector::new(y)
+    }
+}
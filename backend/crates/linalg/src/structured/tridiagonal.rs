@@ -0,0 +1,194 @@
+use super::{Banded, Tridiagonal};
+use crate::matrix::numerical::LinearOperator;
+use crate::{Field, LinalgError, Matrix, Result, Ring, Scalar, Vector};
+
+impl<T: Scalar> Tridiagonal<T> {
+    pub fn new(lower: Vec<T>, diag: Vec<T>, upper: Vec<T>) -> Result<Self> {
+        let off = diag.len().saturating_sub(1);
+        if lower.len() != off || upper.len() != off {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("off-diagonals of length {off}"),
+                found: format!("lengths {} and {}", lower.len(), upper.len()),
+            });
+        }
+        Ok(Self { lower, diag, upper })
+    }
+
+    pub fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            lower: self.upper.clone(),
+            diag: self.diag.clone(),
+            upper: self.lower.clone(),
+        }
+    }
+}
+
+impl<T: Ring> Tridiagonal<T> {
+    /// 対角・副対角がそれぞれ定数の n×n 行列（差分作用素 tridiag(-1, 2, -1) など）
+    pub fn from_constants(n: usize, lower: T, diag: T, upper: T) -> Self {
+        let off = n.saturating_sub(1);
+        Self {
+            lower: vec![lower; off],
+            diag: vec![diag; n],
+            upper: vec![upper; off],
+        }
+    }
+
+    /// 密行列から三重対角部分を取り出す。帯の外に非零があればエラー。
+    pub fn from_dense(dense: &Matrix<T>) -> Result<Self> {
+        let b = Banded::from_dense(dense, 1, 1)?;
+        let n = b.n;
+        let lower = (1..n).map(|i| b.data[3 * i].clone()).collect();
+        let diag = (0..n).map(|i| b.data[3 * i + 1].clone()).collect();
+        let upper = (0..n.saturating_sub(1))
+            .map(|i| b.data[3 * i + 2].clone())
+            .collect();
+        Ok(Self { lower, diag, upper })
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let n = self.size();
+        let mut m = Matrix::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = self.diag[i].clone();
+            if i + 1 < n {
+                m[(i + 1, i)] = self.lower[i].clone();
+                m[(i, i + 1)] = self.upper[i].clone();
+            }
+        }
+        m
+    }
+
+    /// 帯幅 (1, 1) の帯行列として格納し直す
+    pub fn to_banded(&self) -> Banded<T> {
+        let n = self.size();
+        let mut b = Banded::zeros(n, 1, 1);
+        for i in 0..n {
+            b.data[3 * i + 1] = self.diag[i].clone();
+            if i > 0 {
+                b.data[3 * i] = self.lower[i - 1].clone();
+            }
+            if i + 1 < n {
+                b.data[3 * i + 2] = self.upper[i].clone();
+            }
+        }
+        b
+    }
+
+    /// 要素 (r, c) を返す。三重対角の外はゼロ。
+    pub fn get(&self, r: usize, c: usize) -> Result<T> {
+        let n = self.size();
+        if r >= n || c >= n {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: r.max(c),
+                size: n,
+            });
+        }
+        Ok(match c as isize - r as isize {
+            0 => self.diag[r].clone(),
+            -1 => self.lower[c].clone(),
+            1 => self.upper[r].clone(),
+            _ => T::zero(),
+        })
+    }
+
+    /// 三重対角行列 × ベクトル（O(n)）
+    pub fn checked_mul_vector(&self, rhs: &Vector<T>) -> Result<Vector<T>> {
+        if rhs.dim() != self.size() {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("vector dim {}", self.size()),
+                found: format!("{}", rhs.dim()),
+            });
+        }
+        Ok(Vector::new(tridiagonal_mul(
+            &self.lower,
+            &self.diag,
+            &self.upper,
+            &rhs.data,
+        )))
+    }
+}
+
+impl<T: Field> Tridiagonal<T> {
+    /// Thomas 法（ピボットなしの LU）で A x = b を O(n) で解く。
+    /// 対角優位や正定値な行列では安定。それ以外では `to_banded().lu()` でピボット付きに解く。
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        let n = self.size();
+        if b.dim() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: format!("{n}-dimensional vector"),
+                found: format!("{}-dimensional vector", b.dim()),
+            });
+        }
+        if n == 0 {
+            return Ok(Vector::new(vec![]));
+        }
+        // 前進消去: c'_i = c_i / m_i, d'_i = (d_i - a_{i-1} d'_{i-1}) / m_i
+        let mut c: Vec<T> = Vec::with_capacity(n - 1);
+        let mut d: Vec<T> = Vec::with_capacity(n);
+        for i in 0..n {
+            let (mut m, mut rhs) = (self.diag[i].clone(), b[i].clone());
+            if i > 0 {
+                m = m - self.lower[i - 1].clone() * c[i - 1].clone();
+                rhs = rhs - self.lower[i - 1].clone() * d[i - 1].clone();
+            }
+            if m.is_zero() {
+                return Err(LinalgError::SingularMatrix);
+            }
+            if i + 1 < n {
+                c.push(self.upper[i].clone() / m.clone());
+            }
+            d.push(rhs / m);
+        }
+        // 後退代入
+        for i in (0..n - 1).rev() {
+            d[i] = d[i].clone() - c[i].clone() * d[i + 1].clone();
+        }
+        Ok(Vector::new(d))
+    }
+}
+
+impl LinearOperator for Tridiagonal<f64> {
+    fn nrows(&self) -> usize {
+        self.size()
+    }
+    fn ncols(&self) -> usize {
+        self.size()
+    }
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+        Vector::new(tridiagonal_mul(
+            &self.lower,
+            &self.diag,
+            &self.upper,
+            &x.data,
+        ))
+    }
+    fn apply_transpose(&self, x: &Vector<f64>) -> Vector<f64> {
+        Vector::new(tridiagonal_mul(
+            &self.upper,
+            &self.diag,
+            &self.lower,
+            &x.data,
+        ))
+    }
+}
+
+fn tridiagonal_mul<T: Ring>(lower: &[T], diag: &[T], upper: &[T], x: &[T]) -> Vec<T> {
+    let n = diag.len();
+    (0..n)
+        .map(|i| {
+            let mut y = diag[i].clone() * x[i].clone();
+            if i > 0 {
+                y = y + lower[i - 1].clone() * x[i - 1].clone();
+            }
+            if i + 1 < n {
+                y = y + upper[i].clone() * x[i + 1].clone();
+            }
+            y
+        })
+        .collect()
+}
@@ -0,0 +1,21 @@
+//! テスト専用の共通ヘルパー
+
+use crate::Matrix;
+
+/// 決定的な擬似乱数列 (-0.5, 0.5)（PCG の LCG 部分）
+pub(crate) fn pseudo_random(len: usize, seed: u64) -> Vec<f64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+        })
+        .collect()
+}
+
+/// `pseudo_random` を行優先で並べた rows x cols 行列
+pub(crate) fn pseudo_random_matrix(rows: usize, cols: usize, seed: u64) -> Matrix<f64> {
+    Matrix::new(rows, cols, pseudo_random(rows * cols, seed)).unwrap()
+}
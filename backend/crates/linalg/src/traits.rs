@@ -1,3 +1,5 @@
+use num_complex::Complex;
+use num_integer::Integer;
 use num_traits::{One, Signed, Zero};
 use std::fmt::Debug;
 use std::iter::Sum;
@@ -36,7 +38,34 @@ impl<T> Ring for T where
 // Ringであり、かつDiv<Output=T>を持つ型は、自動的にFieldトレイトを実装する
 impl<T: Ring + Div<Output = T>> Field for T {}
 
-pub trait LinalgField: Field + Signed + PartialOrd {
+/// ブロック化 GEMM カーネル（パッキング + マイクロカーネル）を使える実数型
+pub trait GemmScalar: Ring + Copy + Send + Sync {}
+
+impl GemmScalar for f64 {}
+impl GemmScalar for f32 {}
+
+/// 除算を使わずに消去する整数行列アルゴリズム（Hermite / Smith 標準形）用の整数環。
+/// i32 / i64 / i128 / BigInt が該当する。
+pub trait IntegerRing: Ring + Integer + Signed {}
+
+impl<T: Ring + Integer + Signed> IntegerRing for T {}
+
+/// 実数と複素数の両方を扱う数値計算用の体（f32 / f64 / Complex<f32> / Complex<f64>）。
+/// 大小比較の代わりに大きさ `modulus` でピボットを選ぶ LU などで使う。
+pub trait ComplexField: Field {
+    /// 特異判定などに使う絶対許容値（f64 で 1e-12）。丸め誤差の単位ではない点に注意。
+    fn tolerance() -> f64;
+    /// 大きさ（実数は絶対値、複素数は絶対値 |z|）
+    fn modulus(&self) -> f64;
+    /// 計算機イプシロン（1 と、1 より大きい最小の浮動小数点数との差）
+    fn machine_epsilon() -> f64;
+    /// 複素共役（実数はそのまま）
+    fn conj(&self) -> Self;
+    /// 実数からの変換
+    fn from_f64(v: f64) -> Self;
+}
+
+pub trait LinalgField: ComplexField + Signed + PartialOrd {
     // 必要に応じて、epsilon()のような小さな値を返すメソッドを定義
     fn epsilon() -> Self;
 }
@@ -52,3 +81,75 @@ impl LinalgField for f32 {
         1e-6
     }
 }
+
+impl ComplexField for f64 {
+    fn tolerance() -> f64 {
+        1e-12
+    }
+    fn modulus(&self) -> f64 {
+        self.abs()
+    }
+    fn machine_epsilon() -> f64 {
+        f64::EPSILON
+    }
+    fn conj(&self) -> Self {
+        *self
+    }
+    fn from_f64(v: f64) -> Self {
+        v
+    }
+}
+
+impl ComplexField for f32 {
+    fn tolerance() -> f64 {
+        1e-6
+    }
+    fn modulus(&self) -> f64 {
+        self.abs() as f64
+    }
+    fn machine_epsilon() -> f64 {
+        f32::EPSILON as f64
+    }
+    fn conj(&self) -> Self {
+        *self
+    }
+    fn from_f64(v: f64) -> Self {
+        v as f32
+    }
+}
+
+impl ComplexField for Complex<f64> {
+    fn tolerance() -> f64 {
+        1e-12
+    }
+    fn modulus(&self) -> f64 {
+        self.norm()
+    }
+    fn machine_epsilon() -> f64 {
+        f64::EPSILON
+    }
+    fn conj(&self) -> Self {
+        Complex::conj(self)
+    }
+    fn from_f64(v: f64) -> Self {
+        Complex::new(v, 0.0)
+    }
+}
+
+impl ComplexField for Complex<f32> {
+    fn tolerance() -> f64 {
+        1e-6
+    }
+    fn modulus(&self) -> f64 {
+        self.norm() as f64
+    }
+    fn machine_epsilon() -> f64 {
+        f32::EPSILON as f64
+    }
+    fn conj(&self) -> Self {
+        Complex::conj(self)
+    }
+    fn from_f64(v: f64) -> Self {
+        Complex::new(v as f32, 0.0)
+    }
+}
@@ -0,0 +1,57 @@
+use linalg::matrix::numerical::{HermitianEigenDecomposition, SvdDeComposition};
+use linalg::{Matrix, Vector};
+use num_complex::Complex;
+
+type C64 = Complex<f64>;
+
+fn c(re: f64, im: f64) -> C64 {
+    C64::new(re, im)
+}
+
+#[test]
+fn complex_lu_solves_system() {
+    let a = Matrix::new(
+        3,
+        3,
+        vec![
+            c(0.0, 0.0),
+            c(2.0, 1.0),
+            c(1.0, 0.0),
+            c(1.0, -1.0),
+            c(0.0, 3.0),
+            c(2.0, 0.0),
+            c(4.0, 0.0),
+            c(1.0, 1.0),
+            c(0.0, -1.0),
+        ],
+    )
+    .unwrap();
+    let x_true = Vector::new(vec![c(1.0, 2.0), c(-1.0, 0.0), c(0.5, -0.5)]);
+    let b = &a * &x_true;
+    let x = a.solve(&b).expect("complex solve failed");
+    for i in 0..3 {
+        assert!((x[i] - x_true[i]).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn music_style_noise_subspace() {
+    // 2 素子アレイの 1 波源: R = a a^H + σ^2 I。最小固有ベクトルは a に直交する
+    let steer = [c(1.0, 0.0), c(0.0, 1.0)];
+    let mut r: Matrix<C64> = Matrix::identity(2);
+    for i in 0..2 {
+        for j in 0..2 {
+            r[(i, j)] = r[(i, j)] * 0.1 + steer[i] * steer[j].conj();
+        }
+    }
+    let eig = r.hermitian_eigen().unwrap();
+    assert!((eig.eigen_values[0] - 0.1).abs() < 1e-12);
+    assert!((eig.eigen_values[1] - 2.1).abs() < 1e-12);
+    let noise = [eig.eigen_vectors[(0, 0)], eig.eigen_vectors[(1, 0)]];
+    let proj = steer[0].conj() * noise[0] + steer[1].conj() * noise[1];
+    assert!(proj.norm() < 1e-12);
+
+    let svd = r.svd().unwrap();
+    assert!((svd.sigma[0] - 2.1).abs() < 1e-12);
+    assert!((svd.sigma[1] - 0.1).abs() < 1e-12);
+}
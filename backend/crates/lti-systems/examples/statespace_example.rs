@@ -16,11 +16,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // ZOH で離散化
     let fs = 20.0; // 20 Hz
-    let dss = css.c2d_zoh(fs);
+    let dss = css.c2d_zoh(fs).expect("ZOH discretization failed");
     println!("DSS (ZOH @ {fs} Hz): {dss}");
 
     // ショートカット: 直接 TF から ZOH 離散化
-    let dss2 = tf_c2d_zoh_siso(&num, &den, fs);
+    let dss2 = tf_c2d_zoh_siso(&num, &den, fs).expect("ZOH discretization failed");
     println!("DSS2 (from TF, ZOH): {dss2}");
 
     Ok(())
@@ -1,4 +1,5 @@
 use crate::conversions::{bilinear_transform, bilinear_transform_prewarp, inverse_tustin};
+use crate::error::Result;
 use crate::statespace::{tf_c2d_zoh_siso, DiscreteStateSpace};
 use num_complex::Complex;
 use poly::{polynomial::Polynomial, rational_function::RationalFunction};
@@ -26,7 +27,8 @@ impl TransferFunction {
         self.ratio.denominator.find_roots()
     }
     pub fn is_stable(&self) -> bool {
-        self.poles().into_iter().all(|p| p.re < 0.0)
+        // 数値的な極ではなく係数から Routh–Hurwitz で厳密に判定する（虚軸上の極は不安定）
+        self.ratio.denominator.is_hurwitz_stable()
     }
     pub fn eval_s(&self, s: Complex<f64>) -> Complex<f64> {
         let num = self.ratio.numerator.to_complex().eval(s);
@@ -63,7 +65,7 @@ impl TransferFunction {
     }
 
     /// 厳密ZOH離散化（SISO前提）: 離散の状態空間を返す
-    pub fn to_discrete_zoh_statespace(&self, fs: f64) -> DiscreteStateSpace {
+    pub fn to_discrete_zoh_statespace(&self, fs: f64) -> Result<DiscreteStateSpace> {
         tf_c2d_zoh_siso(&self.ratio.numerator, &self.ratio.denominator, fs)
     }
 
@@ -77,15 +79,15 @@ impl TransferFunction {
 
     /// インパルス応答（連続系）をサンプリングして長さ len を返す。
     /// 実装は SISO連続TFを ZOH 厳密離散化して離散状態空間でシミュレーションします。
-    pub fn impulse_response(&self, fs: f64, len: usize) -> Vec<f64> {
-        let ss = self.to_discrete_zoh_statespace(fs);
-        crate::responses::impulse_response_discrete_ss(&ss, len)
+    pub fn impulse_response(&self, fs: f64, len: usize) -> Result<Vec<f64>> {
+        let ss = self.to_discrete_zoh_statespace(fs)?;
+        Ok(crate::responses::impulse_response_discrete_ss(&ss, len))
     }
 
     /// ステップ応答（連続系）をサンプリングして長さ len を返す。
-    pub fn step_response(&self, fs: f64, len: usize) -> Vec<f64> {
-        let ss = self.to_discrete_zoh_statespace(fs);
-        crate::responses::step_response_discrete_ss(&ss, len)
+    pub fn step_response(&self, fs: f64, len: usize) -> Result<Vec<f64>> {
+        let ss = self.to_discrete_zoh_statespace(fs)?;
+        Ok(crate::responses::step_response_discrete_ss(&ss, len))
     }
 
     /// 周波数応答 G(jω) を n_freqs 点で返す（ω [rad/s] を等間隔サンプル）。
@@ -40,7 +40,8 @@ impl TransferFunction {
         self.ratio.denominator.find_roots()
     }
     pub fn is_stable(&self) -> bool {
-        self.poles().into_iter().all(|p| p.norm() < 1.0)
+        // 数値的な極ではなく係数から厳密に判定する（単位円上の極は不安定）
+        self.ratio.denominator.is_schur_stable()
     }
     pub fn eval_z(&self, z: Complex<f64>) -> Complex<f64> {
         let num = self.ratio.numerator.to_complex().eval(z);
@@ -1,9 +1,14 @@
 use std::fmt;
 
+use linalg::LinalgError;
+use ode::OdeError;
+
 #[derive(Debug, Clone)]
 pub enum LtiError {
     InvalidArgument { text: String },
     NotImplemented,
+    Ode(OdeError),
+    Linalg(LinalgError),
 }
 
 impl fmt::Display for LtiError {
@@ -11,10 +16,24 @@ impl fmt::Display for LtiError {
         match self {
             LtiError::InvalidArgument { text } => write!(f, "Invalid argument: {text}"),
             LtiError::NotImplemented => write!(f, "Feature not yet implemented"),
+            LtiError::Ode(e) => write!(f, "ODE error: {e}"),
+            LtiError::Linalg(e) => write!(f, "Linear algebra error: {e}"),
         }
     }
 }
 
 impl std::error::Error for LtiError {}
 
+impl From<OdeError> for LtiError {
+    fn from(e: OdeError) -> Self {
+        LtiError::Ode(e)
+    }
+}
+
+impl From<LinalgError> for LtiError {
+    fn from(e: LinalgError) -> Self {
+        LtiError::Linalg(e)
+    }
+}
+
 pub type Result<T> = std::result::Result<T, LtiError>;
@@ -739,11 +739,7 @@ fn draw_logx_series_multi(
     let origin_y = y0 + margin_t;
 
     // y 範囲
-    let (mut min_y, mut max_y) = if let Some(r) = y_range {
-        r
-    } else {
-        (f64::INFINITY, f64::NEG_INFINITY)
-    };
+    let (mut min_y, mut max_y) = y_range.unwrap_or((f64::INFINITY, f64::NEG_INFINITY));
     if y_range.is_none() {
         for (ys, _, _) in series.iter() {
             for &v in ys.iter() {
@@ -895,11 +891,7 @@ fn draw_linearx_series_multi(
     let origin_y = y0 + margin_t;
 
     // y 範囲
-    let (mut min_y, mut max_y) = if let Some(r) = y_range {
-        r
-    } else {
-        (f64::INFINITY, f64::NEG_INFINITY)
-    };
+    let (mut min_y, mut max_y) = y_range.unwrap_or((f64::INFINITY, f64::NEG_INFINITY));
     if y_range.is_none() {
         for (ys, _, _) in series.iter() {
             for &v in ys.iter() {
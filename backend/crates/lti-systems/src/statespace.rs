@@ -1,9 +1,13 @@
 use linalg::matrix::numerical::exp::MatrixExponential;
 use linalg::matrix::Matrix;
+use linalg::Vector;
+use ode::{OdeError, OdeMethod, OdeOptions, OdeProblem};
 use poly::polynomial::Polynomial;
 use poly::rational_function::RationalFunction;
 use std::fmt;
 
+use crate::error::{LtiError, Result};
+
 /// 連続時間の状態空間表現
 #[derive(Clone, Debug, PartialEq)]
 pub struct ContinuousStateSpace {
@@ -24,8 +28,12 @@ pub struct DiscreteStateSpace {
 
 impl ContinuousStateSpace {
     /// ZOH 厳密離散化（サンプリング周期 Ts = 1/fs）
-    pub fn c2d_zoh(&self, fs: f64) -> DiscreteStateSpace {
-        assert!(fs > 0.0);
+    pub fn c2d_zoh(&self, fs: f64) -> Result<DiscreteStateSpace> {
+        if !(fs > 0.0 && fs.is_finite()) {
+            return Err(LtiError::InvalidArgument {
+                text: format!("c2d_zoh: sample rate must be positive and finite, got {fs}"),
+            });
+        }
         let ts = 1.0 / fs;
         let n = self.a.rows;
         let m = self.b.cols; // 入力数
@@ -43,8 +51,8 @@ impl ContinuousStateSpace {
                 mtx[(i, n + j)] = self.b[(i, j)] * ts;
             }
         }
-        // expm(M)
-        let em = mtx.expm();
+        // expm(M)（Padé 近似なので剛性の強い A でも精度が落ちにくい）
+        let em = mtx.expm()?;
         // Ad = expm(A*Ts) は左上 n×n
         let mut ad = Matrix::zeros(n, n);
         for i in 0..n {
@@ -60,12 +68,88 @@ impl ContinuousStateSpace {
             }
         }
         // ZOH では C, D は同一
-        DiscreteStateSpace {
+        Ok(DiscreteStateSpace {
             a: ad,
             b: bd,
             c: self.c.clone(),
             d: self.d.clone(),
+        })
+    }
+
+    /// 任意入力に対する時間応答（scipy.signal.lsim 相当）。
+    /// u は len×m（行 k が時刻 t[k] の入力）で、サンプル間は線形補間する。
+    /// 各区間を RK45 で積分し、出力 y（len×p）と状態 x（len×n）を返す。
+    pub fn lsim(
+        &self,
+        u: &Matrix<f64>,
+        t: &[f64],
+        x0: &Vector<f64>,
+    ) -> Result<(Matrix<f64>, Matrix<f64>)> {
+        let (n, m, p) = (self.a.rows, self.b.cols, self.c.rows);
+        let len = t.len();
+        if len == 0 || u.rows != len || u.cols != m || x0.dim() != n {
+            return Err(LtiError::InvalidArgument {
+                text: format!(
+                    "lsim: expected u of {len}x{m} and x0 of length {n}, got u {}x{} and x0 of length {}",
+                    u.rows,
+                    u.cols,
+                    x0.dim()
+                ),
+            });
+        }
+        if t.iter().any(|v| !v.is_finite()) || t.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(LtiError::InvalidArgument {
+                text: "lsim: t must be finite and strictly increasing".to_string(),
+            });
+        }
+        let opts = OdeOptions {
+            rtol: 1e-9,
+            atol: 1e-12,
+            ..Default::default()
+        };
+        let mut xs = Matrix::zeros(len, n);
+        let mut ys = Matrix::zeros(len, p);
+        let mut x = x0.clone();
+        for k in 0..len {
+            if k > 0 {
+                // 入力の折れ点で刻み幅制御が乱れないよう、サンプル区間ごとに積分し直す
+                let (ta, tb) = (t[k - 1], t[k]);
+                let rhs = |s: f64, x: &Vector<f64>| {
+                    let w = (s - ta) / (tb - ta);
+                    Vector::new(
+                        (0..n)
+                            .map(|i| {
+                                let ax: f64 = (0..n).map(|j| self.a[(i, j)] * x[j]).sum();
+                                let bu: f64 = (0..m)
+                                    .map(|j| {
+                                        let uj = (1.0 - w) * u[(k - 1, j)] + w * u[(k, j)];
+                                        self.b[(i, j)] * uj
+                                    })
+                                    .sum();
+                                ax + bu
+                            })
+                            .collect(),
+                    )
+                };
+                let sol = OdeProblem::new(rhs, (ta, tb), x).solve(OdeMethod::Rk45, &opts)?;
+                if !sol.status.is_success() {
+                    return Err(LtiError::Ode(OdeError::Incomplete {
+                        status: sol.status,
+                        t: sol.t.last().copied().unwrap_or(ta),
+                    }));
+                }
+                x = sol.y.last().cloned().unwrap_or_else(|| Vector::zeros(n));
+            }
+            for i in 0..n {
+                xs[(k, i)] = x[i];
+            }
+            for i in 0..p {
+                let cx: f64 = (0..n).map(|j| self.c[(i, j)] * x[j]).sum();
+                let du: f64 = (0..m).map(|j| self.d[(i, j)] * u[(k, j)]).sum();
+                ys[(k, i)] = cx + du;
+            }
         }
+        Ok((ys, xs))
     }
 
     /// 伝達関数（単入力単出力）から可制御正準形を構成
@@ -214,7 +298,7 @@ impl DiscreteStateSpace {
         // B0 = 0, c0 = 1
         let mut b_k = Matrix::<f64>::zeros(n, n);
         let mut c_prev = 1.0;
-        for k in 1..=n {
+        for (k, coeff) in coeffs.iter_mut().enumerate().take(n + 1).skip(1) {
             // Bk = A (B_{k-1} + c_{k-1} I)
             let mut inner = b_k.clone();
             for i in 0..n {
@@ -223,17 +307,16 @@ impl DiscreteStateSpace {
             b_k = a * &inner;
             let trace = (0..n).map(|i| b_k[(i, i)]).sum::<f64>();
             let ck = -trace / (k as f64);
-            coeffs[k] = ck;
+            *coeff = ck;
             c_prev = ck;
         }
 
         // 2) w_k の生成（w_0..w_{n-1}）
         let mut w: Vec<Matrix<f64>> = Vec::with_capacity(n);
         w.push(self.b.clone());
-        for k in 1..n {
+        for &ck in coeffs.iter().take(n).skip(1) {
             let mut next = &self.a * w.last().unwrap();
             // next += c_k * B
-            let ck = coeffs[k];
             if ck != 0.0 {
                 for i in 0..self.b.rows {
                     next[(i, 0)] += ck * self.b[(i, 0)];
@@ -291,7 +374,7 @@ pub fn tf_c2d_zoh_siso(
     num: &Polynomial<f64>,
     den: &Polynomial<f64>,
     fs: f64,
-) -> DiscreteStateSpace {
+) -> Result<DiscreteStateSpace> {
     let css = ContinuousStateSpace::from_tf_siso(num, den);
     css.c2d_zoh(fs)
 }
@@ -8,8 +8,8 @@ fn impulse_and_step_match_statespace_sim() {
     let n = 32;
 
     // 連続応答（ZOH離散化＋離散SSシミュレーション）
-    let h = tf.impulse_response(fs, n);
-    let s = tf.step_response(fs, n);
+    let h = tf.impulse_response(fs, n).unwrap();
+    let s = tf.step_response(fs, n).unwrap();
 
     // 1) 解析解（t = n/fs における 1 - e^{-t}）と比較
     let mut max_err = 0.0_f64;
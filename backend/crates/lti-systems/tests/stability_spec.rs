@@ -0,0 +1,32 @@
+use lti_systems::{ContinuousTransferFunction, DiscreteTransferFunction};
+
+#[test]
+fn continuous_stability_is_decided_exactly() {
+    // 1 / ((s + 1)(s² + 1)): 虚軸上の極 ±j は不安定
+    let marginal = ContinuousTransferFunction::from_coeffs(vec![1.0], vec![1.0, 1.0, 1.0, 1.0]);
+    assert!(!marginal.is_stable());
+
+    // s² + 1e−12 s + 1: 極の実部は −5e−13 で、数値的な根では符号が定まらないほど虚軸に近い
+    let damped = ContinuousTransferFunction::from_coeffs(vec![1.0], vec![1.0, 1e-12, 1.0]);
+    assert!(damped.is_stable());
+    let undamped = ContinuousTransferFunction::from_coeffs(vec![1.0], vec![1.0, -1e-12, 1.0]);
+    assert!(!undamped.is_stable());
+
+    // 積分器 1/s と 2 次遅れ系
+    assert!(!ContinuousTransferFunction::from_coeffs(vec![1.0], vec![0.0, 1.0]).is_stable());
+    assert!(ContinuousTransferFunction::from_coeffs(vec![1.0], vec![2.0, 3.0, 1.0]).is_stable());
+}
+
+#[test]
+fn discrete_stability_is_decided_exactly() {
+    // 極 z = 1（和分器）と z = −1 は単位円上なので不安定
+    assert!(!DiscreteTransferFunction::from_coeffs(vec![1.0], vec![-1.0, 1.0]).is_stable());
+    assert!(!DiscreteTransferFunction::from_coeffs(vec![1.0], vec![1.0, 1.0]).is_stable());
+    // (z − 0.5)(z + 0.25)
+    assert!(DiscreteTransferFunction::from_coeffs(vec![1.0], vec![-0.125, -0.25, 1.0]).is_stable());
+    // z² + 1: 極 ±j は単位円上
+    assert!(!DiscreteTransferFunction::from_coeffs(vec![1.0], vec![1.0, 0.0, 1.0]).is_stable());
+    // 単位円のわずかに内側の複素極 0.999999 e^{±jπ/3}: z² − r z + r²
+    let r = 0.999_999;
+    assert!(DiscreteTransferFunction::from_coeffs(vec![1.0], vec![r * r, -r, 1.0]).is_stable());
+}
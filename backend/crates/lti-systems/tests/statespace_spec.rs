@@ -0,0 +1,105 @@
+use linalg::matrix::Matrix;
+use linalg::Vector;
+use lti_systems::error::LtiError;
+use lti_systems::statespace::ContinuousStateSpace;
+
+#[test]
+fn c2d_zoh_stiff_diagonal_system_matches_closed_form() {
+    // A = diag(-1000, -1): ZOH 離散化は Ad = diag(e^{-1000 Ts}, e^{-Ts}), Bd_i = (1 - e^{a_i Ts}) / (-a_i)
+    let css = ContinuousStateSpace {
+        a: Matrix::new(2, 2, vec![-1000.0, 0.0, 0.0, -1.0]).unwrap(),
+        b: Matrix::new(2, 1, vec![1.0, 1.0]).unwrap(),
+        c: Matrix::new(1, 2, vec![1.0, 1.0]).unwrap(),
+        d: Matrix::new(1, 1, vec![0.0]).unwrap(),
+    };
+    let fs = 10.0;
+    let ts = 1.0 / fs;
+    let dss = css.c2d_zoh(fs).unwrap();
+
+    let poles = [-1000.0f64, -1.0];
+    for (i, &p) in poles.iter().enumerate() {
+        let ad = (p * ts).exp();
+        let bd = (1.0 - ad) / -p;
+        assert!(
+            (dss.a[(i, i)] - ad).abs() < 1e-12,
+            "Ad[{i}] = {}",
+            dss.a[(i, i)]
+        );
+        assert!(
+            (dss.b[(i, 0)] - bd).abs() < 1e-12,
+            "Bd[{i}] = {}",
+            dss.b[(i, 0)]
+        );
+    }
+    assert!(dss.a[(0, 1)].abs() < 1e-12 && dss.a[(1, 0)].abs() < 1e-12);
+}
+
+#[test]
+fn c2d_zoh_reports_errors_instead_of_panicking() {
+    let css = ContinuousStateSpace {
+        a: Matrix::new(1, 1, vec![f64::NAN]).unwrap(),
+        b: Matrix::new(1, 1, vec![1.0]).unwrap(),
+        c: Matrix::new(1, 1, vec![1.0]).unwrap(),
+        d: Matrix::new(1, 1, vec![0.0]).unwrap(),
+    };
+    // 拡大系の行列指数関数の失敗は LtiError::Linalg として返る
+    assert!(matches!(css.c2d_zoh(10.0), Err(LtiError::Linalg(_))));
+    let stable = ContinuousStateSpace {
+        a: Matrix::new(1, 1, vec![-1.0]).unwrap(),
+        ..css
+    };
+    assert!(matches!(
+        stable.c2d_zoh(0.0),
+        Err(LtiError::InvalidArgument { .. })
+    ));
+}
+
+#[test]
+fn lsim_matches_analytic_responses() {
+    // 1 / (s + 1) のステップ応答 1 - e^{-t}
+    let den = lti_systems::Polynomial::new(vec![1.0, 1.0]);
+    let num = lti_systems::Polynomial::new(vec![1.0]);
+    let css = ContinuousStateSpace::from_tf_siso(&num, &den);
+    let t: Vec<f64> = (0..=50).map(|k| k as f64 * 0.1).collect();
+    let u = Matrix::new(t.len(), 1, vec![1.0; t.len()]).unwrap();
+    let (y, x) = css.lsim(&u, &t, &Vector::new(vec![0.0])).unwrap();
+    assert_eq!((y.rows, y.cols, x.rows, x.cols), (51, 1, 51, 1));
+    for (k, &tk) in t.iter().enumerate() {
+        assert!((y[(k, 0)] - (1.0 - (-tk).exp())).abs() < 1e-8, "t = {tk}");
+    }
+
+    // ランプ入力 u = t: y = t - 1 + e^{-t}（線形補間は厳密）
+    let ramp = Matrix::new(t.len(), 1, t.clone()).unwrap();
+    let (y, _) = css.lsim(&ramp, &t, &Vector::new(vec![0.0])).unwrap();
+    for (k, &tk) in t.iter().enumerate() {
+        assert!(
+            (y[(k, 0)] - (tk - 1.0 + (-tk).exp())).abs() < 1e-8,
+            "t = {tk}"
+        );
+    }
+
+    // 非減衰の調和振動子 x'' + x = 0 の零入力応答（非等間隔の時刻列）
+    let osc = ContinuousStateSpace {
+        a: Matrix::new(2, 2, vec![0.0, 1.0, -1.0, 0.0]).unwrap(),
+        b: Matrix::new(2, 1, vec![0.0, 1.0]).unwrap(),
+        c: Matrix::new(1, 2, vec![1.0, 0.0]).unwrap(),
+        d: Matrix::new(1, 1, vec![0.0]).unwrap(),
+    };
+    let t = [0.0, 0.5, 2.0, 3.5, 7.0];
+    let zero = Matrix::zeros(t.len(), 1);
+    let (y, _) = osc.lsim(&zero, &t, &Vector::new(vec![1.0, 0.0])).unwrap();
+    for (k, &tk) in t.iter().enumerate() {
+        assert!((y[(k, 0)] - tk.cos()).abs() < 1e-8);
+    }
+
+    assert!(osc
+        .lsim(&zero, &[0.0, 1.0], &Vector::new(vec![1.0, 0.0]))
+        .is_err());
+    assert!(osc
+        .lsim(
+            &Matrix::zeros(2, 1),
+            &[1.0, 0.0],
+            &Vector::new(vec![1.0, 0.0])
+        )
+        .is_err());
+}
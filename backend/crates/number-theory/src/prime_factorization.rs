@@ -21,7 +21,7 @@ pub fn factor(n: u64) -> Vec<u64> {
 
     // 小さい素数での試し割り（2,3,5 の 30進ホイール）
     for &p in [2u64, 3, 5].iter() {
-        while m % p == 0 {
+        while m.is_multiple_of(p) {
             factors.push(p);
             m /= p;
         }
@@ -39,7 +39,7 @@ pub fn factor(n: u64) -> Vec<u64> {
             if d * d > m {
                 break;
             }
-            while m % d == 0 {
+            while m.is_multiple_of(d) {
                 factors.push(d);
                 m /= d;
             }
@@ -495,13 +495,13 @@ fn is_probable_prime_u64(n: u64) -> bool {
         if n == p {
             return true;
         }
-        if n % p == 0 {
+        if n.is_multiple_of(p) {
             return n == p;
         }
     }
     let mut d = n - 1;
     let mut s = 0u32;
-    while d % 2 == 0 {
+    while d.is_multiple_of(2) {
         d /= 2;
         s += 1;
     }
@@ -532,7 +532,7 @@ fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
 }
 
 fn pollards_rho_split_u64(n: u64) -> (u64, u64) {
-    if n % 2 == 0 {
+    if n.is_multiple_of(2) {
         return (2, n / 2);
     }
     let mut rng = thread_rng();
@@ -600,7 +600,7 @@ fn solve_square_roots(_n: &BigInt, _p: u64) -> Option<(BigInt, BigInt)> {
     }
     let mut q = p - 1;
     let mut s = 0u32;
-    while q % 2 == 0 {
+    while q.is_multiple_of(2) {
         q /= 2;
         s += 1;
     }
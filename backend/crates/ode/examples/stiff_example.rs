@@ -0,0 +1,57 @@
+use linalg::Vector;
+use ode::{Event, EventDirection, OdeMethod, OdeOptions, OdeProblem};
+
+fn main() {
+    // 目的: 硬い Van der Pol 方程式を陽的・陰的解法で解き、ステップ数と評価回数を比べる
+    let mu = 100.0;
+    println!("[Goal] Van der Pol 方程式 y'' = μ (1 - y^2) y' - y（μ = {mu}）を t ∈ [0, 200] で積分します。");
+    let rhs = move |_: f64, y: &Vector<f64>| {
+        Vector::new(vec![y[1], mu * (1.0 - y[0] * y[0]) * y[1] - y[0]])
+    };
+    let y0 = Vector::new(vec![2.0, 0.0]);
+    let opts = OdeOptions {
+        rtol: 1e-6,
+        atol: 1e-9,
+        ..Default::default()
+    };
+
+    println!(
+        "{:<8} {:>8} {:>8} {:>10} {:>6} {:>12}",
+        "method", "steps", "rejected", "f evals", "LU", "y(200)"
+    );
+    for (name, method) in [
+        ("RK45", OdeMethod::Rk45),
+        ("BDF", OdeMethod::Bdf),
+        ("Radau", OdeMethod::Radau),
+    ] {
+        let mut problem = OdeProblem::new(rhs, (0.0, 200.0), y0.clone());
+        match problem.solve(method, &opts) {
+            Ok(sol) => println!(
+                "{:<8} {:>8} {:>8} {:>10} {:>6} {:>12.6}",
+                name,
+                sol.steps,
+                sol.rejected_steps,
+                sol.evaluations,
+                sol.lu_decompositions,
+                sol.y.last().unwrap()[0]
+            ),
+            Err(e) => println!("{name:<8} error: {e}"),
+        }
+    }
+
+    // イベント: y が正から負へ横切る時刻（半周期ごと）を記録する
+    println!("\n[Events] y = 0 を正から負へ横切る時刻（周期は約 (3 - 2 ln 2) μ）");
+    let mut problem = OdeProblem::new(rhs, (0.0, 400.0), y0.clone());
+    problem.add_event(Event::new(|_, y| y[0], EventDirection::Falling, false));
+    let sol = problem.solve(OdeMethod::Radau, &opts).unwrap();
+    for (k, t) in sol.t_events[0].iter().enumerate() {
+        println!("  #{k}: t = {t:.4}");
+    }
+    let periods: Vec<f64> = sol.t_events[0].windows(2).map(|w| w[1] - w[0]).collect();
+    if let Some(p) = periods.last() {
+        println!(
+            "  直近の周期 = {p:.4}（漸近値 {:.4}）",
+            (3.0 - 2.0 * 2f64.ln()) * mu
+        );
+    }
+}
@@ -0,0 +1,380 @@
+//! 可変次数・可変刻みの後退差分公式（NDF 修正付き、Shampine–Reichelt の定式化）
+//!
+//! 解の履歴を後退差分表 D（D_0 = y_n, D_k = ∇^k y_n を刻み h で正規化したもの）として持ち、
+//! 刻み幅を変えるときは差分表を補間多項式ごと変換する。
+
+use linalg::matrix::LU;
+use linalg::{Matrix, Vector};
+
+use crate::driver::{min_step, rms_norm, Stepper};
+use crate::error::Result;
+use crate::problem::{OdeOptions, System};
+use crate::solution::Interpolant;
+
+const MAX_ORDER: usize = 5;
+const NEWTON_MAXITER: usize = 4;
+const MIN_FACTOR: f64 = 0.2;
+const MAX_FACTOR: f64 = 10.0;
+/// NDF の係数 κ（Klopfenstein–Shampine）。5 次は安定性のため BDF のまま。
+const KAPPA: [f64; MAX_ORDER + 1] = [0.0, -0.1850, -1.0 / 9.0, -0.0823, -0.0415, 0.0];
+
+pub(crate) struct Bdf {
+    t: f64,
+    y: Vector<f64>,
+    h_abs: f64,
+    order: usize,
+    n_equal_steps: usize,
+    d: Vec<Vector<f64>>,
+    gamma: [f64; MAX_ORDER + 1],
+    alpha: [f64; MAX_ORDER + 1],
+    error_const: [f64; MAX_ORDER + 1],
+    newton_tol: f64,
+    jacobian: Matrix<f64>,
+    lu: Option<LU<f64>>,
+    opts: OdeOptions,
+}
+
+impl Bdf {
+    pub(crate) fn new(
+        sys: &mut System,
+        t: f64,
+        y: Vector<f64>,
+        f: Vector<f64>,
+        h: f64,
+        opts: &OdeOptions,
+    ) -> Self {
+        let mut gamma = [0.0; MAX_ORDER + 1];
+        for k in 1..=MAX_ORDER {
+            gamma[k] = gamma[k - 1] + 1.0 / k as f64;
+        }
+        let alpha = std::array::from_fn(|k| (1.0 - KAPPA[k]) * gamma[k]);
+        let error_const = std::array::from_fn(|k| KAPPA[k] * gamma[k] + 1.0 / (k as f64 + 1.0));
+        let n = y.dim();
+        let mut d = vec![Vector::zeros(n); MAX_ORDER + 3];
+        d[0] = y.clone();
+        d[1] = &f * h;
+        let jacobian = sys.jacobian(t, &y, &f);
+        Self {
+            t,
+            y,
+            h_abs: h,
+            order: 1,
+            n_equal_steps: 0,
+            d,
+            gamma,
+            alpha,
+            error_const,
+            newton_tol: (10.0 * f64::EPSILON / opts.rtol).max(0.03f64.min(opts.rtol.sqrt())),
+            jacobian,
+            lu: None,
+            opts: OdeOptions {
+                t_eval: None,
+                ..opts.clone()
+            },
+        }
+    }
+
+    /// 刻みを factor 倍したときの差分表の変換 D[..=order] ← (R U)^T D[..=order]
+    fn change_d(&mut self, factor: f64) {
+        let order = self.order;
+        let r = compute_r(order, factor);
+        let u = compute_r(order, 1.0);
+        let ru: Vec<Vec<f64>> = (0..=order)
+            .map(|i| {
+                (0..=order)
+                    .map(|j| (0..=order).map(|l| r[i][l] * u[l][j]).sum())
+                    .collect()
+            })
+            .collect();
+        let n = self.y.dim();
+        let new: Vec<Vector<f64>> = (0..=order)
+            .map(|i| {
+                let mut v = Vector::zeros(n);
+                for (j, dj) in self.d[..=order].iter().enumerate() {
+                    for (vk, dk) in v.iter_mut().zip(dj.iter()) {
+                        *vk += ru[j][i] * dk;
+                    }
+                }
+                v
+            })
+            .collect();
+        for (slot, v) in self.d.iter_mut().zip(new) {
+            *slot = v;
+        }
+    }
+
+    /// 簡略 Newton 法で BDF の非線形方程式 y - c f(t_new, y) = y_predict - psi を解く
+    fn solve_system(
+        &self,
+        sys: &mut System,
+        lu: &LU<f64>,
+        p: &Prediction,
+    ) -> Result<Option<Corrected>> {
+        let (t_new, c, psi, scale) = (p.t_new, p.c, &p.psi, &p.scale[..]);
+        let n = p.y.dim();
+        let mut y = p.y.clone();
+        let mut d: Vector<f64> = Vector::zeros(n);
+        let mut dy_norm_old: Option<f64> = None;
+        for k in 0..NEWTON_MAXITER {
+            let f = sys.f(t_new, &y);
+            if f.iter().any(|v| !v.is_finite()) {
+                break;
+            }
+            let rhs = Vector::new((0..n).map(|i| c * f[i] - psi[i] - d[i]).collect());
+            let dy = Matrix::solve_with_lu(lu, &rhs)?;
+            let dy_norm = rms_norm(dy.iter().copied(), scale);
+            let rate = dy_norm_old.map(|old| dy_norm / old);
+            if let Some(rate) = rate {
+                let remaining = rate.powi((NEWTON_MAXITER - k) as i32) / (1.0 - rate) * dy_norm;
+                if rate >= 1.0 || remaining > self.newton_tol {
+                    break;
+                }
+            }
+            for i in 0..n {
+                y[i] += dy[i];
+                d[i] += dy[i];
+            }
+            if dy_norm == 0.0 || rate.is_some_and(|r| r / (1.0 - r) * dy_norm < self.newton_tol) {
+                return Ok(Some(Corrected {
+                    iterations: k + 1,
+                    y,
+                    d,
+                }));
+            }
+            dy_norm_old = Some(dy_norm);
+        }
+        Ok(None)
+    }
+
+    fn iteration_lu(&self, sys: &mut System, c: f64) -> Result<LU<f64>> {
+        let n = self.y.dim();
+        let mut m = Matrix::identity(n);
+        for r in 0..n {
+            for col in 0..n {
+                m[(r, col)] -= c * self.jacobian[(r, col)];
+            }
+        }
+        sys.lu(&m)
+    }
+}
+
+/// 予測子と修正子の方程式の係数
+struct Prediction {
+    t_new: f64,
+    y: Vector<f64>,
+    c: f64,
+    psi: Vector<f64>,
+    scale: Vec<f64>,
+}
+
+/// 収束した修正子と d = y - y_predict
+struct Corrected {
+    iterations: usize,
+    y: Vector<f64>,
+    d: Vector<f64>,
+}
+
+/// 刻み幅を factor 倍したときの補間多項式の基底変換行列
+fn compute_r(order: usize, factor: f64) -> Vec<Vec<f64>> {
+    let mut r = vec![vec![1.0; order + 1]; order + 1];
+    for i in 1..=order {
+        let prev = r[i - 1].clone();
+        for (j, (rij, pj)) in r[i].iter_mut().zip(prev).enumerate() {
+            let m = if j == 0 {
+                0.0
+            } else {
+                (i as f64 - 1.0 - factor * j as f64) / i as f64
+            };
+            *rij = pj * m;
+        }
+    }
+    r
+}
+
+impl Stepper for Bdf {
+    fn t(&self) -> f64 {
+        self.t
+    }
+
+    fn y(&self) -> &Vector<f64> {
+        &self.y
+    }
+
+    fn step(&mut self, sys: &mut System, t_bound: f64) -> Result<Option<Interpolant>> {
+        let t = self.t;
+        let min = min_step(t);
+        let max_step = self.opts.max_step;
+        if self.h_abs > max_step {
+            self.change_d(max_step / self.h_abs);
+            self.h_abs = max_step;
+            self.n_equal_steps = 0;
+        } else if self.h_abs < min {
+            self.change_d(min / self.h_abs);
+            self.h_abs = min;
+            self.n_equal_steps = 0;
+        }
+        let (atol, rtol) = (self.opts.atol, self.opts.rtol);
+        let n = self.y.dim();
+        let order = self.order;
+        let mut h_abs = self.h_abs;
+        let mut lu = self.lu.take();
+        // 既定のヤコビ行列は前のステップのもの。Newton 法が収束しないときだけ評価し直す。
+        let mut current_jacobian = false;
+
+        let (t_new, safety, y_new, d, scale, error_norm) = loop {
+            if h_abs < min {
+                return Ok(None);
+            }
+            let mut t_new = t + h_abs;
+            if t_new > t_bound {
+                t_new = t_bound;
+                self.change_d((t_new - t) / h_abs);
+                self.n_equal_steps = 0;
+                lu = None;
+            }
+            let h = t_new - t;
+            h_abs = h;
+
+            let mut y_predict: Vector<f64> = Vector::zeros(n);
+            for dk in &self.d[..=order] {
+                for (yi, di) in y_predict.iter_mut().zip(dk.iter()) {
+                    *yi += di;
+                }
+            }
+            let scale: Vec<f64> = y_predict.iter().map(|v| atol + rtol * v.abs()).collect();
+            let mut psi: Vector<f64> = Vector::zeros(n);
+            for (dk, g) in self.d[1..=order].iter().zip(&self.gamma[1..=order]) {
+                for (p, di) in psi.iter_mut().zip(dk.iter()) {
+                    *p += g * di / self.alpha[order];
+                }
+            }
+            let prediction = Prediction {
+                t_new,
+                y: y_predict,
+                c: h / self.alpha[order],
+                psi,
+                scale,
+            };
+
+            let solved = loop {
+                let lu_ref = match &lu {
+                    Some(lu) => lu,
+                    None => lu.insert(self.iteration_lu(sys, prediction.c)?),
+                };
+                let solved = self.solve_system(sys, lu_ref, &prediction)?;
+                if solved.is_some() || current_jacobian {
+                    break solved;
+                }
+                let f = sys.f(t_new, &prediction.y);
+                self.jacobian = sys.jacobian(t_new, &prediction.y, &f);
+                lu = None;
+                current_jacobian = true;
+            };
+            let Some(Corrected {
+                iterations: n_iter,
+                y: y_new,
+                d,
+            }) = solved
+            else {
+                h_abs *= 0.5;
+                self.change_d(0.5);
+                self.n_equal_steps = 0;
+                lu = None;
+                sys.rejected_steps += 1;
+                continue;
+            };
+
+            let safety =
+                0.9 * (2 * NEWTON_MAXITER + 1) as f64 / (2 * NEWTON_MAXITER + n_iter) as f64;
+            let scale: Vec<f64> = y_new.iter().map(|v| atol + rtol * v.abs()).collect();
+            let error_norm = rms_norm(d.iter().map(|v| self.error_const[order] * v), &scale);
+            if error_norm > 1.0 {
+                let factor =
+                    (safety * error_norm.powf(-1.0 / (order as f64 + 1.0))).max(MIN_FACTOR);
+                h_abs *= factor;
+                self.change_d(factor);
+                self.n_equal_steps = 0;
+                sys.rejected_steps += 1;
+                continue;
+            }
+            break (t_new, safety, y_new, d, scale, error_norm);
+        };
+        self.n_equal_steps += 1;
+        self.t = t_new;
+        self.y = y_new;
+        self.h_abs = h_abs;
+        self.lu = lu;
+
+        // ∇^{j+1} y_n = ∇^j y_n - ∇^j y_{n-1}。d = ∇^{order+1} y_n なので下から足し上げれば更新できる。
+        self.d[order + 2] = Vector::new(
+            d.iter()
+                .zip(self.d[order + 1].iter())
+                .map(|(a, b)| a - b)
+                .collect(),
+        );
+        self.d[order + 1] = d;
+        for i in (0..=order).rev() {
+            let next = self.d[i + 1].clone();
+            for (a, b) in self.d[i].iter_mut().zip(next.iter()) {
+                *a += b;
+            }
+        }
+
+        // 同じ次数・刻みで order + 1 ステップ進めたら、次数 ±1 の誤差推定から次数と刻みを選び直す
+        if self.n_equal_steps > order {
+            let error_m_norm = if order > 1 {
+                rms_norm(
+                    self.d[order]
+                        .iter()
+                        .map(|v| self.error_const[order - 1] * v),
+                    &scale,
+                )
+            } else {
+                f64::INFINITY
+            };
+            let error_p_norm = if order < MAX_ORDER {
+                rms_norm(
+                    self.d[order + 2]
+                        .iter()
+                        .map(|v| self.error_const[order + 1] * v),
+                    &scale,
+                )
+            } else {
+                f64::INFINITY
+            };
+            let factors: Vec<f64> = [error_m_norm, error_norm, error_p_norm]
+                .iter()
+                .enumerate()
+                .map(|(i, e)| e.powf(-1.0 / (order + i) as f64))
+                .collect();
+            let (best, max_factor) =
+                factors
+                    .iter()
+                    .enumerate()
+                    .fold(
+                        (0, f64::NEG_INFINITY),
+                        |acc, (i, &f)| {
+                            if f > acc.1 {
+                                (i, f)
+                            } else {
+                                acc
+                            }
+                        },
+                    );
+            self.order = order + best - 1;
+            let factor = (safety * max_factor).min(MAX_FACTOR);
+            self.h_abs *= factor;
+            self.change_d(factor);
+            self.n_equal_steps = 0;
+            self.lu = None;
+        }
+
+        // 連続出力は更新後の差分表（次のステップの刻み h で正規化されている）から作る
+        let (h, k) = (self.h_abs, self.order);
+        Ok(Some(Interpolant::Differences {
+            shifts: (0..k).map(|j| self.t - h * j as f64).collect(),
+            denominators: (0..k).map(|j| h * (1.0 + j as f64)).collect(),
+            d: self.d[..=k].to_vec(),
+        }))
+    }
+}
@@ -0,0 +1,190 @@
+//! 全手法に共通する積分ループ（出力時刻・イベント検出・連続出力）
+
+use linalg::Vector;
+use optimization::{brent, RootOptions};
+
+use crate::error::Result;
+use crate::problem::{Event, OdeOptions, System};
+use crate::solution::{DenseOutput, Interpolant, OdeSolution, OdeStatus};
+
+/// 1 ステップずつ進む積分法
+pub(crate) trait Stepper {
+    fn t(&self) -> f64;
+    fn y(&self) -> &Vector<f64>;
+    /// t_bound を越えないように 1 ステップ進め、そのステップの補間多項式を返す。
+    /// 刻み幅が小さくなりすぎて進めなければ `None`。
+    fn step(&mut self, sys: &mut System, t_bound: f64) -> Result<Option<Interpolant>>;
+}
+
+pub(crate) fn integrate<S: Stepper>(
+    mut stepper: S,
+    sys: &mut System,
+    events: &mut [Event],
+    t1: f64,
+    opts: &OdeOptions,
+) -> Result<OdeSolution> {
+    let t0 = stepper.t();
+    let y0 = stepper.y().clone();
+    let t_eval = opts.t_eval.as_deref();
+    let (mut ts, mut ys) = (Vec::new(), Vec::new());
+    let mut next_eval = 0;
+    match t_eval {
+        None => {
+            ts.push(t0);
+            ys.push(y0.clone());
+        }
+        Some(te) => {
+            while next_eval < te.len() && te[next_eval] <= t0 {
+                ts.push(te[next_eval]);
+                ys.push(y0.clone());
+                next_eval += 1;
+            }
+        }
+    }
+    let mut g_old: Vec<f64> = events.iter_mut().map(|e| (e.g)(t0, &y0)).collect();
+    let mut t_events = vec![Vec::new(); events.len()];
+    let mut y_events = vec![Vec::new(); events.len()];
+    let mut segments = Vec::new();
+    let mut steps = 0;
+    let mut status = OdeStatus::Completed;
+
+    while stepper.t() < t1 {
+        if steps >= opts.max_steps {
+            status = OdeStatus::MaxStepsReached;
+            break;
+        }
+        let t_old = stepper.t();
+        let Some(interp) = stepper.step(sys, t1)? else {
+            status = OdeStatus::StepSizeTooSmall;
+            break;
+        };
+        steps += 1;
+        let mut t_end = stepper.t();
+        let mut y_end = stepper.y().clone();
+
+        // 符号変化したイベントの時刻を補間多項式上で Brent 法により求める
+        let mut found = Vec::new();
+        for (k, event) in events.iter_mut().enumerate() {
+            let g_new = (event.g)(t_end, &y_end);
+            if event.direction.crosses(g_old[k], g_new) {
+                let g = &mut event.g;
+                let root = brent(|t| g(t, &interp.eval(t)), t_old, t_end, &EVENT_TOLERANCE)?;
+                found.push((root.root, k));
+            }
+            g_old[k] = g_new;
+        }
+        found.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if let Some(first) = found.iter().position(|&(_, k)| events[k].terminal) {
+            found.truncate(first + 1);
+            t_end = found[first].0;
+            y_end = interp.eval(t_end);
+            status = OdeStatus::TerminatedByEvent;
+        }
+        for (t, k) in found {
+            t_events[k].push(t);
+            y_events[k].push(interp.eval(t));
+        }
+
+        match t_eval {
+            None => {
+                ts.push(t_end);
+                ys.push(y_end);
+            }
+            Some(te) => {
+                while next_eval < te.len() && te[next_eval] <= t_end {
+                    ts.push(te[next_eval]);
+                    ys.push(interp.eval(te[next_eval]));
+                    next_eval += 1;
+                }
+            }
+        }
+        if opts.dense_output {
+            segments.push((t_old, t_end, interp));
+        }
+        if status == OdeStatus::TerminatedByEvent {
+            break;
+        }
+    }
+    Ok(OdeSolution {
+        t: ts,
+        y: ys,
+        t_events,
+        y_events,
+        status,
+        steps,
+        rejected_steps: sys.rejected_steps,
+        evaluations: sys.evaluations,
+        jacobian_evaluations: sys.jacobian_evaluations,
+        lu_decompositions: sys.lu_decompositions,
+        dense: opts.dense_output.then_some(DenseOutput { segments }),
+    })
+}
+
+const EVENT_TOLERANCE: RootOptions = RootOptions {
+    xtol: 4.0 * f64::EPSILON,
+    rtol: 4.0 * f64::EPSILON,
+    ftol: 0.0,
+    max_iterations: 100,
+};
+
+/// 最初の刻み幅を選ぶ（Hairer–Nørsett–Wanner, II.4）。
+/// 局所誤差 ~ h^{order+1} が許容誤差の 1% 程度になる h を、2 回目の評価から見積もった 2 階微分で推定する。
+pub(crate) fn select_initial_step(
+    sys: &mut System,
+    t0: f64,
+    y0: &Vector<f64>,
+    f0: &Vector<f64>,
+    order: usize,
+    t1: f64,
+    opts: &OdeOptions,
+) -> f64 {
+    let bound = opts.max_step.min(t1 - t0);
+    if y0.dim() == 0 {
+        return bound;
+    }
+    let scale: Vec<f64> = y0.iter().map(|v| opts.atol + opts.rtol * v.abs()).collect();
+    let d0 = rms_norm(y0.iter().copied(), &scale);
+    let d1 = rms_norm(f0.iter().copied(), &scale);
+    let h0 = if d0 < 1e-5 || d1 < 1e-5 {
+        1e-6
+    } else {
+        0.01 * d0 / d1
+    }
+    .min(bound);
+    let y1 = axpy(y0, h0, f0);
+    let f1 = sys.f(t0 + h0, &y1);
+    let d2 = rms_norm(f1.iter().zip(f0.iter()).map(|(a, b)| a - b), &scale) / h0;
+    let h1 = if d1 <= 1e-15 && d2 <= 1e-15 {
+        (h0 * 1e-3).max(1e-6)
+    } else {
+        (0.01 / d1.max(d2)).powf(1.0 / (order as f64 + 1.0))
+    };
+    (100.0 * h0).min(h1).min(bound)
+}
+
+/// 重み付き二乗平均平方根ノルム sqrt(mean((v_i / scale_i)^2))
+pub(crate) fn rms_norm(v: impl Iterator<Item = f64>, scale: &[f64]) -> f64 {
+    if scale.is_empty() {
+        return 0.0;
+    }
+    let s: f64 = v.zip(scale).map(|(x, s)| (x / s).powi(2)).sum();
+    (s / scale.len() as f64).sqrt()
+}
+
+/// 許容誤差のスケール atol + rtol max(|y_i|, |z_i|)
+pub(crate) fn error_scale(y: &Vector<f64>, z: &Vector<f64>, opts: &OdeOptions) -> Vec<f64> {
+    y.iter()
+        .zip(z.iter())
+        .map(|(a, b)| opts.atol + opts.rtol * a.abs().max(b.abs()))
+        .collect()
+}
+
+/// y + a x
+pub(crate) fn axpy(y: &Vector<f64>, a: f64, x: &Vector<f64>) -> Vector<f64> {
+    Vector::new(y.iter().zip(x.iter()).map(|(yi, xi)| yi + a * xi).collect())
+}
+
+/// 刻み幅の下限。t の丸め誤差の 10 倍。
+pub(crate) fn min_step(t: f64) -> f64 {
+    10.0 * (t.abs() * f64::EPSILON).max(f64::MIN_POSITIVE)
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+
+use linalg::LinalgError;
+use optimization::OptimizeError;
+
+use crate::solution::OdeStatus;
+
+#[derive(Debug, Clone)]
+pub enum OdeError {
+    InvalidParameter {
+        what: &'static str,
+        details: String,
+    },
+    DimensionMismatch {
+        expected: String,
+        found: String,
+    },
+    NonFiniteValue {
+        what: &'static str,
+        t: f64,
+    },
+    /// 積分が t1 に届かずに止まった（status を失敗として扱う呼び出し側が返す）
+    Incomplete {
+        status: OdeStatus,
+        t: f64,
+    },
+    Linalg(LinalgError),
+    Optimize(OptimizeError),
+}
+
+impl fmt::Display for OdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OdeError::InvalidParameter { what, details } => {
+                write!(f, "Invalid parameter for {what}: {details}")
+            }
+            OdeError::DimensionMismatch { expected, found } => {
+                write!(f, "Dimension mismatch: expected {expected}, found {found}")
+            }
+            OdeError::NonFiniteValue { what, t } => write!(f, "{what} is not finite at t = {t}"),
+            OdeError::Incomplete { status, t } => {
+                write!(f, "Integration stopped at t = {t} ({status:?})")
+            }
+            OdeError::Linalg(e) => write!(f, "Linalg error: {e}"),
+            OdeError::Optimize(e) => write!(f, "Optimization error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for OdeError {}
+
+impl From<LinalgError> for OdeError {
+    fn from(value: LinalgError) -> Self {
+        Self::Linalg(value)
+    }
+}
+
+impl From<OptimizeError> for OdeError {
+    fn from(value: OptimizeError) -> Self {
+        Self::Optimize(value)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, OdeError>;
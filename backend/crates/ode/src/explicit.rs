@@ -0,0 +1,275 @@
+//! 陽的 Runge–Kutta 法
+
+use linalg::Vector;
+
+use crate::driver::{error_scale, min_step, rms_norm, Stepper};
+use crate::error::Result;
+use crate::problem::{OdeOptions, System};
+use crate::solution::Interpolant;
+
+/// Σ_j c_j k_j を y に足した y + h Σ_j c_j k_j
+fn combine(y: &Vector<f64>, h: f64, coeffs: &[f64], k: &[Vector<f64>]) -> Vector<f64> {
+    let mut out = y.clone();
+    for (c, kj) in coeffs.iter().zip(k) {
+        if *c == 0.0 {
+            continue;
+        }
+        for (o, v) in out.iter_mut().zip(kj.iter()) {
+            *o += h * c * v;
+        }
+    }
+    out
+}
+
+/// 古典的 4 次 Runge–Kutta 法（固定刻み）。最後のステップは t1 にちょうど届くよう縮める。
+pub(crate) struct Rk4 {
+    t: f64,
+    y: Vector<f64>,
+    f: Vector<f64>,
+    step: f64,
+}
+
+impl Rk4 {
+    pub(crate) fn new(t: f64, y: Vector<f64>, f: Vector<f64>, step: f64) -> Self {
+        Self { t, y, f, step }
+    }
+}
+
+impl Stepper for Rk4 {
+    fn t(&self) -> f64 {
+        self.t
+    }
+
+    fn y(&self) -> &Vector<f64> {
+        &self.y
+    }
+
+    fn step(&mut self, sys: &mut System, t_bound: f64) -> Result<Option<Interpolant>> {
+        let (t, mut h) = (self.t, self.step);
+        // 丸め誤差で極端に短い最終ステップが残らないようにする
+        if t + h >= t_bound - min_step(t_bound) {
+            h = t_bound - t;
+        }
+        let k1 = self.f.clone();
+        let k2 = sys.f(
+            t + 0.5 * h,
+            &combine(&self.y, 0.5 * h, &[1.0], std::slice::from_ref(&k1)),
+        );
+        let k3 = sys.f(
+            t + 0.5 * h,
+            &combine(&self.y, 0.5 * h, &[1.0], std::slice::from_ref(&k2)),
+        );
+        let k4 = sys.f(
+            t + h,
+            &combine(&self.y, h, &[1.0], std::slice::from_ref(&k3)),
+        );
+        let y_new = combine(
+            &self.y,
+            h,
+            &[1.0 / 6.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 6.0],
+            &[k1, k2, k3, k4],
+        );
+        let t_new = if h == t_bound - t { t_bound } else { t + h };
+        let f_new = sys.f(t_new, &y_new);
+        let interp = Interpolant::Hermite {
+            t0: t,
+            h,
+            y0: std::mem::replace(&mut self.y, y_new.clone()),
+            y1: y_new,
+            f0: std::mem::replace(&mut self.f, f_new.clone()),
+            f1: f_new,
+        };
+        self.t = t_new;
+        Ok(Some(interp))
+    }
+}
+
+const SAFETY: f64 = 0.9;
+const MIN_FACTOR: f64 = 0.2;
+const MAX_FACTOR: f64 = 10.0;
+
+/// Dormand–Prince 5(4) 法の Butcher 表
+const C: [f64; 6] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0];
+const A: [&[f64]; 6] = [
+    &[],
+    &[1.0 / 5.0],
+    &[3.0 / 40.0, 9.0 / 40.0],
+    &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
+    &[
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+    ],
+    &[
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+    ],
+];
+const B: [f64; 6] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+];
+/// 5 次解と埋め込み 4 次解の差（7 段目は FSAL の f(t + h, y_new)）
+const E: [f64; 7] = [
+    -71.0 / 57600.0,
+    0.0,
+    71.0 / 16695.0,
+    -71.0 / 1920.0,
+    17253.0 / 339200.0,
+    -22.0 / 525.0,
+    1.0 / 40.0,
+];
+/// 連続出力 y(t0 + x h) = y0 + h Σ_k (K^T P)_k x^k の係数（Shampine の 4 次補間）
+const P: [[f64; 4]; 7] = [
+    [
+        1.0,
+        -8048581381.0 / 2820520608.0,
+        8663915743.0 / 2820520608.0,
+        -12715105075.0 / 11282082432.0,
+    ],
+    [0.0, 0.0, 0.0, 0.0],
+    [
+        0.0,
+        131558114200.0 / 32700410799.0,
+        -68118460800.0 / 10900136933.0,
+        87487479700.0 / 32700410799.0,
+    ],
+    [
+        0.0,
+        -1754552775.0 / 470086768.0,
+        14199869525.0 / 1410260304.0,
+        -10690763975.0 / 1880347072.0,
+    ],
+    [
+        0.0,
+        127303824393.0 / 49829197408.0,
+        -318862633887.0 / 49829197408.0,
+        701980252875.0 / 199316789632.0,
+    ],
+    [
+        0.0,
+        -282668133.0 / 205662961.0,
+        2019193451.0 / 616988883.0,
+        -1453857185.0 / 822651844.0,
+    ],
+    [
+        0.0,
+        40617522.0 / 29380423.0,
+        -110615467.0 / 29380423.0,
+        69997945.0 / 29380423.0,
+    ],
+];
+
+/// Dormand–Prince 5(4) 法。局所外挿（5 次解で進める）と FSAL を使い、1 ステップ 6 回の評価。
+pub(crate) struct DormandPrince {
+    t: f64,
+    y: Vector<f64>,
+    f: Vector<f64>,
+    h_abs: f64,
+    opts: OdeOptions,
+}
+
+impl DormandPrince {
+    /// 誤差推定の次数（刻み幅の更新則 err^{-1/(order+1)} に使う）
+    pub(crate) const ERROR_ORDER: usize = 4;
+
+    pub(crate) fn new(t: f64, y: Vector<f64>, f: Vector<f64>, h: f64, opts: &OdeOptions) -> Self {
+        Self {
+            t,
+            y,
+            f,
+            h_abs: h,
+            opts: OdeOptions {
+                t_eval: None,
+                ..opts.clone()
+            },
+        }
+    }
+}
+
+impl Stepper for DormandPrince {
+    fn t(&self) -> f64 {
+        self.t
+    }
+
+    fn y(&self) -> &Vector<f64> {
+        &self.y
+    }
+
+    fn step(&mut self, sys: &mut System, t_bound: f64) -> Result<Option<Interpolant>> {
+        let t = self.t;
+        let min = min_step(t);
+        let exponent = -1.0 / (Self::ERROR_ORDER as f64 + 1.0);
+        let mut h_abs = self.h_abs.min(self.opts.max_step).max(min);
+        let mut rejected = false;
+        loop {
+            if h_abs < min {
+                return Ok(None);
+            }
+            let mut t_new = t + h_abs;
+            if t_new >= t_bound {
+                t_new = t_bound;
+            }
+            let h = t_new - t;
+            h_abs = h;
+
+            let mut k = vec![self.f.clone()];
+            for s in 1..6 {
+                let ys = combine(&self.y, h, A[s], &k);
+                k.push(sys.f(t + C[s] * h, &ys));
+            }
+            let y_new = combine(&self.y, h, &B, &k);
+            let f_new = sys.f(t_new, &y_new);
+            k.push(f_new);
+
+            let scale = error_scale(&self.y, &y_new, &self.opts);
+            let err = (0..self.y.dim())
+                .map(|i| h * E.iter().zip(&k).map(|(e, kj)| e * kj[i]).sum::<f64>());
+            let error_norm = rms_norm(err, &scale);
+
+            if error_norm < 1.0 {
+                let mut factor = if error_norm == 0.0 {
+                    MAX_FACTOR
+                } else {
+                    (SAFETY * error_norm.powf(exponent)).min(MAX_FACTOR)
+                };
+                if rejected {
+                    factor = factor.min(1.0);
+                }
+                self.h_abs = h_abs * factor;
+                let q = (0..4)
+                    .map(|c| {
+                        let col: Vec<f64> = P.iter().map(|row| row[c]).collect();
+                        combine(&Vector::zeros(self.y.dim()), h, &col, &k)
+                    })
+                    .collect();
+                let interp = Interpolant::Polynomial {
+                    t0: t,
+                    h,
+                    y0: std::mem::replace(&mut self.y, y_new),
+                    q,
+                };
+                self.f = k.pop().expect("seven stages");
+                self.t = t_new;
+                return Ok(Some(interp));
+            }
+            // NaN も含めて棄却し、刻みを縮めてやり直す
+            let factor = if error_norm.is_nan() {
+                MIN_FACTOR
+            } else {
+                (SAFETY * error_norm.powf(exponent)).max(MIN_FACTOR)
+            };
+            h_abs *= factor;
+            rejected = true;
+            sys.rejected_steps += 1;
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod bdf;
+mod driver;
+pub mod error;
+mod explicit;
+pub mod problem;
+mod radau;
+pub mod solution;
+
+pub use error::{OdeError, Result};
+pub use problem::{Event, EventDirection, OdeMethod, OdeOptions, OdeProblem};
+pub use solution::{DenseOutput, OdeSolution, OdeStatus};
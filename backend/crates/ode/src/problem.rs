@@ -0,0 +1,268 @@
+use linalg::matrix::LU;
+use linalg::{Matrix, Vector};
+
+use crate::bdf::Bdf;
+use crate::driver::{integrate, select_initial_step};
+use crate::error::{OdeError, Result};
+use crate::explicit::{DormandPrince, Rk4};
+use crate::radau::Radau;
+use crate::solution::OdeSolution;
+
+type Rhs<'a> = dyn FnMut(f64, &Vector<f64>) -> Vector<f64> + 'a;
+type Jacobian<'a> = dyn FnMut(f64, &Vector<f64>) -> Matrix<f64> + 'a;
+type EventFn<'a> = dyn FnMut(f64, &Vector<f64>) -> f64 + 'a;
+
+/// 積分法
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OdeMethod {
+    /// 固定刻みの古典的 4 次 Runge–Kutta 法
+    Rk4 { step: f64 },
+    /// Dormand–Prince 5(4) 次の埋め込み Runge–Kutta 法（非硬い問題向け）
+    Rk45,
+    /// 可変次数（1〜5）・可変刻みの後退差分公式（硬い問題向け）
+    Bdf,
+    /// 3 段 5 次の Radau IIA 陰的 Runge–Kutta 法（硬い問題向け、L 安定）
+    Radau,
+}
+
+#[derive(Debug, Clone)]
+pub struct OdeOptions {
+    /// 各成分の許容誤差 atol + rtol |y_i|（刻み幅制御に使う）。rtol, atol とも正
+    pub rtol: f64,
+    pub atol: f64,
+    /// 最初の刻み幅（`None` なら自動選択）
+    pub first_step: Option<f64>,
+    pub max_step: f64,
+    /// 受理したステップ数の上限
+    pub max_steps: usize,
+    /// 解を出力する時刻（昇順、積分区間内）。`None` なら受理した各ステップの終点を出力する。
+    pub t_eval: Option<Vec<f64>>,
+    /// 区間全体の連続出力（`OdeSolution::dense`）を保持する
+    pub dense_output: bool,
+}
+
+impl Default for OdeOptions {
+    fn default() -> Self {
+        Self {
+            rtol: 1e-3,
+            atol: 1e-6,
+            first_step: None,
+            max_step: f64::INFINITY,
+            max_steps: 100_000,
+            t_eval: None,
+            dense_output: false,
+        }
+    }
+}
+
+/// イベント関数 g(t, y) の零点をどちら向きに横切ったときに検出するか
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventDirection {
+    Both,
+    /// 負から正へ
+    Rising,
+    /// 正から負へ
+    Falling,
+}
+
+impl EventDirection {
+    pub(crate) fn crosses(self, old: f64, new: f64) -> bool {
+        let rising = old < 0.0 && new >= 0.0;
+        let falling = old > 0.0 && new <= 0.0;
+        match self {
+            Self::Both => rising || falling,
+            Self::Rising => rising,
+            Self::Falling => falling,
+        }
+    }
+}
+
+/// 積分中に g(t, y) = 0 となる時刻を検出する。`terminal` なら最初の検出で積分を止める。
+pub struct Event<'a> {
+    pub(crate) g: Box<EventFn<'a>>,
+    pub direction: EventDirection,
+    pub terminal: bool,
+}
+
+impl<'a> Event<'a> {
+    pub fn new<G>(g: G, direction: EventDirection, terminal: bool) -> Self
+    where
+        G: FnMut(f64, &Vector<f64>) -> f64 + 'a,
+    {
+        Self {
+            g: Box::new(g),
+            direction,
+            terminal,
+        }
+    }
+}
+
+/// 初期値問題 y' = f(t, y), y(t0) = y0 を t ∈ [t0, t1] で解く
+pub struct OdeProblem<'a> {
+    rhs: Box<Rhs<'a>>,
+    jacobian: Option<Box<Jacobian<'a>>>,
+    events: Vec<Event<'a>>,
+    t_span: (f64, f64),
+    y0: Vector<f64>,
+}
+
+impl<'a> OdeProblem<'a> {
+    pub fn new<F>(rhs: F, t_span: (f64, f64), y0: Vector<f64>) -> Self
+    where
+        F: FnMut(f64, &Vector<f64>) -> Vector<f64> + 'a,
+    {
+        Self {
+            rhs: Box::new(rhs),
+            jacobian: None,
+            events: Vec::new(),
+            t_span,
+            y0,
+        }
+    }
+
+    /// 陰的解法で使う ∂f/∂y。与えなければ前進差分で近似する。
+    pub fn set_jacobian<J>(&mut self, jacobian: J)
+    where
+        J: FnMut(f64, &Vector<f64>) -> Matrix<f64> + 'a,
+    {
+        self.jacobian = Some(Box::new(jacobian));
+    }
+
+    pub fn add_event(&mut self, event: Event<'a>) {
+        self.events.push(event);
+    }
+
+    pub fn solve(&mut self, method: OdeMethod, opts: &OdeOptions) -> Result<OdeSolution> {
+        let (t0, t1) = self.t_span;
+        validate(t0, t1, opts)?;
+        let mut sys = System {
+            rhs: self.rhs.as_mut(),
+            jacobian: self.jacobian.as_deref_mut(),
+            evaluations: 0,
+            jacobian_evaluations: 0,
+            lu_decompositions: 0,
+            rejected_steps: 0,
+        };
+        let y0 = self.y0.clone();
+        let f0 = sys.f(t0, &y0);
+        if f0.dim() != y0.dim() {
+            return Err(OdeError::DimensionMismatch {
+                expected: format!("f(t, y) of dimension {}", y0.dim()),
+                found: format!("{}", f0.dim()),
+            });
+        }
+        if f0.iter().any(|v| !v.is_finite()) {
+            return Err(OdeError::NonFiniteValue { what: "f", t: t0 });
+        }
+        let first_step = |sys: &mut System, order: usize| match opts.first_step {
+            Some(h) => h.min(opts.max_step).min(t1 - t0),
+            None => select_initial_step(sys, t0, &y0, &f0, order, t1, opts),
+        };
+        let events = &mut self.events;
+        match method {
+            OdeMethod::Rk4 { step } => {
+                if step.is_nan() || step <= 0.0 {
+                    return Err(OdeError::InvalidParameter {
+                        what: "step",
+                        details: format!("must be positive, got {step}"),
+                    });
+                }
+                let stepper = Rk4::new(t0, y0.clone(), f0.clone(), step);
+                integrate(stepper, &mut sys, events, t1, opts)
+            }
+            OdeMethod::Rk45 => {
+                let h = first_step(&mut sys, DormandPrince::ERROR_ORDER);
+                let stepper = DormandPrince::new(t0, y0.clone(), f0.clone(), h, opts);
+                integrate(stepper, &mut sys, events, t1, opts)
+            }
+            OdeMethod::Bdf => {
+                let h = first_step(&mut sys, 1);
+                let stepper = Bdf::new(&mut sys, t0, y0.clone(), f0.clone(), h, opts);
+                integrate(stepper, &mut sys, events, t1, opts)
+            }
+            OdeMethod::Radau => {
+                let h = first_step(&mut sys, Radau::ERROR_ORDER);
+                let stepper = Radau::new(&mut sys, t0, y0.clone(), f0.clone(), h, opts);
+                integrate(stepper, &mut sys, events, t1, opts)
+            }
+        }
+    }
+}
+
+fn validate(t0: f64, t1: f64, opts: &OdeOptions) -> Result<()> {
+    if !(t0.is_finite() && t1.is_finite() && t0 < t1) {
+        return Err(OdeError::InvalidParameter {
+            what: "t_span",
+            details: format!("finite t0 < t1 required, got ({t0}, {t1})"),
+        });
+    }
+    if opts.rtol.is_nan() || opts.rtol <= 0.0 || opts.atol.is_nan() || opts.atol <= 0.0 {
+        return Err(OdeError::InvalidParameter {
+            what: "tolerance",
+            details: format!(
+                "rtol > 0 and atol > 0 required, got rtol = {}, atol = {}",
+                opts.rtol, opts.atol
+            ),
+        });
+    }
+    if opts.max_step.is_nan() || opts.max_step <= 0.0 {
+        return Err(OdeError::InvalidParameter {
+            what: "max_step",
+            details: format!("must be positive, got {}", opts.max_step),
+        });
+    }
+    if let Some(te) = &opts.t_eval {
+        let sorted = te.windows(2).all(|w| w[0] <= w[1]);
+        let inside = te.iter().all(|&t| t0 <= t && t <= t1);
+        if !(sorted && inside) {
+            return Err(OdeError::InvalidParameter {
+                what: "t_eval",
+                details: format!("must be sorted and lie within [{t0}, {t1}]"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// 右辺・ヤコビ行列の呼び出しと評価回数を数える
+pub(crate) struct System<'p, 'a> {
+    rhs: &'p mut Rhs<'a>,
+    jacobian: Option<&'p mut Jacobian<'a>>,
+    pub evaluations: usize,
+    pub jacobian_evaluations: usize,
+    pub lu_decompositions: usize,
+    pub rejected_steps: usize,
+}
+
+impl System<'_, '_> {
+    pub fn f(&mut self, t: f64, y: &Vector<f64>) -> Vector<f64> {
+        self.evaluations += 1;
+        (self.rhs)(t, y)
+    }
+
+    /// ∂f/∂y。ユーザーが与えていなければ前進差分（f0 = f(t, y) を再利用）で n 回評価する。
+    pub fn jacobian(&mut self, t: f64, y: &Vector<f64>, f0: &Vector<f64>) -> Matrix<f64> {
+        self.jacobian_evaluations += 1;
+        if let Some(jac) = self.jacobian.as_mut() {
+            return jac(t, y);
+        }
+        let n = y.dim();
+        let mut j = Matrix::zeros(n, n);
+        let mut yp = y.clone();
+        for c in 0..n {
+            let h = f64::EPSILON.sqrt() * y[c].abs().max(1.0);
+            yp[c] = y[c] + h;
+            let fp = self.f(t, &yp);
+            yp[c] = y[c];
+            for r in 0..n {
+                j[(r, c)] = (fp[r] - f0[r]) / h;
+            }
+        }
+        j
+    }
+
+    pub fn lu(&mut self, m: &Matrix<f64>) -> Result<LU<f64>> {
+        self.lu_decompositions += 1;
+        Ok(m.lu_decompose()?)
+    }
+}
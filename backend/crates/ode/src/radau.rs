@@ -0,0 +1,364 @@
+//! 3 段 5 次の Radau IIA 法（Hairer–Wanner, IV.8）
+//!
+//! 段の値 Z_i = Y_i - y_n を 3n 次元の連立方程式として簡略 Newton 法で解く。
+//! 誤差推定と刻み幅の予測は Hairer の RADAU5 にならう。
+
+use linalg::matrix::LU;
+use linalg::{Matrix, Vector};
+
+use crate::driver::{min_step, rms_norm, Stepper};
+use crate::error::Result;
+use crate::problem::{OdeOptions, System};
+use crate::solution::Interpolant;
+
+const NEWTON_MAXITER: usize = 6;
+const MIN_FACTOR: f64 = 0.2;
+const MAX_FACTOR: f64 = 10.0;
+
+const SQRT6: f64 = 2.449_489_742_783_178;
+/// 段の時刻 c_i
+const C: [f64; 3] = [(4.0 - SQRT6) / 10.0, (4.0 + SQRT6) / 10.0, 1.0];
+/// Butcher 表の A
+const A: [[f64; 3]; 3] = [
+    [
+        (88.0 - 7.0 * SQRT6) / 360.0,
+        (296.0 - 169.0 * SQRT6) / 1800.0,
+        (-2.0 + 3.0 * SQRT6) / 225.0,
+    ],
+    [
+        (296.0 + 169.0 * SQRT6) / 1800.0,
+        (88.0 + 7.0 * SQRT6) / 360.0,
+        (-2.0 - 3.0 * SQRT6) / 225.0,
+    ],
+    [(16.0 - SQRT6) / 36.0, (16.0 + SQRT6) / 36.0, 1.0 / 9.0],
+];
+/// 埋め込み 3 次解との差を作る係数
+const E: [f64; 3] = [
+    (-13.0 - 7.0 * SQRT6) / 3.0,
+    (-13.0 + 7.0 * SQRT6) / 3.0,
+    -1.0 / 3.0,
+];
+/// A^{-1} の実固有値 3 + 3^{2/3} - 3^{1/3}
+const MU_REAL: f64 = 3.637_834_252_744_496;
+/// 連続出力 y(t0 + x h) = y0 + Σ_k (Z^T P)_k x^k の係数
+const P: [[f64; 3]; 3] = [
+    [
+        13.0 / 3.0 + 7.0 * SQRT6 / 3.0,
+        -23.0 / 3.0 - 22.0 * SQRT6 / 3.0,
+        10.0 / 3.0 + 5.0 * SQRT6,
+    ],
+    [
+        13.0 / 3.0 - 7.0 * SQRT6 / 3.0,
+        -23.0 / 3.0 + 22.0 * SQRT6 / 3.0,
+        10.0 / 3.0 - 5.0 * SQRT6,
+    ],
+    [1.0 / 3.0, -8.0 / 3.0, 10.0 / 3.0],
+];
+
+pub(crate) struct Radau {
+    t: f64,
+    y: Vector<f64>,
+    f: Vector<f64>,
+    h_abs: f64,
+    h_abs_old: Option<f64>,
+    error_norm_old: Option<f64>,
+    jacobian: Matrix<f64>,
+    current_jacobian: bool,
+    /// Newton 法の反復行列 I - h A⊗J と、誤差推定用の MU_REAL / h I - J の LU 分解
+    lu: Option<(LU<f64>, LU<f64>)>,
+    /// 前のステップの補間多項式（Newton 法の初期値に使う）
+    previous: Option<Interpolant>,
+    newton_tol: f64,
+    opts: OdeOptions,
+}
+
+/// Newton 法の結果
+struct Collocation {
+    converged: bool,
+    iterations: usize,
+    z: [Vector<f64>; 3],
+    rate: Option<f64>,
+}
+
+impl Radau {
+    /// 誤差推定の次数（初期刻みの選択に使う）
+    pub(crate) const ERROR_ORDER: usize = 3;
+
+    pub(crate) fn new(
+        sys: &mut System,
+        t: f64,
+        y: Vector<f64>,
+        f: Vector<f64>,
+        h: f64,
+        opts: &OdeOptions,
+    ) -> Self {
+        let jacobian = sys.jacobian(t, &y, &f);
+        Self {
+            t,
+            y,
+            f,
+            h_abs: h,
+            h_abs_old: None,
+            error_norm_old: None,
+            jacobian,
+            current_jacobian: true,
+            lu: None,
+            previous: None,
+            newton_tol: (10.0 * f64::EPSILON / opts.rtol).max(0.03f64.min(opts.rtol.sqrt())),
+            opts: OdeOptions {
+                t_eval: None,
+                ..opts.clone()
+            },
+        }
+    }
+
+    fn decompose(&self, sys: &mut System, h: f64) -> Result<(LU<f64>, LU<f64>)> {
+        let n = self.y.dim();
+        let mut newton = Matrix::identity(3 * n);
+        let mut error = Matrix::zeros(n, n);
+        for r in 0..n {
+            for c in 0..n {
+                let j = self.jacobian[(r, c)];
+                for (bi, row) in A.iter().enumerate() {
+                    for (bj, a) in row.iter().enumerate() {
+                        newton[(bi * n + r, bj * n + c)] -= h * a * j;
+                    }
+                }
+                error[(r, c)] = -j;
+            }
+            error[(r, r)] += MU_REAL / h;
+        }
+        Ok((sys.lu(&newton)?, sys.lu(&error)?))
+    }
+
+    /// 段の方程式 Z_i = h Σ_j A_ij f(t + c_j h, y + Z_j) を簡略 Newton 法で解く
+    fn solve_collocation(
+        &self,
+        sys: &mut System,
+        lu: &LU<f64>,
+        h: f64,
+        z0: [Vector<f64>; 3],
+        scale: &[f64],
+    ) -> Result<Collocation> {
+        let n = self.y.dim();
+        let mut z = z0;
+        let mut dz_norm_old: Option<f64> = None;
+        let mut rate = None;
+        let stacked_scale: Vec<f64> = scale.iter().cycle().take(3 * n).copied().collect();
+        for k in 0..NEWTON_MAXITER {
+            let f: Vec<Vector<f64>> = (0..3)
+                .map(|i| {
+                    let yi = Vector::new((0..n).map(|r| self.y[r] + z[i][r]).collect());
+                    sys.f(self.t + C[i] * h, &yi)
+                })
+                .collect();
+            if f.iter().any(|fi| fi.iter().any(|v| !v.is_finite())) {
+                return Ok(Collocation {
+                    converged: false,
+                    iterations: k + 1,
+                    z,
+                    rate,
+                });
+            }
+            let residual = Vector::new(
+                (0..3 * n)
+                    .map(|idx| {
+                        let (i, r) = (idx / n, idx % n);
+                        let af: f64 = (0..3).map(|j| A[i][j] * f[j][r]).sum();
+                        h * af - z[i][r]
+                    })
+                    .collect(),
+            );
+            let dz = Matrix::solve_with_lu(lu, &residual)?;
+            let dz_norm = rms_norm(dz.iter().copied(), &stacked_scale);
+            if let Some(old) = dz_norm_old {
+                rate = Some(dz_norm / old);
+            }
+            if let Some(r) = rate {
+                let remaining = r.powi((NEWTON_MAXITER - k) as i32) / (1.0 - r) * dz_norm;
+                if r >= 1.0 || remaining > self.newton_tol {
+                    return Ok(Collocation {
+                        converged: false,
+                        iterations: k + 1,
+                        z,
+                        rate,
+                    });
+                }
+            }
+            for (idx, d) in dz.iter().enumerate() {
+                z[idx / n][idx % n] += d;
+            }
+            if dz_norm == 0.0 || rate.is_some_and(|r| r / (1.0 - r) * dz_norm < self.newton_tol) {
+                return Ok(Collocation {
+                    converged: true,
+                    iterations: k + 1,
+                    z,
+                    rate,
+                });
+            }
+            dz_norm_old = Some(dz_norm);
+        }
+        Ok(Collocation {
+            converged: false,
+            iterations: NEWTON_MAXITER,
+            z,
+            rate,
+        })
+    }
+
+    /// 前の刻みと誤差から次の刻みの倍率を予測する（Gustafsson の制御）
+    fn predict_factor(&self, h_abs: f64, h_abs_old: Option<f64>, error_norm: f64) -> f64 {
+        let multiplier = match (h_abs_old, self.error_norm_old) {
+            (Some(h_old), Some(e_old)) if error_norm > 0.0 => {
+                h_abs / h_old * (e_old / error_norm).powf(0.25)
+            }
+            _ => 1.0,
+        };
+        multiplier.min(1.0) * error_norm.powf(-0.25)
+    }
+}
+
+impl Stepper for Radau {
+    fn t(&self) -> f64 {
+        self.t
+    }
+
+    fn y(&self) -> &Vector<f64> {
+        &self.y
+    }
+
+    fn step(&mut self, sys: &mut System, t_bound: f64) -> Result<Option<Interpolant>> {
+        let (t, n) = (self.t, self.y.dim());
+        let (atol, rtol) = (self.opts.atol, self.opts.rtol);
+        let min = min_step(t);
+        let (mut h_abs, mut h_abs_old) = (self.h_abs, self.h_abs_old);
+        if h_abs > self.opts.max_step || h_abs < min {
+            h_abs = h_abs.clamp(min, self.opts.max_step);
+            h_abs_old = None;
+            self.error_norm_old = None;
+        }
+        let mut lu = self.lu.take();
+        let mut rejected = false;
+
+        let (t_new, y_new, sol, error_norm, safety) = loop {
+            if h_abs < min {
+                return Ok(None);
+            }
+            let t_new = (t + h_abs).min(t_bound);
+            let h = t_new - t;
+            h_abs = h;
+
+            // 前のステップの連続出力を外挿して初期値にする
+            let z0: [Vector<f64>; 3] = std::array::from_fn(|i| match &self.previous {
+                Some(p) => {
+                    let v = p.eval(t + C[i] * h);
+                    Vector::new((0..n).map(|r| v[r] - self.y[r]).collect())
+                }
+                None => Vector::zeros(n),
+            });
+            let scale: Vec<f64> = self.y.iter().map(|v| atol + rtol * v.abs()).collect();
+
+            let sol = loop {
+                let (newton, _) = match &lu {
+                    Some(lu) => lu,
+                    None => lu.insert(self.decompose(sys, h)?),
+                };
+                let sol = self.solve_collocation(sys, newton, h, z0.clone(), &scale)?;
+                if sol.converged || self.current_jacobian {
+                    break sol;
+                }
+                self.jacobian = sys.jacobian(t, &self.y, &self.f);
+                self.current_jacobian = true;
+                lu = None;
+            };
+            if !sol.converged {
+                h_abs *= 0.5;
+                lu = None;
+                sys.rejected_steps += 1;
+                continue;
+            }
+
+            let y_new = Vector::new((0..n).map(|r| self.y[r] + sol.z[2][r]).collect());
+            let ze: Vec<f64> = (0..n)
+                .map(|r| (0..3).map(|i| E[i] * sol.z[i][r]).sum::<f64>() / h)
+                .collect();
+            let (_, error_lu) = lu.as_ref().expect("decomposed above");
+            let rhs = Vector::new((0..n).map(|r| self.f[r] + ze[r]).collect());
+            let mut error = Matrix::solve_with_lu(error_lu, &rhs)?;
+            let scale: Vec<f64> = self
+                .y
+                .iter()
+                .zip(y_new.iter())
+                .map(|(a, b)| atol + rtol * a.abs().max(b.abs()))
+                .collect();
+            let mut error_norm = rms_norm(error.iter().copied(), &scale);
+            let safety = 0.9 * (2 * NEWTON_MAXITER + 1) as f64
+                / (2 * NEWTON_MAXITER + sol.iterations) as f64;
+            // 棄却が続くときは硬い成分の誤差を過大評価しないよう、もう一度 (MU_REAL / h - J)^{-1} を掛ける
+            if rejected && error_norm > 1.0 {
+                let perturbed = Vector::new((0..n).map(|r| self.y[r] + error[r]).collect());
+                let fe = sys.f(t, &perturbed);
+                let rhs = Vector::new((0..n).map(|r| fe[r] + ze[r]).collect());
+                error = Matrix::solve_with_lu(error_lu, &rhs)?;
+                error_norm = rms_norm(error.iter().copied(), &scale);
+            }
+            if error_norm > 1.0 || error_norm.is_nan() {
+                let factor = if error_norm.is_nan() {
+                    MIN_FACTOR
+                } else {
+                    (safety * self.predict_factor(h_abs, h_abs_old, error_norm)).max(MIN_FACTOR)
+                };
+                h_abs *= factor;
+                lu = None;
+                rejected = true;
+                sys.rejected_steps += 1;
+                continue;
+            }
+            break (t_new, y_new, sol, error_norm, safety);
+        };
+
+        // Newton 法の収束が遅ければヤコビ行列を評価し直す
+        let recompute_jacobian = sol.iterations > 2 && sol.rate.is_some_and(|r| r > 1e-3);
+        let mut factor =
+            (safety * self.predict_factor(h_abs, h_abs_old, error_norm)).min(MAX_FACTOR);
+        if !recompute_jacobian && factor < 1.2 {
+            // 刻みをほとんど変えないなら LU 分解を使い回す
+            factor = 1.0;
+        } else {
+            lu = None;
+        }
+        let f_new = sys.f(t_new, &y_new);
+        if recompute_jacobian {
+            self.jacobian = sys.jacobian(t_new, &y_new, &f_new);
+            self.current_jacobian = true;
+        } else {
+            self.current_jacobian = false;
+        }
+
+        let h = t_new - t;
+        let q = (0..3)
+            .map(|k| {
+                Vector::new(
+                    (0..n)
+                        .map(|r| (0..3).map(|i| sol.z[i][r] * P[i][k]).sum())
+                        .collect(),
+                )
+            })
+            .collect();
+        let interp = Interpolant::Polynomial {
+            t0: t,
+            h,
+            y0: self.y.clone(),
+            q,
+        };
+        self.h_abs_old = Some(self.h_abs);
+        self.error_norm_old = Some(error_norm);
+        self.h_abs = h_abs * factor;
+        self.t = t_new;
+        self.y = y_new;
+        self.f = f_new;
+        self.lu = lu;
+        self.previous = Some(interp.clone());
+        Ok(Some(interp))
+    }
+}
@@ -0,0 +1,147 @@
+use linalg::Vector;
+
+/// 積分を終えた理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OdeStatus {
+    /// t1 まで積分した
+    Completed,
+    /// 終端イベントを検出して止めた
+    TerminatedByEvent,
+    /// 刻み幅が t の丸め誤差程度まで小さくなった（特異点や強い硬さ）
+    StepSizeTooSmall,
+    MaxStepsReached,
+}
+
+impl OdeStatus {
+    pub fn is_success(self) -> bool {
+        matches!(self, Self::Completed | Self::TerminatedByEvent)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OdeSolution {
+    pub t: Vec<f64>,
+    pub y: Vec<Vector<f64>>,
+    /// イベントごとの検出時刻と、そのときの状態
+    pub t_events: Vec<Vec<f64>>,
+    pub y_events: Vec<Vec<Vector<f64>>>,
+    pub status: OdeStatus,
+    /// 受理したステップ数
+    pub steps: usize,
+    pub rejected_steps: usize,
+    /// f の評価回数（差分ヤコビ行列の分を含む）
+    pub evaluations: usize,
+    pub jacobian_evaluations: usize,
+    pub lu_decompositions: usize,
+    /// `OdeOptions::dense_output` を指定したときの連続出力
+    pub dense: Option<DenseOutput>,
+}
+
+/// 1 ステップ分の補間多項式。各手法の次数に合った連続出力を与える。
+#[derive(Debug, Clone)]
+pub(crate) enum Interpolant {
+    /// 端点の値と微分による 3 次 Hermite 補間
+    Hermite {
+        t0: f64,
+        h: f64,
+        y0: Vector<f64>,
+        y1: Vector<f64>,
+        f0: Vector<f64>,
+        f1: Vector<f64>,
+    },
+    /// y0 + Σ_k q_k x^k（x = (t - t0) / h, k = 1, 2, ...）
+    Polynomial {
+        t0: f64,
+        h: f64,
+        y0: Vector<f64>,
+        q: Vec<Vector<f64>>,
+    },
+    /// BDF の後方差分表による Newton 形式の補間 D_0 + Σ_k D_k Π_{j<k} (t - t_j) / (j + 1) h
+    Differences {
+        shifts: Vec<f64>,
+        denominators: Vec<f64>,
+        d: Vec<Vector<f64>>,
+    },
+}
+
+impl Interpolant {
+    pub(crate) fn eval(&self, t: f64) -> Vector<f64> {
+        match self {
+            Self::Hermite {
+                t0,
+                h,
+                y0,
+                y1,
+                f0,
+                f1,
+            } => {
+                let s = (t - t0) / h;
+                let (s2, s3) = (s * s, s * s * s);
+                let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+                let h10 = (s3 - 2.0 * s2 + s) * h;
+                let h01 = -2.0 * s3 + 3.0 * s2;
+                let h11 = (s3 - s2) * h;
+                Vector::new(
+                    (0..y0.dim())
+                        .map(|i| h00 * y0[i] + h10 * f0[i] + h01 * y1[i] + h11 * f1[i])
+                        .collect(),
+                )
+            }
+            Self::Polynomial { t0, h, y0, q } => {
+                let x = (t - t0) / h;
+                let mut y = y0.clone();
+                let mut p = 1.0;
+                for qk in q {
+                    p *= x;
+                    for (yi, qi) in y.iter_mut().zip(qk.iter()) {
+                        *yi += p * qi;
+                    }
+                }
+                y
+            }
+            Self::Differences {
+                shifts,
+                denominators,
+                d,
+            } => {
+                let mut y = d[0].clone();
+                let mut p = 1.0;
+                for ((shift, denom), dk) in shifts.iter().zip(denominators).zip(&d[1..]) {
+                    p *= (t - shift) / denom;
+                    for (yi, di) in y.iter_mut().zip(dk.iter()) {
+                        *yi += p * di;
+                    }
+                }
+                y
+            }
+        }
+    }
+}
+
+/// 積分区間全体の連続出力（各ステップの補間多項式をつないだもの）
+#[derive(Debug, Clone)]
+pub struct DenseOutput {
+    /// 各区間の [始点, 終点] と補間多項式（時刻順）
+    pub(crate) segments: Vec<(f64, f64, Interpolant)>,
+}
+
+impl DenseOutput {
+    /// 時刻 t での解。積分した区間の外なら `None`。
+    pub fn eval(&self, t: f64) -> Option<Vector<f64>> {
+        let first = self.segments.first()?;
+        let last = self.segments.last()?;
+        if t < first.0 || t > last.1 {
+            return None;
+        }
+        let k = self.segments.partition_point(|s| s.1 < t);
+        Some(self.segments[k].2.eval(t))
+    }
+
+    pub fn t_min(&self) -> Option<f64> {
+        self.segments.first().map(|s| s.0)
+    }
+
+    pub fn t_max(&self) -> Option<f64> {
+        self.segments.last().map(|s| s.1)
+    }
+}
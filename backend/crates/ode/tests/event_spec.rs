@@ -0,0 +1,103 @@
+use linalg::Vector;
+use ode::{Event, EventDirection, OdeMethod, OdeOptions, OdeProblem, OdeStatus};
+
+const G: f64 = 9.81;
+
+fn falling_ball(height: f64) -> OdeProblem<'static> {
+    OdeProblem::new(
+        |_, y: &Vector<f64>| Vector::new(vec![y[1], -G]),
+        (0.0, 10.0),
+        Vector::new(vec![height, 0.0]),
+    )
+}
+
+#[test]
+fn terminal_event_stops_at_ground() {
+    let mut problem = falling_ball(10.0);
+    problem.add_event(Event::new(|_, y| y[0], EventDirection::Falling, true));
+    let sol = problem
+        .solve(OdeMethod::Rk45, &OdeOptions::default())
+        .unwrap();
+    assert_eq!(sol.status, OdeStatus::TerminatedByEvent);
+    assert!(sol.status.is_success());
+
+    let impact = (2.0 * 10.0 / G).sqrt();
+    assert_eq!(sol.t_events[0].len(), 1);
+    assert!((sol.t_events[0][0] - impact).abs() < 1e-10);
+    assert!(sol.y_events[0][0][0].abs() < 1e-9);
+    // 出力の最後はイベント時刻
+    assert_eq!(*sol.t.last().unwrap(), sol.t_events[0][0]);
+    assert!((sol.y.last().unwrap()[1] + G * impact).abs() < 1e-8);
+}
+
+#[test]
+fn non_terminal_events_respect_direction() {
+    // sin t の零点を向き別に数える
+    let mut problem = OdeProblem::new(
+        |t, _: &Vector<f64>| Vector::new(vec![t.cos()]),
+        (0.0, 20.0),
+        Vector::new(vec![0.0]),
+    );
+    problem.add_event(Event::new(|_, y| y[0], EventDirection::Both, false));
+    problem.add_event(Event::new(|_, y| y[0], EventDirection::Rising, false));
+    problem.add_event(Event::new(|_, y| y[0], EventDirection::Falling, false));
+    let opts = OdeOptions {
+        rtol: 1e-10,
+        atol: 1e-12,
+        ..Default::default()
+    };
+    for method in [
+        OdeMethod::Rk45,
+        OdeMethod::Radau,
+        OdeMethod::Rk4 { step: 0.01 },
+    ] {
+        let sol = problem.solve(method, &opts).unwrap();
+        assert_eq!(sol.status, OdeStatus::Completed);
+        // t = 0 の零点は始点なので数えない。π, 2π, ..., 6π
+        assert_eq!(sol.t_events[0].len(), 6, "{method:?}");
+        assert_eq!(sol.t_events[1].len(), 3);
+        assert_eq!(sol.t_events[2].len(), 3);
+        for (k, t) in sol.t_events[0].iter().enumerate() {
+            assert!(
+                (t - (k + 1) as f64 * std::f64::consts::PI).abs() < 1e-7,
+                "{method:?}: {t}"
+            );
+        }
+        assert!((sol.t_events[1][0] - 2.0 * std::f64::consts::PI).abs() < 1e-7);
+        assert!((sol.t_events[2][0] - std::f64::consts::PI).abs() < 1e-7);
+    }
+}
+
+#[test]
+fn bouncing_ball_restarts_after_each_impact() {
+    // 反発係数 0.8 で跳ねるボールを、終端イベントごとに初期値を更新して積分し直す
+    let restitution = 0.8;
+    let mut state = Vector::new(vec![1.0, 0.0]);
+    let mut t = 0.0;
+    let mut impacts = Vec::new();
+    for _ in 0..5 {
+        let mut problem = OdeProblem::new(
+            |_, y: &Vector<f64>| Vector::new(vec![y[1], -G]),
+            (t, t + 10.0),
+            state.clone(),
+        );
+        problem.add_event(Event::new(|_, y| y[0], EventDirection::Falling, true));
+        let sol = problem
+            .solve(OdeMethod::Rk45, &OdeOptions::default())
+            .unwrap();
+        assert_eq!(sol.status, OdeStatus::TerminatedByEvent);
+        t = sol.t_events[0][0];
+        let y = &sol.y_events[0][0];
+        impacts.push(t);
+        state = Vector::new(vec![0.0, -restitution * y[1]]);
+    }
+    // 跳ねている時間は等比数列 2 v_k / g で縮む
+    let first = (2.0 / G).sqrt();
+    let mut expected = first;
+    let mut v = G * first;
+    for (k, t) in impacts.iter().enumerate() {
+        assert!((t - expected).abs() < 1e-8, "impact {k}: {t} vs {expected}");
+        v *= restitution;
+        expected += 2.0 * v / G;
+    }
+}
@@ -0,0 +1,163 @@
+use linalg::Vector;
+use ode::{OdeError, OdeMethod, OdeOptions, OdeProblem, OdeStatus};
+
+fn oscillator() -> OdeProblem<'static> {
+    // y'' = -y, y(0) = 1, y'(0) = 0 の解は (cos t, -sin t)
+    OdeProblem::new(
+        |_, y: &Vector<f64>| Vector::new(vec![y[1], -y[0]]),
+        (0.0, 10.0),
+        Vector::new(vec![1.0, 0.0]),
+    )
+}
+
+#[test]
+fn rk4_has_fourth_order_global_error() {
+    let mut problem = OdeProblem::new(
+        |_, y: &Vector<f64>| Vector::new(vec![-y[0]]),
+        (0.0, 1.0),
+        Vector::new(vec![1.0]),
+    );
+    let error = |step: f64, problem: &mut OdeProblem| {
+        let sol = problem
+            .solve(OdeMethod::Rk4 { step }, &OdeOptions::default())
+            .unwrap();
+        assert_eq!(*sol.t.last().unwrap(), 1.0);
+        (sol.y.last().unwrap()[0] - (-1.0f64).exp()).abs()
+    };
+    let coarse = error(0.1, &mut problem);
+    let fine = error(0.05, &mut problem);
+    // 刻みを半分にすると誤差は約 1/16
+    let ratio = coarse / fine;
+    assert!((14.0..18.0).contains(&ratio), "ratio = {ratio}");
+
+    // 区間を割り切らない刻みでも最後は t1 にちょうど止まる
+    let sol = problem
+        .solve(OdeMethod::Rk4 { step: 0.3 }, &OdeOptions::default())
+        .unwrap();
+    assert_eq!(sol.t, vec![0.0, 0.3, 0.6, 0.8999999999999999, 1.0]);
+}
+
+#[test]
+fn rk45_tracks_harmonic_oscillator() {
+    let mut problem = oscillator();
+    let opts = OdeOptions {
+        rtol: 1e-8,
+        atol: 1e-10,
+        ..Default::default()
+    };
+    let sol = problem.solve(OdeMethod::Rk45, &opts).unwrap();
+    assert_eq!(sol.status, OdeStatus::Completed);
+    for (t, y) in sol.t.iter().zip(&sol.y) {
+        assert!((y[0] - t.cos()).abs() < 1e-6);
+        assert!((y[1] + t.sin()).abs() < 1e-6);
+    }
+    // 許容誤差を緩めるとステップ数が減る
+    let loose = problem
+        .solve(OdeMethod::Rk45, &OdeOptions::default())
+        .unwrap();
+    assert!(loose.steps * 3 < sol.steps);
+    assert!(loose.evaluations < sol.evaluations);
+}
+
+#[test]
+fn t_eval_and_dense_output_interpolate_between_steps() {
+    let mut problem = oscillator();
+    let t_eval: Vec<f64> = (0..=40).map(|k| k as f64 * 0.25).collect();
+    let opts = OdeOptions {
+        rtol: 1e-9,
+        atol: 1e-12,
+        t_eval: Some(t_eval.clone()),
+        dense_output: true,
+        ..Default::default()
+    };
+    for method in [OdeMethod::Rk45, OdeMethod::Radau, OdeMethod::Bdf] {
+        let sol = problem.solve(method, &opts).unwrap();
+        assert_eq!(sol.t, t_eval);
+        let tol = if method == OdeMethod::Bdf { 1e-5 } else { 1e-7 };
+        for (t, y) in sol.t.iter().zip(&sol.y) {
+            assert!((y[0] - t.cos()).abs() < tol, "{method:?} at {t}");
+        }
+        let dense = sol.dense.unwrap();
+        assert_eq!((dense.t_min(), dense.t_max()), (Some(0.0), Some(10.0)));
+        for k in 0..=100 {
+            let t = 0.1 * k as f64 + 0.0123 * (k % 3) as f64;
+            if t > 10.0 {
+                continue;
+            }
+            let y = dense.eval(t).unwrap();
+            assert!((y[1] + t.sin()).abs() < tol, "{method:?} dense at {t}");
+        }
+        assert!(dense.eval(10.5).is_none());
+    }
+}
+
+#[test]
+fn invalid_problems_are_rejected() {
+    let mut problem = oscillator();
+    let bad = OdeOptions {
+        rtol: 0.0,
+        ..Default::default()
+    };
+    assert!(matches!(
+        problem.solve(OdeMethod::Rk45, &bad),
+        Err(OdeError::InvalidParameter {
+            what: "tolerance",
+            ..
+        })
+    ));
+    // atol = 0 だと y_i = 0 の成分で誤差スケールが 0 になる
+    let zero_atol = OdeOptions {
+        atol: 0.0,
+        ..Default::default()
+    };
+    assert!(matches!(
+        problem.solve(OdeMethod::Rk45, &zero_atol),
+        Err(OdeError::InvalidParameter {
+            what: "tolerance",
+            ..
+        })
+    ));
+    let outside = OdeOptions {
+        t_eval: Some(vec![0.0, 11.0]),
+        ..Default::default()
+    };
+    assert!(problem.solve(OdeMethod::Rk45, &outside).is_err());
+    assert!(problem
+        .solve(OdeMethod::Rk4 { step: -1.0 }, &OdeOptions::default())
+        .is_err());
+
+    let mut reversed = OdeProblem::new(
+        |_, y: &Vector<f64>| y.clone(),
+        (1.0, 0.0),
+        Vector::new(vec![1.0]),
+    );
+    assert!(reversed
+        .solve(OdeMethod::Rk45, &OdeOptions::default())
+        .is_err());
+
+    let mut wrong_dim = OdeProblem::new(
+        |_, _: &Vector<f64>| Vector::new(vec![0.0]),
+        (0.0, 1.0),
+        Vector::new(vec![1.0, 2.0]),
+    );
+    assert!(matches!(
+        wrong_dim.solve(OdeMethod::Rk45, &OdeOptions::default()),
+        Err(OdeError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn blow_up_stops_with_small_step() {
+    // y' = y^2, y(0) = 1 は t = 1 で発散する
+    let mut problem = OdeProblem::new(
+        |_, y: &Vector<f64>| Vector::new(vec![y[0] * y[0]]),
+        (0.0, 2.0),
+        Vector::new(vec![1.0]),
+    );
+    let sol = problem
+        .solve(OdeMethod::Rk45, &OdeOptions::default())
+        .unwrap();
+    assert!(!sol.status.is_success());
+    let t_end = *sol.t.last().unwrap();
+    assert!((t_end - 1.0).abs() < 1e-2, "stopped at {t_end}");
+}
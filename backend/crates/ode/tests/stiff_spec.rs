@@ -0,0 +1,81 @@
+use linalg::{Matrix, Vector};
+use ode::{OdeMethod, OdeOptions, OdeProblem, OdeStatus};
+
+/// Robertson の化学反応系（時定数が 1e-4 から 1e4 まで広がる古典的な硬い問題）
+fn robertson(_: f64, y: &Vector<f64>) -> Vector<f64> {
+    let (a, b, c) = (0.04, 1e4, 3e7);
+    Vector::new(vec![
+        -a * y[0] + b * y[1] * y[2],
+        a * y[0] - b * y[1] * y[2] - c * y[1] * y[1],
+        c * y[1] * y[1],
+    ])
+}
+
+#[test]
+fn implicit_methods_solve_robertson() {
+    let opts = OdeOptions {
+        rtol: 1e-6,
+        atol: 1e-10,
+        ..Default::default()
+    };
+    for method in [OdeMethod::Bdf, OdeMethod::Radau] {
+        let mut problem = OdeProblem::new(robertson, (0.0, 40.0), Vector::new(vec![1.0, 0.0, 0.0]));
+        let sol = problem.solve(method, &opts).unwrap();
+        assert_eq!(sol.status, OdeStatus::Completed);
+        let y = sol.y.last().unwrap();
+        // 参照値（Hairer–Wanner の表）
+        assert!((y[0] - 0.715_827_1).abs() < 1e-5, "{method:?}: {y:?}");
+        assert!((y[1] - 9.185_535e-6).abs() < 1e-9, "{method:?}: {y:?}");
+        // 質量保存 y1 + y2 + y3 = 1
+        assert!((y.iter().sum::<f64>() - 1.0).abs() < 1e-8);
+        assert!(sol.steps < 1_000, "{method:?}: {} steps", sol.steps);
+        assert!(sol.lu_decompositions > 0);
+    }
+}
+
+#[test]
+fn analytic_jacobian_is_used_for_van_der_pol() {
+    let mu = 1000.0;
+    let rhs = move |_: f64, y: &Vector<f64>| {
+        Vector::new(vec![y[1], mu * (1.0 - y[0] * y[0]) * y[1] - y[0]])
+    };
+    let jac = move |_: f64, y: &Vector<f64>| {
+        Matrix::new(
+            2,
+            2,
+            vec![
+                0.0,
+                1.0,
+                -2.0 * mu * y[0] * y[1] - 1.0,
+                mu * (1.0 - y[0] * y[0]),
+            ],
+        )
+        .unwrap()
+    };
+    let opts = OdeOptions::default();
+    let y0 = Vector::new(vec![2.0, 0.0]);
+
+    let mut numeric = OdeProblem::new(rhs, (0.0, 3000.0), y0.clone());
+    let mut analytic = OdeProblem::new(rhs, (0.0, 3000.0), y0.clone());
+    analytic.set_jacobian(jac);
+    for method in [OdeMethod::Bdf, OdeMethod::Radau] {
+        let a = analytic.solve(method, &opts).unwrap();
+        let n = numeric.solve(method, &opts).unwrap();
+        assert!(a.status.is_success() && n.status.is_success());
+        // 周期 (3 - 2 ln 2) μ ≈ 1614 の弛張振動なので、t = 3000 では y1 ≈ -1.5 付近を減衰中
+        let (ya, yn) = (a.y.last().unwrap(), n.y.last().unwrap());
+        assert!((ya[0] - yn[0]).abs() < 1e-2, "{method:?}: {ya:?} vs {yn:?}");
+        assert!(ya[0] < -1.0 && ya[0] > -2.0, "{method:?}: {ya:?}");
+        // 解析的ヤコビ行列なら差分のための f の評価が要らない
+        assert!(a.evaluations < n.evaluations);
+        assert!(a.steps < 2_000, "{method:?}: {} steps", a.steps);
+    }
+
+    // 陽的解法は同じ問題に膨大なステップを要する
+    let capped = OdeOptions {
+        max_steps: 2_000,
+        ..Default::default()
+    };
+    let explicit = numeric.solve(OdeMethod::Rk45, &capped).unwrap();
+    assert_eq!(explicit.status, OdeStatus::MaxStepsReached);
+}
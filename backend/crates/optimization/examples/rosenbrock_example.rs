@@ -0,0 +1,66 @@
+use linalg::{Matrix, Vector};
+use optimization::{
+    bfgs, brent, lbfgs, nelder_mead, newton, MinimizeOptions, NelderMeadOptions, RootOptions,
+};
+
+fn rosenbrock(x: &Vector<f64>) -> f64 {
+    100.0 * (x[1] - x[0] * x[0]).powi(2) + (1.0 - x[0]).powi(2)
+}
+
+fn gradient(x: &Vector<f64>) -> Vector<f64> {
+    Vector::new(vec![
+        -400.0 * x[0] * (x[1] - x[0] * x[0]) - 2.0 * (1.0 - x[0]),
+        200.0 * (x[1] - x[0] * x[0]),
+    ])
+}
+
+fn hessian(x: &Vector<f64>) -> Matrix<f64> {
+    Matrix::new(
+        2,
+        2,
+        vec![
+            1200.0 * x[0] * x[0] - 400.0 * x[1] + 2.0,
+            -400.0 * x[0],
+            -400.0 * x[0],
+            200.0,
+        ],
+    )
+    .unwrap()
+}
+
+fn main() {
+    // 目的: 同じ問題を複数の手法で解き、反復回数と評価回数を比べる
+    println!("[Goal] Rosenbrock 関数 f(x, y) = 100 (y - x^2)^2 + (1 - x)^2 を (-1.2, 1) から最小化します。");
+    let x0 = Vector::new(vec![-1.2, 1.0]);
+    let opts = MinimizeOptions::default();
+
+    let reports = [
+        ("Newton", newton(rosenbrock, gradient, hessian, &x0, &opts)),
+        ("BFGS", bfgs(rosenbrock, gradient, &x0, &opts)),
+        ("L-BFGS", lbfgs(rosenbrock, gradient, &x0, &opts)),
+        (
+            "Nelder-Mead",
+            nelder_mead(rosenbrock, &x0, &NelderMeadOptions::default()),
+        ),
+    ];
+    for (name, report) in reports {
+        let r = report.expect("minimization failed");
+        println!(
+            "[{name}] x = {}, f = {:.3e}, iterations = {}, evaluations = {}, stop = {:?}",
+            r.x, r.value, r.iterations, r.evaluations, r.termination
+        );
+    }
+
+    // 求根: x^3 - 2x - 5 = 0 を [2, 3] で
+    let root = brent(
+        |x| x * x * x - 2.0 * x - 5.0,
+        2.0,
+        3.0,
+        &RootOptions::default(),
+    )
+    .expect("root finding failed");
+    println!(
+        "[Brent] x^3 - 2x - 5 = 0 の根 = {:.16} ({} 回の評価)",
+        root.root, root.evaluations
+    );
+}
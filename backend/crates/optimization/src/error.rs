@@ -0,0 +1,42 @@
+use std::fmt;
+
+use linalg::LinalgError;
+
+#[derive(Debug, Clone)]
+pub enum OptimizeError {
+    InvalidParameter { what: &'static str, details: String },
+    DimensionMismatch { expected: String, found: String },
+    NotBracketed { a: f64, b: f64 },
+    NonFiniteValue { what: &'static str },
+    Linalg(LinalgError),
+}
+
+impl fmt::Display for OptimizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptimizeError::InvalidParameter { what, details } => {
+                write!(f, "Invalid parameter for {what}: {details}")
+            }
+            OptimizeError::DimensionMismatch { expected, found } => {
+                write!(f, "Dimension mismatch: expected {expected}, found {found}")
+            }
+            OptimizeError::NotBracketed { a, b } => {
+                write!(f, "f(a) and f(b) must have opposite signs on [{a}, {b}]")
+            }
+            OptimizeError::NonFiniteValue { what } => {
+                write!(f, "{what} is not finite at the initial point")
+            }
+            OptimizeError::Linalg(e) => write!(f, "Linalg error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for OptimizeError {}
+
+impl From<LinalgError> for OptimizeError {
+    fn from(value: LinalgError) -> Self {
+        Self::Linalg(value)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, OptimizeError>;
@@ -0,0 +1,70 @@
+//! 中心差分による導関数の近似。解析的な勾配を用意できないときや、実装した勾配の検算に使う。
+
+use linalg::{Matrix, Vector};
+
+/// 中心差分の刻み h = ε^{1/3} max(|x_i|, 1)（打ち切り誤差 O(h^2) と丸め誤差 O(ε/h) の釣り合い）
+fn step(xi: f64) -> f64 {
+    f64::EPSILON.cbrt() * xi.abs().max(1.0)
+}
+
+/// ∇f(x) の中心差分近似（2n 回の関数評価）
+pub fn gradient<F>(mut f: F, x: &Vector<f64>) -> Vector<f64>
+where
+    F: FnMut(&Vector<f64>) -> f64,
+{
+    let mut xp = x.clone();
+    let data = (0..x.dim())
+        .map(|i| {
+            let h = step(x[i]);
+            xp[i] = x[i] + h;
+            let fp = f(&xp);
+            xp[i] = x[i] - h;
+            let fm = f(&xp);
+            xp[i] = x[i];
+            (fp - fm) / (2.0 * h)
+        })
+        .collect();
+    Vector::new(data)
+}
+
+/// F: R^n → R^m のヤコビ行列（m×n）の中心差分近似
+pub fn jacobian<F>(mut f: F, x: &Vector<f64>) -> Matrix<f64>
+where
+    F: FnMut(&Vector<f64>) -> Vector<f64>,
+{
+    let n = x.dim();
+    let mut xp = x.clone();
+    let mut columns = Vec::with_capacity(n);
+    for i in 0..n {
+        let h = step(x[i]);
+        xp[i] = x[i] + h;
+        let fp = f(&xp);
+        xp[i] = x[i] - h;
+        let fm = f(&xp);
+        xp[i] = x[i];
+        columns.push(Vector::new(
+            fp.iter()
+                .zip(fm.iter())
+                .map(|(a, b)| (a - b) / (2.0 * h))
+                .collect(),
+        ));
+    }
+    let m = columns.first().map_or(0, |c| c.dim());
+    let mut jac = Matrix::zeros(m, n);
+    for (j, column) in columns.iter().enumerate() {
+        for (i, v) in column.iter().enumerate() {
+            jac[(i, j)] = *v;
+        }
+    }
+    jac
+}
+
+/// 勾配の中心差分から作る Hessian の近似。(H + H^T) / 2 で対称化する。
+pub fn hessian<G>(grad: G, x: &Vector<f64>) -> Matrix<f64>
+where
+    G: FnMut(&Vector<f64>) -> Vector<f64>,
+{
+    let h = jacobian(grad, x);
+    let ht = h.transpose();
+    &(&h + &ht) * 0.5
+}
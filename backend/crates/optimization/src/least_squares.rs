@@ -0,0 +1,141 @@
+//! 非線形最小二乗 min ½ ||r(x)||^2
+
+use linalg::matrix::numerical::CholeskyDecomposition;
+use linalg::{Matrix, Vector};
+
+use crate::error::{OptimizeError, Result};
+use crate::line_search::axpy;
+use crate::minimize::{cholesky_solve, inf_norm, MinimizeReport, Termination};
+
+#[derive(Debug, Clone)]
+pub struct LeastSquaresOptions {
+    pub max_iterations: usize,
+    /// ||J^T r||_∞ ≤ gtol で停止
+    pub gtol: f64,
+    /// ||Δx|| ≤ xtol (||x|| + xtol) で停止
+    pub xtol: f64,
+    /// コストの相対減少 ≤ ftol で停止
+    pub ftol: f64,
+    /// 初期減衰 μ_0 = initial_damping · max diag(J^T J)
+    pub initial_damping: f64,
+}
+
+impl Default for LeastSquaresOptions {
+    fn default() -> Self {
+        Self {
+            max_iterations: 200,
+            gtol: 1e-10,
+            xtol: 1e-12,
+            ftol: 1e-14,
+            initial_damping: 1e-3,
+        }
+    }
+}
+
+/// Levenberg–Marquardt 法。(J^T J + μ I) h = -J^T r を解き、
+/// 実際の減少と線形モデルの予測減少の比 ρ で μ を調整する（Madsen–Nielsen の更新則）。
+/// 返す `value` はコスト ½ ||r||^2。
+pub fn levenberg_marquardt<R, J>(
+    mut residual: R,
+    mut jacobian: J,
+    x0: &Vector<f64>,
+    opts: &LeastSquaresOptions,
+) -> Result<MinimizeReport>
+where
+    R: FnMut(&Vector<f64>) -> Vector<f64>,
+    J: FnMut(&Vector<f64>) -> Matrix<f64>,
+{
+    if opts.initial_damping.is_nan() || opts.initial_damping <= 0.0 {
+        return Err(OptimizeError::InvalidParameter {
+            what: "initial_damping",
+            details: format!("must be positive, got {}", opts.initial_damping),
+        });
+    }
+    let n = x0.dim();
+    let mut x = x0.clone();
+    let mut r = residual(&x);
+    let mut jac = jacobian(&x);
+    if jac.rows != r.dim() || jac.cols != n {
+        return Err(OptimizeError::DimensionMismatch {
+            expected: format!("{}x{n} Jacobian", r.dim()),
+            found: format!("{}x{}", jac.rows, jac.cols),
+        });
+    }
+    let mut cost = 0.5 * r.dot(&r);
+    if !cost.is_finite() {
+        return Err(OptimizeError::NonFiniteValue { what: "residual" });
+    }
+    let mut evaluations = 1;
+    let mut history = vec![cost];
+    let (mut a, mut g) = normal_equations(&jac, &r)?;
+    let max_diag = (0..n).map(|i| a[(i, i)]).fold(0.0, f64::max);
+    let mut mu = opts.initial_damping * max_diag.max(1.0);
+    let mut nu = 2.0;
+    let mut iterations = 0;
+    let mut termination = Termination::MaxIterations;
+
+    while iterations < opts.max_iterations {
+        if inf_norm(&g) <= opts.gtol {
+            termination = Termination::GradientTolerance;
+            break;
+        }
+        iterations += 1;
+        let mut damped = a.clone();
+        for i in 0..n {
+            damped[(i, i)] += mu;
+        }
+        let Ok(l) = damped.cholesky() else {
+            mu *= nu;
+            nu *= 2.0;
+            continue;
+        };
+        let h = cholesky_solve(&l, &g.checked_neg())?;
+        if h.norm() <= opts.xtol * (x.norm() + opts.xtol) {
+            termination = Termination::StepTolerance;
+            break;
+        }
+        let x_new = axpy(&x, 1.0, &h);
+        let r_new = residual(&x_new);
+        evaluations += 1;
+        let cost_new = 0.5 * r_new.dot(&r_new);
+        // L(0) - L(h) = ½ h^T (μ h - g)
+        let predicted = 0.5 * h.dot(&(&(&h * mu) - &g));
+        let rho = (cost - cost_new) / predicted;
+        if rho > 0.0 && cost_new.is_finite() {
+            let decrease = cost - cost_new;
+            x = x_new;
+            r = r_new;
+            cost = cost_new;
+            jac = jacobian(&x);
+            (a, g) = normal_equations(&jac, &r)?;
+            mu *= (1.0 - (2.0 * rho - 1.0).powi(3)).max(1.0 / 3.0);
+            nu = 2.0;
+            history.push(cost);
+            if decrease <= opts.ftol * (cost + decrease) {
+                termination = Termination::FunctionTolerance;
+                break;
+            }
+        } else {
+            mu *= nu;
+            nu *= 2.0;
+        }
+    }
+    Ok(MinimizeReport {
+        x,
+        value: cost,
+        gradient_norm: Some(inf_norm(&g)),
+        iterations,
+        evaluations,
+        converged: termination.is_converged(),
+        termination,
+        history,
+    })
+}
+
+/// (J^T J, J^T r)
+fn normal_equations(jac: &Matrix<f64>, r: &Vector<f64>) -> Result<(Matrix<f64>, Vector<f64>)> {
+    let jt = jac.transpose();
+    let a = &jt * jac;
+    let g = jt.checked_mul_vector(r)?;
+    Ok((a, g))
+}
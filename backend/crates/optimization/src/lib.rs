@@ -0,0 +1,17 @@
+pub mod error;
+pub mod finite_difference;
+pub mod least_squares;
+pub mod line_search;
+pub mod minimize;
+pub mod root;
+
+pub use error::{OptimizeError, Result};
+pub use least_squares::{levenberg_marquardt, LeastSquaresOptions};
+pub use line_search::{LineSearchResult, WolfeParams};
+pub use minimize::{
+    bfgs, lbfgs, nelder_mead, newton, MinimizeOptions, MinimizeReport, NelderMeadOptions,
+    Termination,
+};
+pub use root::{
+    bisection, brent, newton_raphson, newton_system, RootOptions, RootReport, SystemRootReport,
+};
@@ -0,0 +1,242 @@
+use linalg::Vector;
+
+/// 強 Wolfe 条件の定数
+#[derive(Debug, Clone, Copy)]
+pub struct WolfeParams {
+    /// 十分減少条件（Armijo）の係数 0 < c1 < c2
+    pub c1: f64,
+    /// 曲率条件の係数 c1 < c2 < 1（準 Newton 法では 0.9、共役勾配法では 0.1 程度）
+    pub c2: f64,
+    pub max_step: f64,
+    /// 1 回の直線探索で目的関数を評価する上限
+    pub max_evaluations: usize,
+}
+
+impl Default for WolfeParams {
+    fn default() -> Self {
+        Self {
+            c1: 1e-4,
+            c2: 0.9,
+            max_step: 1e10,
+            max_evaluations: 40,
+        }
+    }
+}
+
+/// 直線探索で受理した点 x + step p
+#[derive(Debug, Clone)]
+pub struct LineSearchResult {
+    pub step: f64,
+    pub x: Vector<f64>,
+    pub value: f64,
+    /// 受理点での勾配（`backtracking` では評価しないので `None`）
+    pub gradient: Option<Vector<f64>>,
+    pub evaluations: usize,
+}
+
+/// Armijo 条件 f(x + α p) ≤ f(x) + c1 α ∇f(x)^T p を満たすまで α を半分にするバックトラック法。
+/// p が降下方向でないか、α が極端に小さくなっても減少しなければ `None`。
+pub fn backtracking<F>(
+    mut f: F,
+    x: &Vector<f64>,
+    fx: f64,
+    g: &Vector<f64>,
+    p: &Vector<f64>,
+    initial_step: f64,
+    c1: f64,
+) -> Option<LineSearchResult>
+where
+    F: FnMut(&Vector<f64>) -> f64,
+{
+    let slope = g.dot(p);
+    if slope.is_nan() || slope >= 0.0 {
+        return None;
+    }
+    let mut step = initial_step;
+    for evaluations in 1..=60 {
+        let xa = axpy(x, step, p);
+        let value = f(&xa);
+        if value <= fx + c1 * step * slope {
+            return Some(LineSearchResult {
+                step,
+                x: xa,
+                value,
+                gradient: None,
+                evaluations,
+            });
+        }
+        step *= 0.5;
+    }
+    None
+}
+
+/// 強 Wolfe 条件
+///   f(x + α p) ≤ f(x) + c1 α ∇f^T p,  |∇f(x + α p)^T p| ≤ c2 |∇f^T p|
+/// を満たす α を、区間の拡大と二次補間による絞り込み（Nocedal–Wright Alg. 3.5 / 3.6）で探す。
+/// 見つからなければ `None`。
+#[allow(clippy::too_many_arguments)]
+pub fn strong_wolfe<F, G>(
+    f: F,
+    grad: G,
+    x: &Vector<f64>,
+    fx: f64,
+    g: &Vector<f64>,
+    p: &Vector<f64>,
+    initial_step: f64,
+    params: &WolfeParams,
+) -> Option<LineSearchResult>
+where
+    F: FnMut(&Vector<f64>) -> f64,
+    G: FnMut(&Vector<f64>) -> Vector<f64>,
+{
+    let slope0 = g.dot(p);
+    if slope0.is_nan() || slope0 >= 0.0 {
+        return None;
+    }
+    let mut phi = Phi {
+        f,
+        grad,
+        x,
+        p,
+        fx,
+        slope0,
+        params,
+        evaluations: 0,
+    };
+    let mut prev = Trial {
+        step: 0.0,
+        x: x.clone(),
+        value: fx,
+        gradient: Some(g.clone()),
+        slope: slope0,
+    };
+    let mut step = initial_step.min(params.max_step);
+    for i in 0..params.max_evaluations {
+        let mut trial = phi.value(step);
+        if !phi.armijo(&trial) || (i > 0 && trial.value >= prev.value) {
+            return phi.zoom(prev, trial);
+        }
+        phi.slope(&mut trial);
+        if phi.curvature(&trial) {
+            return Some(phi.accept(trial));
+        }
+        if trial.slope >= 0.0 {
+            return phi.zoom(trial, prev);
+        }
+        if step >= params.max_step {
+            return None;
+        }
+        prev = trial;
+        step = (2.0 * step).min(params.max_step);
+    }
+    None
+}
+
+/// 直線探索の試行点。`slope` は勾配を評価するまで NaN。
+struct Trial {
+    step: f64,
+    x: Vector<f64>,
+    value: f64,
+    gradient: Option<Vector<f64>>,
+    slope: f64,
+}
+
+/// φ(α) = f(x + α p) とその微分を評価する
+struct Phi<'a, F, G> {
+    f: F,
+    grad: G,
+    x: &'a Vector<f64>,
+    p: &'a Vector<f64>,
+    fx: f64,
+    slope0: f64,
+    params: &'a WolfeParams,
+    evaluations: usize,
+}
+
+impl<F, G> Phi<'_, F, G>
+where
+    F: FnMut(&Vector<f64>) -> f64,
+    G: FnMut(&Vector<f64>) -> Vector<f64>,
+{
+    fn value(&mut self, step: f64) -> Trial {
+        self.evaluations += 1;
+        let x = axpy(self.x, step, self.p);
+        let value = (self.f)(&x);
+        Trial {
+            step,
+            x,
+            value,
+            gradient: None,
+            slope: f64::NAN,
+        }
+    }
+
+    fn slope(&mut self, trial: &mut Trial) {
+        let g = (self.grad)(&trial.x);
+        trial.slope = g.dot(self.p);
+        trial.gradient = Some(g);
+    }
+
+    /// 十分減少条件（NaN は満たさない扱い）
+    fn armijo(&self, trial: &Trial) -> bool {
+        trial.value <= self.fx + self.params.c1 * trial.step * self.slope0
+    }
+
+    fn curvature(&self, trial: &Trial) -> bool {
+        trial.slope.abs() <= -self.params.c2 * self.slope0
+    }
+
+    fn accept(&self, trial: Trial) -> LineSearchResult {
+        LineSearchResult {
+            step: trial.step,
+            x: trial.x,
+            value: trial.value,
+            gradient: trial.gradient,
+            evaluations: self.evaluations,
+        }
+    }
+
+    /// lo は十分減少条件を満たし勾配が既知の点、hi は lo との間に条件を満たす点を挟むもう一端
+    fn zoom(&mut self, mut lo: Trial, mut hi: Trial) -> Option<LineSearchResult> {
+        while self.evaluations < self.params.max_evaluations {
+            let d = hi.step - lo.step;
+            if d.abs() <= f64::EPSILON * lo.step.abs().max(1.0) {
+                break;
+            }
+            // φ(lo), φ'(lo), φ(hi) を通る二次式の最小点。端に寄りすぎたら中点を使う。
+            let denom = 2.0 * (hi.value - lo.value - lo.slope * d);
+            let mut step = lo.step - lo.slope * d * d / denom;
+            let (left, right) = (lo.step.min(hi.step), lo.step.max(hi.step));
+            let margin = 0.1 * d.abs();
+            if !(step > left + margin && step < right - margin) {
+                step = lo.step + 0.5 * d;
+            }
+            let mut trial = self.value(step);
+            if !self.armijo(&trial) || trial.value >= lo.value {
+                hi = trial;
+                continue;
+            }
+            self.slope(&mut trial);
+            if self.curvature(&trial) {
+                return Some(self.accept(trial));
+            }
+            if trial.slope * d >= 0.0 {
+                hi = lo;
+            }
+            lo = trial;
+        }
+        // 曲率条件は満たせなかったが、十分減少した点があればそれを使う
+        (lo.step > 0.0).then(|| self.accept(lo))
+    }
+}
+
+/// x + a p
+pub(crate) fn axpy(x: &Vector<f64>, a: f64, p: &Vector<f64>) -> Vector<f64> {
+    Vector::new(
+        x.data
+            .iter()
+            .zip(&p.data)
+            .map(|(xi, pi)| xi + a * pi)
+            .collect(),
+    )
+}
@@ -0,0 +1,211 @@
+//! 無制約最小化 min f(x)。
+//! 勾配法系（Newton・BFGS・L-BFGS）は探索方向だけが異なり、強 Wolfe 直線探索と収束判定は共通。
+
+use linalg::{Matrix, Vector};
+
+use crate::error::{OptimizeError, Result};
+use crate::line_search::{strong_wolfe, WolfeParams};
+
+mod nelder_mead;
+mod newton;
+mod quasi_newton;
+
+pub use nelder_mead::{nelder_mead, NelderMeadOptions};
+pub use newton::newton;
+pub use quasi_newton::{bfgs, lbfgs};
+
+#[derive(Debug, Clone)]
+pub struct MinimizeOptions {
+    pub max_iterations: usize,
+    /// ||∇f||_∞ ≤ gtol で停止
+    pub gtol: f64,
+    /// ||Δx||_∞ ≤ xtol (1 + ||x||_∞) で停止
+    pub xtol: f64,
+    /// f の減少量 ≤ ftol max(|f|, 1) で停止
+    pub ftol: f64,
+    pub wolfe: WolfeParams,
+    /// L-BFGS が保持する (s, y) の組の数
+    pub memory: usize,
+}
+
+impl Default for MinimizeOptions {
+    fn default() -> Self {
+        Self {
+            max_iterations: 1000,
+            gtol: 1e-8,
+            xtol: 1e-14,
+            ftol: f64::EPSILON,
+            wolfe: WolfeParams::default(),
+            memory: 10,
+        }
+    }
+}
+
+/// 反復を止めた理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    GradientTolerance,
+    StepTolerance,
+    FunctionTolerance,
+    /// Nelder–Mead の単体の大きさと関数値の幅が許容値を下回った
+    SimplexTolerance,
+    MaxIterations,
+    /// 最急降下方向に戻しても直線探索が十分な減少を得られなかった
+    LineSearchFailed,
+}
+
+impl Termination {
+    pub fn is_converged(self) -> bool {
+        !matches!(self, Self::MaxIterations | Self::LineSearchFailed)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MinimizeReport {
+    pub x: Vector<f64>,
+    pub value: f64,
+    /// 終了時の ||∇f||_∞（勾配を使わない手法では `None`）
+    pub gradient_norm: Option<f64>,
+    pub iterations: usize,
+    /// 目的関数の評価回数
+    pub evaluations: usize,
+    pub converged: bool,
+    pub termination: Termination,
+    /// 各反復後の目的関数値（先頭は初期点）
+    pub history: Vec<f64>,
+}
+
+/// 勾配法の探索方向。`update` には受理したステップ s = x_{k+1} - x_k と y = ∇f_{k+1} - ∇f_k を渡す。
+trait SearchDirection {
+    fn direction(&mut self, x: &Vector<f64>, g: &Vector<f64>) -> Result<Vector<f64>>;
+    fn update(&mut self, _s: &Vector<f64>, _y: &Vector<f64>) {}
+    /// 曲率情報を捨てて最急降下からやり直す
+    fn reset(&mut self) {}
+    fn initial_step(&self, _g: &Vector<f64>) -> f64 {
+        1.0
+    }
+}
+
+/// 直線探索付きの降下法。方向が降下方向でないか直線探索に失敗したら、一度だけ最急降下に戻す。
+fn descend<F, G, D>(
+    mut f: F,
+    mut grad: G,
+    x0: &Vector<f64>,
+    opts: &MinimizeOptions,
+    dir: &mut D,
+) -> Result<MinimizeReport>
+where
+    F: FnMut(&Vector<f64>) -> f64,
+    G: FnMut(&Vector<f64>) -> Vector<f64>,
+    D: SearchDirection,
+{
+    validate_options(opts)?;
+    let mut x = x0.clone();
+    let mut fx = f(&x);
+    let mut g = grad(&x);
+    check_start(x0, fx, &g)?;
+    let mut evaluations = 1;
+    let mut history = vec![fx];
+    let mut iterations = 0;
+    let mut termination = Termination::MaxIterations;
+    if inf_norm(&g) <= opts.gtol {
+        termination = Termination::GradientTolerance;
+    }
+    while termination == Termination::MaxIterations && iterations < opts.max_iterations {
+        let mut p = dir.direction(&x, &g)?;
+        let mut steepest = false;
+        let slope = g.dot(&p);
+        if slope.is_nan() || slope >= 0.0 {
+            dir.reset();
+            p = g.checked_neg();
+            steepest = true;
+        }
+        let mut step = dir.initial_step(&g);
+        let mut found = strong_wolfe(&mut f, &mut grad, &x, fx, &g, &p, step, &opts.wolfe);
+        if found.is_none() && !steepest {
+            dir.reset();
+            p = g.checked_neg();
+            step = dir.initial_step(&g);
+            found = strong_wolfe(&mut f, &mut grad, &x, fx, &g, &p, step, &opts.wolfe);
+        }
+        let Some(ls) = found else {
+            termination = Termination::LineSearchFailed;
+            break;
+        };
+        evaluations += ls.evaluations;
+        iterations += 1;
+        let g_new = match ls.gradient {
+            Some(g_new) => g_new,
+            None => grad(&ls.x),
+        };
+        let s = ls.x.checked_sub(&x)?;
+        let y = g_new.checked_sub(&g)?;
+        dir.update(&s, &y);
+        let decrease = fx - ls.value;
+        let scale = fx.abs().max(ls.value.abs()).max(1.0);
+        x = ls.x;
+        fx = ls.value;
+        g = g_new;
+        history.push(fx);
+        if inf_norm(&g) <= opts.gtol {
+            termination = Termination::GradientTolerance;
+        } else if inf_norm(&s) <= opts.xtol * (1.0 + inf_norm(&x)) {
+            termination = Termination::StepTolerance;
+        } else if decrease <= opts.ftol * scale {
+            termination = Termination::FunctionTolerance;
+        }
+    }
+    Ok(MinimizeReport {
+        gradient_norm: Some(inf_norm(&g)),
+        x,
+        value: fx,
+        iterations,
+        evaluations,
+        converged: termination.is_converged(),
+        termination,
+        history,
+    })
+}
+
+fn validate_options(opts: &MinimizeOptions) -> Result<()> {
+    let w = &opts.wolfe;
+    if !(0.0 < w.c1 && w.c1 < w.c2 && w.c2 < 1.0) {
+        return Err(OptimizeError::InvalidParameter {
+            what: "wolfe",
+            details: format!("0 < c1 < c2 < 1 required, got c1 = {}, c2 = {}", w.c1, w.c2),
+        });
+    }
+    if w.max_step.is_nan() || w.max_step <= 0.0 {
+        return Err(OptimizeError::InvalidParameter {
+            what: "wolfe",
+            details: format!("max_step must be positive, got {}", w.max_step),
+        });
+    }
+    Ok(())
+}
+
+fn check_start(x0: &Vector<f64>, fx: f64, g: &Vector<f64>) -> Result<()> {
+    if g.dim() != x0.dim() {
+        return Err(OptimizeError::DimensionMismatch {
+            expected: format!("gradient of dimension {}", x0.dim()),
+            found: format!("{}", g.dim()),
+        });
+    }
+    if !fx.is_finite() {
+        return Err(OptimizeError::NonFiniteValue { what: "objective" });
+    }
+    if g.iter().any(|v| !v.is_finite()) {
+        return Err(OptimizeError::NonFiniteValue { what: "gradient" });
+    }
+    Ok(())
+}
+
+pub(crate) fn inf_norm(v: &Vector<f64>) -> f64 {
+    v.iter().fold(0.0, |m, x| m.max(x.abs()))
+}
+
+/// Cholesky 因子 L を使って L L^T x = b を解く
+pub(crate) fn cholesky_solve(l: &Matrix<f64>, b: &Vector<f64>) -> Result<Vector<f64>> {
+    let y = l.forward_substitution(b)?;
+    Ok(l.transpose().backward_substitution(&y)?)
+}
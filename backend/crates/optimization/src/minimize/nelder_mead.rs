@@ -0,0 +1,183 @@
+use linalg::Vector;
+
+use super::{MinimizeReport, Termination};
+use crate::error::{OptimizeError, Result};
+
+#[derive(Debug, Clone)]
+pub struct NelderMeadOptions {
+    pub max_iterations: usize,
+    /// 単体の頂点と最良点の距離 ||x_i - x_0||_∞ の許容値
+    pub xatol: f64,
+    /// 頂点での関数値の幅 |f_i - f_0| の許容値
+    pub fatol: f64,
+    /// 初期単体の辺の長さ（座標に対する相対値。0 の座標では 0.00025）
+    pub initial_step: f64,
+    /// 次元に応じて係数を調整する（Gao–Han 2012）。高次元で停滞しにくくなる。
+    pub adaptive: bool,
+}
+
+impl Default for NelderMeadOptions {
+    fn default() -> Self {
+        Self {
+            max_iterations: 10_000,
+            xatol: 1e-8,
+            fatol: 1e-8,
+            initial_step: 0.05,
+            adaptive: false,
+        }
+    }
+}
+
+/// 勾配を使わない Nelder–Mead 単体法。NaN を返す点は +∞ とみなす。
+pub fn nelder_mead<F>(
+    mut f: F,
+    x0: &Vector<f64>,
+    opts: &NelderMeadOptions,
+) -> Result<MinimizeReport>
+where
+    F: FnMut(&Vector<f64>) -> f64,
+{
+    let n = x0.dim();
+    if n == 0 {
+        return Err(OptimizeError::InvalidParameter {
+            what: "x0",
+            details: "at least one variable is required".into(),
+        });
+    }
+    let (reflect, expand, contract, shrink) = if opts.adaptive {
+        let d = n as f64;
+        (1.0, 1.0 + 2.0 / d, 0.75 - 0.5 / d, 1.0 - 1.0 / d)
+    } else {
+        (1.0, 2.0, 0.5, 0.5)
+    };
+    let mut eval = |x: &Vector<f64>| {
+        let v = f(x);
+        if v.is_nan() {
+            f64::INFINITY
+        } else {
+            v
+        }
+    };
+
+    let mut simplex = vec![x0.clone()];
+    for i in 0..n {
+        let mut v = x0.clone();
+        v[i] = if v[i] != 0.0 {
+            (1.0 + opts.initial_step) * v[i]
+        } else {
+            0.00025
+        };
+        simplex.push(v);
+    }
+    let mut values: Vec<f64> = simplex.iter().map(&mut eval).collect();
+    if !values[0].is_finite() {
+        return Err(OptimizeError::NonFiniteValue { what: "objective" });
+    }
+    let mut evaluations = n + 1;
+    sort_simplex(&mut simplex, &mut values);
+    let mut history = vec![values[0]];
+    let mut iterations = 0;
+    let mut termination = Termination::MaxIterations;
+
+    while iterations < opts.max_iterations {
+        if converged(&simplex, &values, opts) {
+            termination = Termination::SimplexTolerance;
+            break;
+        }
+        iterations += 1;
+        // 最悪点を除いた重心
+        let centroid = Vector::new(
+            (0..n)
+                .map(|j| simplex[..n].iter().map(|v| v[j]).sum::<f64>() / n as f64)
+                .collect(),
+        );
+        let along = |t: f64| -> Vector<f64> {
+            Vector::new(
+                (0..n)
+                    .map(|j| centroid[j] + t * (centroid[j] - simplex[n][j]))
+                    .collect(),
+            )
+        };
+        let xr = along(reflect);
+        let fr = eval(&xr);
+        evaluations += 1;
+        let mut accepted = None;
+        if fr < values[0] {
+            let xe = along(reflect * expand);
+            let fe = eval(&xe);
+            evaluations += 1;
+            accepted = Some(if fe < fr { (xe, fe) } else { (xr, fr) });
+        } else if fr < values[n - 1] {
+            accepted = Some((xr, fr));
+        } else {
+            // 外側（反射点が最悪点よりまし）または内側への縮小
+            let (xc, fc, bound) = if fr < values[n] {
+                let xc = along(reflect * contract);
+                let fc = eval(&xc);
+                (xc, fc, fr)
+            } else {
+                let xc = along(-contract);
+                let fc = eval(&xc);
+                (xc, fc, values[n])
+            };
+            evaluations += 1;
+            if fc <= bound {
+                accepted = Some((xc, fc));
+            }
+        }
+        match accepted {
+            Some((x, fx)) => {
+                simplex[n] = x;
+                values[n] = fx;
+            }
+            None => {
+                // 最良点に向かって全体を縮める
+                for i in 1..=n {
+                    let v = Vector::new(
+                        (0..n)
+                            .map(|j| simplex[0][j] + shrink * (simplex[i][j] - simplex[0][j]))
+                            .collect(),
+                    );
+                    values[i] = eval(&v);
+                    simplex[i] = v;
+                }
+                evaluations += n;
+            }
+        }
+        sort_simplex(&mut simplex, &mut values);
+        history.push(values[0]);
+    }
+    if termination == Termination::MaxIterations && converged(&simplex, &values, opts) {
+        termination = Termination::SimplexTolerance;
+    }
+    Ok(MinimizeReport {
+        x: simplex.swap_remove(0),
+        value: values[0],
+        gradient_norm: None,
+        iterations,
+        evaluations,
+        converged: termination.is_converged(),
+        termination,
+        history,
+    })
+}
+
+fn sort_simplex(simplex: &mut Vec<Vector<f64>>, values: &mut Vec<f64>) {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+    *simplex = order.iter().map(|&i| simplex[i].clone()).collect();
+    *values = order.iter().map(|&i| values[i]).collect();
+}
+
+fn converged(simplex: &[Vector<f64>], values: &[f64], opts: &NelderMeadOptions) -> bool {
+    let best = &simplex[0];
+    let spread_x = simplex[1..]
+        .iter()
+        .flat_map(|v| v.iter().zip(best.iter()).map(|(a, b)| (a - b).abs()))
+        .fold(0.0, f64::max);
+    let spread_f = values[1..]
+        .iter()
+        .map(|v| (v - values[0]).abs())
+        .fold(0.0, f64::max);
+    spread_x <= opts.xatol && spread_f <= opts.fatol
+}
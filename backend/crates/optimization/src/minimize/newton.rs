@@ -0,0 +1,68 @@
+use linalg::matrix::numerical::CholeskyDecomposition;
+use linalg::{Matrix, Vector};
+
+use super::{cholesky_solve, descend, MinimizeOptions, MinimizeReport, SearchDirection};
+use crate::error::{OptimizeError, Result};
+
+/// 修正 Cholesky 法で Hessian の不定性を補う直線探索付き Newton 法。
+/// H + τI が正定値になるまで τ を倍々に増やし、(H + τI) p = -∇f を解いて探索方向とする。
+pub fn newton<F, G, H>(
+    f: F,
+    grad: G,
+    hess: H,
+    x0: &Vector<f64>,
+    opts: &MinimizeOptions,
+) -> Result<MinimizeReport>
+where
+    F: FnMut(&Vector<f64>) -> f64,
+    G: FnMut(&Vector<f64>) -> Vector<f64>,
+    H: FnMut(&Vector<f64>) -> Matrix<f64>,
+{
+    descend(f, grad, x0, opts, &mut NewtonDirection { hess })
+}
+
+struct NewtonDirection<H> {
+    hess: H,
+}
+
+/// 対角シフトの最小値（Nocedal–Wright Alg. 3.3 の β）
+const SHIFT: f64 = 1e-3;
+
+impl<H> SearchDirection for NewtonDirection<H>
+where
+    H: FnMut(&Vector<f64>) -> Matrix<f64>,
+{
+    fn direction(&mut self, x: &Vector<f64>, g: &Vector<f64>) -> Result<Vector<f64>> {
+        let h = (self.hess)(x);
+        let n = x.dim();
+        if h.rows != n || h.cols != n {
+            return Err(OptimizeError::DimensionMismatch {
+                expected: format!("{n}x{n} Hessian"),
+                found: format!("{}x{}", h.rows, h.cols),
+            });
+        }
+        if h.data.iter().any(|v| !v.is_finite()) {
+            return Err(OptimizeError::NonFiniteValue { what: "Hessian" });
+        }
+        let min_diag = (0..n).map(|i| h[(i, i)]).fold(f64::INFINITY, f64::min);
+        let mut tau = if min_diag > 0.0 {
+            0.0
+        } else {
+            SHIFT - min_diag
+        };
+        loop {
+            let mut shifted = h.clone();
+            for i in 0..n {
+                shifted[(i, i)] += tau;
+            }
+            if let Ok(l) = shifted.cholesky() {
+                return cholesky_solve(&l, &g.checked_neg());
+            }
+            tau = (2.0 * tau).max(SHIFT);
+            if !tau.is_finite() {
+                // 降下方向にならない方向を返せば、呼び出し側が最急降下に切り替える
+                return Ok(Vector::zeros(n));
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+
+use linalg::{Matrix, Vector};
+
+use super::{descend, MinimizeOptions, MinimizeReport, SearchDirection};
+use crate::error::{OptimizeError, Result};
+
+/// 逆 Hessian 近似を密行列で持つ BFGS 法。O(n^2) のメモリと 1 反復 O(n^2) の計算。
+pub fn bfgs<F, G>(f: F, grad: G, x0: &Vector<f64>, opts: &MinimizeOptions) -> Result<MinimizeReport>
+where
+    F: FnMut(&Vector<f64>) -> f64,
+    G: FnMut(&Vector<f64>) -> Vector<f64>,
+{
+    let n = x0.dim();
+    let mut dir = Bfgs {
+        inverse: Matrix::identity(n),
+        scaled: false,
+    };
+    descend(f, grad, x0, opts, &mut dir)
+}
+
+/// 直近 m = `opts.memory` 組の (s, y) だけを持ち、二重ループ再帰で H ∇f を計算する L-BFGS 法
+pub fn lbfgs<F, G>(
+    f: F,
+    grad: G,
+    x0: &Vector<f64>,
+    opts: &MinimizeOptions,
+) -> Result<MinimizeReport>
+where
+    F: FnMut(&Vector<f64>) -> f64,
+    G: FnMut(&Vector<f64>) -> Vector<f64>,
+{
+    if opts.memory == 0 {
+        return Err(OptimizeError::InvalidParameter {
+            what: "memory",
+            details: "L-BFGS needs at least one correction pair".into(),
+        });
+    }
+    let mut dir = Lbfgs {
+        memory: opts.memory,
+        pairs: VecDeque::with_capacity(opts.memory),
+    };
+    descend(f, grad, x0, opts, &mut dir)
+}
+
+/// s^T y がこれ以下（相対値）なら曲率が足りないとみなして更新しない
+const CURVATURE_EPS: f64 = 1e-10;
+
+fn has_curvature(s: &Vector<f64>, y: &Vector<f64>) -> Option<f64> {
+    let sy = s.dot(y);
+    (sy > CURVATURE_EPS * s.norm() * y.norm()).then_some(sy)
+}
+
+/// 曲率情報がまだないときは長さ 1 のステップから始める
+fn unit_step(g: &Vector<f64>) -> f64 {
+    let norm = g.norm();
+    if norm > 1.0 {
+        1.0 / norm
+    } else {
+        1.0
+    }
+}
+
+struct Bfgs {
+    inverse: Matrix<f64>,
+    /// 初回の更新で H_0 = (s^T y / y^T y) I に縮尺を合わせたか
+    scaled: bool,
+}
+
+impl SearchDirection for Bfgs {
+    fn direction(&mut self, _x: &Vector<f64>, g: &Vector<f64>) -> Result<Vector<f64>> {
+        Ok(self.inverse.checked_mul_vector(g)?.checked_neg())
+    }
+
+    /// H ← (I - ρ s y^T) H (I - ρ y s^T) + ρ s s^T（ρ = 1 / s^T y）
+    fn update(&mut self, s: &Vector<f64>, y: &Vector<f64>) {
+        let Some(sy) = has_curvature(s, y) else {
+            return;
+        };
+        let n = s.dim();
+        if !self.scaled {
+            self.inverse = Matrix::identity(n) * (sy / y.dot(y));
+            self.scaled = true;
+        }
+        let rho = 1.0 / sy;
+        let hy = self
+            .inverse
+            .checked_mul_vector(y)
+            .expect("dimensions agree");
+        let c = rho * rho * y.dot(&hy) + rho;
+        for i in 0..n {
+            for j in 0..n {
+                self.inverse[(i, j)] += -rho * (s[i] * hy[j] + hy[i] * s[j]) + c * s[i] * s[j];
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.inverse = Matrix::identity(self.inverse.rows);
+        self.scaled = false;
+    }
+
+    fn initial_step(&self, g: &Vector<f64>) -> f64 {
+        if self.scaled {
+            1.0
+        } else {
+            unit_step(g)
+        }
+    }
+}
+
+struct Lbfgs {
+    memory: usize,
+    /// 古い順に (s, y, ρ)
+    pairs: VecDeque<(Vector<f64>, Vector<f64>, f64)>,
+}
+
+impl SearchDirection for Lbfgs {
+    fn direction(&mut self, _x: &Vector<f64>, g: &Vector<f64>) -> Result<Vector<f64>> {
+        let mut q = g.clone();
+        let mut alphas = Vec::with_capacity(self.pairs.len());
+        for (s, y, rho) in self.pairs.iter().rev() {
+            let a = rho * s.dot(&q);
+            q = q.checked_sub(&(y * a))?;
+            alphas.push(a);
+        }
+        // H_0 = γ I, γ = s^T y / y^T y（最新の組）
+        let gamma = self
+            .pairs
+            .back()
+            .map_or(1.0, |(s, y, _)| s.dot(y) / y.dot(y));
+        let mut r = &q * gamma;
+        for ((s, y, rho), a) in self.pairs.iter().zip(alphas.iter().rev()) {
+            let b = rho * y.dot(&r);
+            r = r.checked_add(&(s * (a - b)))?;
+        }
+        Ok(r.checked_neg())
+    }
+
+    fn update(&mut self, s: &Vector<f64>, y: &Vector<f64>) {
+        let Some(sy) = has_curvature(s, y) else {
+            return;
+        };
+        if self.pairs.len() == self.memory {
+            self.pairs.pop_front();
+        }
+        self.pairs.push_back((s.clone(), y.clone(), 1.0 / sy));
+    }
+
+    fn reset(&mut self) {
+        self.pairs.clear();
+    }
+
+    fn initial_step(&self, g: &Vector<f64>) -> f64 {
+        if self.pairs.is_empty() {
+            unit_step(g)
+        } else {
+            1.0
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! 方程式 f(x) = 0 の求根
+
+use linalg::{Matrix, Vector};
+
+use crate::error::{OptimizeError, Result};
+use crate::line_search::backtracking;
+use crate::minimize::inf_norm;
+
+#[derive(Debug, Clone)]
+pub struct RootOptions {
+    /// 区間幅（ステップ幅）が xtol + rtol |x| を下回ったら停止
+    pub xtol: f64,
+    pub rtol: f64,
+    /// |f(x)| ≤ ftol でも停止（0 なら区間幅だけで判定）
+    pub ftol: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for RootOptions {
+    fn default() -> Self {
+        Self {
+            xtol: 1e-12,
+            rtol: 4.0 * f64::EPSILON,
+            ftol: 0.0,
+            max_iterations: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RootReport {
+    pub root: f64,
+    /// f(root)
+    pub value: f64,
+    pub iterations: usize,
+    pub evaluations: usize,
+    pub converged: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SystemRootReport {
+    pub x: Vector<f64>,
+    /// ||F(x)||_2
+    pub residual_norm: f64,
+    pub iterations: usize,
+    pub evaluations: usize,
+    pub converged: bool,
+}
+
+fn check_bracket(fa: f64, fb: f64, a: f64, b: f64) -> Result<()> {
+    if fa.is_nan() || fb.is_nan() {
+        return Err(OptimizeError::NonFiniteValue {
+            what: "f at the bracket ends",
+        });
+    }
+    if fa * fb > 0.0 {
+        return Err(OptimizeError::NotBracketed { a, b });
+    }
+    Ok(())
+}
+
+/// 二分法。f(a) と f(b) の符号が異なる必要がある。反復ごとに区間が半分になる。
+pub fn bisection<F>(mut f: F, a: f64, b: f64, opts: &RootOptions) -> Result<RootReport>
+where
+    F: FnMut(f64) -> f64,
+{
+    let (mut lo, mut hi) = (a, b);
+    let (mut flo, fhi) = (f(lo), f(hi));
+    check_bracket(flo, fhi, a, b)?;
+    let mut evaluations = 2;
+    for (root, value) in [(lo, flo), (hi, fhi)] {
+        if value == 0.0 {
+            return Ok(RootReport {
+                root,
+                value,
+                iterations: 0,
+                evaluations,
+                converged: true,
+            });
+        }
+    }
+    let (mut mid, mut fmid) = (lo, flo);
+    for iterations in 1..=opts.max_iterations {
+        mid = lo + 0.5 * (hi - lo);
+        fmid = f(mid);
+        evaluations += 1;
+        if fmid == 0.0
+            || fmid.abs() <= opts.ftol
+            || 0.5 * (hi - lo).abs() <= opts.xtol + opts.rtol * mid.abs()
+        {
+            return Ok(RootReport {
+                root: mid,
+                value: fmid,
+                iterations,
+                evaluations,
+                converged: true,
+            });
+        }
+        if (fmid < 0.0) == (flo < 0.0) {
+            lo = mid;
+            flo = fmid;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(RootReport {
+        root: mid,
+        value: fmid,
+        iterations: opts.max_iterations,
+        evaluations,
+        converged: false,
+    })
+}
+
+/// Brent 法（brentq）。逆二次補間と割線法を試し、区間が十分縮まなければ二分法に切り替える。
+/// 二分法と同じく根を挟み続けるので必ず収束し、滑らかな f では超一次収束する。
+pub fn brent<F>(mut f: F, a: f64, b: f64, opts: &RootOptions) -> Result<RootReport>
+where
+    F: FnMut(f64) -> f64,
+{
+    // cur は現在の最良点、blk は cur と符号が異なる反対側の端、pre は直前の点
+    let (mut xpre, mut xcur) = (a, b);
+    let (mut fpre, mut fcur) = (f(xpre), f(xcur));
+    check_bracket(fpre, fcur, a, b)?;
+    let mut evaluations = 2;
+    if fpre == 0.0 {
+        return Ok(RootReport {
+            root: xpre,
+            value: fpre,
+            iterations: 0,
+            evaluations,
+            converged: true,
+        });
+    }
+    let (mut xblk, mut fblk) = (0.0, 0.0);
+    let (mut spre, mut scur) = (0.0, 0.0);
+    for iterations in 0..=opts.max_iterations {
+        if fpre != 0.0 && fcur != 0.0 && (fpre < 0.0) != (fcur < 0.0) {
+            xblk = xpre;
+            fblk = fpre;
+            spre = xcur - xpre;
+            scur = spre;
+        }
+        if fblk.abs() < fcur.abs() {
+            xpre = xcur;
+            xcur = xblk;
+            xblk = xpre;
+            fpre = fcur;
+            fcur = fblk;
+            fblk = fpre;
+        }
+        let delta = 0.5 * (opts.xtol + opts.rtol * xcur.abs());
+        let sbis = 0.5 * (xblk - xcur);
+        if fcur == 0.0 || fcur.abs() <= opts.ftol || sbis.abs() < delta {
+            return Ok(RootReport {
+                root: xcur,
+                value: fcur,
+                iterations,
+                evaluations,
+                converged: true,
+            });
+        }
+        if iterations == opts.max_iterations {
+            break;
+        }
+        if spre.abs() > delta && fcur.abs() < fpre.abs() {
+            let stry = if xpre == xblk {
+                // 割線法
+                -fcur * (xcur - xpre) / (fcur - fpre)
+            } else {
+                // 逆二次補間
+                let dpre = (fpre - fcur) / (xpre - xcur);
+                let dblk = (fblk - fcur) / (xblk - xcur);
+                -fcur * (fblk * dblk - fpre * dpre) / (dblk * dpre * (fblk - fpre))
+            };
+            if 2.0 * stry.abs() < spre.abs().min(3.0 * sbis.abs() - delta) {
+                spre = scur;
+                scur = stry;
+            } else {
+                spre = sbis;
+                scur = sbis;
+            }
+        } else {
+            spre = sbis;
+            scur = sbis;
+        }
+        xpre = xcur;
+        fpre = fcur;
+        xcur += if scur.abs() > delta {
+            scur
+        } else if sbis > 0.0 {
+            delta
+        } else {
+            -delta
+        };
+        fcur = f(xcur);
+        evaluations += 1;
+    }
+    Ok(RootReport {
+        root: xcur,
+        value: fcur,
+        iterations: opts.max_iterations,
+        evaluations,
+        converged: false,
+    })
+}
+
+/// スカラーの Newton–Raphson 法 x ← x - f(x) / f'(x)。単根の近くでは二次収束するが、大域収束は保証しない。
+/// f'(x) = 0 の点に着くと未収束のまま止まる。
+pub fn newton_raphson<F, D>(mut f: F, mut df: D, x0: f64, opts: &RootOptions) -> Result<RootReport>
+where
+    F: FnMut(f64) -> f64,
+    D: FnMut(f64) -> f64,
+{
+    let mut x = x0;
+    let mut fx = f(x);
+    if !fx.is_finite() {
+        return Err(OptimizeError::NonFiniteValue { what: "f" });
+    }
+    let mut evaluations = 1;
+    let mut iterations = 0;
+    while iterations < opts.max_iterations {
+        if fx == 0.0 || fx.abs() <= opts.ftol {
+            return Ok(RootReport {
+                root: x,
+                value: fx,
+                iterations,
+                evaluations,
+                converged: true,
+            });
+        }
+        let d = df(x);
+        if d == 0.0 || !d.is_finite() {
+            break;
+        }
+        let step = fx / d;
+        x -= step;
+        fx = f(x);
+        evaluations += 1;
+        iterations += 1;
+        if step.abs() <= opts.xtol + opts.rtol * x.abs() {
+            return Ok(RootReport {
+                root: x,
+                value: fx,
+                iterations,
+                evaluations,
+                converged: true,
+            });
+        }
+    }
+    Ok(RootReport {
+        root: x,
+        value: fx,
+        iterations,
+        evaluations,
+        converged: false,
+    })
+}
+
+/// 連立方程式 F(x) = 0 の Newton 法。J h = -F を LU 分解で解き、
+/// ½ ||F||^2 の Armijo 条件を満たすまでステップを縮める（大域化した Newton 法）。
+/// ||F||_∞ ≤ ftol なら収束とする。ステップ幅が xtol + rtol ||x||_∞ を下回って止まったときは、
+/// 残差も ftol（ftol = 0 なら √ε max(1, ||F(x0)||_∞)）以下の場合だけ収束とし、それ以外は停滞として未収束を返す。
+pub fn newton_system<F, J>(
+    mut f: F,
+    mut jac: J,
+    x0: &Vector<f64>,
+    opts: &RootOptions,
+) -> Result<SystemRootReport>
+where
+    F: FnMut(&Vector<f64>) -> Vector<f64>,
+    J: FnMut(&Vector<f64>) -> Matrix<f64>,
+{
+    let n = x0.dim();
+    let mut x = x0.clone();
+    let mut fx = f(&x);
+    if fx.dim() != n {
+        return Err(OptimizeError::DimensionMismatch {
+            expected: format!("{n} equations"),
+            found: format!("{}", fx.dim()),
+        });
+    }
+    if fx.iter().any(|v| !v.is_finite()) {
+        return Err(OptimizeError::NonFiniteValue { what: "F" });
+    }
+    let mut evaluations = 1;
+    let merit = |v: &Vector<f64>| 0.5 * v.dot(v);
+    let stall_ftol = if opts.ftol > 0.0 {
+        opts.ftol
+    } else {
+        f64::EPSILON.sqrt() * inf_norm(&fx).max(1.0)
+    };
+    let mut converged = false;
+    let mut iterations = 0;
+    while iterations < opts.max_iterations {
+        if fx.iter().all(|v| v.abs() <= opts.ftol) {
+            converged = true;
+            break;
+        }
+        iterations += 1;
+        let j = jac(&x);
+        let h = j.solve(&fx.checked_neg())?;
+        // ∇(½ ||F||^2) = J^T F
+        let g = j.transpose().checked_mul_vector(&fx)?;
+        let phi = merit(&fx);
+        let Some(ls) = backtracking(
+            |y: &Vector<f64>| {
+                let v = merit(&f(y));
+                if v.is_nan() {
+                    f64::INFINITY
+                } else {
+                    v
+                }
+            },
+            &x,
+            phi,
+            &g,
+            &h,
+            1.0,
+            1e-4,
+        ) else {
+            break;
+        };
+        evaluations += ls.evaluations;
+        let small_step = ls.step * inf_norm(&h) <= opts.xtol + opts.rtol * inf_norm(&ls.x);
+        x = ls.x;
+        fx = f(&x);
+        evaluations += 1;
+        if small_step {
+            // ステップが縮んでも残差が大きければ ½ ||F||^2 の停留点で止まっただけ
+            converged = inf_norm(&fx) <= stall_ftol;
+            break;
+        }
+    }
+    Ok(SystemRootReport {
+        residual_norm: fx.norm(),
+        x,
+        iterations,
+        evaluations,
+        converged,
+    })
+}
@@ -0,0 +1,202 @@
+use linalg::{Matrix, Vector};
+use optimization::finite_difference;
+use optimization::{
+    bfgs, lbfgs, levenberg_marquardt, nelder_mead, newton, LeastSquaresOptions, MinimizeOptions,
+    NelderMeadOptions, OptimizeError, Termination,
+};
+
+/// Rosenbrock 関数 Σ 100 (x_{i+1} - x_i^2)^2 + (1 - x_i)^2（最小点は全成分 1）
+fn rosenbrock(x: &Vector<f64>) -> f64 {
+    (0..x.dim() - 1)
+        .map(|i| 100.0 * (x[i + 1] - x[i] * x[i]).powi(2) + (1.0 - x[i]).powi(2))
+        .sum()
+}
+
+fn rosenbrock_grad(x: &Vector<f64>) -> Vector<f64> {
+    let n = x.dim();
+    let mut g = vec![0.0; n];
+    for i in 0..n - 1 {
+        let t = x[i + 1] - x[i] * x[i];
+        g[i] += -400.0 * x[i] * t - 2.0 * (1.0 - x[i]);
+        g[i + 1] += 200.0 * t;
+    }
+    Vector::new(g)
+}
+
+fn rosenbrock_hess(x: &Vector<f64>) -> Matrix<f64> {
+    let n = x.dim();
+    let mut h = Matrix::zeros(n, n);
+    for i in 0..n - 1 {
+        h[(i, i)] += 1200.0 * x[i] * x[i] - 400.0 * x[i + 1] + 2.0;
+        h[(i, i + 1)] -= 400.0 * x[i];
+        h[(i + 1, i)] -= 400.0 * x[i];
+        h[(i + 1, i + 1)] += 200.0;
+    }
+    h
+}
+
+fn distance_to_ones(x: &Vector<f64>) -> f64 {
+    x.iter().map(|v| (v - 1.0).abs()).fold(0.0, f64::max)
+}
+
+#[test]
+fn gradient_methods_solve_rosenbrock() {
+    let x0 = Vector::new(vec![-1.2, 1.0, -1.2, 1.0]);
+    let opts = MinimizeOptions::default();
+
+    let nt = newton(rosenbrock, rosenbrock_grad, rosenbrock_hess, &x0, &opts).unwrap();
+    let bf = bfgs(rosenbrock, rosenbrock_grad, &x0, &opts).unwrap();
+    let lb = lbfgs(rosenbrock, rosenbrock_grad, &x0, &opts).unwrap();
+    for report in [&nt, &bf, &lb] {
+        assert!(report.converged, "{:?}", report.termination);
+        assert!(distance_to_ones(&report.x) < 1e-6, "{}", report.x);
+        assert!(report.value < 1e-12);
+        assert_eq!(report.history.len(), report.iterations + 1);
+        // 直線探索は十分減少を要求するので履歴は単調減少
+        assert!(report.history.windows(2).all(|w| w[1] <= w[0]));
+    }
+    // 曲率情報を使う Newton 法が最も反復が少ない
+    assert!(nt.iterations < bf.iterations);
+    assert_eq!(nt.termination, Termination::GradientTolerance);
+
+    // 既に停留点なら反復しない
+    let at_min = bfgs(rosenbrock, rosenbrock_grad, &Vector::ones(4), &opts).unwrap();
+    assert_eq!(at_min.iterations, 0);
+    assert!(at_min.converged);
+}
+
+#[test]
+fn newton_handles_indefinite_hessian() {
+    // f = x^4/4 - x^2/2 + y^2 は原点で Hessian が不定。修正 Cholesky で降下方向を保つ。
+    let f = |v: &Vector<f64>| v[0].powi(4) / 4.0 - v[0] * v[0] / 2.0 + v[1] * v[1];
+    let g = |v: &Vector<f64>| Vector::new(vec![v[0].powi(3) - v[0], 2.0 * v[1]]);
+    let h =
+        |v: &Vector<f64>| Matrix::new(2, 2, vec![3.0 * v[0] * v[0] - 1.0, 0.0, 0.0, 2.0]).unwrap();
+    let report = newton(
+        f,
+        g,
+        h,
+        &Vector::new(vec![0.1, 1.0]),
+        &MinimizeOptions::default(),
+    )
+    .unwrap();
+    assert!(report.converged);
+    assert!((report.x[0] - 1.0).abs() < 1e-8 && report.x[1].abs() < 1e-8);
+    assert!((report.value + 0.25).abs() < 1e-12);
+}
+
+#[test]
+fn nelder_mead_without_gradient() {
+    let x0 = Vector::new(vec![-1.2, 1.0]);
+    let report = nelder_mead(rosenbrock, &x0, &NelderMeadOptions::default()).unwrap();
+    assert!(report.converged);
+    assert_eq!(report.termination, Termination::SimplexTolerance);
+    assert!(report.gradient_norm.is_none());
+    assert!(distance_to_ones(&report.x) < 1e-4, "{}", report.x);
+
+    let adaptive = NelderMeadOptions {
+        adaptive: true,
+        ..Default::default()
+    };
+    let x0 = Vector::new(vec![0.0; 5]);
+    let report = nelder_mead(rosenbrock, &x0, &adaptive).unwrap();
+    assert!(report.converged);
+    assert!(report.value < 1e-8);
+
+    let capped = NelderMeadOptions {
+        max_iterations: 5,
+        ..Default::default()
+    };
+    let report = nelder_mead(rosenbrock, &x0, &capped).unwrap();
+    assert!(!report.converged);
+    assert_eq!(report.termination, Termination::MaxIterations);
+}
+
+#[test]
+fn levenberg_marquardt_fits_exponential_decay() {
+    // y = a exp(-b t) + c を、ノイズのないデータから推定する
+    let (a, b, c) = (2.5, 1.3, 0.5);
+    let t: Vec<f64> = (0..20).map(|i| i as f64 * 0.25).collect();
+    let y: Vec<f64> = t.iter().map(|&ti| a * (-b * ti).exp() + c).collect();
+    let residual = |p: &Vector<f64>| {
+        Vector::new(
+            t.iter()
+                .zip(&y)
+                .map(|(&ti, &yi)| p[0] * (-p[1] * ti).exp() + p[2] - yi)
+                .collect(),
+        )
+    };
+    let jacobian = |p: &Vector<f64>| {
+        let mut j = Matrix::zeros(t.len(), 3);
+        for (i, &ti) in t.iter().enumerate() {
+            let e = (-p[1] * ti).exp();
+            j[(i, 0)] = e;
+            j[(i, 1)] = -p[0] * ti * e;
+            j[(i, 2)] = 1.0;
+        }
+        j
+    };
+    let x0 = Vector::new(vec![1.0, 0.1, 0.0]);
+    let opts = LeastSquaresOptions::default();
+    let report = levenberg_marquardt(residual, jacobian, &x0, &opts).unwrap();
+    assert!(report.converged, "{:?}", report.termination);
+    assert!(report.value < 1e-20);
+    for (got, want) in report.x.iter().zip([a, b, c]) {
+        assert!((got - want).abs() < 1e-8, "{got} vs {want}");
+    }
+
+    // 差分ヤコビアンでも同じ解に着く
+    let numeric = |p: &Vector<f64>| finite_difference::jacobian(residual, p);
+    let report = levenberg_marquardt(residual, numeric, &x0, &opts).unwrap();
+    assert!(report.converged);
+    assert!((report.x[1] - b).abs() < 1e-6);
+
+    let bad = LeastSquaresOptions {
+        initial_damping: 0.0,
+        ..Default::default()
+    };
+    assert!(matches!(
+        levenberg_marquardt(residual, jacobian, &x0, &bad),
+        Err(OptimizeError::InvalidParameter { .. })
+    ));
+}
+
+#[test]
+fn finite_differences_match_analytic_derivatives() {
+    let x = Vector::new(vec![0.3, -0.7, 1.1]);
+    let g = finite_difference::gradient(rosenbrock, &x);
+    let exact = rosenbrock_grad(&x);
+    assert!(g
+        .iter()
+        .zip(exact.iter())
+        .all(|(a, b)| (a - b).abs() < 1e-6));
+    let h = finite_difference::hessian(rosenbrock_grad, &x);
+    let exact = rosenbrock_hess(&x);
+    assert!(h
+        .data
+        .iter()
+        .zip(&exact.data)
+        .all(|(a, b)| (a - b).abs() < 1e-6));
+    assert_eq!(h, h.transpose());
+}
+
+#[test]
+fn invalid_starting_points_are_reported() {
+    let opts = MinimizeOptions::default();
+    let f = |x: &Vector<f64>| x[0].ln();
+    let g = |x: &Vector<f64>| Vector::new(vec![1.0 / x[0]]);
+    assert!(matches!(
+        bfgs(f, g, &Vector::new(vec![-1.0]), &opts),
+        Err(OptimizeError::NonFiniteValue { .. })
+    ));
+    let short = |_: &Vector<f64>| Vector::new(vec![0.0]);
+    assert!(matches!(
+        lbfgs(rosenbrock, short, &Vector::new(vec![0.0, 0.0]), &opts),
+        Err(OptimizeError::DimensionMismatch { .. })
+    ));
+    let no_memory = MinimizeOptions {
+        memory: 0,
+        ..Default::default()
+    };
+    assert!(lbfgs(rosenbrock, rosenbrock_grad, &Vector::ones(2), &no_memory).is_err());
+}
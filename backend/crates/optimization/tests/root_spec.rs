@@ -0,0 +1,117 @@
+use linalg::{Matrix, Vector};
+use optimization::{bisection, brent, newton_raphson, newton_system, OptimizeError, RootOptions};
+
+#[test]
+fn bracketing_methods_find_cubic_root() {
+    // x^3 - 2x - 5 = 0 の実根（Wallis の例）
+    let f = |x: f64| x * x * x - 2.0 * x - 5.0;
+    let root = 2.094_551_481_542_326_5;
+    let opts = RootOptions::default();
+
+    let bi = bisection(f, 2.0, 3.0, &opts).unwrap();
+    let br = brent(f, 2.0, 3.0, &opts).unwrap();
+    for report in [&bi, &br] {
+        assert!(report.converged);
+        assert!((report.root - root).abs() < 1e-11);
+        assert!(report.value.abs() < 1e-9);
+    }
+    // 超一次収束する Brent 法は二分法よりずっと少ない評価で済む
+    assert!(br.evaluations * 3 < bi.evaluations);
+
+    // 端点が根ならそのまま返す
+    let at_end = brent(|x: f64| x - 1.0, 1.0, 2.0, &opts).unwrap();
+    assert_eq!((at_end.root, at_end.iterations), (1.0, 0));
+
+    assert!(matches!(
+        brent(f, 3.0, 4.0, &opts),
+        Err(OptimizeError::NotBracketed { .. })
+    ));
+    assert!(bisection(f, 3.0, 4.0, &opts).is_err());
+
+    let capped = RootOptions {
+        max_iterations: 3,
+        ..Default::default()
+    };
+    assert!(!bisection(f, 2.0, 3.0, &capped).unwrap().converged);
+}
+
+#[test]
+fn brent_handles_flat_and_steep_functions() {
+    let opts = RootOptions::default();
+    // 根の近くで極端に平坦な関数では補間の歩幅が小さくなり反復が増えるが、区間は挟み続ける
+    let patient = RootOptions {
+        max_iterations: 1000,
+        ..Default::default()
+    };
+    let flat = brent(|x: f64| (x - 1.0).powi(9), 0.0, 1.5, &patient).unwrap();
+    assert!(flat.converged);
+    assert!((flat.root - 1.0).abs() < 1e-2);
+    let steep = brent(|x: f64| (20.0 * (x - 0.3)).tanh(), -1.0, 1.0, &opts).unwrap();
+    assert!((steep.root - 0.3).abs() < 1e-12);
+}
+
+#[test]
+fn newton_raphson_converges_quadratically() {
+    let opts = RootOptions::default();
+    let report = newton_raphson(|x| x * x - 2.0, |x| 2.0 * x, 1.0, &opts).unwrap();
+    assert!(report.converged);
+    assert!((report.root - std::f64::consts::SQRT_2).abs() < 1e-15);
+    assert!(report.iterations <= 7);
+
+    // 微分が 0 の点では止まる
+    let stuck = newton_raphson(|x| x * x + 1.0, |x| 2.0 * x, 0.0, &opts).unwrap();
+    assert!(!stuck.converged);
+    assert_eq!(stuck.iterations, 0);
+
+    // 実根のない x^2 + 1 は反復上限まで回り、反復回数は上限そのもの
+    let capped = RootOptions {
+        max_iterations: 5,
+        ..RootOptions::default()
+    };
+    let report = newton_raphson(|x| x * x + 1.0, |x| 2.0 * x, 0.5, &capped).unwrap();
+    assert!(!report.converged);
+    assert_eq!(report.iterations, 5);
+    assert_eq!(report.evaluations, 6);
+}
+
+#[test]
+fn newton_system_intersects_circle_and_line() {
+    // x^2 + y^2 = 4, x - y = 1（第 1 象限の交点）
+    let f = |v: &Vector<f64>| Vector::new(vec![v[0] * v[0] + v[1] * v[1] - 4.0, v[0] - v[1] - 1.0]);
+    let jac = |v: &Vector<f64>| Matrix::new(2, 2, vec![2.0 * v[0], 2.0 * v[1], 1.0, -1.0]).unwrap();
+    let report = newton_system(
+        f,
+        jac,
+        &Vector::new(vec![10.0, -3.0]),
+        &RootOptions::default(),
+    )
+    .unwrap();
+    assert!(report.converged);
+    let x = (1.0 + 7.0f64.sqrt()) / 2.0;
+    assert!((report.x[0] - x).abs() < 1e-12 && (report.x[1] - (x - 1.0)).abs() < 1e-12);
+    assert!(report.residual_norm < 1e-12);
+
+    let wrong = |_: &Vector<f64>| Vector::new(vec![0.0]);
+    assert!(matches!(
+        newton_system(
+            wrong,
+            jac,
+            &Vector::new(vec![1.0, 1.0]),
+            &RootOptions::default()
+        ),
+        Err(OptimizeError::DimensionMismatch { .. })
+    ));
+
+    // ステップ判定が緩くて止まっても、残差が ftol を超えていれば収束とは報告しない
+    let square = |v: &Vector<f64>| Vector::new(vec![v[0] * v[0] - 2.0]);
+    let square_jac = |v: &Vector<f64>| Matrix::new(1, 1, vec![2.0 * v[0]]).unwrap();
+    let loose = RootOptions {
+        xtol: 1e3,
+        ftol: 1e-10,
+        ..RootOptions::default()
+    };
+    let stalled = newton_system(square, square_jac, &Vector::new(vec![10.0]), &loose).unwrap();
+    assert!(!stalled.converged);
+    assert!(stalled.residual_norm > 1.0);
+    assert_eq!(stalled.iterations, 1);
+}
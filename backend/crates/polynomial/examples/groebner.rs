@@ -0,0 +1,89 @@
+use linalg::BigRational;
+use num_traits::ToPrimitive;
+use poly::multivariate::groebner::is_groebner_basis;
+use poly::multivariate::{GroebnerAlgorithm, GroebnerBasis};
+use poly::{MonomialOrder, MultiPolynomial, Polynomial};
+
+type Q = BigRational;
+
+fn q(n: i64) -> Q {
+    Q::from_integer(n.into())
+}
+
+fn main() {
+    // 円 x² + y² = 4 と双曲線 xy = 1 の交点を lex 基底で三角化して解く
+    let lex = MonomialOrder::Lex;
+    let v = MultiPolynomial::<Q>::vars(2, lex);
+    let (x, y) = (&v[0], &v[1]);
+    let circle = &(&x.pow(2) + &y.pow(2)) - &MultiPolynomial::constant(2, lex, q(4));
+    let hyperbola = &(x * y) - &MultiPolynomial::constant(2, lex, q(1));
+    println!("連立方程式: {circle} = 0, {hyperbola} = 0");
+    let gb = GroebnerBasis::new(&[circle.clone(), hyperbola.clone()]);
+    println!("lex 簡約 Gröbner 基底:");
+    for g in gb.polynomials() {
+        println!("  {g}");
+    }
+
+    // 最後の元は y だけの多項式。その実根を代入すると残りは x の 1 次式になる
+    let last = gb.elimination(1)[0].to_univariate(1).unwrap();
+    let to_f64 = |c: &Q| c.to_f64().unwrap();
+    let last_f64 = Polynomial::new(last.coeffs.iter().map(to_f64).collect());
+    println!("解:");
+    for root in last_f64.find_roots() {
+        if root.im.abs() > 1e-9 {
+            continue;
+        }
+        let b = root.re;
+        // 基底の第 1 元 x + (y の式) = 0 から x を求める
+        let first = &gb.polynomials()[0];
+        let xs = first.terms().iter().fold(0.0, |acc, (m, c)| {
+            if m.exps()[0] == 1 {
+                acc
+            } else {
+                acc - to_f64(c) * b.powi(m.exps()[1] as i32)
+            }
+        });
+        println!(
+            "  (x, y) = ({xs:+.6}, {b:+.6}), x² + y² = {:.6}, xy = {:.6}",
+            xs * xs + b * b,
+            xs * b
+        );
+    }
+
+    // イデアル所属判定と正規形
+    let member = &(&circle * &x.pow(3)) - &(&hyperbola * y);
+    println!(
+        "\nx³(x² + y² − 4) − y(xy − 1) ∈ I: {}",
+        gb.contains(&member)
+    );
+    println!("x⁴ の正規形: {}", gb.normal_form(&x.pow(4)));
+
+    // cyclic-4（Σ_i Π_{j<k} x_{i+j} = 0 (k = 1, 2, 3), abcd = 1）を grevlex で
+    // Buchberger 法と F4 の両方で計算して比べる
+    let grevlex = MonomialOrder::GrevLex;
+    let v = MultiPolynomial::<Q>::vars(4, grevlex);
+    let cyclic = |k: usize| {
+        (0..4).fold(MultiPolynomial::zero(4, grevlex), |acc, i| {
+            let term = (0..k).fold(MultiPolynomial::constant(4, grevlex, q(1)), |t, j| {
+                &t * &v[(i + j) % 4]
+            });
+            &acc + &term
+        })
+    };
+    let mut gens: Vec<MultiPolynomial<Q>> = (1..4).map(cyclic).collect();
+    let abcd = v
+        .iter()
+        .fold(MultiPolynomial::constant(4, grevlex, q(1)), |t, x| &t * x);
+    gens.push(&abcd - &MultiPolynomial::constant(4, grevlex, q(1)));
+    let buch = GroebnerBasis::with_algorithm(&gens, GroebnerAlgorithm::Buchberger);
+    let f4 = GroebnerBasis::with_algorithm(&gens, GroebnerAlgorithm::F4);
+    println!(
+        "\ncyclic-4（grevlex）: 基底の元 {} 個, Buchberger と F4 が一致: {}, Gröbner 基底の判定: {}",
+        buch.polynomials().len(),
+        buch == f4,
+        is_groebner_basis(f4.polynomials())
+    );
+    for g in buch.polynomials() {
+        println!("  {}", g.display_with(&["a", "b", "c", "d"]));
+    }
+}
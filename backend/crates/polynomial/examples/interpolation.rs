@@ -0,0 +1,85 @@
+use poly::polynomial::fit::{chebyshev_points, ChebyshevFit};
+use poly::polynomial::interpolation::Barycentric;
+use poly::polynomial::spline::{CubicSpline, SplineBoundary};
+use poly::Polynomial;
+
+fn main() {
+    // 標本点から補間多項式を作る（Lagrange と Newton は同じ多項式になる）
+    let xs = [0.0, 1.0, 2.0, 4.0];
+    let ys = [1.0, 3.0, 2.0, 5.0];
+    let lag = Polynomial::lagrange(&xs, &ys).unwrap();
+    let newton = Polynomial::newton_interpolation(&xs, &ys).unwrap();
+    println!(
+        "Lagrange: p(x) = {}",
+        lag.display_with("x").unicode_superscript(true)
+    );
+    println!(
+        "Newton  : p(x) = {}",
+        newton.display_with("x").unicode_superscript(true)
+    );
+
+    // Runge 関数: 等間隔点と Chebyshev 点で 21 点補間した最大誤差
+    let f = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+    let n = 21;
+    let equi: Vec<f64> = (0..n)
+        .map(|i| -1.0 + 2.0 * i as f64 / (n - 1) as f64)
+        .collect();
+    let cheb = chebyshev_points(n, -1.0, 1.0);
+    let max_err = |nodes: &[f64]| {
+        let ys: Vec<f64> = nodes.iter().map(|&x| f(x)).collect();
+        let b = Barycentric::new(nodes, &ys).unwrap();
+        (0..=400)
+            .map(|i| -1.0 + i as f64 / 200.0)
+            .map(|x| (b.eval(x) - f(x)).abs())
+            .fold(0.0, f64::max)
+    };
+    println!(
+        "\nRunge 関数の 21 点補間: 等間隔 {:.3e}, Chebyshev 点 {:.3e}",
+        max_err(&equi),
+        max_err(&cheb)
+    );
+
+    // 3 次スプライン: 境界条件ごとに sin を補間した誤差
+    let knots: Vec<f64> = (0..=8)
+        .map(|i| i as f64 * std::f64::consts::PI / 8.0)
+        .collect();
+    let values: Vec<f64> = knots.iter().map(|x| x.sin()).collect();
+    println!("\nsin を [0, π] の 9 節点でスプライン補間:");
+    for (name, boundary) in [
+        ("natural", SplineBoundary::Natural),
+        (
+            "clamped",
+            SplineBoundary::Clamped {
+                start: 1.0,
+                end: -1.0,
+            },
+        ),
+        ("not-a-knot", SplineBoundary::NotAKnot),
+    ] {
+        let s = CubicSpline::new(&knots, &values, boundary).unwrap();
+        let err = (0..=100)
+            .map(|i| i as f64 * std::f64::consts::PI / 100.0)
+            .map(|x| (s.eval(x) - x.sin()).abs())
+            .fold(0.0, f64::max);
+        println!(
+            "  {name:<10} 最大誤差 {err:.2e}, ∫ = {:.8}（厳密値 2）",
+            s.integrate(0.0, std::f64::consts::PI)
+        );
+    }
+
+    // 最小二乗近似: 区間 [1000, 1010] の標本に 8 次式を当てはめる
+    let xs: Vec<f64> = (0..100).map(|i| 1000.0 + i as f64 / 9.9).collect();
+    let ys: Vec<f64> = xs.iter().map(|x| (x - 1005.0).tanh()).collect();
+    let fit = ChebyshevFit::new(&xs, &ys, 8).unwrap();
+    println!(
+        "\ntanh(x - 1005) を [1000, 1010] で 8 次近似: rank = {}, 残差二乗和 = {:.3e}",
+        fit.rank, fit.residuals
+    );
+    println!(
+        "  Chebyshev 係数: {:?}",
+        fit.coeffs
+            .iter()
+            .map(|c| format!("{c:.4}"))
+            .collect::<Vec<_>>()
+    );
+}
@@ -1,6 +1,7 @@
 use num_complex::Complex;
 use std::fmt;
 
+use crate::multivariate::MultiPolynomial;
 use crate::polynomial::Polynomial;
 use crate::rational_function::RationalFunction;
 use linalg::Field;
@@ -238,6 +239,98 @@ where
     }
 }
 
+pub struct MultiPolyDisplay<'a, F: Field> {
+    pub poly: &'a MultiPolynomial<F>,
+    /// 変数名（足りない分は既定名）
+    pub names: Vec<String>,
+    pub style: PolyStyle,
+}
+
+impl<'a, F> MultiPolyDisplay<'a, F>
+where
+    F: Field + fmt::Display + Clone + PartialEq,
+{
+    pub fn new(poly: &'a MultiPolynomial<F>, names: &[&str]) -> Self {
+        // 既定名: 3 変数までは x, y, z、それ以上は x1, x2, ...
+        let n = poly.nvars();
+        let names = (0..n)
+            .map(|i| match names.get(i) {
+                Some(s) => s.to_string(),
+                None if n <= 3 => ["x", "y", "z"][i].to_string(),
+                None => format!("x{}", i + 1),
+            })
+            .collect();
+        Self {
+            poly,
+            names,
+            style: PolyStyle::default(),
+        }
+    }
+    pub fn unicode_superscript(mut self, on: bool) -> Self {
+        self.style.unicode_superscript = on;
+        self
+    }
+    pub fn mul_asterisk(mut self, on: bool) -> Self {
+        self.style.use_asterisk = on;
+        self
+    }
+}
+
+impl<'a, F> fmt::Display for MultiPolyDisplay<'a, F>
+where
+    F: Field + fmt::Display + Clone + PartialEq,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.poly.is_zero() {
+            return write!(f, "0");
+        }
+        for (k, (m, c)) in self.poly.terms().iter().enumerate() {
+            let s = c.to_string();
+            let is_neg = s.starts_with('-');
+            let abs_s: &str = if is_neg { &s[1..] } else { &s };
+            if k == 0 {
+                if is_neg {
+                    write!(f, "-")?;
+                }
+            } else if is_neg {
+                write!(f, " - ")?;
+            } else {
+                write!(f, " + ")?;
+            }
+            // 変数部分は '*' でつなぐ（例: x^2*y）
+            let vars: Vec<String> = m
+                .exps()
+                .iter()
+                .zip(&self.names)
+                .filter(|(&e, _)| e > 0)
+                .map(|(&e, name)| {
+                    if e == 1 {
+                        name.clone()
+                    } else if self.style.unicode_superscript {
+                        format!("{name}{}", to_superscript(e as isize))
+                    } else {
+                        format!("{name}^{e}")
+                    }
+                })
+                .collect();
+            let omit_coeff = !vars.is_empty()
+                && ((is_neg && *c == (F::zero() - F::one())) || (!is_neg && *c == F::one()));
+            if vars.is_empty() {
+                write!(f, "{abs_s}")?;
+            } else {
+                if !omit_coeff {
+                    write!(f, "{abs_s}")?;
+                    if self.style.use_asterisk {
+                        write!(f, "*")?;
+                    }
+                }
+                write!(f, "{}", vars.join("*"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
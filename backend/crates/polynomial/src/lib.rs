@@ -1,4 +1,5 @@
 pub mod format;
+pub mod multivariate;
 pub mod polynomial;
 pub mod rational_function;
 #[macro_use]
@@ -9,6 +10,7 @@ pub mod prelude {
 }
 
 // 使い勝手のため、代表的な型をルート再エクスポート
-pub use format::{PolyDisplay, PolyStyle, RfDisplay};
+pub use format::{MultiPolyDisplay, PolyDisplay, PolyStyle, RfDisplay};
+pub use multivariate::{MonomialOrder, MultiPolynomial};
 pub use polynomial::Polynomial;
 pub use rational_function::RationalFunction;
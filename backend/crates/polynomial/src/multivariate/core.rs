@@ -0,0 +1,443 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use linalg::Field;
+
+use super::monomial::{Monomial, MonomialOrder};
+use crate::format::MultiPolyDisplay;
+use crate::polynomial::Polynomial;
+
+/// 体 F 上の n 変数多項式。項は単項式順序の降順に並べ、係数 0 の項は持たない。
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultiPolynomial<F: Field> {
+    nvars: usize,
+    order: MonomialOrder,
+    terms: Vec<(Monomial, F)>,
+}
+
+impl<F: Field> MultiPolynomial<F> {
+    /// (指数ベクトル, 係数) の列から作る。同じ単項式はまとめ、変数の個数は最長の指数ベクトルに合わせる。
+    pub fn new(nvars: usize, order: MonomialOrder, terms: Vec<(Vec<u32>, F)>) -> Self {
+        let nvars = terms.iter().map(|(e, _)| e.len()).fold(nvars, usize::max);
+        let mut acc: HashMap<Monomial, F> = HashMap::new();
+        for (exps, c) in terms {
+            let m = Monomial::new(exps).extended(nvars);
+            let entry = acc.entry(m).or_insert_with(F::zero);
+            *entry = entry.clone() + c;
+        }
+        Self::from_map(nvars, order, acc)
+    }
+
+    pub fn zero(nvars: usize, order: MonomialOrder) -> Self {
+        Self {
+            nvars,
+            order,
+            terms: Vec::new(),
+        }
+    }
+
+    pub fn constant(nvars: usize, order: MonomialOrder, c: F) -> Self {
+        Self::monomial(Monomial::one(nvars), order, c)
+    }
+
+    /// 単項 c·m
+    pub fn monomial(m: Monomial, order: MonomialOrder, c: F) -> Self {
+        let nvars = m.nvars();
+        let terms = if c.is_zero() {
+            Vec::new()
+        } else {
+            vec![(m, c)]
+        };
+        Self {
+            nvars,
+            order,
+            terms,
+        }
+    }
+
+    /// 変数 x_i（i < nvars）
+    pub fn var(nvars: usize, order: MonomialOrder, i: usize) -> Self {
+        Self::monomial(Monomial::var(nvars, i), order, F::one())
+    }
+
+    /// 変数 x_1, ..., x_n をまとめて作る
+    pub fn vars(nvars: usize, order: MonomialOrder) -> Vec<Self> {
+        (0..nvars).map(|i| Self::var(nvars, order, i)).collect()
+    }
+
+    /// 1 変数多項式を変数 x_var の多項式として埋め込む
+    pub fn from_univariate(
+        p: &Polynomial<F>,
+        nvars: usize,
+        order: MonomialOrder,
+        var: usize,
+    ) -> Self {
+        let terms = p
+            .coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, c)| {
+                let mut exps = vec![0; nvars];
+                exps[var] = k as u32;
+                (exps, c.clone())
+            })
+            .collect();
+        Self::new(nvars, order, terms)
+    }
+
+    fn from_map(nvars: usize, order: MonomialOrder, acc: HashMap<Monomial, F>) -> Self {
+        let mut terms: Vec<(Monomial, F)> = acc.into_iter().filter(|(_, c)| !c.is_zero()).collect();
+        terms.sort_by(|a, b| order.cmp(&b.0, &a.0));
+        Self {
+            nvars,
+            order,
+            terms,
+        }
+    }
+
+    pub fn nvars(&self) -> usize {
+        self.nvars
+    }
+
+    pub fn order(&self) -> MonomialOrder {
+        self.order
+    }
+
+    /// 項の列（単項式順序の降順）
+    pub fn terms(&self) -> &[(Monomial, F)] {
+        &self.terms
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// 全次数（零多項式は -1）
+    pub fn total_degree(&self) -> isize {
+        self.terms
+            .iter()
+            .map(|(m, _)| m.degree() as isize)
+            .max()
+            .unwrap_or(-1)
+    }
+
+    /// 先頭項 LT(f)
+    pub fn leading_term(&self) -> Option<&(Monomial, F)> {
+        self.terms.first()
+    }
+
+    /// 先頭単項式 LM(f)
+    pub fn leading_monomial(&self) -> Option<&Monomial> {
+        self.terms.first().map(|(m, _)| m)
+    }
+
+    /// 先頭係数 LC(f)（零多項式は 0）
+    pub fn leading_coeff(&self) -> F {
+        self.terms.first().map_or_else(F::zero, |(_, c)| c.clone())
+    }
+
+    /// 単項式 m の係数
+    pub fn coeff(&self, m: &Monomial) -> F {
+        let m = m.extended(self.nvars);
+        self.terms
+            .iter()
+            .find(|(t, _)| *t == m)
+            .map_or_else(F::zero, |(_, c)| c.clone())
+    }
+
+    /// 先頭係数を 1 にする
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let inv = F::one() / self.leading_coeff();
+        self * inv
+    }
+
+    /// 単項式順序を変えて並べ直す
+    pub fn with_order(&self, order: MonomialOrder) -> Self {
+        let mut terms = self.terms.clone();
+        terms.sort_by(|a, b| order.cmp(&b.0, &a.0));
+        Self {
+            nvars: self.nvars,
+            order,
+            terms,
+        }
+    }
+
+    /// nvars と単項式順序を揃えたもの（同じならコピーしない）
+    pub(crate) fn conformed(&self, nvars: usize, order: MonomialOrder) -> Cow<'_, Self> {
+        if self.nvars == nvars && self.order == order {
+            return Cow::Borrowed(self);
+        }
+        let mut terms: Vec<(Monomial, F)> = self
+            .terms
+            .iter()
+            .map(|(m, c)| (m.extended(nvars), c.clone()))
+            .collect();
+        terms.sort_by(|a, b| order.cmp(&b.0, &a.0));
+        Cow::Owned(Self {
+            nvars,
+            order,
+            terms,
+        })
+    }
+
+    /// 点 (x_1, ..., x_n) での値（足りない座標は 0）
+    pub fn eval(&self, point: &[F]) -> F {
+        self.terms.iter().fold(F::zero(), |acc, (m, c)| {
+            let v = m.exps().iter().enumerate().fold(c.clone(), |v, (i, &e)| {
+                v * pow(point.get(i).cloned().unwrap_or_else(F::zero), e)
+            });
+            acc + v
+        })
+    }
+
+    /// x_var に値を代入する（変数の個数は変えない）
+    pub fn substitute(&self, var: usize, value: F) -> Self {
+        let mut acc: HashMap<Monomial, F> = HashMap::new();
+        for (m, c) in &self.terms {
+            let mut exps = m.exps().to_vec();
+            let e = std::mem::take(&mut exps[var]);
+            let entry = acc.entry(Monomial::new(exps)).or_insert_with(F::zero);
+            *entry = entry.clone() + c.clone() * pow(value.clone(), e);
+        }
+        Self::from_map(self.nvars, self.order, acc)
+    }
+
+    /// x_var 以外の変数を含まなければ 1 変数多項式として返す
+    pub fn to_univariate(&self, var: usize) -> Option<Polynomial<F>> {
+        let mut coeffs = vec![F::zero(); 1];
+        for (m, c) in &self.terms {
+            if m.exps()
+                .iter()
+                .enumerate()
+                .any(|(i, &e)| i != var && e != 0)
+            {
+                return None;
+            }
+            let k = m.exps()[var] as usize;
+            if coeffs.len() <= k {
+                coeffs.resize(k + 1, F::zero());
+            }
+            coeffs[k] = c.clone();
+        }
+        Some(Polynomial::new(coeffs))
+    }
+
+    /// 含まれる変数の添字（昇順）
+    pub fn variables(&self) -> Vec<usize> {
+        (0..self.nvars)
+            .filter(|&i| self.terms.iter().any(|(m, _)| m.exps()[i] != 0))
+            .collect()
+    }
+
+    pub fn pow(&self, mut k: u32) -> Self {
+        let mut base = self.clone();
+        let mut acc = Self::constant(self.nvars, self.order, F::one());
+        while k > 0 {
+            if k & 1 == 1 {
+                acc = &acc * &base;
+            }
+            k >>= 1;
+            if k > 0 {
+                base = &base * &base;
+            }
+        }
+        acc
+    }
+
+    /// c·m·self（単項式順序は積で保たれるので並べ替え不要）
+    pub fn mul_term(&self, m: &Monomial, c: &F) -> Self {
+        if c.is_zero() {
+            return Self::zero(self.nvars, self.order);
+        }
+        let m = m.extended(self.nvars);
+        Self {
+            nvars: self.nvars,
+            order: self.order,
+            terms: self
+                .terms
+                .iter()
+                .map(|(t, d)| (t.mul(&m), d.clone() * c.clone()))
+                .collect(),
+        }
+    }
+
+    /// self -= c·m·g（項の併合で O(|self| + |g|)）
+    pub(crate) fn sub_mul_term(&mut self, c: &F, m: &Monomial, g: &Self) {
+        let scaled: Vec<(Monomial, F)> = g
+            .terms
+            .iter()
+            .map(|(t, d)| (t.mul(m), -(d.clone() * c.clone())))
+            .collect();
+        self.terms = merge(self.order, std::mem::take(&mut self.terms), scaled);
+    }
+
+    /// 先頭項を取り除いて返す
+    pub(crate) fn pop_leading(&mut self) -> Option<(Monomial, F)> {
+        if self.terms.is_empty() {
+            None
+        } else {
+            Some(self.terms.remove(0))
+        }
+    }
+
+    /// 降順を保つ末尾への追加（呼び出し側が順序を保証する）
+    pub(crate) fn push_smallest(&mut self, m: Monomial, c: F) {
+        debug_assert!(self
+            .terms
+            .last()
+            .is_none_or(|(t, _)| self.order.cmp(t, &m).is_gt()));
+        self.terms.push((m, c));
+    }
+
+    fn combine(&self, rhs: &Self, negate: bool) -> Self {
+        let nvars = self.nvars.max(rhs.nvars);
+        let lhs = self.conformed(nvars, self.order);
+        let rhs = rhs.conformed(nvars, self.order);
+        let rhs_terms: Vec<(Monomial, F)> = if negate {
+            rhs.terms
+                .iter()
+                .map(|(m, c)| (m.clone(), -c.clone()))
+                .collect()
+        } else {
+            rhs.terms.clone()
+        };
+        Self {
+            nvars,
+            order: self.order,
+            terms: merge(self.order, lhs.terms.clone(), rhs_terms),
+        }
+    }
+}
+
+/// 降順の項の列を併合する（同じ単項式は足し、0 になれば捨てる）
+fn merge<F: Field>(
+    order: MonomialOrder,
+    a: Vec<(Monomial, F)>,
+    b: Vec<(Monomial, F)>,
+) -> Vec<(Monomial, F)> {
+    let mut out = Vec::with_capacity(a.len() + b.len());
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+    loop {
+        let ord = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) => order.cmp(&x.0, &y.0),
+            (Some(_), None) => std::cmp::Ordering::Greater,
+            (None, Some(_)) => std::cmp::Ordering::Less,
+            (None, None) => break,
+        };
+        match ord {
+            std::cmp::Ordering::Greater => out.extend(a.next()),
+            std::cmp::Ordering::Less => out.extend(b.next()),
+            std::cmp::Ordering::Equal => {
+                let (m, c) = a.next().unwrap();
+                let (_, d) = b.next().unwrap();
+                let s = c + d;
+                if !s.is_zero() {
+                    out.push((m, s));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn pow<F: Field>(x: F, e: u32) -> F {
+    (0..e).fold(F::one(), |acc, _| acc * x.clone())
+}
+
+// -------- 演算子 --------
+impl<F: Field> Add for &MultiPolynomial<F> {
+    type Output = MultiPolynomial<F>;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, false)
+    }
+}
+
+impl<F: Field> Sub for &MultiPolynomial<F> {
+    type Output = MultiPolynomial<F>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, true)
+    }
+}
+
+impl<F: Field> Mul for &MultiPolynomial<F> {
+    type Output = MultiPolynomial<F>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let nvars = self.nvars.max(rhs.nvars);
+        let lhs = self.conformed(nvars, self.order);
+        let rhs = rhs.conformed(nvars, self.order);
+        let mut acc: HashMap<Monomial, F> = HashMap::new();
+        for (m, c) in &lhs.terms {
+            for (t, d) in &rhs.terms {
+                let entry = acc.entry(m.mul(t)).or_insert_with(F::zero);
+                *entry = entry.clone() + c.clone() * d.clone();
+            }
+        }
+        MultiPolynomial::from_map(nvars, self.order, acc)
+    }
+}
+
+impl<F: Field> Mul<F> for &MultiPolynomial<F> {
+    type Output = MultiPolynomial<F>;
+    fn mul(self, rhs: F) -> Self::Output {
+        self.mul_term(&Monomial::one(self.nvars), &rhs)
+    }
+}
+
+impl<F: Field> Mul<F> for MultiPolynomial<F> {
+    type Output = MultiPolynomial<F>;
+    fn mul(self, rhs: F) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<F: Field> Neg for &MultiPolynomial<F> {
+    type Output = MultiPolynomial<F>;
+    fn neg(self) -> Self::Output {
+        MultiPolynomial {
+            nvars: self.nvars,
+            order: self.order,
+            terms: self
+                .terms
+                .iter()
+                .map(|(m, c)| (m.clone(), -c.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<F: Field> Neg for MultiPolynomial<F> {
+    type Output = MultiPolynomial<F>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+crate::impl_ops_by_ref_variants!(MultiPolynomial<F>, Add, add, linalg::Field);
+crate::impl_ops_by_ref_variants!(MultiPolynomial<F>, Sub, sub, linalg::Field);
+crate::impl_ops_by_ref_variants!(MultiPolynomial<F>, Mul, mul, linalg::Field);
+
+impl<F> MultiPolynomial<F>
+where
+    F: Field + fmt::Display + PartialEq,
+{
+    /// 表示用のラッパー（変数名を指定、足りない分は既定名）
+    pub fn display_with<'a>(&'a self, names: &[&str]) -> MultiPolyDisplay<'a, F> {
+        MultiPolyDisplay::new(self, names)
+    }
+}
+
+impl<F> fmt::Display for MultiPolynomial<F>
+where
+    F: Field + fmt::Display + PartialEq,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 既定の変数名は 3 変数までなら x, y, z、それ以上は x1, ..., xn
+        write!(f, "{}", MultiPolyDisplay::new(self, &[]))
+    }
+}
@@ -0,0 +1,382 @@
+//! 多変数の割り算と Gröbner 基底
+//!
+//! イデアル I = ⟨f_1, ..., f_s⟩ の Gröbner 基底 G は「LM(I) が LM(G) で生成される」生成系で、
+//! G による割り算の余り（正規形）が割る順番によらず一意になる。したがって f ∈ I ⇔ 余りが 0。
+//! lex 順序の簡約 Gröbner 基底は三角化された連立方程式になり（消去定理）、後ろの変数から解ける。
+
+use std::collections::HashSet;
+
+use linalg::Field;
+
+use super::core::MultiPolynomial;
+use super::monomial::{Monomial, MonomialOrder};
+
+impl<F: Field> MultiPolynomial<F> {
+    /// 多変数の割り算 self = Σ q_i g_i + r。r のどの項も LM(g_i) で割り切れない。
+    /// 商と余りは割る多項式の並び順に依存する（Gröbner 基底で割れば余りは一意）。
+    pub fn div_rem(&self, divisors: &[Self]) -> (Vec<Self>, Self) {
+        let (nvars, order) = common_shape(self, divisors);
+        let divisors: Vec<Self> = divisors
+            .iter()
+            .map(|g| g.conformed(nvars, order).into_owned())
+            .collect();
+        let mut p = self.conformed(nvars, order).into_owned();
+        let mut quotients = vec![Self::zero(nvars, order); divisors.len()];
+        let mut rem = Self::zero(nvars, order);
+        while let Some((m, c)) = p.leading_term().cloned() {
+            let hit = divisors.iter().enumerate().find_map(|(i, g)| {
+                let (lm, lc) = g.leading_term()?;
+                m.checked_div(lm).map(|t| (i, t, c.clone() / lc.clone()))
+            });
+            match hit {
+                Some((i, t, coeff)) => {
+                    p.sub_mul_term(&coeff, &t, &divisors[i]);
+                    quotients[i].push_smallest(t, coeff);
+                }
+                None => {
+                    p.pop_leading();
+                    rem.push_smallest(m, c);
+                }
+            }
+        }
+        (quotients, rem)
+    }
+
+    /// divisors による割り算の余り（正規形）
+    pub fn reduce(&self, divisors: &[Self]) -> Self {
+        self.div_rem(divisors).1
+    }
+
+    /// S 多項式 S(f, g) = (L / LT(f)) f − (L / LT(g)) g、L = lcm(LM(f), LM(g))
+    pub fn s_polynomial(&self, other: &Self) -> Self {
+        let nvars = self.nvars().max(other.nvars());
+        let f = self.conformed(nvars, self.order());
+        let g = other.conformed(nvars, self.order());
+        match (f.leading_term(), g.leading_term()) {
+            (Some((mf, cf)), Some((mg, cg))) => {
+                let l = mf.lcm(mg);
+                let a = f.mul_term(&l.checked_div(mf).unwrap(), &(F::one() / cf.clone()));
+                let b = g.mul_term(&l.checked_div(mg).unwrap(), &(F::one() / cg.clone()));
+                &a - &b
+            }
+            _ => MultiPolynomial::zero(nvars, self.order()),
+        }
+    }
+}
+
+/// Gröbner 基底の計算法
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum GroebnerAlgorithm {
+    /// S 多項式を 1 つずつ割り算で簡約する（積判定・連鎖判定で不要な対を省く）
+    #[default]
+    Buchberger,
+    /// 同じ次数の S 多項式をまとめて Macaulay 行列の行簡約で処理する（F4 の簡易版）
+    F4,
+}
+
+/// 簡約 Gröbner 基底（各元はモニックで、どの項も他の元の先頭単項式で割り切れない）
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroebnerBasis<F: Field> {
+    polys: Vec<MultiPolynomial<F>>,
+    nvars: usize,
+    order: MonomialOrder,
+}
+
+impl<F: Field> GroebnerBasis<F> {
+    /// 生成系の簡約 Gröbner 基底を Buchberger 法で求める。
+    /// 変数の個数・単項式順序は最初の生成元に揃える。
+    pub fn new(generators: &[MultiPolynomial<F>]) -> Self {
+        Self::with_algorithm(generators, GroebnerAlgorithm::default())
+    }
+
+    pub fn with_algorithm(generators: &[MultiPolynomial<F>], algorithm: GroebnerAlgorithm) -> Self {
+        let (nvars, order) = match generators.first() {
+            Some(g) => common_shape(g, generators),
+            None => (0, MonomialOrder::default()),
+        };
+        let gens: Vec<MultiPolynomial<F>> = generators
+            .iter()
+            .filter(|g| !g.is_zero())
+            .map(|g| g.conformed(nvars, order).monic())
+            .collect();
+        let basis = match algorithm {
+            GroebnerAlgorithm::Buchberger => buchberger(gens),
+            GroebnerAlgorithm::F4 => f4(gens),
+        };
+        Self {
+            polys: reduce_basis(basis),
+            nvars,
+            order,
+        }
+    }
+
+    /// 基底の元（先頭単項式の降順）
+    pub fn polynomials(&self) -> &[MultiPolynomial<F>] {
+        &self.polys
+    }
+
+    pub fn nvars(&self) -> usize {
+        self.nvars
+    }
+
+    pub fn order(&self) -> MonomialOrder {
+        self.order
+    }
+
+    /// f の正規形（I を法とした一意な代表元）
+    pub fn normal_form(&self, f: &MultiPolynomial<F>) -> MultiPolynomial<F> {
+        f.conformed(self.nvars, self.order).reduce(&self.polys)
+    }
+
+    /// イデアル所属判定 f ∈ I
+    pub fn contains(&self, f: &MultiPolynomial<F>) -> bool {
+        self.normal_form(f).is_zero()
+    }
+
+    /// I = ⟨1⟩ か（弱零点定理より、代数閉包に共通零点を持たないことと同値）
+    pub fn is_unit_ideal(&self) -> bool {
+        self.polys.len() == 1 && self.polys[0].leading_monomial().is_some_and(|m| m.is_one())
+    }
+
+    /// 第 k 消去イデアル I ∩ F[x_{k+1}, ..., x_n] の基底（lex 順序のときに限り消去定理が成り立つ）
+    pub fn elimination(&self, k: usize) -> Vec<&MultiPolynomial<F>> {
+        self.polys
+            .iter()
+            .filter(|g| g.variables().iter().all(|&i| i >= k))
+            .collect()
+    }
+}
+
+/// 与えた多項式の列が（生成するイデアルの）Gröbner 基底か。すべての S 多項式が 0 に簡約されれば真。
+pub fn is_groebner_basis<F: Field>(polys: &[MultiPolynomial<F>]) -> bool {
+    let polys: Vec<&MultiPolynomial<F>> = polys.iter().filter(|g| !g.is_zero()).collect();
+    let owned: Vec<MultiPolynomial<F>> = polys.iter().map(|g| (*g).clone()).collect();
+    (0..polys.len()).all(|i| {
+        (i + 1..polys.len()).all(|j| polys[i].s_polynomial(polys[j]).reduce(&owned).is_zero())
+    })
+}
+
+/// 変数の個数は最大、順序は先頭の多項式のもの
+fn common_shape<F: Field>(
+    head: &MultiPolynomial<F>,
+    rest: &[MultiPolynomial<F>],
+) -> (usize, MonomialOrder) {
+    let nvars = rest
+        .iter()
+        .map(|g| g.nvars())
+        .fold(head.nvars(), usize::max);
+    (nvars, head.order())
+}
+
+fn leading(g: &MultiPolynomial<impl Field>) -> &Monomial {
+    g.leading_monomial().expect("basis elements are nonzero")
+}
+
+fn pair_lcm<F: Field>(basis: &[MultiPolynomial<F>], (i, j): (usize, usize)) -> Monomial {
+    leading(&basis[i]).lcm(leading(&basis[j]))
+}
+
+fn all_pairs(n: usize) -> Vec<(usize, usize)> {
+    (0..n).flat_map(|j| (0..j).map(move |i| (i, j))).collect()
+}
+
+/// Buchberger 法。対は lcm が最小のものから選ぶ（正規選択戦略）。
+fn buchberger<F: Field>(mut basis: Vec<MultiPolynomial<F>>) -> Vec<MultiPolynomial<F>> {
+    let Some(order) = basis.first().map(|g| g.order()) else {
+        return basis;
+    };
+    let mut pairs = all_pairs(basis.len());
+    while !pairs.is_empty() {
+        let idx = (1..pairs.len()).fold(0, |best, k| {
+            let a = pair_lcm(&basis, pairs[k]);
+            let b = pair_lcm(&basis, pairs[best]);
+            if order.cmp(&a, &b).is_lt() {
+                k
+            } else {
+                best
+            }
+        });
+        let (i, j) = pairs.swap_remove(idx);
+        let (lm_i, lm_j) = (leading(&basis[i]), leading(&basis[j]));
+        // 積判定: 先頭単項式が互いに素なら S 多項式は 0 に簡約される
+        if lm_i.is_coprime(lm_j) {
+            continue;
+        }
+        // 連鎖判定: LM(g_k) | lcm で (i, k), (j, k) を処理済みなら不要
+        let l = lm_i.lcm(lm_j);
+        let key = |a: usize, b: usize| (a.min(b), a.max(b));
+        let redundant = (0..basis.len()).any(|k| {
+            k != i
+                && k != j
+                && leading(&basis[k]).divides(&l)
+                && !pairs.contains(&key(i, k))
+                && !pairs.contains(&key(j, k))
+        });
+        if redundant {
+            continue;
+        }
+        let r = basis[i].s_polynomial(&basis[j]).reduce(&basis);
+        if !r.is_zero() {
+            let new = basis.len();
+            pairs.extend((0..new).map(|k| (k, new)));
+            basis.push(r.monic());
+        }
+    }
+    basis
+}
+
+/// F4 の簡易版。全次数が最小の対をまとめ、記号的前処理で簡約に必要な行を加えた
+/// Macaulay 行列を行簡約して、新しい先頭単項式を持つ行を基底へ加える。
+fn f4<F: Field>(mut basis: Vec<MultiPolynomial<F>>) -> Vec<MultiPolynomial<F>> {
+    let Some(order) = basis.first().map(|g| g.order()) else {
+        return basis;
+    };
+    let mut pairs = all_pairs(basis.len());
+    while !pairs.is_empty() {
+        let dmin = pairs
+            .iter()
+            .map(|&p| pair_lcm(&basis, p).degree())
+            .min()
+            .unwrap();
+        let (selected, rest): (Vec<_>, Vec<_>) = pairs
+            .into_iter()
+            .partition(|&p| pair_lcm(&basis, p).degree() == dmin);
+        pairs = rest;
+
+        // 各対 (i, j) から (L / LM(g_i)) g_i と (L / LM(g_j)) g_j を行にする（基底はモニック）
+        let one = F::one();
+        let mut rows: Vec<MultiPolynomial<F>> = Vec::new();
+        let mut used: HashSet<(usize, Monomial)> = HashSet::new();
+        for (i, j) in selected {
+            let (lm_i, lm_j) = (leading(&basis[i]), leading(&basis[j]));
+            if lm_i.is_coprime(lm_j) {
+                continue;
+            }
+            let l = lm_i.lcm(lm_j);
+            for k in [i, j] {
+                let t = l.checked_div(leading(&basis[k])).unwrap();
+                if used.insert((k, t.clone())) {
+                    rows.push(basis[k].mul_term(&t, &one));
+                }
+            }
+        }
+        if rows.is_empty() {
+            continue;
+        }
+
+        // 記号的前処理: 行に現れる単項式のうち基底の先頭単項式で割り切れるものには簡約用の行を足す
+        let mut pivots: HashSet<Monomial> = rows.iter().map(|r| leading(r).clone()).collect();
+        let mut seen = pivots.clone();
+        let mut idx = 0;
+        while idx < rows.len() {
+            let monomials: Vec<Monomial> =
+                rows[idx].terms().iter().map(|(m, _)| m.clone()).collect();
+            for m in monomials {
+                if !seen.insert(m.clone()) {
+                    continue;
+                }
+                if let Some(g) = basis.iter().find(|g| leading(g).divides(&m)) {
+                    let t = m.checked_div(leading(g)).unwrap();
+                    rows.push(g.mul_term(&t, &one));
+                    pivots.insert(m);
+                }
+            }
+            idx += 1;
+        }
+
+        let mut columns: Vec<Monomial> = seen.into_iter().collect();
+        columns.sort_by(|a, b| order.cmp(b, a));
+        let reduced = row_reduce(&rows, &columns);
+        let nvars = basis[0].nvars();
+        for row in reduced {
+            let p = MultiPolynomial::new(
+                nvars,
+                order,
+                columns
+                    .iter()
+                    .zip(row)
+                    .filter(|(_, c)| !c.is_zero())
+                    .map(|(m, c)| (m.exps().to_vec(), c))
+                    .collect(),
+            );
+            if pivots.contains(leading(&p)) {
+                continue;
+            }
+            let new = basis.len();
+            pairs.extend((0..new).map(|k| (k, new)));
+            basis.push(p);
+        }
+    }
+    basis
+}
+
+/// 行を列（単項式の降順）で密行列にし、簡約行階段形の非零行をモニックにして返す
+fn row_reduce<F: Field>(rows: &[MultiPolynomial<F>], columns: &[Monomial]) -> Vec<Vec<F>> {
+    let col_of: std::collections::HashMap<&Monomial, usize> =
+        columns.iter().enumerate().map(|(k, m)| (m, k)).collect();
+    let mut mat: Vec<Vec<F>> = rows
+        .iter()
+        .map(|r| {
+            let mut v = vec![F::zero(); columns.len()];
+            for (m, c) in r.terms() {
+                v[col_of[m]] = c.clone();
+            }
+            v
+        })
+        .collect();
+    let mut rank = 0;
+    for col in 0..columns.len() {
+        let Some(p) = (rank..mat.len()).find(|&r| !mat[r][col].is_zero()) else {
+            continue;
+        };
+        mat.swap(rank, p);
+        let inv = F::one() / mat[rank][col].clone();
+        for v in mat[rank].iter_mut() {
+            *v = v.clone() * inv.clone();
+        }
+        let pivot = mat[rank].clone();
+        for (r, row) in mat.iter_mut().enumerate() {
+            if r == rank || row[col].is_zero() {
+                continue;
+            }
+            let factor = row[col].clone();
+            for (v, pv) in row.iter_mut().zip(&pivot) {
+                *v = v.clone() - factor.clone() * pv.clone();
+            }
+        }
+        rank += 1;
+    }
+    mat.truncate(rank);
+    mat
+}
+
+/// Gröbner 基底を簡約 Gröbner 基底にする: 先頭単項式が他で割り切れる元を除き、
+/// 残りを互いに簡約してモニックにし、先頭単項式の降順に並べる。
+fn reduce_basis<F: Field>(basis: Vec<MultiPolynomial<F>>) -> Vec<MultiPolynomial<F>> {
+    let mut minimal: Vec<MultiPolynomial<F>> = Vec::new();
+    for (i, g) in basis.iter().enumerate() {
+        let lm = leading(g);
+        let dominated = basis.iter().enumerate().any(|(j, h)| {
+            let lh = leading(h);
+            j != i && lh.divides(lm) && (lh != lm || j < i)
+        });
+        if !dominated {
+            minimal.push(g.clone());
+        }
+    }
+    let mut reduced: Vec<MultiPolynomial<F>> = (0..minimal.len())
+        .map(|i| {
+            let others: Vec<MultiPolynomial<F>> = minimal
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, h)| h.clone())
+                .collect();
+            minimal[i].reduce(&others).monic()
+        })
+        .collect();
+    if let Some(order) = reduced.first().map(|g| g.order()) {
+        reduced.sort_by(|a, b| order.cmp(leading(b), leading(a)));
+    }
+    reduced
+}
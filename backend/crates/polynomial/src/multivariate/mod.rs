@@ -0,0 +1,9 @@
+//! 多変数多項式と Gröbner 基底
+mod core;
+pub use core::MultiPolynomial;
+
+pub mod groebner;
+pub mod monomial;
+
+pub use groebner::{GroebnerAlgorithm, GroebnerBasis};
+pub use monomial::{Monomial, MonomialOrder};
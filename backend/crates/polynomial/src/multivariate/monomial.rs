@@ -0,0 +1,135 @@
+//! 単項式 x_1^{a_1} ... x_n^{a_n} と単項式順序
+
+use std::cmp::Ordering;
+
+/// 指数ベクトル (a_1, ..., a_n) で表した単項式
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Monomial {
+    exps: Vec<u32>,
+}
+
+impl Monomial {
+    pub fn new(exps: Vec<u32>) -> Self {
+        Self { exps }
+    }
+
+    /// 単項式 1（全指数 0）
+    pub fn one(nvars: usize) -> Self {
+        Self {
+            exps: vec![0; nvars],
+        }
+    }
+
+    /// 変数 x_i そのもの
+    pub fn var(nvars: usize, i: usize) -> Self {
+        let mut exps = vec![0; nvars];
+        exps[i] = 1;
+        Self { exps }
+    }
+
+    pub fn exps(&self) -> &[u32] {
+        &self.exps
+    }
+
+    pub fn nvars(&self) -> usize {
+        self.exps.len()
+    }
+
+    /// 全次数 a_1 + ... + a_n
+    pub fn degree(&self) -> u32 {
+        self.exps.iter().sum()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.exps.iter().all(|&e| e == 0)
+    }
+
+    /// 変数の個数を nvars へ広げる（足りない指数は 0）
+    pub(crate) fn extended(&self, nvars: usize) -> Self {
+        let mut exps = self.exps.clone();
+        exps.resize(nvars.max(exps.len()), 0);
+        Self { exps }
+    }
+
+    /// self が other を割り切るか（各指数が other 以下）
+    pub fn divides(&self, other: &Self) -> bool {
+        self.exps.iter().zip(&other.exps).all(|(a, b)| a <= b)
+    }
+
+    /// 共通の変数を持たないか（Buchberger の積判定に使う）
+    pub fn is_coprime(&self, other: &Self) -> bool {
+        self.exps
+            .iter()
+            .zip(&other.exps)
+            .all(|(&a, &b)| a == 0 || b == 0)
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        Self {
+            exps: self
+                .exps
+                .iter()
+                .zip(&other.exps)
+                .map(|(a, b)| a + b)
+                .collect(),
+        }
+    }
+
+    /// self / other（割り切れなければ None）
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.exps
+            .iter()
+            .zip(&other.exps)
+            .map(|(a, b)| a.checked_sub(*b))
+            .collect::<Option<Vec<u32>>>()
+            .map(Self::new)
+    }
+
+    /// 最小公倍数（各指数の最大）
+    pub fn lcm(&self, other: &Self) -> Self {
+        Self {
+            exps: self
+                .exps
+                .iter()
+                .zip(&other.exps)
+                .map(|(&a, &b)| a.max(b))
+                .collect(),
+        }
+    }
+}
+
+/// 単項式順序。いずれも x_1 > x_2 > ... > x_n
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum MonomialOrder {
+    /// 辞書式順序: 最初に異なる指数が大きい方が大きい（消去に向く）
+    Lex,
+    /// 次数付き辞書式順序: 全次数で比べ、同じなら lex
+    GrLex,
+    /// 次数付き逆辞書式順序: 全次数で比べ、同じなら最後に異なる指数が小さい方が大きい
+    #[default]
+    GrevLex,
+}
+
+impl MonomialOrder {
+    pub fn cmp(&self, a: &Monomial, b: &Monomial) -> Ordering {
+        let lex = || {
+            a.exps
+                .iter()
+                .zip(&b.exps)
+                .find(|(x, y)| x != y)
+                .map_or(Ordering::Equal, |(x, y)| x.cmp(y))
+        };
+        match self {
+            MonomialOrder::Lex => lex(),
+            MonomialOrder::GrLex => a.degree().cmp(&b.degree()).then_with(lex),
+            MonomialOrder::GrevLex => a.degree().cmp(&b.degree()).then_with(|| {
+                a.exps
+                    .iter()
+                    .zip(&b.exps)
+                    .rev()
+                    .find(|(x, y)| x != y)
+                    .map_or(Ordering::Equal, |(x, y)| y.cmp(x))
+            }),
+        }
+    }
+}
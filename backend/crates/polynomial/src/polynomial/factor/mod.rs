@@ -0,0 +1,124 @@
+//! 多項式の因数分解
+//!
+//! - 標数 0 の体: Yun の算法による無平方分解 `square_free_factorization`
+//! - 有理数係数: 無平方分解した各部分を Zassenhaus 法（mod p 分解 → Hensel 持ち上げ → 因子の組み合わせ）で
+//!   Z[x] の既約因子へ分解する `factor`
+//!
+//! 有限体上の分解は `finite-field` クレートの `factor` モジュールが同じ `Factorization` を返す。
+
+mod modp;
+mod zassenhaus;
+
+use linalg::{BigInt, BigRational, Field};
+use num_traits::{FromPrimitive, One, Zero};
+
+use crate::error::{PolynomialError, Result};
+use crate::polynomial::Polynomial;
+
+/// f = unit · Π f_i^{e_i} の形の分解
+#[derive(Clone, Debug, PartialEq)]
+pub struct Factorization<F: Field> {
+    pub unit: F,
+    /// (因子, 重複度)。次数の昇順
+    pub factors: Vec<(Polynomial<F>, usize)>,
+}
+
+impl<F: Field> Factorization<F> {
+    /// unit · Π f_i^{e_i} を展開して元の多項式に戻す
+    pub fn expand(&self) -> Polynomial<F> {
+        self.factors
+            .iter()
+            .fold(Polynomial::new(vec![self.unit.clone()]), |acc, (f, e)| {
+                (0..*e).fold(acc, |acc, _| &acc * f)
+            })
+    }
+
+    /// 重複度を数えた既約因子の個数
+    pub fn count(&self) -> usize {
+        self.factors.iter().map(|(_, e)| e).sum()
+    }
+}
+
+impl<F: Field + FromPrimitive> Polynomial<F> {
+    /// 標数 0 の体での無平方分解 f = lc · Π a_i^i（Yun の算法）。a_i はモニックで互いに素。
+    /// 有限体では f' = 0 となる p 乗の部分を扱えないので `finite_field::factor` を使う。
+    pub fn square_free_factorization(&self) -> Factorization<F> {
+        if self.deg() <= 0 {
+            return Factorization {
+                unit: self.get(0),
+                factors: Vec::new(),
+            };
+        }
+        let unit = self.coeffs.last().cloned().unwrap();
+        let f = self.monic();
+        let df = f.differentiate();
+        let a0 = Polynomial::gcd(&f, &df);
+        let mut b = f.div_rem(&a0).0;
+        let c = df.div_rem(&a0).0;
+        let mut d = &c - &b.differentiate();
+        let mut factors = Vec::new();
+        let mut i = 1;
+        while b.deg() > 0 {
+            let a = Polynomial::gcd(&b, &d);
+            b = b.div_rem(&a).0;
+            let c = d.div_rem(&a).0;
+            d = &c - &b.differentiate();
+            if a.deg() > 0 {
+                factors.push((a, i));
+            }
+            i += 1;
+        }
+        Factorization { unit, factors }
+    }
+}
+
+impl Polynomial<BigRational> {
+    /// 有理数係数多項式を Q 上（Gauss の補題により Z 上）の既約因子へ分解する。
+    /// 因子は整数係数・原始的で最高次係数が正、unit は有理数の定数。
+    pub fn factor(&self) -> Result<Factorization<BigRational>> {
+        if self.is_zero() {
+            return Err(PolynomialError::InvalidArgument {
+                text: "cannot factor the zero polynomial".to_string(),
+            });
+        }
+        let mut factors = Vec::new();
+        for (part, e) in self.square_free_factorization().factors {
+            for g in zassenhaus::factor_squarefree(&to_primitive_integer(&part)) {
+                let g = Polynomial::new(g.into_iter().map(BigRational::from_integer).collect());
+                factors.push((g, e));
+            }
+        }
+        factors
+            .sort_by(|(a, _), (b, _)| a.deg().cmp(&b.deg()).then_with(|| a.coeffs.cmp(&b.coeffs)));
+        let lc_product = factors.iter().fold(BigRational::one(), |acc, (g, e)| {
+            (0..*e).fold(acc, |acc, _| acc * g.coeffs.last().unwrap())
+        });
+        Ok(Factorization {
+            unit: self.coeffs.last().unwrap() / lc_product,
+            factors,
+        })
+    }
+
+    /// Q 上で既約か（定数は既約でない）
+    pub fn is_irreducible(&self) -> bool {
+        self.deg() >= 1
+            && self
+                .factor()
+                .is_ok_and(|f| f.factors.len() == 1 && f.factors[0].1 == 1)
+    }
+}
+
+/// 分母を払って内容で割った整数係数の原始多項式
+pub(crate) fn to_primitive_integer(f: &Polynomial<BigRational>) -> Vec<BigInt> {
+    let denom_lcm = f.coeffs.iter().fold(BigInt::one(), |acc, c| {
+        let g = zassenhaus::gcd(&acc, c.denom());
+        &acc / g * c.denom()
+    });
+    let ints: Vec<BigInt> = f
+        .coeffs
+        .iter()
+        .map(|c| (c * BigRational::from_integer(denom_lcm.clone())).to_integer())
+        .collect();
+    debug_assert!(!ints.iter().all(|c| c.is_zero()));
+    zassenhaus::primitive_part(&ints)
+}
@@ -0,0 +1,228 @@
+//! 実行時に選ぶ小さな素数 p を法とする多項式演算（Zassenhaus 法の下請け）
+//!
+//! 係数は 0..p の u64、低次→高次で、零多項式は空ベクトルで表す。p < 2^31 を仮定する。
+
+pub(super) type Zp = Vec<u64>;
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct ModP {
+    pub p: u64,
+}
+
+impl ModP {
+    pub fn new(p: u64) -> Self {
+        Self { p }
+    }
+
+    fn trim(mut v: Zp) -> Zp {
+        while v.last() == Some(&0) {
+            v.pop();
+        }
+        v
+    }
+
+    pub fn deg(a: &[u64]) -> isize {
+        a.len() as isize - 1
+    }
+
+    pub fn pow(&self, mut b: u64, mut e: u64) -> u64 {
+        let mut acc = 1;
+        b %= self.p;
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = acc * b % self.p;
+            }
+            b = b * b % self.p;
+            e >>= 1;
+        }
+        acc
+    }
+
+    pub fn inv(&self, a: u64) -> u64 {
+        self.pow(a, self.p - 2)
+    }
+
+    pub fn add(&self, a: &[u64], b: &[u64]) -> Zp {
+        let n = a.len().max(b.len());
+        Self::trim(
+            (0..n)
+                .map(|i| (a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0)) % self.p)
+                .collect(),
+        )
+    }
+
+    pub fn sub(&self, a: &[u64], b: &[u64]) -> Zp {
+        let n = a.len().max(b.len());
+        Self::trim(
+            (0..n)
+                .map(|i| (a.get(i).unwrap_or(&0) + self.p - b.get(i).unwrap_or(&0)) % self.p)
+                .collect(),
+        )
+    }
+
+    pub fn scale(&self, a: &[u64], c: u64) -> Zp {
+        Self::trim(a.iter().map(|&x| x * c % self.p).collect())
+    }
+
+    pub fn mul(&self, a: &[u64], b: &[u64]) -> Zp {
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        let mut out = vec![0; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                out[i + j] = (out[i + j] + x * y) % self.p;
+            }
+        }
+        Self::trim(out)
+    }
+
+    /// (商, 余り)。b は非零
+    pub fn div_rem(&self, a: &[u64], b: &[u64]) -> (Zp, Zp) {
+        let mut r = a.to_vec();
+        if r.len() < b.len() {
+            return (Vec::new(), r);
+        }
+        let inv = self.inv(*b.last().unwrap());
+        let mut q = vec![0; r.len() - b.len() + 1];
+        for shift in (0..q.len()).rev() {
+            let c = r[shift + b.len() - 1] * inv % self.p;
+            q[shift] = c;
+            for (i, &y) in b.iter().enumerate() {
+                r[shift + i] = (r[shift + i] + self.p - c * y % self.p) % self.p;
+            }
+        }
+        (Self::trim(q), Self::trim(r))
+    }
+
+    pub fn rem(&self, a: &[u64], b: &[u64]) -> Zp {
+        self.div_rem(a, b).1
+    }
+
+    pub fn monic(&self, a: &[u64]) -> Zp {
+        match a.last() {
+            Some(&lc) => self.scale(a, self.inv(lc)),
+            None => Vec::new(),
+        }
+    }
+
+    /// モニックな gcd
+    pub fn gcd(&self, a: &[u64], b: &[u64]) -> Zp {
+        let (mut a, mut b) = (a.to_vec(), b.to_vec());
+        while !b.is_empty() {
+            let r = self.rem(&a, &b);
+            a = b;
+            b = r;
+        }
+        self.monic(&a)
+    }
+
+    /// s a + t b = 1 となる (s, t)（a, b は互いに素）
+    pub fn ext_gcd(&self, a: &[u64], b: &[u64]) -> (Zp, Zp) {
+        let (mut r0, mut r1) = (a.to_vec(), b.to_vec());
+        let (mut s0, mut s1) = (vec![1], Vec::new());
+        let (mut t0, mut t1) = (Vec::new(), vec![1]);
+        while !r1.is_empty() {
+            let (q, r) = self.div_rem(&r0, &r1);
+            let s = self.sub(&s0, &self.mul(&q, &s1));
+            let t = self.sub(&t0, &self.mul(&q, &t1));
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1, s);
+            (t0, t1) = (t1, t);
+        }
+        let inv = self.inv(r0[0]);
+        (self.scale(&s0, inv), self.scale(&t0, inv))
+    }
+
+    pub fn derivative(&self, a: &[u64]) -> Zp {
+        Self::trim(
+            a.iter()
+                .enumerate()
+                .skip(1)
+                .map(|(k, &c)| (k as u64 % self.p) * c % self.p)
+                .collect(),
+        )
+    }
+
+    /// base^e mod m
+    pub fn pow_mod(&self, base: &[u64], mut e: u64, m: &[u64]) -> Zp {
+        let mut acc = vec![1];
+        let mut b = self.rem(base, m);
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = self.rem(&self.mul(&acc, &b), m);
+            }
+            b = self.rem(&self.mul(&b, &b), m);
+            e >>= 1;
+        }
+        self.rem(&acc, m)
+    }
+
+    /// 無平方なモニック多項式 f を既約因子（モニック）へ分解する（p は奇素数）。
+    /// 次数別分解のあと Cantor–Zassenhaus 法で同じ次数の因子を分ける。
+    pub fn factor_squarefree(&self, f: &[u64]) -> Vec<Zp> {
+        let mut out = Vec::new();
+        let x = vec![0, 1];
+        let mut rest = f.to_vec();
+        let mut h = x.clone();
+        let mut d = 1;
+        while 2 * d as isize <= Self::deg(&rest) {
+            h = self.pow_mod(&h, self.p, &rest);
+            let g = self.gcd(&rest, &self.sub(&h, &x));
+            if g.len() > 1 {
+                rest = self.div_rem(&rest, &g).0;
+                h = self.rem(&h, &rest);
+                self.split_equal_degree(&g, d, &mut out);
+            }
+            d += 1;
+        }
+        if rest.len() > 1 {
+            out.push(rest);
+        }
+        out
+    }
+
+    /// 次数 d の既約因子の積 f を乱択で分ける: gcd(a^((p^d - 1)/2) - 1, f)
+    fn split_equal_degree(&self, f: &[u64], d: usize, out: &mut Vec<Zp>) {
+        let n = f.len() - 1;
+        if n == d {
+            out.push(f.to_vec());
+            return;
+        }
+        let mut rng = SplitMix64(0x9E37_79B9_7F4A_7C15 ^ ((n as u64) << 8) ^ self.p);
+        loop {
+            let a: Zp = Self::trim((0..n).map(|_| rng.next() % self.p).collect());
+            if a.len() < 2 {
+                continue;
+            }
+            // (p^d - 1)/2 = (p - 1)/2 · (1 + p + ... + p^{d-1}) なので、ノルムを取ってから冪乗する
+            let mut norm = a.clone();
+            let mut frob = a.clone();
+            for _ in 1..d {
+                frob = self.pow_mod(&frob, self.p, f);
+                norm = self.rem(&self.mul(&norm, &frob), f);
+            }
+            let b = self.pow_mod(&norm, (self.p - 1) / 2, f);
+            let g = self.gcd(&self.sub(&b, &[1]), f);
+            if g.len() > 1 && g.len() < f.len() {
+                let h = self.div_rem(f, &g).0;
+                self.split_equal_degree(&g, d, out);
+                self.split_equal_degree(&self.monic(&h), d, out);
+                return;
+            }
+        }
+    }
+}
+
+/// 乱択用の SplitMix64
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,293 @@
+//! 整数係数の Zassenhaus 法: mod p 分解 → Hensel 持ち上げ → 因子の組み合わせ
+//!
+//! 整数多項式は BigInt の係数列（低次→高次、末尾は非零）で表す。
+
+use linalg::BigInt;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+use super::modp::{ModP, Zp};
+
+pub(super) type ZPoly = Vec<BigInt>;
+
+/// 原始的・無平方・最高次係数が正で次数 1 以上の f を Z[x] の既約因子へ分解する
+pub(super) fn factor_squarefree(f: &[BigInt]) -> Vec<ZPoly> {
+    let n = f.len() - 1;
+    if n == 1 {
+        return vec![f.to_vec()];
+    }
+    let lc = f.last().unwrap();
+    // 最高次係数を割らず、f mod p が無平方になる素数のうち、因子数が最小のものを選ぶ
+    let mut best: Option<(ModP, Vec<Zp>)> = None;
+    let mut tried = 0;
+    for p in (3u64..).step_by(2).filter(|&p| is_prime(p)) {
+        if tried == 5 || p > 1 << 20 {
+            break;
+        }
+        let ctx = ModP::new(p);
+        if (lc % BigInt::from(p)).is_zero() {
+            continue;
+        }
+        let fp = reduce(&ctx, f);
+        if ctx.gcd(&fp, &ctx.derivative(&fp)).len() != 1 {
+            continue;
+        }
+        tried += 1;
+        let factors = ctx.factor_squarefree(&ctx.monic(&fp));
+        if best.as_ref().is_none_or(|(_, b)| factors.len() < b.len()) {
+            best = Some((ctx, factors));
+        }
+    }
+    let (ctx, modular) = best.expect("a suitable prime exists for squarefree input");
+    if modular.len() == 1 {
+        return vec![f.to_vec()];
+    }
+
+    // Mignotte 型の上界: lc(f) · g の係数は |lc| · 2^n · ||f||_1 以下。p^k がその 2 倍を超えるまで持ち上げる
+    let norm: BigInt = f.iter().map(|c| c.abs()).sum();
+    let bound = lc.abs() * (BigInt::one() << n) * norm * 2;
+    let p = BigInt::from(ctx.p);
+    let mut modulus = p.clone();
+    let mut k = 1;
+    while modulus <= bound {
+        modulus *= &p;
+        k += 1;
+    }
+    let lifted = hensel_lift(&ctx, f, &modular, k);
+    recombine(f, lifted, &modulus)
+}
+
+fn is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+fn reduce(ctx: &ModP, f: &[BigInt]) -> Zp {
+    let p = BigInt::from(ctx.p);
+    let mut v: Zp = f
+        .iter()
+        .map(|c| mod_floor(c, &p).to_u64().unwrap())
+        .collect();
+    while v.last() == Some(&0) {
+        v.pop();
+    }
+    v
+}
+
+fn mod_floor(a: &BigInt, m: &BigInt) -> BigInt {
+    let r = a % m;
+    if r.is_negative() {
+        r + m
+    } else {
+        r
+    }
+}
+
+/// f ≡ lc(f) · Π u_i (mod p) を p^k まで持ち上げ、モニックな因子（係数は 0..p^k）を返す
+fn hensel_lift(ctx: &ModP, f: &[BigInt], us: &[Zp], k: u32) -> Vec<ZPoly> {
+    let p = BigInt::from(ctx.p);
+    let modulus = p.pow(k);
+    if us.len() == 1 {
+        let inv = inverse_mod(f.last().unwrap(), &modulus);
+        return vec![f.iter().map(|c| mod_floor(&(c * &inv), &modulus)).collect()];
+    }
+    // f ≡ u_0 · h と 2 つに分けて持ち上げ、残り h を再帰的に分ける
+    let lc = f.last().unwrap();
+    let lc_p = mod_floor(lc, &p).to_u64().unwrap();
+    let h = us[1..].iter().fold(vec![lc_p], |acc, u| ctx.mul(&acc, u));
+    let (g, h) = lift_pair(ctx, f, &us[0], &h, k);
+    let mut out = vec![g];
+    out.extend(hensel_lift(ctx, &h, &us[1..], k));
+    out
+}
+
+/// f ≡ g h (mod p)、g モニック、gcd(g, h) = 1 から f ≡ G H (mod p^k) を 1 桁ずつ求める。
+/// H の最高次係数は lc(f) のまま保つ。
+fn lift_pair(ctx: &ModP, f: &[BigInt], g: &[u64], h: &[u64], k: u32) -> (ZPoly, ZPoly) {
+    let (s, t) = ctx.ext_gcd(g, h);
+    let p = BigInt::from(ctx.p);
+    let mut big_g: ZPoly = g.iter().map(|&c| BigInt::from(c)).collect();
+    let mut big_h: ZPoly = h.iter().map(|&c| BigInt::from(c)).collect();
+    *big_h.last_mut().unwrap() = f.last().unwrap().clone();
+    let mut pj = p.clone();
+    for _ in 1..k {
+        // e = (f − G H) / p^j、A = (e t) mod g、B = e s + ((e t) div g) h とすれば A h + B g ≡ e
+        let diff = sub(f, &mul(&big_g, &big_h));
+        let e: ZPoly = diff.iter().map(|c| c / &pj).collect();
+        let e = reduce(ctx, &e);
+        let h_p = reduce(ctx, &big_h);
+        let g_p = reduce(ctx, &big_g);
+        let (q, a) = ctx.div_rem(&ctx.mul(&e, &t), &g_p);
+        let b = ctx.add(&ctx.mul(&e, &s), &ctx.mul(&q, &h_p));
+        big_g = add_scaled(&big_g, &a, &pj);
+        big_h = add_scaled(&big_h, &b, &pj);
+        pj *= &p;
+    }
+    (big_g, big_h)
+}
+
+/// 持ち上げた因子の部分集合を小さい順に試し、f を割り切るものを既約因子として取り出す
+fn recombine(f: &[BigInt], mut lifted: Vec<ZPoly>, modulus: &BigInt) -> Vec<ZPoly> {
+    let mut f = f.to_vec();
+    let mut out = Vec::new();
+    let mut size = 1;
+    while 2 * size <= lifted.len() {
+        let mut found = None;
+        for subset in combinations(lifted.len(), size) {
+            let lc = f.last().unwrap().clone();
+            let product = subset.iter().fold(vec![lc], |acc, &i| {
+                mul(&acc, &lifted[i])
+                    .iter()
+                    .map(|c| mod_floor(c, modulus))
+                    .collect()
+            });
+            let candidate = primitive_part(&symmetric(&product, modulus));
+            if let Some(q) = div_exact(&f, &candidate) {
+                found = Some((subset, candidate, q));
+                break;
+            }
+        }
+        match found {
+            Some((subset, g, q)) => {
+                out.push(g);
+                f = q;
+                for &i in subset.iter().rev() {
+                    lifted.remove(i);
+                }
+            }
+            None => size += 1,
+        }
+    }
+    if f.len() > 1 {
+        out.push(f);
+    }
+    out
+}
+
+/// {0, ..., n−1} から k 個選ぶ組み合わせ（辞書順）
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    let mut out = Vec::new();
+    let mut idx: Vec<usize> = (0..k).collect();
+    loop {
+        out.push(idx.clone());
+        let Some(i) = (0..k).rev().find(|&i| idx[i] < n - k + i) else {
+            return out;
+        };
+        idx[i] += 1;
+        for j in i + 1..k {
+            idx[j] = idx[j - 1] + 1;
+        }
+    }
+}
+
+fn inverse_mod(a: &BigInt, m: &BigInt) -> BigInt {
+    let (mut r0, mut r1) = (mod_floor(a, m), m.clone());
+    let (mut s0, mut s1) = (BigInt::one(), BigInt::zero());
+    while !r1.is_zero() {
+        let q = &r0 / &r1;
+        let r = &r0 - &q * &r1;
+        let s = &s0 - &q * &s1;
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s);
+    }
+    mod_floor(&s0, m)
+}
+
+fn symmetric(f: &[BigInt], m: &BigInt) -> ZPoly {
+    let half = m / 2;
+    trim(
+        f.iter()
+            .map(|c| if *c > half { c - m } else { c.clone() })
+            .collect(),
+    )
+}
+
+pub(super) fn gcd(a: &BigInt, b: &BigInt) -> BigInt {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while !b.is_zero() {
+        let r = &a % &b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// 内容（係数の gcd）で割り、最高次係数を正にする
+pub(super) fn primitive_part(f: &[BigInt]) -> ZPoly {
+    let content = f.iter().fold(BigInt::zero(), |acc, c| gcd(&acc, c));
+    let sign = if f.last().is_some_and(|c| c.is_negative()) {
+        -BigInt::one()
+    } else {
+        BigInt::one()
+    };
+    let d = content * sign;
+    f.iter().map(|c| c / &d).collect()
+}
+
+fn trim(mut v: ZPoly) -> ZPoly {
+    while v.last().is_some_and(|c| c.is_zero()) {
+        v.pop();
+    }
+    v
+}
+
+fn mul(a: &[BigInt], b: &[BigInt]) -> ZPoly {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![BigInt::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] += x * y;
+        }
+    }
+    trim(out)
+}
+
+fn sub(a: &[BigInt], b: &[BigInt]) -> ZPoly {
+    let n = a.len().max(b.len());
+    trim(
+        (0..n)
+            .map(|i| a.get(i).cloned().unwrap_or_default() - b.get(i).cloned().unwrap_or_default())
+            .collect(),
+    )
+}
+
+/// a + c · b（b は mod p の係数）
+fn add_scaled(a: &[BigInt], b: &[u64], c: &BigInt) -> ZPoly {
+    let n = a.len().max(b.len());
+    trim(
+        (0..n)
+            .map(|i| {
+                a.get(i).cloned().unwrap_or_default() + c * BigInt::from(*b.get(i).unwrap_or(&0))
+            })
+            .collect(),
+    )
+}
+
+/// Z[x] での割り算。割り切れなければ None
+pub(super) fn div_exact(f: &[BigInt], g: &[BigInt]) -> Option<ZPoly> {
+    if g.len() > f.len() {
+        return None;
+    }
+    let lc = g.last().unwrap();
+    // 定数項が割り切れなければ早めに打ち切る
+    if !g[0].is_zero() && !(&f[0] % &g[0]).is_zero() {
+        return None;
+    }
+    let mut r = f.to_vec();
+    let mut q = vec![BigInt::zero(); f.len() - g.len() + 1];
+    for shift in (0..q.len()).rev() {
+        let top = &r[shift + g.len() - 1];
+        if !(top % lc).is_zero() {
+            return None;
+        }
+        let c = top / lc;
+        for (i, y) in g.iter().enumerate() {
+            r[shift + i] -= &c * y;
+        }
+        q[shift] = c;
+    }
+    r.iter().all(|c| c.is_zero()).then(|| trim(q))
+}
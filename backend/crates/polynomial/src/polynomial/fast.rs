@@ -0,0 +1,317 @@
+//! 高速な多項式演算: Newton 反復による逆数と割り算、部分積木による多点評価・補間、half-GCD
+//!
+//! 乗算は係数型ごとの `FastConvolution::convolve` に任せる。`f64` は `convolution::convolve_fft_f64`、
+//! 有限体 `GFp<P>` は finite-field クレートが NTT（`convolution::ntt`）で実装する。
+//! 作業量が小さいうちは筆算の方が速いので、`mul_auto` と同じ閾値で筆算に切り替える。
+//! `f64` では節点が多いと部分積木の余りに丸め誤差がたまるので、多点評価・補間は厳密な体向け。
+
+use linalg::Field;
+
+use crate::error::{PolynomialError, Result};
+use crate::polynomial::Polynomial;
+
+/// この作業量（係数の個数の積）以下なら筆算
+const SCHOOLBOOK_WORK: usize = 2048;
+
+/// 高速な畳み込みを持つ係数体
+pub trait FastConvolution: Field {
+    /// 係数列の線形畳み込み（長さ a.len() + b.len() − 1）。a, b は空でない
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self>;
+}
+
+impl FastConvolution for f64 {
+    fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+        match convolution::convolve_fft_f64(a, b) {
+            Ok(y) => y,
+            Err(e) => panic!("convolution failed: {e}"),
+        }
+    }
+}
+
+/// 2×2 の多項式行列。half-GCD の変換を表す
+pub type PolyMatrix<F> = [[Polynomial<F>; 2]; 2];
+
+impl<F: FastConvolution> Polynomial<F> {
+    /// 畳み込みによる乗算（小さいときは筆算）
+    pub fn mul_fast(&self, other: &Self) -> Self {
+        if self.deg() < 0 || other.deg() < 0 {
+            return Polynomial::zero();
+        }
+        Polynomial::new(mul_vec(&self.coeffs, &other.coeffs))
+    }
+
+    /// 形式的冪級数としての逆数 g（f g ≡ 1 mod x^k）。g ← g (2 − f g) で精度を倍々にする
+    pub fn inverse_series(&self, k: usize) -> Result<Self> {
+        let c0 = self.get(0);
+        if c0.is_zero() {
+            return Err(PolynomialError::InvalidArgument {
+                text: "power series inverse needs a non-zero constant term".to_string(),
+            });
+        }
+        let two = F::one() + F::one();
+        let mut g = vec![F::one() / c0];
+        let mut len = 1;
+        while len < k {
+            len = (2 * len).min(k);
+            let f = &self.coeffs[..len.min(self.coeffs.len())];
+            let mut e = truncated(mul_vec(f, &g), len);
+            for c in e.iter_mut() {
+                *c = -c.clone();
+            }
+            e[0] = e[0].clone() + two.clone();
+            g = truncated(mul_vec(&g, &e), len);
+        }
+        g.truncate(k.max(1));
+        Ok(Polynomial::new(g))
+    }
+
+    /// 割り算 (商, 余り)。rev(q) = rev(a) · rev(b)^{-1} mod x^{n−m+1} を Newton 反復の逆数で求める。
+    /// 零多項式で割ったときは `div_rem` と同じく (0, self) を返す。
+    pub fn div_rem_fast(&self, divisor: &Self) -> (Self, Self) {
+        let (n, m) = (self.deg(), divisor.deg());
+        if m < 0 || n < m {
+            return (Polynomial::zero(), self.clone());
+        }
+        let k = (n - m + 1) as usize;
+        let m = m as usize;
+        if k * (m + 1) <= SCHOOLBOOK_WORK {
+            return self.div_rem(divisor);
+        }
+        let rev_a: Vec<F> = self.coeffs.iter().rev().take(k).cloned().collect();
+        let rev_b = Polynomial::new(divisor.coeffs.iter().rev().cloned().collect());
+        let inv = rev_b
+            .inverse_series(k)
+            .expect("leading coefficient of a non-zero divisor is non-zero");
+        let mut q = truncated(mul_vec(&rev_a, &inv.coeffs), k);
+        q.reverse();
+        let q = Polynomial::new(q);
+        // 余りは次数 m 未満の部分だけを取る（f64 では上位に丸め誤差が残るため）
+        let bq = mul_vec(&divisor.coeffs, &q.coeffs);
+        let r = (0..m)
+            .map(|i| self.get(i) - bq.get(i).cloned().unwrap_or_else(F::zero))
+            .collect();
+        (q, Polynomial::new(r))
+    }
+
+    /// 多点評価 [f(x_0), ..., f(x_{n−1})]。部分積木を下りながら余りを取る
+    pub fn eval_many(&self, points: &[F]) -> Vec<F> {
+        match SubproductTree::new(points) {
+            Ok(tree) => tree.evaluate(self),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// 部分積木による高速補間（節点は相異なること）
+    pub fn interpolate_fast(xs: &[F], ys: &[F]) -> Result<Self> {
+        SubproductTree::new(xs)?.interpolate(ys)
+    }
+
+    /// half-GCD: deg a > deg b に対し、ユークリッド互除法の途中までの変換 M を返す。
+    /// M (a, b)ᵀ = (c, d)ᵀ のとき deg c ≥ ⌈deg a / 2⌉ > deg d。M は商 q_i による [[0, 1], [1, −q_i]] の積。
+    pub fn half_gcd(a: &Self, b: &Self) -> PolyMatrix<F> {
+        let n = a.deg();
+        let m = ((n + 1) / 2) as usize;
+        if b.deg() < m as isize {
+            return identity();
+        }
+        let r = Self::half_gcd(&shift_down(a, m), &shift_down(b, m));
+        let (c, d) = apply(&r, a, b);
+        if d.deg() < m as isize {
+            return r;
+        }
+        let (q, e) = c.div_rem_fast(&d);
+        let k = 2 * m - d.deg() as usize;
+        let s = Self::half_gcd(&shift_down(&d, k), &shift_down(&e, k));
+        let step = [
+            [Polynomial::zero(), Polynomial::one()],
+            [Polynomial::one(), &Polynomial::zero() - &q],
+        ];
+        mat_mul(&mat_mul(&s, &step), &r)
+    }
+
+    /// half-GCD で互除法の半分ずつを一度に進める gcd（モニック）。結果は `Polynomial::gcd` と同じ。
+    /// 零判定は厳密比較なので `GFp` などの厳密な体向け
+    pub fn gcd_fast(a: &Self, b: &Self) -> Self {
+        let (mut a, mut b) = if a.deg() >= b.deg() {
+            (a.clone(), b.clone())
+        } else {
+            (b.clone(), a.clone())
+        };
+        while !b.is_zero() {
+            if a.deg() > b.deg() {
+                let m = Self::half_gcd(&a, &b);
+                (a, b) = apply(&m, &a, &b);
+                if b.is_zero() {
+                    break;
+                }
+            }
+            let r = a.div_rem_fast(&b).1;
+            (a, b) = (b, r);
+        }
+        a.monic()
+    }
+}
+
+/// 部分積木: 葉は (x − x_i)、各節点は 2 つの子の積、根は Π (x − x_i)
+#[derive(Clone, Debug)]
+pub struct SubproductTree<F: Field> {
+    points: Vec<F>,
+    /// levels[0] が葉、最後の段が根。節点 levels[l + 1][j] = levels[l][2j] · levels[l][2j + 1]
+    levels: Vec<Vec<Polynomial<F>>>,
+}
+
+impl<F: FastConvolution> SubproductTree<F> {
+    pub fn new(points: &[F]) -> Result<Self> {
+        if points.is_empty() {
+            return Err(PolynomialError::InvalidArgument {
+                text: "subproduct tree needs at least one point".to_string(),
+            });
+        }
+        let leaves: Vec<Polynomial<F>> = points
+            .iter()
+            .map(|x| Polynomial::new(vec![-x.clone(), F::one()]))
+            .collect();
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [l, r] => l.mul_fast(r),
+                    [l] => l.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Ok(Self {
+            points: points.to_vec(),
+            levels,
+        })
+    }
+
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// 根 Π (x − x_i)
+    pub fn root(&self) -> &Polynomial<F> {
+        &self.levels.last().unwrap()[0]
+    }
+
+    /// f(x_i) を全節点で求める。f mod (節点の多項式) を根から葉へ下ろす
+    pub fn evaluate(&self, f: &Polynomial<F>) -> Vec<F> {
+        let mut rems = vec![f.div_rem_fast(self.root()).1];
+        for level in self.levels.iter().rev().skip(1) {
+            rems = level
+                .iter()
+                .enumerate()
+                .map(|(i, node)| rems[i / 2].div_rem_fast(node).1)
+                .collect();
+        }
+        rems.iter().map(|r| r.get(0)).collect()
+    }
+
+    /// Σ y_i / m'(x_i) · m(x) / (x − x_i)（m は根）を葉から根へ組み上げる
+    pub fn interpolate(&self, values: &[F]) -> Result<Polynomial<F>> {
+        if values.len() != self.points.len() {
+            return Err(PolynomialError::InvalidArgument {
+                text: format!(
+                    "interpolation needs the same non-zero number of nodes and values, got {} and {}",
+                    self.points.len(),
+                    values.len()
+                ),
+            });
+        }
+        let weights = self.evaluate(&derivative(self.root()));
+        if let Some(j) = weights.iter().position(|w| w.is_zero()) {
+            return Err(PolynomialError::InvalidArgument {
+                text: format!("interpolation nodes must be distinct: x[{j}] is repeated"),
+            });
+        }
+        let mut acc: Vec<Polynomial<F>> = values
+            .iter()
+            .zip(weights)
+            .map(|(y, w)| Polynomial::new(vec![y.clone() / w]))
+            .collect();
+        for level in &self.levels[..self.levels.len() - 1] {
+            acc = acc
+                .chunks(2)
+                .enumerate()
+                .map(|(j, pair)| match pair {
+                    [l, r] => &l.mul_fast(&level[2 * j + 1]) + &r.mul_fast(&level[2 * j]),
+                    [l] => l.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        Ok(acc.pop().unwrap())
+    }
+}
+
+fn mul_vec<F: FastConvolution>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![F::zero()];
+    }
+    if a.len() * b.len() <= SCHOOLBOOK_WORK {
+        let mut v = vec![F::zero(); a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                v[i + j] = v[i + j].clone() + x.clone() * y.clone();
+            }
+        }
+        v
+    } else {
+        F::convolve(a, b)
+    }
+}
+
+/// 長さ len に切り詰める（足りなければ 0 で埋める）
+fn truncated<F: Field>(mut v: Vec<F>, len: usize) -> Vec<F> {
+    v.resize(len, F::zero());
+    v
+}
+
+/// f div x^k
+fn shift_down<F: Field>(f: &Polynomial<F>, k: usize) -> Polynomial<F> {
+    Polynomial::new(f.coeffs.iter().skip(k).cloned().collect())
+}
+
+/// 形式微分。係数 i を 1 の i 回和として作るので標数や `FromPrimitive` に依らない
+fn derivative<F: Field>(f: &Polynomial<F>) -> Polynomial<F> {
+    let mut k = F::zero();
+    Polynomial::new(
+        f.coeffs
+            .iter()
+            .skip(1)
+            .map(|c| {
+                k = k.clone() + F::one();
+                c.clone() * k.clone()
+            })
+            .collect(),
+    )
+}
+
+fn identity<F: Field>() -> PolyMatrix<F> {
+    [
+        [Polynomial::one(), Polynomial::zero()],
+        [Polynomial::zero(), Polynomial::one()],
+    ]
+}
+
+fn apply<F: FastConvolution>(
+    m: &PolyMatrix<F>,
+    a: &Polynomial<F>,
+    b: &Polynomial<F>,
+) -> (Polynomial<F>, Polynomial<F>) {
+    (
+        &m[0][0].mul_fast(a) + &m[0][1].mul_fast(b),
+        &m[1][0].mul_fast(a) + &m[1][1].mul_fast(b),
+    )
+}
+
+fn mat_mul<F: FastConvolution>(x: &PolyMatrix<F>, y: &PolyMatrix<F>) -> PolyMatrix<F> {
+    let entry = |i: usize, j: usize| &x[i][0].mul_fast(&y[0][j]) + &x[i][1].mul_fast(&y[1][j]);
+    [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]]
+}
@@ -0,0 +1,146 @@
+//! Chebyshev 基底による多項式の最小二乗近似
+//!
+//! 単項式基底 1, x, ..., x^d の Vandermonde 行列は次数とともに条件数が指数的に悪化する。
+//! 標本の範囲 [a, b] を [-1, 1] へ写し、`special::chebyshev_first_kind` の T_0, ..., T_d を基底に
+//! 取ると列がほぼ直交するので、次数を上げても係数が安定して求まる。
+
+use linalg::matrix::numerical::LeastSquares;
+use linalg::{Matrix, Vector};
+
+use crate::error::{PolynomialError, Result};
+use crate::polynomial::special::chebyshev_first_kind;
+use crate::polynomial::Polynomial;
+
+/// Σ c_k T_k(t)、t = (2x - (a + b)) / (b - a) の形の近似多項式
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChebyshevFit {
+    /// Chebyshev 係数 c_0, ..., c_d
+    pub coeffs: Vec<f64>,
+    /// 標本の範囲 [a, b]
+    pub domain: (f64, f64),
+    /// 残差二乗和 Σ (p(x_i) - y_i)²
+    pub residuals: f64,
+    /// 計画行列の数値ランク（d + 1 未満なら最小ノルム解）
+    pub rank: usize,
+}
+
+impl ChebyshevFit {
+    /// 標本 (x_i, y_i) に次数 degree の多項式を最小二乗で当てはめる
+    pub fn new(xs: &[f64], ys: &[f64], degree: usize) -> Result<Self> {
+        if xs.len() != ys.len() || xs.len() <= degree {
+            return Err(PolynomialError::InvalidArgument {
+                text: format!(
+                    "fitting degree {degree} needs more than {degree} samples with one value each, got {} and {}",
+                    xs.len(),
+                    ys.len()
+                ),
+            });
+        }
+        if xs.iter().chain(ys).any(|v| !v.is_finite()) {
+            return Err(PolynomialError::InvalidArgument {
+                text: "fitting samples must be finite".to_string(),
+            });
+        }
+        let a = xs.iter().copied().fold(f64::INFINITY, f64::min);
+        let b = xs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if a >= b {
+            return Err(PolynomialError::InvalidArgument {
+                text: "fitting needs at least two distinct abscissae".to_string(),
+            });
+        }
+        let domain = (a, b);
+        // T_k(t_i) は T_{k+1} = 2t T_k - T_{k-1} で直接求める（単項式の係数を経由しない）
+        let mut design = Matrix::zeros(xs.len(), degree + 1);
+        for (i, &x) in xs.iter().enumerate() {
+            for (k, v) in chebyshev_values(to_unit(domain, x), degree)
+                .into_iter()
+                .enumerate()
+            {
+                design[(i, k)] = v;
+            }
+        }
+        let sol = design.lstsq(&Vector::new(ys.to_vec()), None).map_err(|e| {
+            PolynomialError::InvalidArgument {
+                text: format!("least-squares fit failed: {e}"),
+            }
+        })?;
+        Ok(Self {
+            coeffs: sol.solution.data,
+            domain,
+            residuals: sol.residuals,
+            rank: sol.rank,
+        })
+    }
+
+    pub fn degree(&self) -> usize {
+        self.coeffs.len() - 1
+    }
+
+    /// Clenshaw の漸化式で Σ c_k T_k(t) を評価する
+    pub fn eval(&self, x: f64) -> f64 {
+        let t = to_unit(self.domain, x);
+        let (mut b1, mut b2) = (0.0, 0.0);
+        for &c in self.coeffs[1..].iter().rev() {
+            let b0 = 2.0 * t * b1 - b2 + c;
+            b2 = b1;
+            b1 = b0;
+        }
+        t * b1 - b2 + self.coeffs[0]
+    }
+
+    /// x の単項式基底の多項式へ展開する。高次では係数が大きく打ち消し合うので、
+    /// 評価には `eval` を使う方が精度がよい。
+    pub fn to_polynomial(&self) -> Polynomial<f64> {
+        let in_t = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .fold(Polynomial::zero(), |acc, (k, &c)| {
+                &acc + &(&chebyshev_first_kind::<f64>(k) * c)
+            });
+        // t = α x + β を Horner 法で代入する
+        let (a, b) = self.domain;
+        let unit = Polynomial::new(vec![-(a + b) / (b - a), 2.0 / (b - a)]);
+        in_t.coeffs
+            .iter()
+            .rev()
+            .fold(Polynomial::zero(), |acc, &c| {
+                &(&acc * &unit) + &Polynomial::new(vec![c])
+            })
+    }
+}
+
+impl Polynomial<f64> {
+    /// 次数 degree の最小二乗近似多項式（Chebyshev 基底で解いてから単項式へ展開する）
+    pub fn fit(xs: &[f64], ys: &[f64], degree: usize) -> Result<Self> {
+        Ok(ChebyshevFit::new(xs, ys, degree)?.to_polynomial())
+    }
+}
+
+/// [a, b] 上の n 個の Chebyshev 点 (a + b)/2 + (b - a)/2 cos((2j + 1)π / 2n)（T_n の零点、昇順）。
+/// 等間隔点で高次補間すると端で振動する（Runge 現象）が、この点で補間すると一様に収束する。
+pub fn chebyshev_points(n: usize, a: f64, b: f64) -> Vec<f64> {
+    (0..n)
+        .rev()
+        .map(|j| {
+            let theta = std::f64::consts::PI * (2 * j + 1) as f64 / (2 * n) as f64;
+            0.5 * (a + b) + 0.5 * (b - a) * theta.cos()
+        })
+        .collect()
+}
+
+fn to_unit((a, b): (f64, f64), x: f64) -> f64 {
+    (2.0 * x - (a + b)) / (b - a)
+}
+
+fn chebyshev_values(t: f64, degree: usize) -> Vec<f64> {
+    let mut values = Vec::with_capacity(degree + 1);
+    values.push(1.0);
+    if degree >= 1 {
+        values.push(t);
+    }
+    for k in 2..=degree {
+        values.push(2.0 * t * values[k - 1] - values[k - 2]);
+    }
+    values
+}
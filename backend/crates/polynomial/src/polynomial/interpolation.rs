@@ -0,0 +1,151 @@
+//! 標本点からの多項式補間（Lagrange・Newton 差分商・重心形式）
+//!
+//! いずれも一般の体 F 上で動き、相異なる n 個の節点を通る次数 n - 1 以下の多項式を返す。
+//! 割り算はすべて節点の差 x_j - x_k で行うので、有限体 `GFp` でも節点が相異なれば使える。
+
+use linalg::Field;
+
+use crate::error::{PolynomialError, Result};
+use crate::polynomial::Polynomial;
+
+impl<F: Field> Polynomial<F> {
+    /// Lagrange 補間 Σ y_j ℓ_j(x)。ℓ_j(x) = Π_{k≠j} (x - x_k) / (x_j - x_k) は
+    /// 節点多項式 ℓ(x) = Π (x - x_k) を (x - x_j) で組立除算して作るので全体で O(n²)。
+    pub fn lagrange(xs: &[F], ys: &[F]) -> Result<Self> {
+        Ok(Barycentric::new(xs, ys)?.to_polynomial())
+    }
+
+    /// Newton の差分商による補間
+    /// p(x) = f[x_0] + f[x_0, x_1] (x - x_0) + ... + f[x_0, ..., x_{n-1}] Π_{k<n-1} (x - x_k)
+    pub fn newton_interpolation(xs: &[F], ys: &[F]) -> Result<Self> {
+        let dd = divided_differences(xs, ys)?;
+        // 入れ子形式 (..(c_{n-1} (x - x_{n-2}) + c_{n-2}) (x - x_{n-3}) + ..) を後ろから展開する
+        let mut acc = vec![dd[dd.len() - 1].clone()];
+        for k in (0..dd.len() - 1).rev() {
+            acc = mul_linear(&acc, &xs[k]);
+            acc[0] = acc[0].clone() + dd[k].clone();
+        }
+        Ok(Polynomial::new(acc))
+    }
+}
+
+/// 差分商 f[x_0], f[x_0, x_1], ..., f[x_0, ..., x_{n-1}]（Newton 形式の係数）
+pub fn divided_differences<F: Field>(xs: &[F], ys: &[F]) -> Result<Vec<F>> {
+    check_nodes(xs, ys)?;
+    let n = xs.len();
+    let mut table = ys.to_vec();
+    for level in 1..n {
+        for i in (level..n).rev() {
+            table[i] =
+                (table[i].clone() - table[i - 1].clone()) / (xs[i].clone() - xs[i - level].clone());
+        }
+    }
+    Ok(table)
+}
+
+/// 重心形式の補間 p(x) = Σ w_j y_j / (x - x_j) / Σ w_j / (x - x_j)、w_j = 1 / Π_{k≠j} (x_j - x_k)。
+/// 重みを一度 O(n²) で作れば、係数を展開せずに O(n) で評価でき、Chebyshev 点などでは数値的にも安定。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Barycentric<F: Field> {
+    nodes: Vec<F>,
+    values: Vec<F>,
+    weights: Vec<F>,
+}
+
+impl<F: Field> Barycentric<F> {
+    pub fn new(xs: &[F], ys: &[F]) -> Result<Self> {
+        check_nodes(xs, ys)?;
+        let weights = (0..xs.len())
+            .map(|j| {
+                let prod = (0..xs.len())
+                    .filter(|&k| k != j)
+                    .fold(F::one(), |acc, k| acc * (xs[j].clone() - xs[k].clone()));
+                F::one() / prod
+            })
+            .collect();
+        Ok(Self {
+            nodes: xs.to_vec(),
+            values: ys.to_vec(),
+            weights,
+        })
+    }
+
+    pub fn nodes(&self) -> &[F] {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// 節点と一致する x ではその値をそのまま返す
+    pub fn eval(&self, x: F) -> F {
+        let mut num = F::zero();
+        let mut den = F::zero();
+        for ((xj, yj), wj) in self.nodes.iter().zip(&self.values).zip(&self.weights) {
+            let diff = x.clone() - xj.clone();
+            if diff.is_zero() {
+                return yj.clone();
+            }
+            let t = wj.clone() / diff;
+            num = num + t.clone() * yj.clone();
+            den = den + t;
+        }
+        num / den
+    }
+
+    /// 係数表示の多項式 Σ w_j y_j ℓ(x) / (x - x_j) に展開する
+    pub fn to_polynomial(&self) -> Polynomial<F> {
+        let master = self
+            .nodes
+            .iter()
+            .fold(vec![F::one()], |acc, xk| mul_linear(&acc, xk));
+        let n = self.nodes.len();
+        let mut coeffs = vec![F::zero(); n];
+        for ((xj, yj), wj) in self.nodes.iter().zip(&self.values).zip(&self.weights) {
+            let scale = wj.clone() * yj.clone();
+            if scale.is_zero() {
+                continue;
+            }
+            // ℓ(x) / (x - x_j) を高次から組立除算する（余りは 0）
+            let mut carry = F::zero();
+            for k in (0..n).rev() {
+                carry = master[k + 1].clone() + carry * xj.clone();
+                coeffs[k] = coeffs[k].clone() + scale.clone() * carry.clone();
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+/// 係数列（低次→高次）に (x - root) を掛ける
+fn mul_linear<F: Field>(coeffs: &[F], root: &F) -> Vec<F> {
+    let mut out = vec![F::zero(); coeffs.len() + 1];
+    for (k, c) in coeffs.iter().enumerate() {
+        out[k + 1] = out[k + 1].clone() + c.clone();
+        out[k] = out[k].clone() - c.clone() * root.clone();
+    }
+    out
+}
+
+fn check_nodes<F: Field>(xs: &[F], ys: &[F]) -> Result<()> {
+    if xs.is_empty() || xs.len() != ys.len() {
+        return Err(PolynomialError::InvalidArgument {
+            text: format!(
+                "interpolation needs the same non-zero number of nodes and values, got {} and {}",
+                xs.len(),
+                ys.len()
+            ),
+        });
+    }
+    for j in 0..xs.len() {
+        for k in 0..j {
+            if (xs[j].clone() - xs[k].clone()).is_zero() {
+                return Err(PolynomialError::InvalidArgument {
+                    text: format!("interpolation nodes must be distinct: x[{k}] = x[{j}]"),
+                });
+            }
+        }
+    }
+    Ok(())
+}
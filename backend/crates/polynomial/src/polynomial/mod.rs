@@ -3,5 +3,12 @@ mod core;
 pub use core::Polynomial;
 
 // サブモジュールを公開
+pub mod factor;
+pub mod fast;
+pub mod fit;
+pub mod interpolation;
+pub mod real_roots;
+pub mod root_bounds;
 pub mod solver;
 pub mod special;
+pub mod spline;
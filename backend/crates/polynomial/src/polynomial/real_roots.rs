@@ -0,0 +1,412 @@
+//! 実根の保証付き計算: Sturm 列による個数、Descartes 法（Vincent–Collins–Akritas）による分離、
+//! 二分法による精密化、Routh–Hurwitz / Schur の安定判定
+//!
+//! すべて有理数係数で厳密に計算する。`f64` の係数は `to_rational` で誤差なく有理数に直してから使うので、
+//! 丸め誤差で根を見落としたり、虚軸上の極を安定と判定したりしない。
+
+use std::cmp::Ordering;
+
+use linalg::{BigInt, BigRational};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+use crate::error::{PolynomialError, Result};
+use crate::polynomial::factor::to_primitive_integer;
+use crate::polynomial::Polynomial;
+
+type Q = BigRational;
+
+/// 実根をちょうど 1 つ含む開区間 (lo, hi)。lo == hi なら有理数の根そのもの
+#[derive(Clone, Debug, PartialEq)]
+pub struct IsolatingInterval {
+    pub lo: BigRational,
+    pub hi: BigRational,
+    /// 根の重複度
+    pub multiplicity: usize,
+}
+
+impl IsolatingInterval {
+    pub fn is_exact(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    pub fn width(&self) -> BigRational {
+        &self.hi - &self.lo
+    }
+
+    pub fn midpoint(&self) -> BigRational {
+        (&self.lo + &self.hi) / Q::from_integer(2.into())
+    }
+
+    /// 区間を含む f64 の区間（外向きに丸める）
+    pub fn to_f64_bounds(&self) -> (f64, f64) {
+        let mut lo = self.lo.to_f64().unwrap_or(f64::NEG_INFINITY);
+        if Q::from_float(lo).is_some_and(|v| v > self.lo) {
+            lo = lo.next_down();
+        }
+        let mut hi = self.hi.to_f64().unwrap_or(f64::INFINITY);
+        if Q::from_float(hi).is_some_and(|v| v < self.hi) {
+            hi = hi.next_up();
+        }
+        (lo, hi)
+    }
+}
+
+impl Polynomial<BigRational> {
+    /// Sturm 列 p_0 = p, p_1 = p', p_{k+1} = −(p_{k−1} mod p_k)
+    pub fn sturm_sequence(&self) -> Vec<Self> {
+        if self.is_zero() {
+            return Vec::new();
+        }
+        let mut seq = vec![self.clone(), self.differentiate()];
+        while !seq.last().unwrap().is_zero() {
+            let n = seq.len();
+            let r = seq[n - 2].div_rem(&seq[n - 1]).1;
+            seq.push(&Polynomial::zero() - &r);
+        }
+        seq.pop();
+        seq
+    }
+
+    /// 相異なる実根の個数（零多項式は 0）
+    pub fn count_real_roots(&self) -> usize {
+        let seq = self.sturm_sequence();
+        let at_neg_inf: Vec<i8> = seq
+            .iter()
+            .map(|p| {
+                let s = sign(p.coeffs.last().unwrap());
+                if p.deg() % 2 == 0 {
+                    s
+                } else {
+                    -s
+                }
+            })
+            .collect();
+        let at_pos_inf: Vec<i8> = seq.iter().map(|p| sign(p.coeffs.last().unwrap())).collect();
+        variations(&at_neg_inf) - variations(&at_pos_inf)
+    }
+
+    /// 半開区間 (a, b] にある相異なる実根の個数（Sturm の定理）
+    pub fn count_roots_between(&self, a: &BigRational, b: &BigRational) -> usize {
+        if a >= b {
+            return 0;
+        }
+        let seq = self.sturm_sequence();
+        let at = |x: &Q| -> usize {
+            let signs: Vec<i8> = seq.iter().map(|p| sign(&p.eval(x.clone()))).collect();
+            variations(&signs)
+        };
+        at(a).saturating_sub(at(b))
+    }
+
+    /// 係数の符号変化の回数。Descartes の符号律により正の根の個数（重複度込み）以上で、差は偶数
+    pub fn descartes_sign_variations(&self) -> usize {
+        let signs: Vec<i8> = self.coeffs.iter().map(sign).collect();
+        variations(&signs)
+    }
+
+    /// 実根を互いに素な区間に分離する（Vincent–Collins–Akritas 法）。区間は昇順
+    pub fn isolate_real_roots(&self) -> Result<Vec<IsolatingInterval>> {
+        if self.is_zero() {
+            return Err(PolynomialError::InvalidArgument {
+                text: "the zero polynomial has no isolated roots".to_string(),
+            });
+        }
+        if self.deg() == 0 {
+            return Ok(Vec::new());
+        }
+        let sqf = self.square_free_factorization();
+        let q = sqf
+            .factors
+            .iter()
+            .fold(Polynomial::one(), |acc, (g, _)| &acc * g);
+        let mut out = Vec::new();
+        let mut z = to_primitive_integer(&q);
+        let zero_root = z[0].is_zero();
+        if zero_root {
+            z.remove(0);
+        }
+        // 負の根は q(−x) の正の根
+        let neg: Vec<BigInt> = z
+            .iter()
+            .enumerate()
+            .map(|(i, c)| if i % 2 == 1 { -c } else { c.clone() })
+            .collect();
+        for (lo, hi) in positive_roots(&neg).into_iter().rev() {
+            out.push((-hi, -lo));
+        }
+        if zero_root {
+            out.push((Q::zero(), Q::zero()));
+        }
+        out.extend(positive_roots(&z));
+
+        Ok(out
+            .into_iter()
+            .map(|(lo, hi)| {
+                let multiplicity = sqf
+                    .factors
+                    .iter()
+                    .find(|(g, _)| has_root_in(g, &lo, &hi))
+                    .map_or(1, |(_, e)| *e);
+                IsolatingInterval {
+                    lo,
+                    hi,
+                    multiplicity,
+                }
+            })
+            .collect())
+    }
+
+    /// 分離区間を二分法で幅 eps 以下に縮める
+    pub fn refine_root(
+        &self,
+        interval: &IsolatingInterval,
+        eps: &BigRational,
+    ) -> IsolatingInterval {
+        let q = self
+            .square_free_factorization()
+            .factors
+            .iter()
+            .fold(Polynomial::one(), |acc, (g, _)| &acc * g);
+        refine(&q, interval, eps)
+    }
+
+    /// すべての実根を幅 eps 以下の区間で返す
+    pub fn real_roots(&self, eps: &BigRational) -> Result<Vec<IsolatingInterval>> {
+        let roots = self.isolate_real_roots()?;
+        let q = self
+            .square_free_factorization()
+            .factors
+            .iter()
+            .fold(Polynomial::one(), |acc, (g, _)| &acc * g);
+        Ok(roots.iter().map(|iv| refine(&q, iv, eps)).collect())
+    }
+
+    /// 全根の実部が負か（Routh–Hurwitz）。Routh 表の第 1 列がすべて同符号で 0 を含まないときに限る。
+    /// 定数多項式（根なし）は安定、零多項式は不安定とする
+    pub fn is_hurwitz_stable(&self) -> bool {
+        if self.is_zero() {
+            return false;
+        }
+        let n = self.deg() as usize;
+        // 最高次係数を正にそろえ、降べきの順に並べる
+        let s = sign(self.coeffs.last().unwrap());
+        let a: Vec<Q> = self
+            .coeffs
+            .iter()
+            .rev()
+            .map(|c| if s < 0 { -c } else { c.clone() })
+            .collect();
+        if a.iter().any(|c| !c.is_positive()) {
+            return false;
+        }
+        let mut prev: Vec<Q> = a.iter().step_by(2).cloned().collect();
+        let mut cur: Vec<Q> = a.iter().skip(1).step_by(2).cloned().collect();
+        for _ in 1..n {
+            if !cur[0].is_positive() {
+                return false;
+            }
+            let next: Vec<Q> = (0..prev.len().saturating_sub(1))
+                .map(|j| {
+                    let p = prev.get(j + 1).cloned().unwrap_or_else(Q::zero);
+                    let c = cur.get(j + 1).cloned().unwrap_or_else(Q::zero);
+                    (&cur[0] * p - &prev[0] * c) / &cur[0]
+                })
+                .collect();
+            if next.is_empty() {
+                return false;
+            }
+            prev = cur;
+            cur = next;
+        }
+        n == 0 || cur[0].is_positive()
+    }
+
+    /// 全根が単位円の内側にあるか。z = (1 + s)/(1 − s) で左半平面へ写して Routh–Hurwitz で判定する
+    pub fn is_schur_stable(&self) -> bool {
+        if self.is_zero() {
+            return false;
+        }
+        let n = self.deg() as usize;
+        // Σ a_k (1 + s)^k (1 − s)^{n−k}。次数が落ちるのは z = −1 が根のとき
+        let plus = Polynomial::new(vec![Q::one(), Q::one()]);
+        let minus = Polynomial::new(vec![Q::one(), -Q::one()]);
+        let mapped = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .fold(Polynomial::zero(), |acc, (k, c)| {
+                let term = (0..k).fold(Polynomial::new(vec![c.clone()]), |t, _| &t * &plus);
+                let term = (k..n).fold(term, |t, _| &t * &minus);
+                &acc + &term
+            });
+        mapped.deg() == n as isize && mapped.is_hurwitz_stable()
+    }
+}
+
+impl Polynomial<f64> {
+    /// 係数を誤差なく有理数に直す（NaN・無限大はエラー）
+    pub fn to_rational(&self) -> Result<Polynomial<BigRational>> {
+        self.coeffs
+            .iter()
+            .map(|&c| {
+                Q::from_float(c).ok_or_else(|| PolynomialError::InvalidArgument {
+                    text: format!("coefficient {c} is not finite"),
+                })
+            })
+            .collect::<Result<Vec<Q>>>()
+            .map(Polynomial::new)
+    }
+
+    /// 係数を厳密な有理数とみなした実根の分離区間
+    pub fn isolate_real_roots(&self) -> Result<Vec<IsolatingInterval>> {
+        self.to_rational()?.isolate_real_roots()
+    }
+
+    /// 連続時間系の安定性（全根の実部が負）を係数から厳密に判定する
+    pub fn is_hurwitz_stable(&self) -> bool {
+        self.to_rational().is_ok_and(|p| p.is_hurwitz_stable())
+    }
+
+    /// 離散時間系の安定性（全根が単位円の内側）を係数から厳密に判定する
+    pub fn is_schur_stable(&self) -> bool {
+        self.to_rational().is_ok_and(|p| p.is_schur_stable())
+    }
+}
+
+fn sign(x: &Q) -> i8 {
+    match x.cmp(&Q::zero()) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+fn int_sign(x: &BigInt) -> i8 {
+    if x.is_negative() {
+        -1
+    } else if x.is_zero() {
+        0
+    } else {
+        1
+    }
+}
+
+/// 0 を飛ばした符号変化の回数
+fn variations(signs: &[i8]) -> usize {
+    let mut last = 0;
+    let mut count = 0;
+    for &s in signs.iter().filter(|&&s| s != 0) {
+        if last != 0 && s != last {
+            count += 1;
+        }
+        last = s;
+    }
+    count
+}
+
+/// 無平方な整数多項式 p（p(0) ≠ 0）の正の根の分離区間
+fn positive_roots(p: &[BigInt]) -> Vec<(Q, Q)> {
+    let n = p.len() - 1;
+    if n == 0 {
+        return Vec::new();
+    }
+    // 根の上界 1 + max|a_i / a_n| 以上の 2 の冪 B を取り、p(B x) の (0, 1) の根を探す
+    let lc = p[n].abs();
+    let max = p[..n].iter().map(|c| c.abs()).max().unwrap();
+    let bound = Q::one() + Q::new(max, lc);
+    let mut b = BigInt::one();
+    let mut k = 0;
+    while Q::from_integer(b.clone()) < bound {
+        b <<= 1;
+        k += 1;
+    }
+    let scaled: Vec<BigInt> = p.iter().enumerate().map(|(i, c)| c << (k * i)).collect();
+    let mut out = Vec::new();
+    vca(scaled, Q::zero(), Q::from_integer(b), &mut out);
+    out
+}
+
+/// p の (0, 1) の根が元の多項式の (a, b) の根に a + (b − a) x で対応する
+fn vca(p: Vec<BigInt>, a: Q, b: Q, out: &mut Vec<(Q, Q)>) {
+    // (x + 1)^n p(1/(x + 1)) の符号変化は (0, 1) の根の個数の上界
+    let mut t = p.clone();
+    t.reverse();
+    taylor_shift_one(&mut t);
+    let v = variations(&t.iter().map(int_sign).collect::<Vec<i8>>());
+    if v == 0 {
+        return;
+    }
+    if v == 1 {
+        out.push((a, b));
+        return;
+    }
+    let n = p.len() - 1;
+    let mid = (&a + &b) / Q::from_integer(2.into());
+    // 左半分 2^n p(x/2)、右半分はそれを 1 だけずらしたもの
+    let left: Vec<BigInt> = p.iter().enumerate().map(|(i, c)| c << (n - i)).collect();
+    let mut right = left.clone();
+    taylor_shift_one(&mut right);
+    vca(left, a, mid.clone(), out);
+    if right[0].is_zero() {
+        out.push((mid.clone(), mid.clone()));
+        right.remove(0);
+    }
+    vca(right, mid, b, out);
+}
+
+/// p(x) ← p(x + 1)
+fn taylor_shift_one(p: &mut [BigInt]) {
+    let n = p.len();
+    for i in 0..n {
+        for j in (i..n - 1).rev() {
+            let next = p[j + 1].clone();
+            p[j] += next;
+        }
+    }
+}
+
+/// 単根だけを持つ g の根が (lo, hi) または lo = hi にあるか
+fn has_root_in(g: &Polynomial<Q>, lo: &Q, hi: &Q) -> bool {
+    if lo == hi {
+        return g.eval(lo.clone()).is_zero();
+    }
+    sign_right(g, lo) != sign_left(g, hi)
+}
+
+/// x の少し右での符号（x が単根ならその先の符号は導関数で決まる）
+fn sign_right(g: &Polynomial<Q>, x: &Q) -> i8 {
+    match sign(&g.eval(x.clone())) {
+        0 => sign(&g.differentiate().eval(x.clone())),
+        s => s,
+    }
+}
+
+fn sign_left(g: &Polynomial<Q>, x: &Q) -> i8 {
+    match sign(&g.eval(x.clone())) {
+        0 => -sign(&g.differentiate().eval(x.clone())),
+        s => s,
+    }
+}
+
+/// 無平方な q の単根を含む区間を二分法で縮める。中点が根なら区間は 1 点になる
+fn refine(q: &Polynomial<Q>, interval: &IsolatingInterval, eps: &Q) -> IsolatingInterval {
+    let mut lo = interval.lo.clone();
+    let mut hi = interval.hi.clone();
+    let s_lo = sign_right(q, &lo);
+    while &hi - &lo > *eps {
+        let mid = (&lo + &hi) / Q::from_integer(2.into());
+        match sign(&q.eval(mid.clone())) {
+            0 => {
+                lo = mid.clone();
+                hi = mid;
+            }
+            s if s == s_lo => lo = mid,
+            _ => hi = mid,
+        }
+    }
+    IsolatingInterval {
+        lo,
+        hi,
+        multiplicity: interval.multiplicity,
+    }
+}
@@ -0,0 +1,125 @@
+//! 複素根の近似値に対する誤差限界（Smith の包含円）
+//!
+//! n 次多項式 p の相異なる近似根 z_1, ..., z_n に対し、円 |z − z_i| ≤ n |p(z_i)| / |a_n Π_{j≠i} (z_i − z_j)|
+//! の和集合は p の全根を含み、k 個の円からなる連結成分にはちょうど k 個の根がある。
+//! これは同伴行列を Lagrange 基底で書いたときの Gershgorin 円と同じものである。
+
+use num_complex::Complex;
+
+use crate::error::{PolynomialError, Result};
+use crate::polynomial::Polynomial;
+
+/// 根を含む円。`cluster` は重なり合う円の連結成分の番号で、成分ごとの円の個数だけ根がある
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RootDisk {
+    pub center: Complex<f64>,
+    pub radius: f64,
+    pub cluster: usize,
+}
+
+impl RootDisk {
+    pub fn contains(&self, z: Complex<f64>) -> bool {
+        (z - self.center).norm() <= self.radius
+    }
+}
+
+impl Polynomial<f64> {
+    /// 近似根 `approx`（重複度込みで deg 個）に Smith の包含円を付ける。
+    /// |p(z_i)| には Horner 法の丸め誤差の上界を足すので、円は浮動小数点の評価誤差込みで根を含む
+    pub fn root_inclusion_disks(&self, approx: &[Complex<f64>]) -> Result<Vec<RootDisk>> {
+        let n = self.deg();
+        if n < 1 || approx.len() != n as usize {
+            return Err(PolynomialError::InvalidArgument {
+                text: format!(
+                    "expected {} approximate roots for a degree {} polynomial, got {}",
+                    n.max(0),
+                    n,
+                    approx.len()
+                ),
+            });
+        }
+        if approx
+            .iter()
+            .any(|z| !z.re.is_finite() || !z.im.is_finite())
+        {
+            return Err(PolynomialError::InvalidArgument {
+                text: "approximate roots must be finite".to_string(),
+            });
+        }
+        let nf = n as f64;
+        let lead = self.coeffs.last().unwrap().abs();
+        // 評価・積・割り算の丸めを吸収する係数
+        let u = f64::EPSILON;
+        let inflate = 1.0 + 8.0 * (nf + 2.0) * u;
+        let radii: Vec<f64> = approx
+            .iter()
+            .enumerate()
+            .map(|(i, &z)| {
+                let (value, error) = horner_with_bound(&self.coeffs, z);
+                let denom = approx
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .fold(lead, |acc, (_, &w)| acc * (z - w).norm());
+                if denom == 0.0 {
+                    f64::INFINITY
+                } else {
+                    nf * (value.norm() + error) / denom * inflate
+                }
+            })
+            .collect();
+
+        // 重なる円を union-find でまとめる
+        let mut parent: Vec<usize> = (0..approx.len()).collect();
+        fn find(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        for i in 0..approx.len() {
+            for j in i + 1..approx.len() {
+                if (approx[i] - approx[j]).norm() <= radii[i] + radii[j] {
+                    let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+                    parent[a] = b;
+                }
+            }
+        }
+        let mut labels: Vec<usize> = Vec::new();
+        Ok((0..approx.len())
+            .map(|i| {
+                let root = find(&mut parent, i);
+                let cluster = labels.iter().position(|&r| r == root).unwrap_or_else(|| {
+                    labels.push(root);
+                    labels.len() - 1
+                });
+                RootDisk {
+                    center: approx[i],
+                    radius: radii[i],
+                    cluster,
+                }
+            })
+            .collect())
+    }
+
+    /// `find_roots` の結果に包含円を付けて返す
+    pub fn find_roots_certified(&self) -> Result<Vec<RootDisk>> {
+        let roots = self.find_roots();
+        self.root_inclusion_disks(&roots)
+    }
+}
+
+/// Horner 法による p(z) と、その丸め誤差の上界 γ · Σ |a_k| |z|^k
+fn horner_with_bound(coeffs: &[f64], z: Complex<f64>) -> (Complex<f64>, f64) {
+    let r = z.norm();
+    let mut value = Complex::new(0.0, 0.0);
+    let mut magnitude = 0.0;
+    for &c in coeffs.iter().rev() {
+        value = value * z + c;
+        magnitude = magnitude * r + c.abs();
+    }
+    // 各段で複素乗算 2√2 u と加算 u を見込んで余裕を持たせる
+    let gamma = 8.0 * coeffs.len() as f64 * f64::EPSILON;
+    (value, gamma * magnitude)
+}
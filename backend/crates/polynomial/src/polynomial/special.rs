@@ -1,3 +1,5 @@
+use std::f64::consts::PI;
+
 use linalg::Field;
 use num_traits::FromPrimitive;
 
@@ -161,3 +163,41 @@ pub fn laguerre<F: Field + FromPrimitive>(n: usize) -> Polynomial<F> {
     }
     l_nm1
 }
+
+/// (P_n(x), P_{n-1}(x)) を三項漸化式 (k + 1) P_{k+1} = (2k + 1) x P_k - k P_{k-1} で直接評価する。
+/// 係数を展開しないので、高次でも `legendre(n).eval(x)` のような桁落ちがない。
+pub fn legendre_pair(n: usize, x: f64) -> (f64, f64) {
+    let (mut p, mut p_prev) = (1.0, 0.0);
+    for k in 0..n {
+        let kf = k as f64;
+        let next = ((2.0 * kf + 1.0) * x * p - kf * p_prev) / (kf + 1.0);
+        p_prev = p;
+        p = next;
+    }
+    (p, p_prev)
+}
+
+/// 正規直交化した Hermite 関数の組 (h̃_n(x), h̃_{n-1}(x))。h̃_n = H_n / √(2^n n! √π)。
+/// 漸化式 h̃_k = x √(2/k) h̃_{k-1} - √((k-1)/k) h̃_{k-2} は H_n と違って大きな n でも桁あふれしない。
+pub fn hermite_normalized_pair(n: usize, x: f64) -> (f64, f64) {
+    let (mut p, mut p_prev) = (PI.powf(-0.25), 0.0);
+    for k in 1..=n {
+        let kf = k as f64;
+        let next = x * (2.0 / kf).sqrt() * p - ((kf - 1.0) / kf).sqrt() * p_prev;
+        p_prev = p;
+        p = next;
+    }
+    (p, p_prev)
+}
+
+/// (L_n(x), L_{n-1}(x)) を三項漸化式 k L_k = (2k - 1 - x) L_{k-1} - (k - 1) L_{k-2} で直接評価する。
+pub fn laguerre_pair(n: usize, x: f64) -> (f64, f64) {
+    let (mut p, mut p_prev) = (1.0, 0.0);
+    for k in 1..=n {
+        let kf = k as f64;
+        let next = ((2.0 * kf - 1.0 - x) * p - (kf - 1.0) * p_prev) / kf;
+        p_prev = p;
+        p = next;
+    }
+    (p, p_prev)
+}
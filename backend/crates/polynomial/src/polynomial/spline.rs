@@ -0,0 +1,237 @@
+//! 3 次スプライン補間
+//!
+//! 各区間 [x_i, x_{i+1}] 上の 3 次式を、節点での 2 階微分 M_i を未知数とする三重対角方程式
+//! h_{i-1} M_{i-1} + 2 (h_{i-1} + h_i) M_i + h_i M_{i+1} = 6 (δ_i - δ_{i-1}) から求める
+//! （h_i = x_{i+1} - x_i、δ_i = (y_{i+1} - y_i) / h_i）。両端の 2 式は境界条件で決まる。
+
+use linalg::{Tridiagonal, Vector};
+
+use crate::error::{PolynomialError, Result};
+use crate::polynomial::Polynomial;
+
+/// 両端の境界条件
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplineBoundary {
+    /// 両端の 2 階微分が 0
+    Natural,
+    /// 両端の 1 階微分を与える
+    Clamped { start: f64, end: f64 },
+    /// 最初と最後の内部節点で 3 階微分も連続（端の 2 区間が同じ 3 次式になる）
+    NotAKnot,
+}
+
+/// 区間ごとの 3 次式を局所変数 t = x - x_i の `Polynomial` として持つ
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline {
+    knots: Vec<f64>,
+    pieces: Vec<Polynomial<f64>>,
+}
+
+impl CubicSpline {
+    /// 狭義単調増加の節点 xs と値 ys を通るスプライン。節点が 2 個なら直線、
+    /// not-a-knot で 3 個なら 3 点を通る放物線になる。
+    pub fn new(xs: &[f64], ys: &[f64], boundary: SplineBoundary) -> Result<Self> {
+        check_knots(xs, ys)?;
+        let n = xs.len();
+        let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+        let delta: Vec<f64> = (0..n - 1).map(|i| (ys[i + 1] - ys[i]) / h[i]).collect();
+        let m = match boundary {
+            SplineBoundary::Natural => natural_moments(&h, &delta)?,
+            SplineBoundary::Clamped { start, end } => {
+                if !(start.is_finite() && end.is_finite()) {
+                    return Err(PolynomialError::InvalidArgument {
+                        text: format!("clamped end slopes must be finite, got {start} and {end}"),
+                    });
+                }
+                clamped_moments(&h, &delta, start, end)?
+            }
+            SplineBoundary::NotAKnot => not_a_knot_moments(&h, &delta)?,
+        };
+        let pieces = (0..n - 1)
+            .map(|i| {
+                Polynomial::new(vec![
+                    ys[i],
+                    delta[i] - h[i] * (2.0 * m[i] + m[i + 1]) / 6.0,
+                    m[i] / 2.0,
+                    (m[i + 1] - m[i]) / (6.0 * h[i]),
+                ])
+            })
+            .collect();
+        Ok(Self {
+            knots: xs.to_vec(),
+            pieces,
+        })
+    }
+
+    pub fn knots(&self) -> &[f64] {
+        &self.knots
+    }
+
+    /// 区間 [x_i, x_{i+1}] 上の 3 次式（変数は t = x - x_i）
+    pub fn pieces(&self) -> &[Polynomial<f64>] {
+        &self.pieces
+    }
+
+    /// 範囲外では端の区間の 3 次式で外挿する
+    pub fn eval(&self, x: f64) -> f64 {
+        let i = self.segment(x);
+        self.pieces[i].eval(x - self.knots[i])
+    }
+
+    /// order 階微分の値（order >= 4 では 0）
+    pub fn derivative(&self, x: f64, order: usize) -> f64 {
+        let i = self.segment(x);
+        let mut p = self.pieces[i].clone();
+        for _ in 0..order {
+            p = p.differentiate();
+        }
+        p.eval(x - self.knots[i])
+    }
+
+    /// ∫_a^b s(x) dx
+    pub fn integrate(&self, a: f64, b: f64) -> f64 {
+        self.antiderivative(b) - self.antiderivative(a)
+    }
+
+    /// ∫_{x_0}^x s(t) dt
+    fn antiderivative(&self, x: f64) -> f64 {
+        let i = self.segment(x);
+        let full: f64 = (0..i)
+            .map(|j| {
+                let width = self.knots[j + 1] - self.knots[j];
+                self.pieces[j].integrate().eval(width)
+            })
+            .sum();
+        full + self.pieces[i].integrate().eval(x - self.knots[i])
+    }
+
+    /// x を含む区間の番号（範囲外は端の区間）
+    fn segment(&self, x: f64) -> usize {
+        let upper = self.knots.partition_point(|&k| k <= x);
+        upper.saturating_sub(1).min(self.pieces.len() - 1)
+    }
+}
+
+/// M_0 = M_{n-1} = 0 として内部の n - 2 個を解く
+fn natural_moments(h: &[f64], delta: &[f64]) -> Result<Vec<f64>> {
+    let n = h.len() + 1;
+    let mut m = vec![0.0; n];
+    if n > 2 {
+        let (lower, diag, upper, rhs) = interior_system(h, delta);
+        let sol = solve_tridiagonal(lower, diag, upper, rhs)?;
+        m[1..n - 1].copy_from_slice(&sol);
+    }
+    Ok(m)
+}
+
+/// 端の式 2 h_0 M_0 + h_0 M_1 = 6 (δ_0 - s_0) と h_{n-2} M_{n-2} + 2 h_{n-2} M_{n-1} = 6 (s_1 - δ_{n-2})
+/// を加えた n 元の系（対角優位）
+fn clamped_moments(h: &[f64], delta: &[f64], start: f64, end: f64) -> Result<Vec<f64>> {
+    let n = h.len() + 1;
+    let mut lower = Vec::with_capacity(n - 1);
+    let mut diag = Vec::with_capacity(n);
+    let mut upper = Vec::with_capacity(n - 1);
+    let mut rhs = Vec::with_capacity(n);
+    diag.push(2.0 * h[0]);
+    upper.push(h[0]);
+    rhs.push(6.0 * (delta[0] - start));
+    for i in 1..n - 1 {
+        lower.push(h[i - 1]);
+        diag.push(2.0 * (h[i - 1] + h[i]));
+        upper.push(h[i]);
+        rhs.push(6.0 * (delta[i] - delta[i - 1]));
+    }
+    lower.push(h[n - 2]);
+    diag.push(2.0 * h[n - 2]);
+    rhs.push(6.0 * (end - delta[n - 2]));
+    solve_tridiagonal(lower, diag, upper, rhs)
+}
+
+/// M_0 = ((h_0 + h_1) M_1 - h_0 M_2) / h_1 と右端の同様の式を最初と最後の内部の式へ代入して
+/// 内部の n - 2 元の三重対角系にする。対角優位でなくなるのでピボット付きの帯 LU で解く。
+fn not_a_knot_moments(h: &[f64], delta: &[f64]) -> Result<Vec<f64>> {
+    let n = h.len() + 1;
+    match n {
+        2 => return Ok(vec![0.0; 2]),
+        // 3 点を通る放物線: 2 階微分は 2 f[x_0, x_1, x_2] で一定
+        3 => return Ok(vec![2.0 * (delta[1] - delta[0]) / (h[0] + h[1]); 3]),
+        _ => {}
+    }
+    let (mut lower, mut diag, mut upper, rhs) = interior_system(h, delta);
+    let k = diag.len() - 1;
+    let (h0, h1) = (h[0], h[1]);
+    diag[0] = (h0 + h1) * (h0 + 2.0 * h1) / h1;
+    upper[0] = (h1 * h1 - h0 * h0) / h1;
+    let (ha, hb) = (h[n - 3], h[n - 2]);
+    diag[k] = (ha + hb) * (2.0 * ha + hb) / ha;
+    lower[k - 1] = (ha * ha - hb * hb) / ha;
+    let a = Tridiagonal::new(lower, diag, upper).map_err(linalg_error)?;
+    let sol = a
+        .to_banded()
+        .solve(&Vector::new(rhs))
+        .map_err(linalg_error)?;
+    let mut m = vec![0.0; n];
+    m[1..n - 1].copy_from_slice(&sol.data);
+    m[0] = ((h0 + h1) * m[1] - h0 * m[2]) / h1;
+    m[n - 1] = ((ha + hb) * m[n - 2] - hb * m[n - 3]) / ha;
+    Ok(m)
+}
+
+type TridiagonalSystem = (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>);
+
+/// 内部節点 i = 1..n-2 の式の (下副対角, 対角, 上副対角, 右辺)
+fn interior_system(h: &[f64], delta: &[f64]) -> TridiagonalSystem {
+    let n = h.len() + 1;
+    let lower = (2..n - 1).map(|i| h[i - 1]).collect();
+    let diag = (1..n - 1).map(|i| 2.0 * (h[i - 1] + h[i])).collect();
+    let upper = (1..n - 2).map(|i| h[i]).collect();
+    let rhs = (1..n - 1)
+        .map(|i| 6.0 * (delta[i] - delta[i - 1]))
+        .collect();
+    (lower, diag, upper, rhs)
+}
+
+fn solve_tridiagonal(
+    lower: Vec<f64>,
+    diag: Vec<f64>,
+    upper: Vec<f64>,
+    rhs: Vec<f64>,
+) -> Result<Vec<f64>> {
+    let a = Tridiagonal::new(lower, diag, upper).map_err(linalg_error)?;
+    let sol = a.solve(&Vector::new(rhs)).map_err(linalg_error)?;
+    Ok(sol.data)
+}
+
+fn linalg_error(e: linalg::LinalgError) -> PolynomialError {
+    PolynomialError::InvalidArgument {
+        text: format!("spline system could not be solved: {e}"),
+    }
+}
+
+fn check_knots(xs: &[f64], ys: &[f64]) -> Result<()> {
+    if xs.len() < 2 || xs.len() != ys.len() {
+        return Err(PolynomialError::InvalidArgument {
+            text: format!(
+                "spline needs at least 2 knots and one value per knot, got {} knots and {} values",
+                xs.len(),
+                ys.len()
+            ),
+        });
+    }
+    if xs.iter().chain(ys).any(|v| !v.is_finite()) {
+        return Err(PolynomialError::InvalidArgument {
+            text: "spline knots and values must be finite".to_string(),
+        });
+    }
+    if let Some(i) = xs.windows(2).position(|w| w[0] >= w[1]) {
+        return Err(PolynomialError::InvalidArgument {
+            text: format!(
+                "spline knots must be strictly increasing: x[{i}] = {} >= x[{}] = {}",
+                xs[i],
+                i + 1,
+                xs[i + 1]
+            ),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use linalg::BigRational;
+use poly::Polynomial;
+
+type Q = BigRational;
+
+fn q(n: i64, d: i64) -> Q {
+    Q::new(n.into(), d.into())
+}
+
+fn zpoly(coeffs: &[i64]) -> Polynomial<Q> {
+    Polynomial::new(coeffs.iter().map(|&c| q(c, 1)).collect())
+}
+
+#[test]
+fn yun_square_free_factorization() {
+    // 3 (x − 1)^3 (x + 2)^2 (x² + 1)
+    let f = &(&(&zpoly(&[-1, 1]) * &zpoly(&[-1, 1])) * &zpoly(&[-1, 1]))
+        * &(&(&zpoly(&[2, 1]) * &zpoly(&[2, 1])) * &zpoly(&[1, 0, 1]));
+    let f = &f * q(3, 1);
+    let sqf = f.square_free_factorization();
+    assert_eq!(sqf.unit, q(3, 1));
+    assert_eq!(
+        sqf.factors,
+        vec![
+            (zpoly(&[1, 0, 1]), 1),
+            (zpoly(&[2, 1]), 2),
+            (zpoly(&[-1, 1]), 3)
+        ]
+    );
+    assert_eq!(sqf.expand(), f);
+    assert_eq!(sqf.count(), 6);
+
+    // f64 でも使える（x² − 2x + 1 = (x − 1)²）
+    let g = Polynomial::new(vec![1.0, -2.0, 1.0]).square_free_factorization();
+    assert_eq!(g.factors, vec![(Polynomial::new(vec![-1.0, 1.0]), 2)]);
+}
+
+#[test]
+fn rational_factorization_small_cases() {
+    // x⁴ − 1 = (x − 1)(x + 1)(x² + 1)
+    let f = zpoly(&[-1, 0, 0, 0, 1]).factor().unwrap();
+    assert_eq!(f.unit, q(1, 1));
+    assert_eq!(
+        f.factors,
+        vec![
+            (zpoly(&[-1, 1]), 1),
+            (zpoly(&[1, 1]), 1),
+            (zpoly(&[1, 0, 1]), 1)
+        ]
+    );
+
+    // 最高次係数が 1 でない: 6x² + 5x + 1 = (2x + 1)(3x + 1)
+    let f = zpoly(&[1, 5, 6]).factor().unwrap();
+    assert_eq!(f.factors, vec![(zpoly(&[1, 2]), 1), (zpoly(&[1, 3]), 1)]);
+
+    // 有理数係数: x²/2 − 1/2 = 1/2 (x − 1)(x + 1)、−4x³ + 4x = −4 x (x − 1)(x + 1)
+    let f = Polynomial::new(vec![q(-1, 2), q(0, 1), q(1, 2)])
+        .factor()
+        .unwrap();
+    assert_eq!(f.unit, q(1, 2));
+    assert_eq!(f.count(), 2);
+    let f = zpoly(&[0, 4, 0, -4]).factor().unwrap();
+    assert_eq!(f.unit, q(-4, 1));
+    assert_eq!(f.factors[0].0, zpoly(&[-1, 1]));
+    assert_eq!(f.factors.len(), 3);
+    assert!(f.factors.iter().any(|(g, _)| *g == zpoly(&[0, 1])));
+
+    // 定数と零多項式
+    let c = zpoly(&[7]).factor().unwrap();
+    assert_eq!((c.unit, c.factors.len()), (q(7, 1), 0));
+    assert!(zpoly(&[0]).factor().is_err());
+
+    assert!(zpoly(&[-2, 0, 1]).is_irreducible());
+    assert!(!zpoly(&[-4, 0, 1]).is_irreducible());
+    assert!(!zpoly(&[3]).is_irreducible());
+}
+
+#[test]
+fn zassenhaus_recombines_modular_factors() {
+    // x⁴ + 1 はどの素数 p でも 2 次以下に分解するが Q 上では既約
+    assert!(zpoly(&[1, 0, 0, 0, 1]).is_irreducible());
+    // Swinnerton-Dyer 多項式（√2 + √3 + √5 の最小多項式）も mod p では 2 次以下に分解する
+    assert!(zpoly(&[576, 0, -960, 0, 352, 0, -40, 0, 1]).is_irreducible());
+
+    // x¹² − 1 は 6 個の円分多項式の積
+    let f = zpoly(&[-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+        .factor()
+        .unwrap();
+    let degrees: Vec<isize> = f.factors.iter().map(|(g, _)| g.deg()).collect();
+    assert_eq!(degrees, vec![1, 1, 2, 2, 2, 4]);
+    assert!(f.factors.contains(&(zpoly(&[1, 0, -1, 0, 1]), 1)));
+
+    // 係数の大きな因子と重複度の組み合わせ
+    let a = zpoly(&[-89, 1_234_567, 0, 1]);
+    let b = zpoly(&[-3, 0, 7]);
+    let c = zpoly(&[5, -1, 1]);
+    let f = &(&(&a * &b) * &(&c * &c)) * q(-10, 3);
+    let fact = f.factor().unwrap();
+    assert_eq!(fact.expand(), f);
+    assert_eq!(fact.unit, q(-10, 3));
+    assert_eq!(fact.factors, vec![(b, 1), (c, 2), (a, 1)]);
+}
@@ -0,0 +1,115 @@
+use convolution::convolve_naive_f64;
+use convolution::ntt::{convolve_mod_u64, convolve_ntt_u64, NTT_MODULUS};
+use poly::polynomial::fast::SubproductTree;
+use poly::Polynomial;
+
+/// 再現可能な疑似乱数列（線形合同法）
+fn lcg(seed: u64) -> impl FnMut() -> u64 {
+    let mut s = seed;
+    move || {
+        s = s
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        s >> 33
+    }
+}
+
+fn random_f64(n: usize, seed: u64, scale: f64) -> Vec<f64> {
+    let mut next = lcg(seed);
+    (0..n)
+        .map(|_| (next() % 2001) as f64 / 1000.0 - 1.0)
+        .map(|v| v * scale)
+        .collect()
+}
+
+fn close(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol * (1.0 + b.abs())
+}
+
+#[test]
+fn ntt_convolution_is_exact() {
+    let mut next = lcg(7);
+    let x: Vec<u64> = (0..300).map(|_| next() % 1_000_000).collect();
+    let h: Vec<u64> = (0..177).map(|_| next() % 1_000_000).collect();
+    let naive: Vec<u64> = (0..x.len() + h.len() - 1)
+        .map(|k| {
+            (0..x.len())
+                .filter(|&i| k >= i && k - i < h.len())
+                .map(|i| x[i] * h[k - i])
+                .sum()
+        })
+        .collect();
+    assert_eq!(convolve_ntt_u64(&x, &h).unwrap(), naive);
+
+    // mod m の畳み込み: 結果は naive mod m
+    let m = 65_521;
+    let got = convolve_mod_u64(&x, &h, m).unwrap();
+    assert!(got.iter().zip(&naive).all(|(g, n)| *g == n % m));
+    // 巨大な法は正確に計算できないので拒否する
+    assert!(convolve_mod_u64(&x, &h, 1 << 40).is_err());
+    assert!(convolve_ntt_u64(&[], &h).is_err());
+    assert_eq!(convolve_ntt_u64(&[NTT_MODULUS + 2], &[3]).unwrap(), vec![6]);
+}
+
+#[test]
+fn fft_division_matches_long_division() {
+    // b = x^m + 小さな下位項なので、rev(b) の冪級数逆数は安定
+    let mut lower = random_f64(120, 3, 0.05);
+    lower.push(1.0);
+    let b = Polynomial::new(lower);
+    let q0 = Polynomial::new(random_f64(200, 5, 1.0));
+    let r0 = Polynomial::new(random_f64(120, 9, 1.0));
+    let a = &(&b * &q0) + &r0;
+    let prod = a.mul_fast(&Polynomial::one());
+    assert_eq!(prod.coeffs.len(), a.coeffs.len());
+
+    let (q, r) = a.div_rem_fast(&b);
+    assert_eq!((q.deg(), r.deg()), (q0.deg(), r0.deg()));
+    for (x, y) in q.coeffs.iter().zip(&q0.coeffs) {
+        assert!(close(*x, *y, 1e-8));
+    }
+    for (x, y) in r.coeffs.iter().zip(&r0.coeffs) {
+        assert!(close(*x, *y, 1e-8));
+    }
+
+    // 冪級数の逆数: (1 − x)^{-1} = 1 + x + x^2 + ...
+    let inv = Polynomial::new(vec![1.0, -1.0]).inverse_series(6).unwrap();
+    assert_eq!(inv.coeffs, vec![1.0; 6]);
+    assert!(Polynomial::new(vec![0.0, 1.0]).inverse_series(3).is_err());
+
+    // FFT 乗算は畳み込みと一致
+    let x = random_f64(90, 11, 1.0);
+    let y = random_f64(70, 13, 1.0);
+    let fast = Polynomial::new(x.clone()).mul_fast(&Polynomial::new(y.clone()));
+    for (u, v) in fast.coeffs.iter().zip(convolve_naive_f64(&x, &y)) {
+        assert!(close(*u, v, 1e-10));
+    }
+}
+
+#[test]
+fn subproduct_tree_evaluates_and_interpolates() {
+    // Chebyshev 点での多点評価と補間（f64 では点数を控えめにする）
+    let n = 24;
+    let xs: Vec<f64> = (0..n)
+        .map(|k| ((2 * k + 1) as f64 * std::f64::consts::PI / (2 * n) as f64).cos())
+        .collect();
+    let p = Polynomial::new(random_f64(n, 17, 1.0));
+    let tree = SubproductTree::new(&xs).unwrap();
+    assert_eq!(tree.root().deg(), n as isize);
+    let ys = tree.evaluate(&p);
+    for (x, y) in xs.iter().zip(&ys) {
+        assert!(close(*y, p.eval(*x), 1e-9));
+    }
+    assert_eq!(p.eval_many(&xs), ys);
+
+    // 単項式基底では m'(x_i) の丸め誤差が増幅されるので、f64 の補間は節点での値を緩めに比べる。
+    // 係数の厳密な一致は finite-field の GFp で確かめる
+    let back = Polynomial::interpolate_fast(&xs, &ys).unwrap();
+    for (x, y) in xs.iter().zip(&ys) {
+        assert!(close(back.eval(*x), *y, 1e-5));
+    }
+    // 長さの不一致と重複した節点
+    assert!(tree.interpolate(&ys[1..]).is_err());
+    assert!(Polynomial::interpolate_fast(&[1.0, 2.0, 1.0], &[0.0, 0.0, 0.0]).is_err());
+    assert!(SubproductTree::<f64>::new(&[]).is_err());
+}
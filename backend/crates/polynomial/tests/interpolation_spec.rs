@@ -0,0 +1,201 @@
+use poly::polynomial::fit::{chebyshev_points, ChebyshevFit};
+use poly::polynomial::interpolation::{divided_differences, Barycentric};
+use poly::polynomial::spline::{CubicSpline, SplineBoundary};
+use poly::Polynomial;
+
+fn close(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol * (1.0 + b.abs())
+}
+
+#[test]
+fn lagrange_newton_and_barycentric_agree() {
+    // p(x) = 2x^3 - x + 5 を 4 点から復元する
+    let p = Polynomial::new(vec![5.0, -1.0, 0.0, 2.0]);
+    let xs = [-1.0, 0.5, 2.0, 3.0];
+    let ys: Vec<f64> = xs.iter().map(|&x| p.eval(x)).collect();
+    let lag = Polynomial::lagrange(&xs, &ys).unwrap();
+    let newton = Polynomial::newton_interpolation(&xs, &ys).unwrap();
+    for (c, e) in lag.coeffs.iter().zip(&p.coeffs) {
+        assert!(close(*c, *e, 1e-12), "{:?}", lag.coeffs);
+    }
+    for (c, e) in newton.coeffs.iter().zip(&p.coeffs) {
+        assert!(close(*c, *e, 1e-12), "{:?}", newton.coeffs);
+    }
+    // 差分商の最後は最高次係数
+    let dd = divided_differences(&xs, &ys).unwrap();
+    assert!(close(dd[3], 2.0, 1e-12));
+
+    let bary = Barycentric::new(&xs, &ys).unwrap();
+    for x in [-2.0, 0.0, 0.5, 1.7, 4.0] {
+        assert!(close(bary.eval(x), p.eval(x), 1e-12));
+    }
+    assert_eq!(bary.eval(2.0), ys[2]);
+
+    // 点が 1 個なら定数、値がすべて 0 なら零多項式
+    assert_eq!(
+        Polynomial::lagrange(&[3.0], &[7.0]).unwrap().coeffs,
+        vec![7.0]
+    );
+    assert!(Polynomial::newton_interpolation(&xs, &[0.0; 4])
+        .unwrap()
+        .is_zero());
+
+    assert!(Polynomial::lagrange(&[1.0, 2.0, 1.0], &[0.0, 1.0, 2.0]).is_err());
+    assert!(Polynomial::newton_interpolation(&[1.0, 2.0], &[0.0]).is_err());
+    assert!(Barycentric::<f64>::new(&[], &[]).is_err());
+}
+
+#[test]
+fn barycentric_on_chebyshev_points_avoids_runge() {
+    // Runge 関数 1 / (1 + 25 x^2) は等間隔点では発散するが Chebyshev 点では収束する
+    let f = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+    let n = 41;
+    let cheb = chebyshev_points(n, -1.0, 1.0);
+    assert!(cheb.windows(2).all(|w| w[0] < w[1]));
+    let ys: Vec<f64> = cheb.iter().map(|&x| f(x)).collect();
+    let bary = Barycentric::new(&cheb, &ys).unwrap();
+    let equi: Vec<f64> = (0..n)
+        .map(|i| -1.0 + 2.0 * i as f64 / (n - 1) as f64)
+        .collect();
+    let ys_equi: Vec<f64> = equi.iter().map(|&x| f(x)).collect();
+    let bary_equi = Barycentric::new(&equi, &ys_equi).unwrap();
+    let grid = (0..=200).map(|i| -1.0 + i as f64 / 100.0);
+    let err_cheb = grid
+        .clone()
+        .map(|x| (bary.eval(x) - f(x)).abs())
+        .fold(0.0, f64::max);
+    let err_equi = grid
+        .map(|x| (bary_equi.eval(x) - f(x)).abs())
+        .fold(0.0, f64::max);
+    assert!(err_cheb < 1e-3, "{err_cheb}");
+    assert!(err_equi > 1.0, "{err_equi}");
+}
+
+#[test]
+fn cubic_splines_satisfy_boundary_conditions() {
+    let xs = [0.0, 0.7, 1.5, 2.0, 3.2, 4.0];
+    let ys: Vec<f64> = xs.iter().map(|&x: &f64| x.sin()).collect();
+
+    let natural = CubicSpline::new(&xs, &ys, SplineBoundary::Natural).unwrap();
+    let clamped = CubicSpline::new(
+        &xs,
+        &ys,
+        SplineBoundary::Clamped {
+            start: 1.0,
+            end: 4f64.cos(),
+        },
+    )
+    .unwrap();
+    let nak = CubicSpline::new(&xs, &ys, SplineBoundary::NotAKnot).unwrap();
+    for s in [&natural, &clamped, &nak] {
+        for (x, y) in xs.iter().zip(&ys) {
+            assert!(close(s.eval(*x), *y, 1e-14));
+        }
+        // 内部節点で 1 階・2 階微分が連続
+        for &x in &xs[1..xs.len() - 1] {
+            for order in 1..=2 {
+                let left = s.derivative(x - 1e-9, order);
+                let right = s.derivative(x + 1e-9, order);
+                assert!((left - right).abs() < 1e-6, "order {order} at {x}");
+            }
+        }
+    }
+    assert!(natural.derivative(0.0, 2).abs() < 1e-12);
+    assert!(natural.derivative(4.0, 2).abs() < 1e-12);
+    assert!(close(clamped.derivative(0.0, 1), 1.0, 1e-12));
+    assert!(close(clamped.derivative(4.0, 1), 4f64.cos(), 1e-12));
+    // not-a-knot: 最初と最後の内部節点で 3 階微分も連続
+    for x in [0.7, 3.2] {
+        assert!((nak.derivative(x - 1e-9, 3) - nak.derivative(x + 1e-9, 3)).abs() < 1e-6);
+    }
+    // clamped スプラインは sin を 1e-2 程度で近似し、積分も近い
+    assert!((clamped.eval(2.5) - 2.5f64.sin()).abs() < 1e-2);
+    assert!((clamped.integrate(0.0, 4.0) - (1.0 - 4f64.cos())).abs() < 1e-2);
+    assert!(close(
+        clamped.integrate(1.0, 3.0),
+        clamped.integrate(1.0, 2.0) + clamped.integrate(2.0, 3.0),
+        1e-12
+    ));
+}
+
+#[test]
+fn splines_reproduce_low_degree_polynomials() {
+    // 3 次式は clamped（端の微分が正しい）と not-a-knot で厳密に再現される
+    let p = |x: f64| x * x * x - 2.0 * x + 1.0;
+    let dp = |x: f64| 3.0 * x * x - 2.0;
+    let xs = [-1.0, -0.2, 0.5, 1.0, 2.5];
+    let ys: Vec<f64> = xs.iter().map(|&x| p(x)).collect();
+    let clamped = CubicSpline::new(
+        &xs,
+        &ys,
+        SplineBoundary::Clamped {
+            start: dp(-1.0),
+            end: dp(2.5),
+        },
+    )
+    .unwrap();
+    let nak = CubicSpline::new(&xs, &ys, SplineBoundary::NotAKnot).unwrap();
+    for x in [-1.5, -0.7, 0.1, 0.8, 2.0, 3.0] {
+        assert!(close(clamped.eval(x), p(x), 1e-12));
+        assert!(close(nak.eval(x), p(x), 1e-12));
+    }
+    // 少ない点: 2 点は直線、not-a-knot の 3 点は放物線
+    let line = CubicSpline::new(&[0.0, 2.0], &[1.0, 5.0], SplineBoundary::Natural).unwrap();
+    assert!(close(line.eval(1.5), 4.0, 1e-15));
+    let parabola =
+        CubicSpline::new(&[0.0, 1.0, 3.0], &[0.0, 1.0, 9.0], SplineBoundary::NotAKnot).unwrap();
+    assert!(close(parabola.eval(2.0), 4.0, 1e-14));
+    assert_eq!(parabola.pieces().len(), 2);
+
+    assert!(CubicSpline::new(&[0.0, 1.0, 1.0], &[0.0; 3], SplineBoundary::Natural).is_err());
+    assert!(CubicSpline::new(&[0.0], &[0.0], SplineBoundary::Natural).is_err());
+    assert!(CubicSpline::new(
+        &[0.0, 1.0],
+        &[0.0, 1.0],
+        SplineBoundary::Clamped {
+            start: f64::NAN,
+            end: 0.0
+        }
+    )
+    .is_err());
+}
+
+#[test]
+fn chebyshev_least_squares_is_well_conditioned() {
+    // 区間 [100, 101] 上の標本: 単項式基底では条件数が巨大になる
+    let xs: Vec<f64> = (0..200).map(|i| 100.0 + i as f64 / 199.0).collect();
+    let f = |x: f64| (3.0 * (x - 100.0)).sin();
+    let ys: Vec<f64> = xs.iter().map(|&x| f(x)).collect();
+    let fit = ChebyshevFit::new(&xs, &ys, 15).unwrap();
+    assert_eq!(fit.rank, 16);
+    assert_eq!(fit.degree(), 15);
+    assert_eq!(fit.domain, (100.0, 101.0));
+    for &x in xs.iter().step_by(7) {
+        assert!((fit.eval(x) - f(x)).abs() < 1e-12);
+    }
+    assert!(fit.residuals < 1e-22);
+
+    // 雑音のない 2 次式は低次の当てはめで厳密に再現される
+    let q = Polynomial::new(vec![1.0, -3.0, 0.5]);
+    let xs: Vec<f64> = (0..10).map(|i| i as f64 * 0.3 - 1.0).collect();
+    let ys: Vec<f64> = xs.iter().map(|&x| q.eval(x)).collect();
+    let p = Polynomial::fit(&xs, &ys, 2).unwrap();
+    for (c, e) in p.coeffs.iter().zip(&q.coeffs) {
+        assert!(close(*c, *e, 1e-12), "{:?}", p.coeffs);
+    }
+    // 直線当てはめ: y = 2x + 1 に ±0.1 を交互に足しても傾きと切片はほぼ変わらない
+    let ys: Vec<f64> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| 2.0 * x + 1.0 + if i % 2 == 0 { 0.1 } else { -0.1 })
+        .collect();
+    let line = ChebyshevFit::new(&xs, &ys, 1).unwrap();
+    let poly = line.to_polynomial();
+    assert!((poly.get(1) - 2.0).abs() < 0.05);
+    assert!((poly.get(0) - 1.0).abs() < 0.05);
+    // 最小二乗解の残差は真の直線の残差 10 × 0.1² 以下
+    assert!(line.residuals > 0.0 && line.residuals <= 0.1 + 1e-12);
+
+    assert!(ChebyshevFit::new(&[1.0, 2.0], &[1.0, 2.0], 2).is_err());
+    assert!(ChebyshevFit::new(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0], 1).is_err());
+}
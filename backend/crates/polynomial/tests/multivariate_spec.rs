@@ -0,0 +1,212 @@
+use linalg::BigRational;
+use poly::multivariate::groebner::is_groebner_basis;
+use poly::multivariate::{GroebnerAlgorithm, GroebnerBasis, Monomial};
+use poly::{MonomialOrder, MultiPolynomial, Polynomial};
+
+type Q = BigRational;
+
+fn q(n: i64, d: i64) -> Q {
+    Q::new(n.into(), d.into())
+}
+
+fn m(exps: &[u32]) -> Monomial {
+    Monomial::new(exps.to_vec())
+}
+
+#[test]
+fn monomial_orders_follow_textbook_examples() {
+    use MonomialOrder::*;
+    let gt = |o: MonomialOrder, a: &[u32], b: &[u32]| o.cmp(&m(a), &m(b)).is_gt();
+    assert!(gt(Lex, &[1, 2, 0], &[0, 3, 4]));
+    assert!(gt(Lex, &[3, 2, 4], &[3, 2, 1]));
+    assert!(gt(GrLex, &[1, 2, 3], &[3, 2, 0]));
+    assert!(gt(GrLex, &[1, 2, 4], &[1, 1, 5]));
+    assert!(gt(GrevLex, &[4, 7, 1], &[4, 2, 3]));
+    assert!(gt(GrevLex, &[1, 5, 2], &[4, 1, 3]));
+    // 全次数が同じ xz² と y²z で grlex と grevlex の大小が入れ替わる
+    assert!(gt(GrLex, &[1, 0, 2], &[0, 2, 1]));
+    assert!(gt(GrevLex, &[0, 2, 1], &[1, 0, 2]));
+
+    // f = 4xy²z + 4z² − 5x³ + 7x²z² の項の並び
+    let terms = vec![
+        (vec![1, 2, 1], 4.0),
+        (vec![0, 0, 2], 4.0),
+        (vec![3, 0, 0], -5.0),
+        (vec![2, 0, 2], 7.0),
+    ];
+    let show = |o| MultiPolynomial::new(3, o, terms.clone()).to_string();
+    assert_eq!(show(Lex), "-5x^3 + 7x^2*z^2 + 4x*y^2*z + 4z^2");
+    assert_eq!(show(GrLex), "7x^2*z^2 + 4x*y^2*z - 5x^3 + 4z^2");
+    assert_eq!(show(GrevLex), "4x*y^2*z + 7x^2*z^2 - 5x^3 + 4z^2");
+    let f = MultiPolynomial::new(3, Lex, terms.clone());
+    assert_eq!(f.with_order(GrevLex).to_string(), show(GrevLex));
+    assert_eq!(f.leading_monomial(), Some(&m(&[3, 0, 0])));
+    assert_eq!(f.total_degree(), 4);
+}
+
+#[test]
+fn arithmetic_evaluation_and_substitution() {
+    let v = MultiPolynomial::<f64>::vars(2, MonomialOrder::GrLex);
+    let (x, y) = (&v[0], &v[1]);
+    let sum = x + y;
+    let square = sum.pow(2);
+    assert_eq!(
+        square,
+        MultiPolynomial::new(
+            2,
+            MonomialOrder::GrLex,
+            vec![(vec![2, 0], 1.0), (vec![1, 1], 2.0), (vec![0, 2], 1.0)]
+        )
+    );
+    assert_eq!((&sum * &(x - y)).to_string(), "x^2 - y^2");
+    assert!((x - x).is_zero());
+    assert_eq!((-&square * 2.0).leading_coeff(), -2.0);
+    assert_eq!(
+        square
+            .display_with(&["a", "b"])
+            .unicode_superscript(true)
+            .to_string(),
+        "a² + 2a*b + b²"
+    );
+
+    // 評価・代入・1 変数への変換
+    assert_eq!(square.eval(&[2.0, 3.0]), 25.0);
+    let at = square.substitute(0, 1.0);
+    assert_eq!(at.variables(), vec![1]);
+    assert_eq!(
+        at.to_univariate(1).unwrap(),
+        Polynomial::new(vec![1.0, 2.0, 1.0])
+    );
+    assert!(square.to_univariate(1).is_none());
+    let p = Polynomial::new(vec![-1.0, 0.0, 3.0]);
+    let embedded = MultiPolynomial::from_univariate(&p, 2, MonomialOrder::GrLex, 1);
+    assert_eq!(embedded.to_string(), "3y^2 - 1");
+    assert_eq!(embedded.coeff(&m(&[0, 2])), 3.0);
+
+    // 変数の個数が違う多項式は多い方に揃える
+    let z = MultiPolynomial::<f64>::var(3, MonomialOrder::GrLex, 2);
+    assert_eq!((x + &z).nvars(), 3);
+}
+
+#[test]
+fn multivariate_division_depends_on_divisor_order() {
+    // Cox–Little–O'Shea: f = x²y + xy² + y² を (xy − 1, y² − 1) で lex の割り算
+    let lex = MonomialOrder::Lex;
+    let f = MultiPolynomial::new(
+        2,
+        lex,
+        vec![(vec![2, 1], 1.0), (vec![1, 2], 1.0), (vec![0, 2], 1.0)],
+    );
+    let f1 = MultiPolynomial::new(2, lex, vec![(vec![1, 1], 1.0), (vec![0, 0], -1.0)]);
+    let f2 = MultiPolynomial::new(2, lex, vec![(vec![0, 2], 1.0), (vec![0, 0], -1.0)]);
+
+    let (qs, r) = f.div_rem(&[f1.clone(), f2.clone()]);
+    assert_eq!(qs[0].to_string(), "x + y");
+    assert_eq!(qs[1].to_string(), "1");
+    assert_eq!(r.to_string(), "x + y + 1");
+    assert_eq!(&(&(&qs[0] * &f1) + &(&qs[1] * &f2)) + &r, f);
+
+    // 順番を入れ替えると余りが変わる
+    let (qs, r) = f.div_rem(&[f2.clone(), f1.clone()]);
+    assert_eq!(qs[0].to_string(), "x + 1");
+    assert_eq!(qs[1].to_string(), "x");
+    assert_eq!(r.to_string(), "2x + 1");
+    assert_eq!(&(&(&qs[0] * &f2) + &(&qs[1] * &f1)) + &r, f);
+}
+
+#[test]
+fn groebner_basis_with_buchberger_and_f4() {
+    // f1 = x³ − 2xy, f2 = x²y − 2y² + x（grlex）の簡約 Gröbner 基底は {x², xy, y² − x/2}
+    let grlex = MonomialOrder::GrLex;
+    let f1 = MultiPolynomial::new(
+        2,
+        grlex,
+        vec![(vec![3, 0], q(1, 1)), (vec![1, 1], q(-2, 1))],
+    );
+    let f2 = MultiPolynomial::new(
+        2,
+        grlex,
+        vec![
+            (vec![2, 1], q(1, 1)),
+            (vec![0, 2], q(-2, 1)),
+            (vec![1, 0], q(1, 1)),
+        ],
+    );
+    assert!(!is_groebner_basis(&[f1.clone(), f2.clone()]));
+    let gb = GroebnerBasis::new(&[f1.clone(), f2.clone()]);
+    let expected = vec![
+        MultiPolynomial::new(2, grlex, vec![(vec![2, 0], q(1, 1))]),
+        MultiPolynomial::new(2, grlex, vec![(vec![1, 1], q(1, 1))]),
+        MultiPolynomial::new(
+            2,
+            grlex,
+            vec![(vec![0, 2], q(1, 1)), (vec![1, 0], q(-1, 2))],
+        ),
+    ];
+    assert_eq!(gb.polynomials(), expected.as_slice());
+    assert!(is_groebner_basis(gb.polynomials()));
+    let f4 = GroebnerBasis::with_algorithm(&[f1.clone(), f2.clone()], GroebnerAlgorithm::F4);
+    assert_eq!(f4, gb);
+
+    // イデアル所属判定: 生成元の組み合わせは属し、x は属さない
+    let combo = &(&f1 * &f2) - &(&f2 * q(3, 1));
+    assert!(gb.contains(&combo));
+    assert!(gb.contains(&f1) && gb.contains(&f2));
+    let x = MultiPolynomial::var(2, grlex, 0);
+    assert!(!gb.contains(&x));
+    assert_eq!(gb.normal_form(&(&x + &combo)), x);
+}
+
+#[test]
+fn lex_basis_triangularizes_polynomial_systems() {
+    // x² + y² + z² = 1, x² + z² = y, x = z
+    let lex = MonomialOrder::Lex;
+    let one = || q(1, 1);
+    let gens = vec![
+        MultiPolynomial::new(
+            3,
+            lex,
+            vec![
+                (vec![2, 0, 0], one()),
+                (vec![0, 2, 0], one()),
+                (vec![0, 0, 2], one()),
+                (vec![0, 0, 0], -one()),
+            ],
+        ),
+        MultiPolynomial::new(
+            3,
+            lex,
+            vec![
+                (vec![2, 0, 0], one()),
+                (vec![0, 0, 2], one()),
+                (vec![0, 1, 0], -one()),
+            ],
+        ),
+        MultiPolynomial::new(
+            3,
+            lex,
+            vec![(vec![1, 0, 0], one()), (vec![0, 0, 1], -one())],
+        ),
+    ];
+    for algorithm in [GroebnerAlgorithm::Buchberger, GroebnerAlgorithm::F4] {
+        let gb = GroebnerBasis::with_algorithm(&gens, algorithm);
+        let shown: Vec<String> = gb.polynomials().iter().map(|g| g.to_string()).collect();
+        assert_eq!(shown, vec!["x - z", "y - 2z^2", "z^4 + 1/2z^2 - 1/4"]);
+        // 第 2 消去イデアルは z だけの多項式
+        let last = gb.elimination(2);
+        assert_eq!(last.len(), 1);
+        assert_eq!(
+            last[0].to_univariate(2).unwrap(),
+            Polynomial::new(vec![q(-1, 4), q(0, 1), q(1, 2), q(0, 1), one()])
+        );
+        assert!(!gb.is_unit_ideal());
+    }
+
+    // xy = 1 と x = 0 は共通零点を持たない
+    let inconsistent = vec![
+        MultiPolynomial::new(2, lex, vec![(vec![1, 1], one()), (vec![0, 0], -one())]),
+        MultiPolynomial::var(2, lex, 0),
+    ];
+    assert!(GroebnerBasis::new(&inconsistent).is_unit_ideal());
+    assert!(GroebnerBasis::<Q>::new(&[]).polynomials().is_empty());
+}
@@ -0,0 +1,170 @@
+use linalg::{BigInt, BigRational};
+use num_complex::Complex;
+use poly::Polynomial;
+
+fn q(n: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(n))
+}
+
+fn qpoly(coeffs: &[i64]) -> Polynomial<BigRational> {
+    Polynomial::new(coeffs.iter().map(|&c| q(c)).collect())
+}
+
+#[test]
+fn sturm_and_descartes_count_real_roots() {
+    // (x − 1)(x − 2)(x − 3)(x² + 1)
+    let p = &qpoly(&[-6, 11, -6, 1]) * &qpoly(&[1, 0, 1]);
+    assert_eq!(p.count_real_roots(), 3);
+    assert_eq!(p.count_roots_between(&q(0), &q(2)), 2);
+    assert_eq!(p.count_roots_between(&q(1), &q(3)), 2);
+    assert_eq!(p.count_roots_between(&q(3), &q(0)), 0);
+    // 正の根 3 個、符号変化は 3 以上で差は偶数
+    let v = p.descartes_sign_variations();
+    assert!(v >= 3 && (v - 3).is_multiple_of(2));
+    // 重根は 1 つと数える
+    let double = &qpoly(&[-1, 1]) * &qpoly(&[-1, 1]);
+    assert_eq!(double.count_real_roots(), 1);
+    assert_eq!(qpoly(&[1, 0, 1]).count_real_roots(), 0);
+}
+
+#[test]
+fn isolation_gives_disjoint_intervals_with_multiplicities() {
+    // x (x − 1)² (x + 2)³ (2x − 1)(x² − 2)
+    let mut p = qpoly(&[0, 1]);
+    for _ in 0..2 {
+        p = &p * &qpoly(&[-1, 1]);
+    }
+    for _ in 0..3 {
+        p = &p * &qpoly(&[2, 1]);
+    }
+    p = &(&p * &qpoly(&[-1, 2])) * &qpoly(&[-2, 0, 1]);
+    let roots = p.isolate_real_roots().unwrap();
+    let expected = [
+        (-2.0, 3),
+        (-std::f64::consts::SQRT_2, 1),
+        (0.0, 1),
+        (0.5, 1),
+        (1.0, 2),
+        (std::f64::consts::SQRT_2, 1),
+    ];
+    assert_eq!(roots.len(), expected.len());
+    for (iv, (x, m)) in roots.iter().zip(expected) {
+        let (lo, hi) = iv.to_f64_bounds();
+        assert!(lo <= x && x <= hi, "{x} not in [{lo}, {hi}]");
+        assert_eq!(iv.multiplicity, m);
+    }
+    for w in roots.windows(2) {
+        assert!(w[0].hi <= w[1].lo);
+    }
+    // 0 は区間の端ではなく厳密な根として得られる
+    assert!(roots[2].is_exact() && roots[2].lo == q(0));
+    assert!(Polynomial::<BigRational>::zero()
+        .isolate_real_roots()
+        .is_err());
+    assert!(qpoly(&[5]).isolate_real_roots().unwrap().is_empty());
+}
+
+#[test]
+fn refinement_reaches_requested_precision() {
+    let p = qpoly(&[-2, 0, 1]);
+    let eps = BigRational::new(BigInt::from(1), BigInt::from(1) << 60);
+    let roots = p.real_roots(&eps).unwrap();
+    assert_eq!(roots.len(), 2);
+    for iv in &roots {
+        assert!(iv.width() <= eps);
+        // 区間の端で符号が変わる
+        assert!(p.eval(iv.lo.clone()) * p.eval(iv.hi.clone()) < q(0));
+    }
+    let (lo, hi) = roots[1].to_f64_bounds();
+    assert!(lo <= std::f64::consts::SQRT_2 && std::f64::consts::SQRT_2 <= hi);
+
+    // 有理数の根は 1 点に縮む: 3x − 1
+    let third = qpoly(&[-1, 3]);
+    let iv = third.isolate_real_roots().unwrap().remove(0);
+    let refined = third.refine_root(&iv, &eps);
+    assert!(refined.width() <= eps);
+    assert!(refined.lo <= BigRational::new(1.into(), 3.into()));
+    assert!(refined.hi >= BigRational::new(1.into(), 3.into()));
+}
+
+#[test]
+fn chebyshev_roots_are_all_separated() {
+    // T_20 の根は (−1, 1) に 20 個あり、端の方では間隔が 1e−2 程度まで詰まる
+    let mut t0 = qpoly(&[1]);
+    let mut t1 = qpoly(&[0, 1]);
+    for _ in 1..20 {
+        let t2 = &(&qpoly(&[0, 2]) * &t1) - &t0;
+        t0 = t1;
+        t1 = t2;
+    }
+    assert_eq!(t1.count_real_roots(), 20);
+    let roots = t1.isolate_real_roots().unwrap();
+    assert_eq!(roots.len(), 20);
+    for (k, iv) in roots.iter().enumerate() {
+        let x = ((2 * (19 - k) + 1) as f64 * std::f64::consts::PI / 40.0).cos();
+        let (lo, hi) = iv.to_f64_bounds();
+        assert!(lo <= x + 1e-15 && x - 1e-15 <= hi);
+        assert_eq!(t1.count_roots_between(&iv.lo, &iv.hi), 1);
+    }
+}
+
+#[test]
+fn smith_disks_enclose_the_roots() {
+    // (x − 1)(x − 2)(x² + 2x + 5): 根 1, 2, −1 ± 2j
+    let p = Polynomial::new(vec![10.0, -11.0, 1.0, -1.0, 1.0]);
+    let exact = [
+        Complex::new(1.0, 0.0),
+        Complex::new(2.0, 0.0),
+        Complex::new(-1.0, 2.0),
+        Complex::new(-1.0, -2.0),
+    ];
+    let disks = p.find_roots_certified().unwrap();
+    assert_eq!(disks.len(), 4);
+    for z in exact {
+        assert!(disks.iter().any(|d| d.contains(z) && d.radius < 1e-10));
+    }
+
+    // 粗い近似でも円は根を含み、各連結成分には円の個数と同じだけ根がある
+    let approx = [
+        Complex::new(1.3, 0.1),
+        Complex::new(1.6, -0.1),
+        Complex::new(-1.0, 2.01),
+        Complex::new(-1.05, -2.0),
+    ];
+    let disks = p.root_inclusion_disks(&approx).unwrap();
+    assert_eq!(disks[0].cluster, disks[1].cluster);
+    assert_ne!(disks[0].cluster, disks[2].cluster);
+    assert_ne!(disks[2].cluster, disks[3].cluster);
+    for d in &disks {
+        let members: Vec<_> = disks.iter().filter(|e| e.cluster == d.cluster).collect();
+        let inside = exact
+            .iter()
+            .filter(|&&z| members.iter().any(|e| e.contains(z)))
+            .count();
+        assert_eq!(inside, members.len());
+    }
+
+    assert!(p.root_inclusion_disks(&approx[..3]).is_err());
+}
+
+#[test]
+fn hurwitz_and_schur_tests_are_exact() {
+    // (s + 1)(s² + 1) は虚軸上に根を持つ
+    assert!(!Polynomial::new(vec![1.0, 1.0, 1.0, 1.0]).is_hurwitz_stable());
+    assert!(Polynomial::new(vec![1.0, 1e-12, 1.0]).is_hurwitz_stable());
+    assert!(!Polynomial::new(vec![1.0, -1e-12, 1.0]).is_hurwitz_stable());
+    // (s + 1)(s + 2)(s + 3)、最高次係数が負でも同じ
+    assert!(Polynomial::new(vec![6.0, 11.0, 6.0, 1.0]).is_hurwitz_stable());
+    assert!(Polynomial::new(vec![-6.0, -11.0, -6.0, -1.0]).is_hurwitz_stable());
+    // 係数は正でも s³ + s² + 2s + 8 は右半平面に根を持つ
+    assert!(!Polynomial::new(vec![8.0, 2.0, 1.0, 1.0]).is_hurwitz_stable());
+    assert!(Polynomial::new(vec![3.0]).is_hurwitz_stable());
+    assert!(!Polynomial::new(vec![f64::NAN, 1.0]).is_hurwitz_stable());
+
+    assert!(!Polynomial::new(vec![1.0, 1.0]).is_schur_stable());
+    assert!(!Polynomial::new(vec![-1.0, 1.0]).is_schur_stable());
+    assert!(Polynomial::new(vec![-0.125, -0.25, 1.0]).is_schur_stable());
+    assert!(!Polynomial::new(vec![-2.0, 0.0, 0.0, 1.0]).is_schur_stable());
+    // z³ − 0.5: 根の絶対値は 0.79
+    assert!(Polynomial::new(vec![-0.5, 0.0, 0.0, 1.0]).is_schur_stable());
+}
@@ -1,5 +1,6 @@
 use poly::polynomial::special::{
-    chebyshev_first_kind, chebyshev_second_kind, hermite_physicists, laguerre, legendre,
+    chebyshev_first_kind, chebyshev_second_kind, hermite_normalized_pair, hermite_physicists,
+    laguerre, laguerre_pair, legendre, legendre_pair,
 };
 
 #[test]
@@ -49,3 +50,26 @@ fn laguerre_low_degree() {
     // 実装の漸化式から得られる形を直接比較（[1, -2, 0.5] は 1 - 2x + 0.5x^2 = (x^2 - 4x + 2)/2）
     assert_eq!(l2.coeffs, vec![1.0, -2.0, 0.5]);
 }
+
+#[test]
+fn recurrence_pairs_match_expanded_polynomials() {
+    for n in 1..8 {
+        for &x in &[-0.9, -0.3, 0.0, 0.4, 1.7] {
+            let (p, p_prev) = legendre_pair(n, x);
+            assert!((p - legendre::<f64>(n).eval(x)).abs() < 1e-12);
+            assert!((p_prev - legendre::<f64>(n - 1).eval(x)).abs() < 1e-12);
+
+            let (l, l_prev) = laguerre_pair(n, x);
+            assert!((l - laguerre::<f64>(n).eval(x)).abs() < 1e-12);
+            assert!((l_prev - laguerre::<f64>(n - 1).eval(x)).abs() < 1e-12);
+
+            // h̃_n = H_n / √(2^n n! √π)
+            let norm = (2f64.powi(n as i32)
+                * (1..=n).map(|k| k as f64).product::<f64>()
+                * std::f64::consts::PI.sqrt())
+            .sqrt();
+            let (h, _) = hermite_normalized_pair(n, x);
+            assert!((h - hermite_physicists::<f64>(n).eval(x) / norm).abs() < 1e-12);
+        }
+    }
+}
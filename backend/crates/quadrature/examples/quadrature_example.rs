@@ -0,0 +1,116 @@
+use quadrature::{
+    gauss_kronrod, gauss_legendre, monte_carlo, romberg, tanh_sinh, GaussRule, QuadratureOptions,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use statistics::distribution::continuous::core::Distribution;
+use statistics::distribution::continuous::gamma::Gamma;
+use std::f64::consts::PI;
+
+fn main() {
+    let opts = QuadratureOptions::default();
+
+    // 目的: 滑らかな積分で各手法の評価回数を比べる
+    println!("[Smooth] ∫_0^1 4 / (1 + x^2) dx = π");
+    let f = |x: f64| 4.0 / (1.0 + x * x);
+    println!(
+        "{:<14} {:>20} {:>12} {:>8}",
+        "method", "value", "|error|", "evals"
+    );
+    for (name, report) in [
+        ("Gauss–Kronrod", gauss_kronrod(f, 0.0, 1.0, &opts).unwrap()),
+        ("Romberg", romberg(f, 0.0, 1.0, &opts).unwrap()),
+        ("tanh-sinh", tanh_sinh(f, 0.0, 1.0, &opts).unwrap()),
+    ] {
+        println!(
+            "{:<14} {:>20.15} {:>12.2e} {:>8}",
+            name,
+            report.value,
+            (report.value - PI).abs(),
+            report.evaluations
+        );
+    }
+    let g10 = gauss_legendre(f, 0.0, 1.0, 10).unwrap();
+    println!(
+        "{:<14} {:>20.15} {:>12.2e} {:>8}",
+        "Gauss 10 点",
+        g10,
+        (g10 - PI).abs(),
+        10
+    );
+
+    // 端点特異性: Romberg は端点で評価できないが tanh-sinh は端点を避ける
+    println!("\n[Singular] ∫_0^1 ln(x) / √x dx = -4");
+    let singular = |x: f64| x.ln() / x.sqrt();
+    match romberg(singular, 0.0, 1.0, &opts) {
+        Ok(r) => println!("  Romberg       : {:.12}", r.value),
+        Err(e) => println!("  Romberg       : {e}"),
+    }
+    let gk = gauss_kronrod(singular, 0.0, 1.0, &opts).unwrap();
+    println!(
+        "  Gauss–Kronrod : {:.12} ({} evals)",
+        gk.value, gk.evaluations
+    );
+    let ts = tanh_sinh(singular, 0.0, 1.0, &opts).unwrap();
+    println!(
+        "  tanh-sinh     : {:.12} ({} evals)",
+        ts.value, ts.evaluations
+    );
+
+    // 重み付き Gauss 則: e^{-x^2}, e^{-x} を重み関数に含めて積分する
+    println!("\n[Weighted] ∫ e^(-x^2) cos x dx = √π e^(-1/4), ∫_0^∞ e^(-x) sin x dx = 1/2");
+    for n in [4, 8, 16] {
+        let her = GaussRule::hermite(n).unwrap().integrate(f64::cos);
+        let lag = GaussRule::laguerre(n).unwrap().integrate(f64::sin);
+        println!(
+            "  n = {n:>2}: Hermite err = {:.2e}, Laguerre err = {:.2e}",
+            (her - PI.sqrt() * (-0.25f64).exp()).abs(),
+            (lag - 0.5).abs()
+        );
+    }
+
+    // 統計クレートの密度を積分して分布関数と比べる
+    println!("\n[Density] Gamma(shape = 2.5, rate = 1.5)");
+    let gamma = Gamma::new(2.5, 1.5).unwrap();
+    for x in [0.5, 1.0, 2.0, 4.0] {
+        let r = tanh_sinh(|t| gamma.pdf(t), 0.0, x, &opts).unwrap();
+        println!(
+            "  ∫_0^{x} pdf = {:.12}  cdf = {:.12}",
+            r.value,
+            gamma.cdf(x)
+        );
+    }
+    let mean = gauss_kronrod(|t| t * gamma.pdf(t), 0.0, f64::INFINITY, &opts).unwrap();
+    println!("  E[X] = {:.12} (厳密値 {:.12})", mean.value, gamma.mean());
+
+    // 多次元: 単位球の体積を Monte Carlo で推定する
+    println!("\n[Monte Carlo] d 次元単位球の体積");
+    let mut rng = StdRng::seed_from_u64(7);
+    for d in [2usize, 3, 5] {
+        let lower = vec![-1.0; d];
+        let upper = vec![1.0; d];
+        let inside = |x: &[f64]| {
+            if x.iter().map(|v| v * v).sum::<f64>() <= 1.0 {
+                1.0
+            } else {
+                0.0
+            }
+        };
+        let r = monte_carlo(inside, &lower, &upper, 100_000, &mut rng).unwrap();
+        let exact = PI.powf(d as f64 / 2.0) / special_gamma(d as f64 / 2.0 + 1.0);
+        println!(
+            "  d = {d}: {:.5} ± {:.5}（厳密値 {:.5}）",
+            r.value, r.error, exact
+        );
+    }
+}
+
+// Γ(d/2 + 1) は整数か半整数なので Γ(1) = 1, Γ(1/2) = √π から漸化式で求める
+fn special_gamma(x: f64) -> f64 {
+    if x == 1.0 {
+        1.0
+    } else if x == 0.5 {
+        PI.sqrt()
+    } else {
+        (x - 1.0) * special_gamma(x - 1.0)
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuadratureError {
+    InvalidParameter {
+        what: &'static str,
+        details: String,
+    },
+    /// 被積分関数が x で NaN または ±∞ を返した
+    NonFiniteValue {
+        x: f64,
+    },
+}
+
+impl fmt::Display for QuadratureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuadratureError::InvalidParameter { what, details } => {
+                write!(f, "Invalid parameter for {what}: {details}")
+            }
+            QuadratureError::NonFiniteValue { x } => {
+                write!(f, "Integrand is not finite at x = {x}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for QuadratureError {}
+
+pub type Result<T> = std::result::Result<T, QuadratureError>;
@@ -0,0 +1,241 @@
+//! Gauss 型求積（Legendre・Hermite・Laguerre）
+//!
+//! 節点は直交多項式 `poly::polynomial::special::{legendre, hermite_physicists, laguerre}` の零点。
+//! 単項式の係数から根を求めると次数 10 程度で桁落ちするため、同じ三項漸化式で値を直接評価する
+//! `special::{legendre_pair, hermite_normalized_pair, laguerre_pair}` で値と微分を求めながら、
+//! 漸近近似を初期値とする Newton 法で求める（Numerical Recipes 4.6 の gauleg/gauher/gaulag）。
+//! Hermite は n が大きいと初期値が外れるため、求めた零点で割った関数に Newton 法を使う。
+
+use std::f64::consts::PI;
+
+use poly::polynomial::special::{hermite_normalized_pair, laguerre_pair, legendre_pair};
+
+use crate::error::{QuadratureError, Result};
+use crate::report::check_finite_bounds;
+
+/// 1 つの規則が持てる節点数の上限
+pub const MAX_NODES: usize = 256;
+
+const NEWTON_MAXITER: usize = 100;
+
+/// 重み関数と積分区間の組
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GaussFamily {
+    /// ∫_{-1}^{1} f(x) dx
+    Legendre,
+    /// ∫_{-∞}^{∞} e^{-x²} f(x) dx
+    Hermite,
+    /// ∫_0^∞ e^{-x} f(x) dx
+    Laguerre,
+}
+
+/// n 点 Gauss 則。次数 2n - 1 以下の多項式 f に対して ∫ w(x) f(x) dx を厳密に与える。
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussRule {
+    pub family: GaussFamily,
+    /// 昇順の節点
+    pub nodes: Vec<f64>,
+    pub weights: Vec<f64>,
+}
+
+impl GaussRule {
+    /// P_n(x) の零点 x_i と重み 2 / ((1 - x_i²) P_n'(x_i)²)
+    pub fn legendre(n: usize) -> Result<Self> {
+        check_order(n)?;
+        let mut nodes = vec![0.0; n];
+        let mut weights = vec![0.0; n];
+        for i in 0..n.div_ceil(2) {
+            // 大きい方から i 番目の零点の近似 cos(π (i + 3/4) / (n + 1/2))
+            let guess = (PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+            let (z, dp) = newton(guess, (-1.0, 1.0), |z| {
+                let (p, p_prev) = legendre_pair(n, z);
+                (p, n as f64 * (z * p - p_prev) / (z * z - 1.0))
+            })?;
+            nodes[n - 1 - i] = z;
+            nodes[i] = -z;
+            let w = 2.0 / ((1.0 - z * z) * dp * dp);
+            weights[i] = w;
+            weights[n - 1 - i] = w;
+        }
+        Self::checked(GaussFamily::Legendre, nodes, weights)
+    }
+
+    /// H_n(x)（物理学者の規格化）の零点と重み 2^{n-1} n! √π / (n² H_{n-1}(x_i)²)。
+    /// 桁あふれを避けるため正規直交化した漸化式 h̃_k = x √(2/k) h̃_{k-1} - √((k-1)/k) h̃_{k-2} を使う。
+    pub fn hermite(n: usize) -> Result<Self> {
+        check_order(n)?;
+        let mut nodes = vec![0.0; n];
+        let mut weights = vec![0.0; n];
+        let nf = n as f64;
+        let hermite_weight = |z: f64| {
+            let dp = (2.0 * nf).sqrt() * hermite_normalized_pair(n, z).1;
+            2.0 / (dp * dp)
+        };
+        for i in 0..n / 2 {
+            // 最大の零点は漸近近似から求める。それ以降は求めた零点を割り算で取り除いた関数に
+            // Newton 法を使う（Maehly の方法）。零点がすべて実数なので、直前の零点のすぐ内側から
+            // 始めれば次の零点へ単調に収束し、零点を飛び越えない。
+            let found = &nodes[n - i..];
+            let (z, bounds) = match found.first() {
+                None => (
+                    (2.0 * nf + 1.0).sqrt() - 1.85575 * (2.0 * nf + 1.0).powf(-1.0 / 6.0),
+                    (0.0, f64::INFINITY),
+                ),
+                Some(&upper) => (upper * (1.0 - 1e-6), (0.0, upper)),
+            };
+            let (root, _) = newton(z, bounds, |z| {
+                let (p, p_prev) = hermite_normalized_pair(n, z);
+                let dp = (2.0 * nf).sqrt() * p_prev;
+                let shift: f64 = found.iter().map(|r| 1.0 / (z - r)).sum();
+                (p, dp - p * shift)
+            })?;
+            nodes[n - 1 - i] = root;
+            nodes[i] = -root;
+            let w = hermite_weight(root);
+            weights[i] = w;
+            weights[n - 1 - i] = w;
+        }
+        if n % 2 == 1 {
+            // 中央の零点は厳密に 0
+            weights[n / 2] = hermite_weight(0.0);
+        }
+        Self::checked(GaussFamily::Hermite, nodes, weights)
+    }
+
+    /// L_n(x) の零点と重み -1 / (n L_n'(x_i) L_{n-1}(x_i))
+    pub fn laguerre(n: usize) -> Result<Self> {
+        check_order(n)?;
+        let mut nodes: Vec<f64> = Vec::with_capacity(n);
+        let mut weights = Vec::with_capacity(n);
+        let nf = n as f64;
+        let mut z = 0.0;
+        for i in 0..n {
+            // 最小の零点から順に、零点の間隔の増え方を見込んで外挿する
+            z = match i {
+                0 => 3.0 / (1.0 + 2.4 * nf),
+                1 => z + 15.0 / (1.0 + 2.5 * nf),
+                _ => {
+                    let ai = (i - 1) as f64;
+                    z + (1.0 + 2.55 * ai) / (1.9 * ai) * (z - nodes[i - 2])
+                }
+            };
+            let (root, dp) = newton(z, (0.0, f64::INFINITY), |z| {
+                let (p, p_prev) = laguerre_pair(n, z);
+                (p, (nf * p - nf * p_prev) / z)
+            })?;
+            z = root;
+            let (_, p_prev) = laguerre_pair(n, z);
+            nodes.push(z);
+            weights.push(-1.0 / (dp * nf * p_prev));
+        }
+        Self::checked(GaussFamily::Laguerre, nodes, weights)
+    }
+
+    /// Newton 法が同じ零点に落ちていないか（節点が狭義単調増加か）を確かめる
+    fn checked(family: GaussFamily, nodes: Vec<f64>, weights: Vec<f64>) -> Result<Self> {
+        if nodes.windows(2).any(|w| w[0] >= w[1]) || weights.iter().any(|w| w.is_nan() || *w < 0.0)
+        {
+            return Err(QuadratureError::InvalidParameter {
+                what: "n",
+                details: format!(
+                    "Newton iteration failed to resolve {} distinct {family:?} nodes",
+                    nodes.len()
+                ),
+            });
+        }
+        Ok(Self {
+            family,
+            nodes,
+            weights,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// 重み付き積分 Σ w_i f(x_i)（重み関数と区間は `family` のもの）
+    pub fn integrate<F: FnMut(f64) -> f64>(&self, mut f: F) -> f64 {
+        self.nodes
+            .iter()
+            .zip(&self.weights)
+            .map(|(&x, &w)| w * f(x))
+            .sum()
+    }
+
+    /// Legendre 則を [a, b] へアフィン変換して ∫_a^b f(x) dx を求める
+    pub fn integrate_interval<F: FnMut(f64) -> f64>(
+        &self,
+        mut f: F,
+        a: f64,
+        b: f64,
+    ) -> Result<f64> {
+        if self.family != GaussFamily::Legendre {
+            return Err(QuadratureError::InvalidParameter {
+                what: "family",
+                details: format!(
+                    "interval mapping requires a Legendre rule, got {:?}",
+                    self.family
+                ),
+            });
+        }
+        check_finite_bounds(a, b)?;
+        let (mid, half) = ((a + b) / 2.0, (b - a) / 2.0);
+        Ok(half * self.integrate(|x| f(mid + half * x)))
+    }
+}
+
+/// n 点 Gauss–Legendre 則で ∫_a^b f(x) dx を求める
+pub fn gauss_legendre<F: FnMut(f64) -> f64>(f: F, a: f64, b: f64, n: usize) -> Result<f64> {
+    GaussRule::legendre(n)?.integrate_interval(f, a, b)
+}
+
+fn check_order(n: usize) -> Result<()> {
+    if n == 0 || n > MAX_NODES {
+        return Err(QuadratureError::InvalidParameter {
+            what: "n",
+            details: format!("number of nodes must be in 1..={MAX_NODES}, got {n}"),
+        });
+    }
+    Ok(())
+}
+
+/// 値と微分を返す関数 eval の零点を Newton 法で求め、(零点, 零点での微分) を返す。
+/// 漸化式の丸め誤差で歩幅が ε 程度まで下がらないことがあるため、十分小さい歩幅が
+/// 減らなくなった時点でも収束とみなす。歩幅が区間 (lo, hi) の外へ出るときは境界との中点へ進む。
+fn newton<E: Fn(f64) -> (f64, f64)>(
+    mut z: f64,
+    (lo, hi): (f64, f64),
+    eval: E,
+) -> Result<(f64, f64)> {
+    let mut prev_step = f64::INFINITY;
+    for _ in 0..NEWTON_MAXITER {
+        let (p, dp) = eval(z);
+        let mut next = z - p / dp;
+        if next.is_nan() {
+            break;
+        }
+        if next <= lo {
+            next = 0.5 * (z + lo);
+        } else if next >= hi {
+            next = 0.5 * (z + hi);
+        }
+        let step = z - next;
+        z = next;
+        let scale = z.abs().max(1.0);
+        let stalled = step.abs() >= prev_step && step.abs() <= f64::EPSILON.sqrt() * scale;
+        if step.abs() <= 4.0 * f64::EPSILON * scale || stalled {
+            let (_, dp) = eval(z);
+            return Ok((z, dp));
+        }
+        prev_step = step.abs();
+    }
+    Err(QuadratureError::InvalidParameter {
+        what: "n",
+        details: format!("Newton iteration for a node did not converge near {z}"),
+    })
+}
@@ -0,0 +1,15 @@
+pub mod error;
+pub mod gauss;
+pub mod kronrod;
+pub mod monte_carlo;
+pub mod report;
+pub mod romberg;
+pub mod tanh_sinh;
+
+pub use error::{QuadratureError, Result};
+pub use gauss::{gauss_legendre, GaussFamily, GaussRule};
+pub use kronrod::gauss_kronrod;
+pub use monte_carlo::monte_carlo;
+pub use report::{QuadratureOptions, QuadratureReport};
+pub use romberg::romberg;
+pub use tanh_sinh::tanh_sinh;
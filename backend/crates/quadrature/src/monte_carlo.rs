@@ -0,0 +1,70 @@
+//! 多次元の Monte Carlo 積分
+
+use rand::Rng;
+
+use crate::error::{QuadratureError, Result};
+use crate::report::QuadratureReport;
+
+/// 直方体 Π [lower_i, upper_i] 上の ∫ f(x) dx を一様乱数の標本平均で推定する。
+/// 誤差は標準誤差 V s / √N（V は体積、s は標本標準偏差）で、次元によらず O(N^{-1/2}) で減る。
+pub fn monte_carlo<F, R>(
+    mut f: F,
+    lower: &[f64],
+    upper: &[f64],
+    samples: usize,
+    rng: &mut R,
+) -> Result<QuadratureReport>
+where
+    F: FnMut(&[f64]) -> f64,
+    R: Rng + ?Sized,
+{
+    if lower.len() != upper.len() || lower.is_empty() {
+        return Err(QuadratureError::InvalidParameter {
+            what: "bounds",
+            details: format!(
+                "lower and upper must have the same non-zero length, got {} and {}",
+                lower.len(),
+                upper.len()
+            ),
+        });
+    }
+    if lower
+        .iter()
+        .zip(upper)
+        .any(|(l, u)| !(l.is_finite() && u.is_finite() && l <= u))
+    {
+        return Err(QuadratureError::InvalidParameter {
+            what: "bounds",
+            details: "finite bounds with lower <= upper required".to_string(),
+        });
+    }
+    if samples < 2 {
+        return Err(QuadratureError::InvalidParameter {
+            what: "samples",
+            details: format!("at least 2 samples required, got {samples}"),
+        });
+    }
+    let volume: f64 = lower.iter().zip(upper).map(|(l, u)| u - l).product();
+    let mut x = vec![0.0; lower.len()];
+    // Welford の逐次平均・分散
+    let (mut mean, mut m2) = (0.0, 0.0);
+    for k in 1..=samples {
+        for ((xi, l), u) in x.iter_mut().zip(lower).zip(upper) {
+            *xi = l + (u - l) * rng.gen::<f64>();
+        }
+        let y = f(&x);
+        if !y.is_finite() {
+            return Err(QuadratureError::NonFiniteValue { x: x[0] });
+        }
+        let delta = y - mean;
+        mean += delta / k as f64;
+        m2 += delta * (y - mean);
+    }
+    let variance = m2 / (samples - 1) as f64;
+    Ok(QuadratureReport {
+        value: volume * mean,
+        error: volume * (variance / samples as f64).sqrt(),
+        evaluations: samples,
+        converged: true,
+    })
+}
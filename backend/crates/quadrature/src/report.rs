@@ -0,0 +1,98 @@
+use crate::error::{QuadratureError, Result};
+
+/// 適応型積分の許容誤差と打ち切り条件
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadratureOptions {
+    /// 誤差推定が max(abs_tol, rel_tol |I|) 以下になったら止める
+    pub abs_tol: f64,
+    pub rel_tol: f64,
+    /// Gauss–Kronrod の区間分割数の上限
+    pub max_subdivisions: usize,
+    /// Romberg・tanh-sinh の刻みを半分にする回数の上限
+    pub max_levels: usize,
+}
+
+impl Default for QuadratureOptions {
+    fn default() -> Self {
+        Self {
+            abs_tol: 1e-10,
+            rel_tol: 1e-10,
+            max_subdivisions: 500,
+            max_levels: 16,
+        }
+    }
+}
+
+impl QuadratureOptions {
+    pub(crate) fn validate(&self) -> Result<()> {
+        let ok = |v: f64| v.is_finite() && v >= 0.0;
+        if !(ok(self.abs_tol) && ok(self.rel_tol)) || (self.abs_tol == 0.0 && self.rel_tol == 0.0) {
+            return Err(QuadratureError::InvalidParameter {
+                what: "tolerance",
+                details: format!(
+                    "non-negative finite tolerances, not both zero, required (abs_tol = {}, rel_tol = {})",
+                    self.abs_tol, self.rel_tol
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    pub(crate) fn tolerance(&self, value: f64) -> f64 {
+        self.abs_tol.max(self.rel_tol * value.abs())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuadratureReport {
+    pub value: f64,
+    /// 誤差の推定値（Monte Carlo では標準誤差）
+    pub error: f64,
+    /// 被積分関数の評価回数
+    pub evaluations: usize,
+    /// 許容誤差を満たして止まったか
+    pub converged: bool,
+}
+
+/// 積分区間の端点が NaN でないことを確かめる
+pub(crate) fn check_bounds(a: f64, b: f64) -> Result<()> {
+    if a.is_nan() || b.is_nan() {
+        return Err(QuadratureError::InvalidParameter {
+            what: "interval",
+            details: format!("bounds must not be NaN, got [{a}, {b}]"),
+        });
+    }
+    Ok(())
+}
+
+/// 有限区間を要求する手法の端点検査
+pub(crate) fn check_finite_bounds(a: f64, b: f64) -> Result<()> {
+    if !(a.is_finite() && b.is_finite()) {
+        return Err(QuadratureError::InvalidParameter {
+            what: "interval",
+            details: format!("finite bounds required, got [{a}, {b}]"),
+        });
+    }
+    Ok(())
+}
+
+/// 評価回数を数え、非有限値をエラーにする
+pub(crate) struct Integrand<F> {
+    f: F,
+    pub(crate) evaluations: usize,
+}
+
+impl<F: FnMut(f64) -> f64> Integrand<F> {
+    pub(crate) fn new(f: F) -> Self {
+        Self { f, evaluations: 0 }
+    }
+
+    pub(crate) fn eval(&mut self, x: f64) -> Result<f64> {
+        self.evaluations += 1;
+        let y = (self.f)(x);
+        if !y.is_finite() {
+            return Err(QuadratureError::NonFiniteValue { x });
+        }
+        Ok(y)
+    }
+}
@@ -0,0 +1,132 @@
+use quadrature::{
+    gauss_kronrod, monte_carlo, romberg, tanh_sinh, QuadratureError, QuadratureOptions,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use statistics::distribution::continuous::core::Distribution;
+use statistics::distribution::continuous::gamma::Gamma;
+use std::f64::consts::PI;
+
+#[test]
+fn methods_agree_on_smooth_integrals() {
+    let opts = QuadratureOptions::default();
+    // ∫_0^1 4 / (1 + x^2) dx = π
+    let f = |x: f64| 4.0 / (1.0 + x * x);
+    let gk = gauss_kronrod(f, 0.0, 1.0, &opts).unwrap();
+    let rb = romberg(f, 0.0, 1.0, &opts).unwrap();
+    let ts = tanh_sinh(f, 0.0, 1.0, &opts).unwrap();
+    for report in [&gk, &rb, &ts] {
+        assert!(report.converged);
+        assert!((report.value - PI).abs() < 1e-10, "{report:?}");
+    }
+    // 滑らかな関数なら数回の二分で足りる
+    assert!(gk.evaluations <= 45, "{gk:?}");
+
+    // 向きを逆にすると符号が変わる
+    let rev = gauss_kronrod(f, 1.0, 0.0, &opts).unwrap();
+    assert_eq!(rev.value, -gk.value);
+    assert_eq!(gauss_kronrod(f, 2.0, 2.0, &opts).unwrap().value, 0.0);
+}
+
+#[test]
+fn gauss_kronrod_adapts_to_peaks_and_infinite_ranges() {
+    let opts = QuadratureOptions::default();
+    // 鋭いピーク: ∫_{-1}^{1} 1 / (1e-4 + x^2) dx = 2 atan(100) / 0.01
+    let peak = gauss_kronrod(|x| 1.0 / (1e-4 + x * x), -1.0, 1.0, &opts).unwrap();
+    assert!(peak.converged);
+    assert!((peak.value - 200.0 * 100f64.atan()).abs() < 1e-7);
+    assert!(peak.evaluations > 15);
+
+    let gauss = gauss_kronrod(|x| (-x * x).exp(), f64::NEG_INFINITY, f64::INFINITY, &opts).unwrap();
+    assert!((gauss.value - PI.sqrt()).abs() < 1e-10);
+    let tail = gauss_kronrod(|x| 1.0 / (x * x), 1.0, f64::INFINITY, &opts).unwrap();
+    assert!((tail.value - 1.0).abs() < 1e-10);
+    let left = gauss_kronrod(|x: f64| x.exp(), f64::NEG_INFINITY, 0.0, &opts).unwrap();
+    assert!((left.value - 1.0).abs() < 1e-10);
+
+    // 分割数の上限に達したら未収束として返す
+    let capped = QuadratureOptions {
+        max_subdivisions: 3,
+        ..Default::default()
+    };
+    let report = gauss_kronrod(|x: f64| x.sqrt().recip(), 0.0, 1.0, &capped).unwrap();
+    assert!(!report.converged);
+}
+
+#[test]
+fn tanh_sinh_handles_endpoint_singularities() {
+    let opts = QuadratureOptions::default();
+    // ∫_0^1 ln x dx = -1, ∫_0^1 x^{-1/2} dx = 2, ∫_{-1}^{1} 1/√(1 - x^2) dx = π
+    // 最後の例は x = ±1 の近くで 1 - x が丸めで潰れるため、精度は √ε 程度にとどまる
+    // (被積分関数, a, b, 厳密値, 許容誤差)
+    type Case = (fn(f64) -> f64, f64, f64, f64, f64);
+    let cases: [Case; 3] = [
+        (|x| x.ln(), 0.0, 1.0, -1.0, 1e-9),
+        (|x| x.sqrt().recip(), 0.0, 1.0, 2.0, 1e-9),
+        (|x| (1.0 - x * x).sqrt().recip(), -1.0, 1.0, PI, 1e-7),
+    ];
+    for (f, a, b, exact, tol) in cases {
+        let report = tanh_sinh(f, a, b, &opts).unwrap();
+        assert!(report.converged, "{report:?}");
+        assert!((report.value - exact).abs() < tol, "{report:?} vs {exact}");
+        assert!(report.evaluations < 500);
+    }
+    // Romberg は端点で f を評価するので ln 0 でエラーになる
+    assert!(matches!(
+        romberg(|x: f64| x.ln(), 0.0, 1.0, &opts),
+        Err(QuadratureError::NonFiniteValue { x }) if x == 0.0
+    ));
+}
+
+#[test]
+fn integrates_custom_densities() {
+    // 統計クレートの Gamma 分布の密度を積分すると累積分布関数に一致する
+    let gamma = Gamma::new(2.5, 1.5).unwrap();
+    let opts = QuadratureOptions::default();
+    for x in [0.5, 1.0, 3.0] {
+        let report = tanh_sinh(|t| gamma.pdf(t), 0.0, x, &opts).unwrap();
+        assert!(
+            (report.value - gamma.cdf(x)).abs() < 1e-9,
+            "x = {x}: {} vs {}",
+            report.value,
+            gamma.cdf(x)
+        );
+    }
+    let total = gauss_kronrod(|t| gamma.pdf(t), 0.0, f64::INFINITY, &opts).unwrap();
+    assert!((total.value - 1.0).abs() < 1e-9);
+    let mean = gauss_kronrod(|t| t * gamma.pdf(t), 0.0, f64::INFINITY, &opts).unwrap();
+    assert!((mean.value - gamma.mean()).abs() < 1e-8);
+}
+
+#[test]
+fn monte_carlo_estimates_ball_volume() {
+    let mut rng = StdRng::seed_from_u64(20);
+    // 3 次元単位球の体積 4π/3（[-1, 1]^3 上の指示関数）
+    let inside = |x: &[f64]| {
+        if x.iter().map(|v| v * v).sum::<f64>() <= 1.0 {
+            1.0
+        } else {
+            0.0
+        }
+    };
+    let report = monte_carlo(inside, &[-1.0; 3], &[1.0; 3], 200_000, &mut rng).unwrap();
+    let exact = 4.0 * PI / 3.0;
+    assert!(
+        (report.value - exact).abs() < 4.0 * report.error,
+        "{report:?}"
+    );
+    assert!(report.error < 0.01);
+
+    // 分離可能な被積分関数 ∫_{[0,1]^4} Π x_i dx = 1/16
+    let product = monte_carlo(
+        |x| x.iter().product(),
+        &[0.0; 4],
+        &[1.0; 4],
+        100_000,
+        &mut rng,
+    )
+    .unwrap();
+    assert!((product.value - 0.0625).abs() < 4.0 * product.error);
+
+    assert!(monte_carlo(inside, &[0.0], &[1.0, 1.0], 10, &mut rng).is_err());
+    assert!(monte_carlo(inside, &[1.0], &[0.0], 10, &mut rng).is_err());
+}
@@ -0,0 +1,71 @@
+use poly::polynomial::special::{hermite_physicists, laguerre, legendre};
+use quadrature::{gauss_legendre, GaussRule, QuadratureError};
+use std::f64::consts::PI;
+
+#[test]
+fn nodes_are_roots_of_special_polynomials() {
+    for n in 1..=8 {
+        let rules = [
+            (GaussRule::legendre(n).unwrap(), legendre::<f64>(n)),
+            (GaussRule::hermite(n).unwrap(), hermite_physicists::<f64>(n)),
+            (GaussRule::laguerre(n).unwrap(), laguerre::<f64>(n)),
+        ];
+        for (rule, p) in rules {
+            assert_eq!(rule.len(), n);
+            assert!(rule.nodes.windows(2).all(|w| w[0] < w[1]));
+            // 零点での値を導関数の大きさで正規化して比べる
+            let dp = p.differentiate();
+            for &x in &rule.nodes {
+                assert!(
+                    p.eval(x).abs() <= 1e-12 * dp.eval(x).abs().max(1.0),
+                    "{:?} n = {n}, x = {x}",
+                    rule.family
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn weights_integrate_moments_exactly() {
+    for n in [1, 2, 5, 16, 64, 201, 256] {
+        // Σ w_i は重み関数の全積分: 2, √π, 1
+        let leg = GaussRule::legendre(n).unwrap();
+        let her = GaussRule::hermite(n).unwrap();
+        let lag = GaussRule::laguerre(n).unwrap();
+        assert!((leg.integrate(|_| 1.0) - 2.0).abs() < 1e-13, "n = {n}");
+        assert!(
+            (her.integrate(|_| 1.0) - PI.sqrt()).abs() < 1e-13,
+            "n = {n}"
+        );
+        // Laguerre は漸化式の値の桁が大きく変わるため、誤差が n² ε 程度まで増える
+        assert!((lag.integrate(|_| 1.0) - 1.0).abs() < 1e-11, "n = {n}");
+        // 次数 2n - 1 まで厳密: ∫ x^2 e^{-x^2} = √π / 2, ∫ x e^{-x} = 1
+        if n >= 2 {
+            assert!((her.integrate(|x| x * x) - PI.sqrt() / 2.0).abs() < 1e-12);
+            assert!((lag.integrate(|x| x) - 1.0).abs() < 1e-11);
+        }
+    }
+    // 5 点則は 9 次まで厳密: ∫ x^k e^{-x} dx = k!
+    let lag = GaussRule::laguerre(5).unwrap();
+    assert!((lag.integrate(|x| x.powi(9)) - 362_880.0).abs() < 1e-7);
+    let leg = GaussRule::legendre(5).unwrap();
+    assert!((leg.integrate(|x| x.powi(8)) - 2.0 / 9.0).abs() < 1e-15);
+}
+
+#[test]
+fn gauss_legendre_on_intervals() {
+    let value = gauss_legendre(|x: f64| x.exp(), 0.0, 1.0, 10).unwrap();
+    assert!((value - (1f64.exp() - 1.0)).abs() < 1e-15);
+    let value = gauss_legendre(|x: f64| x.sin(), 0.0, PI, 20).unwrap();
+    assert!((value - 2.0).abs() < 1e-14);
+
+    assert!(matches!(
+        GaussRule::legendre(0),
+        Err(QuadratureError::InvalidParameter { what: "n", .. })
+    ));
+    assert!(GaussRule::hermite(10_000).is_err());
+    let her = GaussRule::hermite(4).unwrap();
+    assert!(her.integrate_interval(|x| x, 0.0, 1.0).is_err());
+    assert!(gauss_legendre(|x| x, 0.0, f64::INFINITY, 4).is_err());
+}
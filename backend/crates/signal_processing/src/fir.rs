@@ -126,7 +126,7 @@ pub fn design_fir_lowpass(
         "Normalized cutoff must be between 0.0 and 0.5."
     );
     assert!(
-        num_taps % 2 != 0,
+        !num_taps.is_multiple_of(2),
         "Number of taps should be odd for a Type I filter."
     );
 
@@ -152,7 +152,7 @@ pub fn design_fir_highpass(
         "Normalized cutoff must be between 0.0 and 0.5."
     );
     assert!(
-        num_taps % 2 != 0,
+        !num_taps.is_multiple_of(2),
         "Number of taps should be odd for a Type I filter."
     );
 
@@ -187,7 +187,7 @@ pub fn design_fir_bandpass(
         "require f1 < f2 for bandpass"
     );
     assert!(
-        num_taps % 2 != 0,
+        !num_taps.is_multiple_of(2),
         "Number of taps should be odd for a Type I filter."
     );
 
@@ -222,7 +222,7 @@ pub fn design_fir_bandstop(
         "require f1 < f2 for bandstop"
     );
     assert!(
-        num_taps % 2 != 0,
+        !num_taps.is_multiple_of(2),
         "Number of taps should be odd for a Type I filter."
     );
 
@@ -256,7 +256,7 @@ pub fn design_fir_lowpass_poly(
         "Normalized cutoff must be between 0.0 and 0.5."
     );
     assert!(
-        num_taps % 2 != 0,
+        !num_taps.is_multiple_of(2),
         "Number of taps should be odd for a Type I filter."
     );
 
@@ -292,7 +292,7 @@ pub fn design_fir_highpass_poly(
         "Normalized cutoff must be between 0.0 and 0.5."
     );
     assert!(
-        num_taps % 2 != 0,
+        !num_taps.is_multiple_of(2),
         "Number of taps should be odd for a Type I filter."
     );
 
@@ -337,7 +337,7 @@ pub fn design_fir_bandpass_poly(
         "require f1 < f2 for bandpass"
     );
     assert!(
-        num_taps % 2 != 0,
+        !num_taps.is_multiple_of(2),
         "Number of taps should be odd for a Type I filter."
     );
 
@@ -383,7 +383,7 @@ pub fn design_fir_bandstop_poly(
         "require f1 < f2 for bandstop"
     );
     assert!(
-        num_taps % 2 != 0,
+        !num_taps.is_multiple_of(2),
         "Number of taps should be odd for a Type I filter."
     );
 
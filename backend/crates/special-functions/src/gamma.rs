@@ -86,23 +86,24 @@ pub fn regularized_gamma(s: f64, x: f64) -> f64 {
     }
 }
 
+const FPMIN: f64 = 1e-300;
+
 // 上側正規化ガンマ Q(s,x) を連分数（Lentz法）で評価
 fn upper_gamma(s: f64, x: f64, lg: f64) -> f64 {
     const EPS: f64 = 1e-15;
-    const FPMIN: f64 = 1e-300;
     // Lentz's algorithm initialization
     let mut b = x + 1.0 - s;
     let mut c = 1.0 / FPMIN;
-    let mut d = 1.0 / b.max(FPMIN);
+    let mut d = 1.0 / guard(b);
     let mut h = d;
     for i in 1..=10_000 {
-        // a_i = -i (s - i)
-        let a_i = -(i as f64) * (s - i as f64);
+        // a_i = -i (i - s)
+        let a_i = -(i as f64) * (i as f64 - s);
         b += 2.0;
         // d = 1 / (b + a_i * d)
-        d = 1.0 / (b + a_i * d).max(FPMIN);
+        d = 1.0 / guard(b + a_i * d);
         // c = b + a_i / c
-        c = (b + a_i / c).max(FPMIN);
+        c = guard(b + a_i / c);
         let delta = c * d;
         h *= delta;
         if (delta - 1.0).abs() < EPS {
@@ -111,3 +112,12 @@ fn upper_gamma(s: f64, x: f64, lg: f64) -> f64 {
     }
     (s * x.ln() - x - lg).exp() * h
 }
+
+// Lentz 法で分母がほぼ 0 になったら FPMIN に置き換える（負の値はそのまま使う）
+fn guard(v: f64) -> f64 {
+    if v.abs() < FPMIN {
+        FPMIN
+    } else {
+        v
+    }
+}
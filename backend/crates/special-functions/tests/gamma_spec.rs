@@ -62,3 +62,17 @@ fn lower_regularized_gamma_bounds() {
     let p = regularized_gamma(s, 100.0);
     assert!(p > 0.999);
 }
+
+#[test]
+fn lower_regularized_gamma_matches_closed_form() {
+    // 整数 s では P(s, x) = 1 - e^{-x} Σ_{k<s} x^k / k!
+    for x in [0.5f64, 3.0, 4.5, 10.0, 30.0] {
+        let closed = 1.0 - (-x).exp() * (1.0 + x + x * x / 2.0);
+        assert!(
+            (regularized_gamma(3.0, x) - closed).abs() < 1e-12,
+            "x = {x}"
+        );
+    }
+    // P(1/2, x) = erf(√x): erf(√4.5) = erf(2.1213...) ≈ 0.997300203936740
+    assert!((regularized_gamma(0.5, 4.5) - 0.997_300_203_936_74).abs() < 1e-12);
+}
@@ -43,7 +43,7 @@ where
         }
         clean.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
         let len = clean.len();
-        if len % 2 == 0 {
+        if len.is_multiple_of(2) {
             Some((clean[len / 2 - 1] + clean[len / 2]) / T::from(2).unwrap())
         } else {
             Some(clean[len / 2])
@@ -38,7 +38,7 @@ where
         let mut sorted = self.to_vec();
         sorted.sort();
         let len = sorted.len();
-        if len % 2 == 0 {
+        if len.is_multiple_of(2) {
             let mid_right = len / 2;
             let mid_left = mid_right - 1;
             // 整数同士の平均は小数になる可能性があるのでf64で計算
@@ -213,8 +213,8 @@ pub fn f_test_variance_ratio(
     let nx = clean_finite(x).len();
     let ny = clean_finite(y).len();
     let f_stat = sx2 / sy2;
-    let d1 = (nx - 1) as usize;
-    let d2 = (ny - 1) as usize;
+    let d1 = nx - 1;
+    let d2 = ny - 1;
     let fdist = F::new(d1, d2).unwrap();
     let p_left = fdist.cdf(f_stat);
     let p_right = 1.0 - p_left;
@@ -32,8 +32,8 @@ fn main() {
         "[Goal] ロジスティック回帰: P(y=1|x)=σ(β₀ + β₁x₁ + β₂x₂)。閾値0.5でクラスを決定します。"
     );
 
-    // 学習 (単純勾配上昇: alpha=学習率, max_iter=反復回数)
-    let model = LogisticRegression::fit(&x, &y, 0.5, 200);
+    // 学習 (L-BFGS: max_step=直線探索の最大ステップ, max_iter=反復上限)
+    let model = LogisticRegression::fit(&x, &y, 0.5, 200).expect("logistic fit failed");
 
     println!("[Truth] 真のパラメータ β_true (先頭が切片): {beta_true}");
     println!(
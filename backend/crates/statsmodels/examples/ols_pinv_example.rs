@@ -7,7 +7,7 @@ fn main() {
     let b = Vector::new(vec![0.0, 1.0, 2.0, 3.0]);
 
     let x = solve_linear_system(&a, &b).unwrap();
-    println!("pinv solution x: {x}");
+    println!("least-squares solution x: {x}");
     let fit = &a * &x;
     println!("fitted Ax: {fit}");
 }
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use linalg::LinalgError;
+use optimization::OptimizeError;
 use statistics::error::StatisticsError;
 
 #[derive(Debug, Clone)]
@@ -11,6 +12,7 @@ pub enum StatsModelsError {
     ConvergenceFailure { details: String },
     Linalg(LinalgError),
     Statistics(StatisticsError),
+    Optimize(OptimizeError),
 }
 
 impl fmt::Display for StatsModelsError {
@@ -28,6 +30,7 @@ impl fmt::Display for StatsModelsError {
             }
             StatsModelsError::Linalg(e) => write!(f, "Linalg error: {e}"),
             StatsModelsError::Statistics(e) => write!(f, "Statistics error: {e}"),
+            StatsModelsError::Optimize(e) => write!(f, "Optimization error: {e}"),
         }
     }
 }
@@ -46,4 +49,10 @@ impl From<StatisticsError> for StatsModelsError {
     }
 }
 
+impl From<OptimizeError> for StatsModelsError {
+    fn from(value: OptimizeError) -> Self {
+        Self::Optimize(value)
+    }
+}
+
 pub type Result<T> = std::result::Result<T, StatsModelsError>;
@@ -10,19 +10,16 @@ pub fn bayesian_estimation(
     prior_cov: &Matrix<f64>,
     noise_cov: &Matrix<f64>,
 ) -> Result<MultivariateNormal> {
-    let prior_precision = prior_cov
-        .inverse()
-        .ok_or(StatsModelsError::InvalidParameter {
-            what: "bayesian_estimation",
-            details: "prior_cov is not invertible".to_string(),
-        })?;
+    let prior_precision =
+        prior_cov
+            .inverse_checked()
+            .map_err(|e| StatsModelsError::InvalidParameter {
+                what: "bayesian_estimation",
+                details: format!("prior_cov is not invertible: {e}"),
+            })?;
     let (posterior_mean, posterior_precision) =
         bayesian_estimation_with_precision(y, h, prior_mean, &prior_precision, noise_cov)?;
-    let posterior_cov = posterior_precision
-        .inverse()
-        .ok_or(StatsModelsError::Linalg(
-            linalg::LinalgError::SingularMatrix,
-        ))?;
+    let posterior_cov = posterior_precision.inverse_checked()?;
     let mvn = MultivariateNormal::new(posterior_mean, posterior_cov).map_err(|e| {
         StatsModelsError::InvalidParameter {
             what: "MultivariateNormal::new",
@@ -151,8 +148,6 @@ pub fn fit_em(
         noise_cov = new_noise_cov.clone();
     }
 
-    let prior_cov = prior_precision.inverse().ok_or(StatsModelsError::Linalg(
-        linalg::LinalgError::SingularMatrix,
-    ))?;
+    let prior_cov = prior_precision.inverse_checked()?;
     Ok((prior_mean, prior_cov, noise_cov))
 }
@@ -1,4 +1,7 @@
 use linalg::{Matrix, Vector};
+use optimization::{lbfgs, newton, MinimizeOptions, WolfeParams};
+
+use crate::error::{Result, StatsModelsError};
 
 pub struct LogisticRegression {
     // 外部から直接アクセスできないようにprivateにするのが一般的
@@ -14,30 +17,93 @@ impl LogisticRegression {
         &self.coefficients
     }
 
-    // fitは学習を行い、新しいモデルインスタンスを返す
-    pub fn fit(x_input: &Matrix<f64>, y: &Vector<f64>, alpha: f64, max_iter: usize) -> Self {
-        // xの先頭に1の列を追加して、切片（バイアス）項に対応
-        let ones = Matrix::new(x_input.rows, 1, vec![1.0; x_input.rows]).unwrap();
-        // 切片を先頭列に置く: 係数[0]が切片というAPI契約と整合
-        let x = ones.hstack(x_input).unwrap();
-
-        let mut beta = Vector::zeros(x.cols);
-
-        for _ in 0..max_iter {
-            // mapの中でbetaをmoveしないように、参照で渡す
-            let p = Vector::new(
-                (0..x.rows)
-                    .map(|i| Self::predict_proba_internal(&beta, &x.row(i).unwrap()))
-                    .collect(),
-            );
-
-            let error = y - &p;
-            let gradient = &x.transpose() * &error;
+    /// 負の対数尤度を optimization::lbfgs（強 Wolfe 直線探索付き）で最小化する。
+    /// max_step は 1 回の直線探索で進める最大の歩幅 α（探索方向 p に対し x + α p の α の上限）で、
+    /// 学習率ではない（以前の勾配上昇版の学習率引数とは意味が違う）。max_iter は L-BFGS の反復上限。
+    /// 完全分離なデータでは係数が発散し続けるので、未収束でも打ち切った時点の係数を返す。
+    pub fn fit(
+        x_input: &Matrix<f64>,
+        y: &Vector<f64>,
+        max_step: f64,
+        max_iter: usize,
+    ) -> Result<Self> {
+        Self::check_inputs(x_input, y)?;
+        if max_step.is_nan() || max_step <= 0.0 {
+            return Err(StatsModelsError::InvalidParameter {
+                what: "max_step",
+                details: format!("must be positive, got {max_step}"),
+            });
+        }
+        let x = Self::design_matrix(x_input)?;
+        let xt = x.transpose();
+        let opts = MinimizeOptions {
+            max_iterations: max_iter,
+            wolfe: WolfeParams {
+                max_step,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let report = lbfgs(
+            |beta: &Vector<f64>| Self::neg_log_likelihood(&x, y, beta, 0.0),
+            |beta: &Vector<f64>| Self::nll_gradient(&x, &xt, y, beta, 0.0),
+            &Vector::zeros(x.cols),
+            &opts,
+        )?;
+        Ok(Self {
+            coefficients: report.x,
+        })
+    }
 
-            beta = beta + &(&gradient * alpha);
+    /// 最尤推定（L2 正則化付き）。負の対数尤度
+    ///   Σ log(1 + exp(x_i^T β)) - y_i x_i^T β + (l2 / 2) Σ_{j≥1} β_j^2
+    /// を直線探索付き Newton 法で最小化する。切片は正則化しない。
+    /// 完全分離なデータでは l2 = 0 の最尤推定量が存在しない（係数が発散する）ので l2 > 0 を使う。
+    pub fn fit_mle(x_input: &Matrix<f64>, y: &Vector<f64>, l2: f64) -> Result<Self> {
+        Self::check_inputs(x_input, y)?;
+        if l2.is_nan() || l2 < 0.0 {
+            return Err(StatsModelsError::InvalidParameter {
+                what: "l2",
+                details: format!("must be non-negative, got {l2}"),
+            });
         }
+        let x = Self::design_matrix(x_input)?;
+        let xt = x.transpose();
 
-        Self { coefficients: beta }
+        let nll = |beta: &Vector<f64>| Self::neg_log_likelihood(&x, y, beta, l2);
+        let grad = |beta: &Vector<f64>| Self::nll_gradient(&x, &xt, y, beta, l2);
+        let hess = |beta: &Vector<f64>| {
+            // X^T W X（W = diag(p (1 - p))）
+            let mut weighted = x.clone();
+            for i in 0..x.rows {
+                let p = Self::sigmoid(beta.dot(&x.row(i).unwrap()));
+                for j in 0..x.cols {
+                    weighted[(i, j)] *= p * (1.0 - p);
+                }
+            }
+            let mut h = &xt * &weighted;
+            for j in 1..x.cols {
+                h[(j, j)] += l2;
+            }
+            h
+        };
+        let opts = MinimizeOptions {
+            max_iterations: 100,
+            gtol: 1e-8,
+            ..Default::default()
+        };
+        let report = newton(nll, grad, hess, &Vector::zeros(x.cols), &opts)?;
+        if !report.converged {
+            return Err(StatsModelsError::ConvergenceFailure {
+                details: format!(
+                    "Newton iterations stopped by {:?} after {} steps",
+                    report.termination, report.iterations
+                ),
+            });
+        }
+        Ok(Self {
+            coefficients: report.x,
+        })
     }
 
     // &self を取るメソッドに変更
@@ -61,10 +127,50 @@ impl LogisticRegression {
         }
     }
 
-    // 内部計算用のヘルパー関数（これはこのままでOK）
-    fn predict_proba_internal(beta: &Vector<f64>, x_row: &Vector<f64>) -> f64 {
-        let z = beta.dot(x_row);
-        Self::sigmoid(z)
+    fn check_inputs(x_input: &Matrix<f64>, y: &Vector<f64>) -> Result<()> {
+        if x_input.rows == 0 {
+            return Err(StatsModelsError::EmptyInput);
+        }
+        if y.dim() != x_input.rows {
+            return Err(StatsModelsError::DimensionMismatch {
+                expected: format!("{} labels", x_input.rows),
+                found: format!("{}", y.dim()),
+            });
+        }
+        Ok(())
+    }
+
+    // xの先頭に1の列を追加して、切片（バイアス）項に対応（係数[0]が切片）
+    fn design_matrix(x_input: &Matrix<f64>) -> Result<Matrix<f64>> {
+        let ones = Matrix::new(x_input.rows, 1, vec![1.0; x_input.rows])?;
+        Ok(ones.hstack(x_input)?)
+    }
+
+    // log(1 + e^z) = max(z, 0) + log(1 + e^{-|z|}) で溢れずに評価する。切片は正則化しない
+    fn neg_log_likelihood(x: &Matrix<f64>, y: &Vector<f64>, beta: &Vector<f64>, l2: f64) -> f64 {
+        let z = x * beta;
+        let loss: f64 = z
+            .iter()
+            .zip(y.iter())
+            .map(|(&zi, &yi)| zi.max(0.0) + (-zi.abs()).exp().ln_1p() - yi * zi)
+            .sum();
+        let penalty: f64 = beta.iter().skip(1).map(|b| b * b).sum();
+        loss + 0.5 * l2 * penalty
+    }
+
+    fn nll_gradient(
+        x: &Matrix<f64>,
+        xt: &Matrix<f64>,
+        y: &Vector<f64>,
+        beta: &Vector<f64>,
+        l2: f64,
+    ) -> Vector<f64> {
+        let p = (x * beta).map(|&z| Self::sigmoid(z));
+        let mut g = xt * &(&p - y);
+        for j in 1..beta.dim() {
+            g[j] += l2 * beta[j];
+        }
+        g
     }
 
     fn sigmoid(z: f64) -> f64 {
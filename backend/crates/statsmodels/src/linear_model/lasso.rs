@@ -1,5 +1,7 @@
 use linalg::{Matrix, Result, Vector};
 
+/// ½||Ax − b||² + α||x||_1 をソフトしきい値の座標降下で最小化する。
+/// L1 項は微分不能なので、滑らかな目的関数向けの optimization クレートは使わない。
 pub fn lasso_regression(
     a: &Matrix<f64>,
     b: &Vector<f64>,
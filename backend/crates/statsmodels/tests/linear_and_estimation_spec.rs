@@ -53,7 +53,7 @@ fn logistic_fits_simple_separable_data() {
     let x = Matrix::new(4, 2, vec![0.0, 0.0, 1.0, 0.4, 2.0, 3.0, 4.0, 5.0]).unwrap();
     let y = Vector::new(vec![0.0, 0.0, 1.0, 1.0]);
 
-    let model = LogisticRegression::fit(&x, &y, 0.5, 500);
+    let model = LogisticRegression::fit(&x, &y, 0.5, 500).unwrap();
     // 学習データでの分類精度が高いこと
     let mut correct = 0;
     for i in 0..x.rows {
@@ -66,6 +66,36 @@ fn logistic_fits_simple_separable_data() {
     assert!(correct >= 3, "accuracy too low: {}/{}", correct, x.rows);
 }
 
+#[test]
+fn logistic_mle_satisfies_score_equations() {
+    // 分離していないデータ（x = 1.5 付近でラベルが混ざる）
+    let x = Matrix::new(8, 1, vec![0.0, 0.5, 1.0, 1.5, 1.5, 2.0, 2.5, 3.0]).unwrap();
+    let y = Vector::new(vec![0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0]);
+    let model = LogisticRegression::fit_mle(&x, &y, 0.0).unwrap();
+    // 最尤推定量では Σ (y_i - p_i) = 0、Σ (y_i - p_i) x_i = 0
+    let (mut s0, mut s1) = (0.0, 0.0);
+    for i in 0..x.rows {
+        let r = y[i] - model.predict_proba(&x.row(i).unwrap());
+        s0 += r;
+        s1 += r * x[(i, 0)];
+    }
+    assert!(s0.abs() < 1e-8 && s1.abs() < 1e-8, "score = ({s0}, {s1})");
+    assert!(model.coefficients()[1] > 0.0);
+
+    // L2 正則化で傾きが縮む
+    let shrunk = LogisticRegression::fit_mle(&x, &y, 5.0).unwrap();
+    assert!(shrunk.coefficients()[1].abs() < model.coefficients()[1].abs());
+    assert!(LogisticRegression::fit_mle(&x, &Vector::new(vec![0.0; 3]), 0.0).is_err());
+
+    // fit も同じ負の対数尤度を L-BFGS で最小化するので、十分な反復で最尤推定量に一致する
+    let lbfgs_fit = LogisticRegression::fit(&x, &y, 1.0, 200).unwrap();
+    for j in 0..2 {
+        let (a, b) = (lbfgs_fit.coefficients()[j], model.coefficients()[j]);
+        assert!((a - b).abs() < 1e-5, "coef {j}: {a} vs {b}");
+    }
+    assert!(LogisticRegression::fit(&x, &y, 0.0, 10).is_err());
+}
+
 #[test]
 fn gmm_separates_two_clusters() {
     // 二つのクラスタ [1,1] と [-1,-1] に小さな球状ノイズ
@@ -88,3 +118,13 @@ fn gmm_separates_two_clusters() {
     let c2 = gmm.predict(&near_minus);
     assert_ne!(c1, c2);
 }
+
+#[test]
+fn ols_rank_deficient_returns_minimum_norm_solution() {
+    // 2 列目は 1 列目の 2 倍。最小ノルム解は x = t (1, 2) / 5 の形になる
+    let a = Matrix::new(3, 2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]).unwrap();
+    let b = Vector::new(vec![1.0, 2.0, 3.0]);
+    let x = solve_linear_system(&a, &b).expect("ols failed");
+    assert!((x[0] - 0.2).abs() < 1e-10, "x0 = {}", x[0]);
+    assert!((x[1] - 0.4).abs() < 1e-10, "x1 = {}", x[1]);
+}
@@ -221,14 +221,12 @@ impl Parse for WasmClassArgs {
                         }
                     }
                 }
-                Meta::List(ml) => {
-                    if ml.path.is_ident("ops") {
-                        let parser = Punctuated::<Path, Token![,]>::parse_terminated;
-                        let paths = parser.parse(ml.tokens.into())?;
-                        for p in paths {
-                            if let Some(ident) = p.get_ident() {
-                                ops.push(ident.clone());
-                            }
+                Meta::List(ml) if ml.path.is_ident("ops") => {
+                    let parser = Punctuated::<Path, Token![,]>::parse_terminated;
+                    let paths = parser.parse(ml.tokens.into())?;
+                    for p in paths {
+                        if let Some(ident) = p.get_ident() {
+                            ops.push(ident.clone());
                         }
                     }
                 }
@@ -670,9 +668,10 @@ fn build_args(sig: &syn::Signature, wasm_ty: &Ident) -> (proc_macro2::TokenStrea
                 let arg_ty_str = quote!(#arg_ty).to_string();
                 let arg_ty_norm = arg_ty_str.replace([' ', '\n', '\t'], "");
                 // Wrapper公開用の型（isizeはi32に正規化）
-                let new_arg_ty = if arg_ty_str == "Self" || arg_ty_str == "&Self" {
-                    quote! { &#wasm_ty }
-                } else if arg_ty_str.starts_with("&") && arg_ty_str.contains("Self") {
+                let new_arg_ty = if arg_ty_str == "Self"
+                    || arg_ty_str == "&Self"
+                    || (arg_ty_str.starts_with("&") && arg_ty_str.contains("Self"))
+                {
                     quote! { &#wasm_ty }
                 } else if arg_ty_norm == "isize" || arg_ty_norm == "&isize" {
                     // isize は JS 側に安全に出せないため、公開シグネチャでは i32 に固定
@@ -684,9 +683,10 @@ fn build_args(sig: &syn::Signature, wasm_ty: &Ident) -> (proc_macro2::TokenStrea
                 fn_args_without_receiver.extend(quote! { #arg_name: #new_arg_ty, });
 
                 // 呼び出し時の引数変換
-                if arg_ty_str == "Self" || arg_ty_str == "&Self" {
-                    call_args.extend(quote! { &#arg_name.0, });
-                } else if arg_ty_str.starts_with("&") && arg_ty_str.contains("Self") {
+                if arg_ty_str == "Self"
+                    || arg_ty_str == "&Self"
+                    || (arg_ty_str.starts_with("&") && arg_ty_str.contains("Self"))
+                {
                     call_args.extend(quote! { &#arg_name.0, });
                 } else if arg_ty_norm == "isize" || arg_ty_norm == "&isize" {
                     // i32 で受け取り、内部呼び出しでは isize にキャスト
@@ -27,6 +27,10 @@ impl WasmHamming74 {
 	}
 }
 
+impl Default for WasmHamming74 {
+	fn default() -> Self { Self::new() }
+}
+
 // LinearCode<GF(2)> 固定（JS 利用が多い想定）
 #[wasm_bindgen(js_name = LinearCode)]
 pub struct WasmLinearCodeGF2(coding::LinearCode<GF2>);
@@ -131,7 +135,7 @@ pub fn hamming_distance_gf2(a: Vec<u8>, b: Vec<u8>) -> usize {
 #[wasm_bindgen(js_name = weightDistributionGF2)]
 pub fn weight_distribution_gf2(codebook_flat: Vec<u8>, n: usize) -> Result<Vec<usize>, JsError> {
 	if n == 0 { return Err(JsError::new("n must be > 0")); }
-	if codebook_flat.len() % n != 0 { return Err(JsError::new("codebook_flat length must be multiple of n")); }
+	if !codebook_flat.len().is_multiple_of(n) { return Err(JsError::new("codebook_flat length must be multiple of n")); }
 	let m = codebook_flat.len() / n;
 	let mut cws: Vec<CodewordGF2> = Vec::with_capacity(m);
 	for i in 0..m {
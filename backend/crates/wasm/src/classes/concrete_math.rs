@@ -14,7 +14,7 @@ pub fn stirling2_js(n: usize, k: usize) -> f64 { concrete_math::combinatorics::n
 // ---- 複素係数多項式: Float64Array を [re0, im0, re1, im1, ...] として受け渡し ----
 
 fn flat_to_poly(mut flat: Vec<f64>) -> poly::polynomial::Polynomial<Complex<f64>> {
-    if flat.len() % 2 != 0 { flat.pop(); }
+    if !flat.len().is_multiple_of(2) { flat.pop(); }
     let mut coeffs: Vec<Complex<f64>> = Vec::with_capacity(flat.len() / 2);
     let mut i = 0usize;
     while i + 1 < flat.len() {
@@ -116,7 +116,7 @@ pub fn solve_recurrence(
     nh_bases: Vec<f64>,
     initial_values: Vec<f64>,
 ) -> Result<WasmClosedForm, JsError> {
-    if nh_bases.len() % 2 != 0 { return Err(JsError::new("nh_bases length must be even")); }
+    if !nh_bases.len().is_multiple_of(2) { return Err(JsError::new("nh_bases length must be even")); }
     let m = nh_bases.len() / 2;
     if nh_offsets.len() != m + 1 { return Err(JsError::new("nh_offsets length must be m+1")); }
     let last = *nh_offsets.last().unwrap_or(&0) as usize;
@@ -126,7 +126,7 @@ pub fn solve_recurrence(
     for i in 0..m {
         let s = nh_offsets[i] as usize;
         let e = nh_offsets[i+1] as usize;
-        if (e < s) || ((e - s) % 2 != 0) { return Err(JsError::new("invalid nh_offsets segment")); }
+        if (e < s) || !(e - s).is_multiple_of(2) { return Err(JsError::new("invalid nh_offsets segment")); }
         let poly = flat_to_poly(nh_polys_flat[s..e].to_vec());
         let base = num_complex::Complex::new(nh_bases[2*i], nh_bases[2*i+1]);
         terms.push(concrete_math::sequence::core::GeneralTerm { polynomial: poly, base });
@@ -6,7 +6,7 @@ use wasm_bindgen::prelude::*;
 // JS との受け渡しは Float64Array を [re0, im0, re1, im1, ...] とする
 
 fn vec_to_complex(mut flat: Vec<f64>) -> Vec<Complex<f64>> {
-	if flat.len() % 2 != 0 { flat.pop(); }
+	if !flat.len().is_multiple_of(2) { flat.pop(); }
 	let mut out = Vec::with_capacity(flat.len() / 2);
 	let mut i = 0;
 	while i + 1 < flat.len() {
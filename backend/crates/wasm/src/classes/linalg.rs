@@ -58,7 +58,7 @@ macro_rules! matrix_field_methods {
 macro_rules! matrix_f64_methods {
     () => {
         pub fn frobenius_norm(&self) -> f64 {}
-        pub fn expm(&self) -> Self {}
+        pub fn expm(&self) -> std::result::Result<Self, wasm_bindgen::JsValue> {}
         pub fn qr_decomposition(&self) -> std::result::Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {}
         pub fn svd(&self) -> std::result::Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {}
         pub fn eigen_decomposition(&self) -> std::result::Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {}
@@ -143,7 +143,7 @@ impl MatrixF64 {
 
     // f64 numerical
     pub fn frobenius_norm(&self) -> f64 {}
-    pub fn expm(&self) -> Self {}
+    pub fn expm(&self) -> std::result::Result<Self, wasm_bindgen::JsValue> {}
     pub fn qr_decomposition(&self) -> std::result::Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {}
     pub fn svd(&self) -> std::result::Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {}
     pub fn eigen_decomposition(&self) -> std::result::Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {}
@@ -240,7 +240,7 @@ impl MatrixF64 {
         let n = self.0.rows.min(self.0.cols);
         let mut data = Vec::with_capacity(n);
         for i in 0..n {
-            data.push(self.0.data[i * self.0.cols + i].clone());
+            data.push(self.0.data[i * self.0.cols + i]);
         }
         VectorF64(linalg::Vector::new(data))
     }
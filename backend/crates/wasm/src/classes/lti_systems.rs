@@ -98,8 +98,8 @@ impl ContinuousTF {
     pub fn a_coeffs(&self) -> Vec<f64> { self.inner.a_coeffs().to_vec() }
     pub fn is_stable(&self) -> bool { self.inner.is_stable() }
 
-    pub fn impulse_response(&self, fs: f64, len: usize) -> Vec<f64> { self.inner.impulse_response(fs, len) }
-    pub fn step_response(&self, fs: f64, len: usize) -> Vec<f64> { self.inner.step_response(fs, len) }
+    pub fn impulse_response(&self, fs: f64, len: usize) -> Result<Vec<f64>, JsError> { self.inner.impulse_response(fs, len).map_err(|e| JsError::new(&e.to_string())) }
+    pub fn step_response(&self, fs: f64, len: usize) -> Result<Vec<f64>, JsError> { self.inner.step_response(fs, len).map_err(|e| JsError::new(&e.to_string())) }
 
     pub fn frequency_response_mag_phase(&self, omega_max: f64, n_freqs: usize) -> Vec<f64> {
         let resp = self.inner.frequency_response(omega_max.max(0.0), n_freqs);
@@ -119,6 +119,7 @@ impl ContinuousTF {
         let opts = lti_systems::plot::ContinuousBodeOptions { n_points: n_points.max(2), f_min_hz, f_max_hz, legend, ..Default::default() };
     lti_systems::plot::continuous_bode_svg_string(&self.inner, width, height, &opts)
     }
+    #[allow(clippy::too_many_arguments)] // JS 側の引数形状に合わせる
     pub fn nyquist_svg(&self, width: u32, height: u32, f_min_hz: f64, f_max_hz: f64, n_points: usize, log_freq: bool, legend: bool) -> String {
     let opts = lti_systems::plot::ContinuousNyquistOptions { n_points: n_points.max(2), f_min_hz, f_max_hz, log_freq, legend, ..Default::default() };
     lti_systems::plot::continuous_nyquist_svg_string(&self.inner, width, height, &opts)
@@ -223,6 +224,7 @@ impl ContinuousSS {
 #[wasm_bindgen]
 impl ContinuousSS {
     #[wasm_bindgen(constructor)]
+    #[allow(clippy::too_many_arguments)] // 行列を (データ, 行, 列) の組で受け取る JS 向けコンストラクタ
     pub fn new(a: Vec<f64>, na: usize, ma: usize,
                b: Vec<f64>, nb: usize, mb: usize,
                c: Vec<f64>, nc: usize, mc: usize,
@@ -252,14 +254,14 @@ impl ContinuousSS {
         out
     }
 
-    pub fn c2d_zoh(&self, fs: f64) -> DiscreteSS {
+    pub fn c2d_zoh(&self, fs: f64) -> Result<DiscreteSS, JsError> {
         let a = linalg::matrix::Matrix::new(self.na, self.ma, self.a.clone()).unwrap();
         let b = linalg::matrix::Matrix::new(self.nb, self.mb, self.b.clone()).unwrap();
         let c = linalg::matrix::Matrix::new(self.nc, self.mc, self.c.clone()).unwrap();
         let d = linalg::matrix::Matrix::new(self.nd, self.md, self.d.clone()).unwrap();
         let css = lti_systems::statespace::ContinuousStateSpace { a, b, c, d };
-        let dss = css.c2d_zoh(fs);
-        DiscreteSS::from_inner(&dss)
+        let dss = css.c2d_zoh(fs).map_err(|e| JsError::new(&e.to_string()))?;
+        Ok(DiscreteSS::from_inner(&dss))
     }
 
     // getters（行列内容をそのまま返す）
@@ -291,6 +293,7 @@ impl DiscreteSS {
 #[wasm_bindgen]
 impl DiscreteSS {
     #[wasm_bindgen(constructor)]
+    #[allow(clippy::too_many_arguments)] // 行列を (データ, 行, 列) の組で受け取る JS 向けコンストラクタ
     pub fn new(a: Vec<f64>, na: usize, ma: usize,
                b: Vec<f64>, nb: usize, mb: usize,
                c: Vec<f64>, nc: usize, mc: usize,
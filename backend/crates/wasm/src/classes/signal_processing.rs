@@ -19,6 +19,7 @@ impl WasmSignal {
 	pub fn data(&self) -> Vec<f64> { self.data.clone() }
 	pub fn sample_rate(&self) -> f64 { self.fs }
 	pub fn len(&self) -> usize { self.data.len() }
+	pub fn is_empty(&self) -> bool { self.data.is_empty() }
 
 	pub fn dft(&self) -> WasmSpectrum {
 		let sig = sp::signal::Signal::new(self.data.clone(), self.fs);
@@ -108,6 +109,7 @@ impl WasmSpectrum {
 	pub fn data_interleaved(&self) -> Vec<f64> { self.data_interleaved.clone() }
 	pub fn sample_rate(&self) -> f64 { self.fs }
 	pub fn len(&self) -> usize { self.data_interleaved.len() / 2 }
+	pub fn is_empty(&self) -> bool { self.data_interleaved.len() < 2 }
 
 	pub fn ift(&self) -> WasmSignal {
 		use num_complex::Complex;
@@ -524,8 +526,8 @@ pub fn gray_f64_to_rgba_u8(gray: Vec<f64>, width: usize, height: usize) -> Vec<u
 	let n = width * height;
 	assert_eq!(gray.len(), n, "gray length must be width*height");
 	let mut out = Vec::with_capacity(n * 4);
-	for i in 0..n {
-		let v = gray[i].round().clamp(0.0, 255.0) as u8;
+	for g in gray.iter() {
+		let v = g.round().clamp(0.0, 255.0) as u8;
 		out.push(v);
 		out.push(v);
 		out.push(v);
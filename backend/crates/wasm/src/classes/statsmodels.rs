@@ -79,13 +79,13 @@ pub fn logistic_fit_js(
 	cols: usize,
 	x_data: Vec<f64>,
 	y: Vec<f64>,
-	lr: f64,
+	max_step: f64,
 	max_iter: usize,
 ) -> Result<Vec<f64>, JsValue> {
 	if y.len() != rows { return Err(JsValue::from_str("y length must equal rows")); }
 	let x = mat_from_flat(rows, cols, x_data)?;
 	let yv = vec_from(y);
-	let model = LogisticRegression::fit(&x, &yv, lr, max_iter);
+	let model = LogisticRegression::fit(&x, &yv, max_step, max_iter).map_err(|e| JsValue::from_str(&format!("{}", e)))?;
 	Ok(model.coefficients().as_slice().to_vec())
 }
 
@@ -241,7 +241,7 @@ pub fn kalman_update_js(
 ) -> Result<Vec<f64>, JsValue> {
 	if x_flat.len() != n { return Err(JsValue::from_str("x length must equal n")); }
 	if p_flat.len() != n * n { return Err(JsValue::from_str("P must be n*n")); }
-	if r_flat.len() == 0 || h_flat.len() == 0 || z_flat.len() == 0 { return Err(JsValue::from_str("H, R, z must be non-empty")); }
+	if r_flat.is_empty() || h_flat.is_empty() || z_flat.is_empty() { return Err(JsValue::from_str("H, R, z must be non-empty")); }
 	let m = z_flat.len();
 	if h_flat.len() != m * n { return Err(JsValue::from_str("H must be m*n")); }
 	if r_flat.len() != m * m { return Err(JsValue::from_str("R must be m*m")); }
@@ -126,7 +126,7 @@ impl JsMatrix {
     pub fn rank(&self) -> Result<usize, JsValue> { self.0.rank() }
     pub fn inverse(&self) -> Option<JsMatrix> { self.0.inverse().map(JsMatrix) }
     pub fn frobenius_norm(&self) -> f64 { self.0.frobenius_norm() }
-    pub fn expm(&self) -> JsMatrix { JsMatrix(self.0.expm()) }
+    pub fn expm(&self) -> Result<JsMatrix, JsValue> { self.0.expm().map(JsMatrix) }
     pub fn qr_decomposition(&self) -> Result<JsValue, JsValue> { self.0.qr_decomposition() }
     pub fn svd(&self) -> Result<JsValue, JsValue> { self.0.svd() }
     pub fn eigen_decomposition(&self) -> Result<JsValue, JsValue> { self.0.eigen_decomposition() }
@@ -186,9 +186,10 @@ pub fn lasso_regression(rows: usize, cols: usize, a_data: Vec<f64>, b: Vec<f64>,
 }
 
 // ---- statsmodels: additional estimators ----
+// ロジスティック回帰（L-BFGS）。max_step は直線探索の最大ステップ、max_iter は反復上限。
 #[wasm_bindgen(js_name = logisticFit)]
-pub fn logistic_fit(rows: usize, cols: usize, x_data: Vec<f64>, y: Vec<f64>, lr: f64, max_iter: usize) -> Result<Vec<f64>, JsValue> {
-    crate::classes::statsmodels::logistic_fit_js(rows, cols, x_data, y, lr, max_iter)
+pub fn logistic_fit(rows: usize, cols: usize, x_data: Vec<f64>, y: Vec<f64>, max_step: f64, max_iter: usize) -> Result<Vec<f64>, JsValue> {
+    crate::classes::statsmodels::logistic_fit_js(rows, cols, x_data, y, max_step, max_iter)
 }
 #[wasm_bindgen(js_name = logisticPredictProba)]
 pub fn logistic_predict_proba(cols: usize, coeffs: Vec<f64>, x: Vec<f64>) -> Result<f64, JsValue> {
@@ -60,7 +60,7 @@ export function __probe(): number;
 export function solveLinearSystem(rows: number, cols: number, a_data: Float64Array, b: Float64Array): Float64Array;
 export function ridgeRegression(rows: number, cols: number, a_data: Float64Array, b: Float64Array, alpha: number): Float64Array;
 export function lassoRegression(rows: number, cols: number, a_data: Float64Array, b: Float64Array, alpha: number, max_iter: number, tol: number): Float64Array;
-export function logisticFit(rows: number, cols: number, x_data: Float64Array, y: Float64Array, lr: number, max_iter: number): Float64Array;
+export function logisticFit(rows: number, cols: number, x_data: Float64Array, y: Float64Array, max_step: number, max_iter: number): Float64Array;
 export function logisticPredictProba(cols: number, coeffs: Float64Array, x: Float64Array): number;
 export function gmmFit(n_samples: number, n_features: number, data: Float64Array, k: number, max_iter: number, tol: number): Float64Array;
 export function gmmPredictProba(n_features: number, params: Float64Array, x: Float64Array): Float64Array;
@@ -1187,16 +1187,16 @@ export function lassoRegression(rows, cols, a_data, b, alpha, max_iter, tol) {
  * @param {number} cols
  * @param {Float64Array} x_data
  * @param {Float64Array} y
- * @param {number} lr
+ * @param {number} max_step
  * @param {number} max_iter
  * @returns {Float64Array}
  */
-export function logisticFit(rows, cols, x_data, y, lr, max_iter) {
+export function logisticFit(rows, cols, x_data, y, max_step, max_iter) {
     const ptr0 = passArrayF64ToWasm0(x_data, wasm.__wbindgen_malloc);
     const len0 = WASM_VECTOR_LEN;
     const ptr1 = passArrayF64ToWasm0(y, wasm.__wbindgen_malloc);
     const len1 = WASM_VECTOR_LEN;
-    const ret = wasm.logisticFit(rows, cols, ptr0, len0, ptr1, len1, lr, max_iter);
+    const ret = wasm.logisticFit(rows, cols, ptr0, len0, ptr1, len1, max_step, max_iter);
     if (ret[3]) {
         throw takeFromExternrefTable0(ret[2]);
     }